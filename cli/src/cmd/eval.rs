@@ -12,7 +12,8 @@ pub fn eval(args: &ArgMatches) -> anyhow::Result<()> {
     let mut vm = Vm::new(Default::default());
 
     match vm.eval(source, opt) {
-        Ok(value) | Err(EvalError::Exception(value)) => util::print_value(value, &mut vm).unwrap(),
+        Ok(value) => util::print_value(value, &mut vm).unwrap(),
+        Err(EvalError::Exception(value)) => util::print_uncaught_exception(value, source, &mut vm).unwrap(),
         Err(e) => println!("{e}"),
     };
 
@@ -18,7 +18,8 @@ pub fn repl() -> anyhow::Result<()> {
         rl.add_history_entry(&input);
 
         match vm.eval(&input, OptLevel::Aggressive) {
-            Ok(value) | Err(EvalError::Exception(value)) => util::print_value(value, &mut vm).unwrap(),
+            Ok(value) => util::print_value(value, &mut vm).unwrap(),
+            Err(EvalError::Exception(value)) => util::print_uncaught_exception(value, &input, &mut vm).unwrap(),
             Err(e) => println!("{e}"),
         }
 
@@ -38,9 +38,11 @@ async fn inner(source: String, opt: OptLevel, quiet: bool, initial_gc_threshold:
 
     let module = dash_rt_modules::init_modules();
     rt.set_module_manager(module);
+    rt.set_exit_on_unhandled_rejection(true);
 
     let value = match rt.eval(&source, opt) {
-        Ok(val) | Err(EvalError::Exception(val)) => val,
+        Ok(val) => Ok(val),
+        Err(EvalError::Exception(val)) => Err(val),
         Err(e) => {
             println!("{e}");
             return Ok(());
@@ -51,8 +53,10 @@ async fn inner(source: String, opt: OptLevel, quiet: bool, initial_gc_threshold:
 
     // TODO: EvalError::VmError should probably bail too?
 
-    if !quiet {
-        util::print_value(value, rt.vm_mut()).unwrap();
+    match value {
+        Ok(value) if !quiet => util::print_value(value, rt.vm_mut()).unwrap(),
+        Ok(_) => {}
+        Err(value) => util::print_uncaught_exception(value, &source, rt.vm_mut()).unwrap(),
     }
 
     let state = State::from_vm(rt.vm());
@@ -1,10 +1,15 @@
 use anyhow::Context;
 use clap::ArgMatches;
+use dash_middle::compiler::line_table::SourcePosition;
+use dash_middle::lexer::token::FormattableError;
+use dash_middle::lexer::token::Location;
 use dash_optimizer::OptLevel;
 use dash_vm::local::LocalScope;
+use dash_vm::value::error::Error;
 use dash_vm::value::ops::abstractions::conversions::ValueConversion;
 use dash_vm::value::Value;
 use dash_vm::Vm;
+use either::Either;
 
 pub fn opt_level_from_matches(args: &ArgMatches) -> anyhow::Result<OptLevel> {
     args.value_of("opt")
@@ -18,3 +23,60 @@ pub fn print_value(value: Value, vm: &mut Vm) -> Result<(), Value> {
     println!("{s}");
     Ok(())
 }
+
+/// Converts a line and column back into a [`Location`] in the given source code
+fn location_in_source(source: &str, position: SourcePosition) -> Option<Location> {
+    let line = position.line as usize;
+    let line_offset = match line {
+        0 => return None,
+        1 => 0,
+        _ => source.match_indices('\n').nth(line - 2)?.0,
+    };
+    let line_start = if line == 1 { line_offset } else { line_offset + 1 };
+    let offset = line_start + position.column as usize - 1;
+
+    (offset < source.len()).then_some(Location {
+        line,
+        offset,
+        line_offset,
+    })
+}
+
+/// Prints an uncaught exception, along with a snippet of the source code at which it was created if it is an error
+pub fn print_uncaught_exception(value: Value, source: &str, vm: &mut Vm) -> Result<(), Value> {
+    let error = match Error::from_value(&value) {
+        Some(error) => error,
+        None => return print_value(value, vm),
+    };
+
+    let Some(loc) = error.position.and_then(|position| location_in_source(source, position)) else {
+        return print_value(value, vm);
+    };
+
+    // Highlight the identifier at the error position, or a single character if there is none
+    let rest = &source[loc.offset..];
+    let token_len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(rest.len());
+    let tok = match token_len {
+        0 => Either::Right(rest.chars().next().unwrap_or(' ')),
+        _ => Either::Left(&rest[..token_len]),
+    };
+
+    let message = format!("Uncaught {}: {}", error.name, error.message);
+    let format_err = FormattableError {
+        loc: &loc,
+        source: source.as_bytes(),
+        tok,
+        message: &message,
+        display_token: true,
+        help: None,
+    };
+
+    println!("{format_err}");
+    for frame in error.stack.lines().skip(1) {
+        println!("{frame}");
+    }
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use std::ops::DerefMut;
 
 use dash_middle::compiler::instruction as inst;
 use dash_middle::compiler::instruction::Instruction;
+use dash_middle::lexer::token::Location;
 
 use crate::jump_container;
 use crate::jump_container::JumpContainer;
@@ -32,10 +33,44 @@ pub enum Label {
     SwitchEnd {
         switch_id: usize,
     },
+    /// The end of a labelled statement, jumped to by `break label`
+    LabelledStatementEnd {
+        label_id: usize,
+    },
     Catch,
+    /// The exception handler of a try statement with a finally block
+    Finally,
     TryEnd,
+    /// Jumped to when an optional link of a chain is applied to a nullish value
+    ///
+    /// If `pop_this` is set, the `this` value of an optional method call is still on the stack
+    OptionalChainShortCircuit {
+        chain_id: usize,
+        pop_this: bool,
+    },
     InitParamWithDefaultValue,
     FinishParamDefaultValueInit,
+    /// Jumped to when the strings array of a tagged template literal has not been created yet
+    InitTemplateStrings,
+    /// Skips the code after a `yield` that handles the generator being resumed with `return()`
+    YieldEnd,
+    /// Calls `next()` on the iterator that a `yield*` expression delegates to
+    YieldDelegateNext,
+    /// Checks whether the iterator that a `yield*` expression delegates to is done
+    YieldDelegateCheck,
+    /// Yields the current value of the iterator that a `yield*` expression delegates to
+    YieldDelegateYield,
+    /// Returns the sent value when the iterator that a `yield*` expression delegates to has no `return()` method
+    YieldDelegateReturn,
+    /// Closes the iterator that a `yield*` expression delegates to when it has no `throw()` method
+    YieldDelegateNoThrow,
+    YieldDelegateEnd,
+    /// Skips calling the `return()` method of an iterator that does not have one
+    IteratorClosed,
+    /// Throws when the `return()` method of an iterator returns something other than an object
+    IteratorCloseNotObject,
+    /// The exception handler for errors thrown while closing an iterator because of another exception
+    IteratorCloseError,
 }
 
 pub struct InstructionBuilder<'cx, 'inp> {
@@ -83,6 +118,14 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         }
     }
 
+    /// Records the source location of the instructions emitted after this call, if there is one
+    pub fn add_source_location(&mut self, loc: Option<Location>) {
+        if let Some(loc) = loc {
+            let function = self.current_function_mut();
+            function.line_table.add(function.buf.len(), loc.into());
+        }
+    }
+
     pub fn remove_pop_end(&mut self) {
         if let Some(&inst::POP) = self.current_function_mut().buf.last() {
             self.current_function_mut().buf.pop();
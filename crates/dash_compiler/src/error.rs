@@ -20,7 +20,14 @@ pub enum CompileError {
     AwaitOutsideAsync,
     UnknownBinding,
     IllegalBreak,
+    IllegalContinue,
+    UndefinedLabel(String),
+    DuplicateLabel(String),
     MissingInitializerInDestructuring,
+    IllegalSuper,
+    IllegalSuperCall,
+    UndeclaredPrivateName(String),
+    IllegalPrivateNameDelete,
 }
 
 impl From<LimitExceededError> for CompileError {
@@ -54,7 +61,14 @@ impl fmt::Display for CompileError {
             Self::UnknownBinding => f.write_str("Attempted to visit unknown binding"),
             Self::AwaitOutsideAsync => f.write_str("`await` is only available in async functions"),
             Self::IllegalBreak => f.write_str("`break` is only available in switch-case and loop"),
+            Self::IllegalContinue => f.write_str("`continue` is only available in loops"),
+            Self::UndefinedLabel(label) => write!(f, "Undefined label `{label}`"),
+            Self::DuplicateLabel(label) => write!(f, "Label `{label}` has already been declared"),
             Self::MissingInitializerInDestructuring => f.write_str("Missing initializer in destructuring pattern"),
+            Self::IllegalSuper => f.write_str("`super` is only available in class methods"),
+            Self::IllegalSuperCall => f.write_str("`super()` is only available in constructors of derived classes"),
+            Self::UndeclaredPrivateName(name) => write!(f, "Private name `#{name}` must be declared in an enclosing class"),
+            Self::IllegalPrivateNameDelete => f.write_str("Private fields cannot be deleted"),
         }
     }
 }
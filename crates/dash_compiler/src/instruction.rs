@@ -4,7 +4,7 @@ use dash_middle::{
     compiler::{
         constant::{Constant, LimitExceededError},
         instruction::{AssignKind, Instruction, IntrinsicOperation},
-        FunctionCallMetadata, ObjectMemberKind as CompilerObjectMemberKind, StaticImportKind,
+        FunctionCallMetadata, ObjectMemberKind as CompilerObjectMemberKind, PropertyDefinitionKind, StaticImportKind,
     },
     parser::expr::ObjectMemberKind,
 };
@@ -66,6 +66,7 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         build_bitshr Instruction::BitShr,
         build_bitushr Instruction::BitUshr,
         build_objin Instruction::ObjIn,
+        build_super_prop_access Instruction::SuperPropAccess,
         build_instanceof Instruction::InstanceOf,
         build_default_export Instruction::ExportDefault,
         build_debugger Instruction::Debugger,
@@ -77,7 +78,11 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         build_break Instruction::Break,
         build_symbol_iterator Instruction::CallSymbolIterator,
         build_for_in_iterator Instruction::CallForInIterator,
-        build_dynamic_delete Instruction::DeletePropertyDynamic
+        build_async_iterator Instruction::CallAsyncIterator,
+        build_extends Instruction::Extends,
+        build_dynamic_delete Instruction::DeletePropertyDynamic,
+        build_array_push Instruction::ArrayPush,
+        build_array_spread Instruction::ArraySpread
     }
 
     pub fn build_ret(&mut self, tc_depth: u16) {
@@ -91,10 +96,11 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         Ok(())
     }
 
-    pub fn build_try_block(&mut self) {
+    /// Registers a try block whose exception handler is at the given local label
+    pub fn build_try_block(&mut self, handler: Label) {
         self.write_instr(Instruction::Try);
         self.write_all(&[0, 0]);
-        self.add_local_jump(Label::Catch);
+        self.add_local_jump(handler);
     }
 
     pub fn build_local_load(&mut self, index: u16, is_extern: bool) {
@@ -125,9 +131,10 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         self.write(kind as u8);
     }
 
-    pub fn build_call(&mut self, meta: FunctionCallMetadata) {
+    pub fn build_call(&mut self, meta: FunctionCallMetadata, argc: u16) {
         self.write_instr(Instruction::Call);
         self.write(meta.into());
+        self.writew(argc);
     }
 
     pub fn build_jmpfalsep(&mut self, label: Label, is_local_label: bool) {
@@ -160,6 +167,17 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         self.build_jmp_header(label, is_local_label);
     }
 
+    /// Emits a jump to the end of the innermost optional chain, which is taken if the value on the stack is nullish
+    pub fn build_optional_chain_jmp(&mut self, pop_this: bool) {
+        let chain_id = *self
+            .current_function()
+            .optional_chains
+            .last()
+            .expect("Optional link outside of an optional chain");
+
+        self.build_jmpnullishnp(Label::OptionalChainShortCircuit { chain_id, pop_this }, false);
+    }
+
     pub fn build_jmpundefinednp(&mut self, label: Label, is_local_label: bool) {
         self.write_instr(Instruction::JmpUndefinedNP);
         self.build_jmp_header(label, is_local_label);
@@ -235,7 +253,7 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         for member in constants.into_iter().rev() {
             let kind_id = CompilerObjectMemberKind::from(&member) as u8;
             match member {
-                ObjectMemberKind::Dynamic(..) => self.write(CompilerObjectMemberKind::Dynamic as u8),
+                ObjectMemberKind::Dynamic(..) | ObjectMemberKind::Spread => self.write(kind_id),
                 ObjectMemberKind::Static(name) => compile_object_member_kind(self, name, kind_id)?,
                 ObjectMemberKind::Getter(name) | ObjectMemberKind::Setter(name) => {
                     compile_object_member_kind(self, &name, kind_id)?
@@ -295,14 +313,32 @@ impl<'cx, 'inp> InstructionBuilder<'cx, 'inp> {
         self.write(has_default.into());
     }
 
-    pub fn build_objdestruct(&mut self, count: u16) {
-        self.write_instr(Instruction::ObjDestruct);
-        self.writew(count);
+    pub fn build_private_name(&mut self, name: &str) -> Result<(), CompileError> {
+        let id = self.current_function_mut().cp.add(Constant::Identifier(name.into()))?;
+        self.write_instr(Instruction::PrivateName);
+        self.writew(id);
+        Ok(())
+    }
+
+    pub fn build_define_property(&mut self, kind: PropertyDefinitionKind) {
+        self.write_instr(Instruction::DefineProperty);
+        self.write(kind as u8);
+    }
+
+    pub fn build_template_strings(&mut self, len: u16) {
+        self.write_instr(Instruction::TemplateStrings);
+        self.writew(len);
+    }
+
+    pub fn build_objrest(&mut self, excluded_count: u16) {
+        self.write_instr(Instruction::ObjRest);
+        self.writew(excluded_count);
     }
 
-    pub fn build_arraydestruct(&mut self, count: u16) {
-        self.write_instr(Instruction::ArrayDestruct);
+    pub fn build_iterdestruct(&mut self, count: u16, has_rest: bool) {
+        self.write_instr(Instruction::IterDestruct);
         self.writew(count);
+        self.write(has_rest.into());
     }
 
     pub fn build_intrinsic_op(&mut self, op: IntrinsicOperation) {
@@ -7,12 +7,15 @@ use std::{convert::TryInto, usize};
 use dash_log::{debug, span, Level};
 use dash_middle::compiler::constant::{Constant, Function};
 use dash_middle::compiler::instruction::{AssignKind, IntrinsicOperation};
+use dash_middle::compiler::line_table::LineTable;
 use dash_middle::compiler::scope::ScopeLocal;
 use dash_middle::compiler::scope::{CompileValueType, Scope};
 use dash_middle::compiler::{constant::ConstantPool, external::External};
-use dash_middle::compiler::{CompileResult, FunctionCallMetadata, StaticImportKind};
+use dash_middle::compiler::{CompileResult, FunctionCallMetadata, PropertyDefinitionKind, StaticImportKind};
+use dash_middle::lexer::token::Location;
 use dash_middle::lexer::token::TokenType;
 use dash_middle::parser::expr::BinaryExpr;
+use dash_middle::parser::expr::CallArgumentKind;
 use dash_middle::parser::expr::ConditionalExpr;
 use dash_middle::parser::expr::Expr;
 use dash_middle::parser::expr::FunctionCall;
@@ -22,8 +25,9 @@ use dash_middle::parser::expr::ObjectLiteral;
 use dash_middle::parser::expr::Postfix;
 use dash_middle::parser::expr::PropertyAccessExpr;
 use dash_middle::parser::expr::Seq;
+use dash_middle::parser::expr::TemplateStrings;
 use dash_middle::parser::expr::UnaryExpr;
-use dash_middle::parser::expr::{ArrayLiteral, ObjectMemberKind};
+use dash_middle::parser::expr::{ArrayLiteral, ArrayMemberKind, ObjectMemberKind};
 use dash_middle::parser::expr::{AssignmentExpr, AssignmentTarget};
 use dash_middle::parser::statement::ReturnStatement;
 use dash_middle::parser::statement::SpecifierKind;
@@ -35,13 +39,15 @@ use dash_middle::parser::statement::VariableDeclarationKind;
 use dash_middle::parser::statement::WhileLoop;
 use dash_middle::parser::statement::{BlockStatement, Loop};
 use dash_middle::parser::statement::{Class, Parameter};
-use dash_middle::parser::statement::{ClassMemberKind, ExportKind};
+use dash_middle::parser::statement::{ClassMember, ClassMemberKind, ClassProperty, ExportKind};
 use dash_middle::parser::statement::{DoWhileLoop, ForLoop};
 use dash_middle::parser::statement::{ForInLoop, ForOfLoop};
 use dash_middle::parser::statement::{FuncId, ImportKind};
 use dash_middle::parser::statement::{FunctionDeclaration, SwitchStatement};
 use dash_middle::parser::statement::{FunctionKind, VariableDeclarationName};
 use dash_middle::parser::statement::{IfStatement, VariableDeclarations};
+use dash_middle::parser::statement::{Pattern, PatternElement, PatternKey, PatternTarget};
+use dash_middle::parser::types::TypeSegment;
 use dash_middle::visitor::Visitor;
 use dash_optimizer::consteval::ConstFunctionEvalCtx;
 use dash_optimizer::type_infer::TypeInferCtx;
@@ -69,51 +75,90 @@ macro_rules! unimplementedc {
     };
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Breakable {
-    Loop { loop_id: usize },
-    Switch { switch_id: usize },
+#[derive(Debug, Clone)]
+enum Breakable<'a> {
+    Loop {
+        loop_id: usize,
+    },
+    Switch {
+        switch_id: usize,
+    },
+    Labelled {
+        name: &'a str,
+        label_id: usize,
+        is_loop: bool,
+    },
+    /// A try block, which is registered in the VM at runtime and needs to be removed when jumping out of it.
+    /// If the try statement has a finally block, it needs to run before leaving the try (or catch) block
+    Try {
+        finally: Option<Box<Statement<'a>>>,
+    },
+    /// The iterator of a for..of loop, which is registered right before the loop itself.
+    /// It needs to be closed when leaving the loop through anything other than `continue`
+    Iterator {
+        iter_id: u16,
+        r#async: bool,
+    },
+}
+
+/// Returns the number of try blocks in the given breakable stack
+fn try_catch_depth(breakables: &[Breakable<'_>]) -> u16 {
+    breakables
+        .iter()
+        .filter(|b| matches!(b, Breakable::Try { .. }))
+        .count()
+        .try_into()
+        .expect("Try block depth exceeds u16")
 }
 
 /// Function-specific state, such as
 #[derive(Debug)]
-struct FunctionLocalState {
+struct FunctionLocalState<'a> {
     /// Instruction buffer
     buf: Vec<u8>,
     /// A list of constants used throughout this function.
     ///
     /// Bytecode can refer to constants using the [Instruction::Constant] instruction, followed by a u8 index.
     cp: ConstantPool,
-    /// Current try catch depth
-    try_catch_depth: u16,
     /// The type of function that this FunctionCompiler compiles
     ty: FunctionKind,
     /// Whether the function being compiled is async
     r#async: bool,
     /// Container, used for storing global labels that can be jumped to
     jc: JumpContainer,
-    /// A stack of breakable labels (loop/switch)
-    breakables: Vec<Breakable>,
+    /// A stack of breakable labels (loop/switch/labelled statement/try block)
+    breakables: Vec<Breakable<'a>>,
     /// Keeps track of the total number of loops to be able to have unique IDs
     loop_counter: usize,
     /// Keeps track of the total number of loops to be able to have unique IDs
     switch_counter: usize,
+    /// Keeps track of the total number of labelled statements to be able to have unique IDs
+    labelled_statement_counter: usize,
+    /// A stack of optional chains that are currently being compiled
+    optional_chains: Vec<usize>,
+    /// Keeps track of the total number of optional chains to be able to have unique IDs
+    optional_chain_counter: usize,
     id: FuncId,
+    /// Source positions of the emitted instructions
+    line_table: LineTable,
 }
 
-impl FunctionLocalState {
+impl<'a> FunctionLocalState<'a> {
     pub fn new(ty: FunctionKind, id: FuncId) -> Self {
         Self {
             buf: Vec::new(),
             cp: ConstantPool::new(),
-            try_catch_depth: 0,
             ty,
             r#async: false,
             jc: JumpContainer::new(),
             breakables: Vec::new(),
             loop_counter: 0,
             switch_counter: 0,
+            labelled_statement_counter: 0,
+            optional_chains: Vec::new(),
+            optional_chain_counter: 0,
             id,
+            line_table: LineTable::new(),
         }
     }
 
@@ -136,10 +181,33 @@ impl FunctionLocalState {
         self.switch_counter += 1;
         switch_id
     }
+
+    /// Same as [`prepare_loop`] but for labelled statements.
+    ///
+    /// `is_loop` must be set if the labelled statement is a loop, which makes it a valid target for `continue`
+    fn prepare_labelled_statement(&mut self, name: &'a str, is_loop: bool) -> usize {
+        let label_id = self.labelled_statement_counter;
+        self.breakables.push(Breakable::Labelled {
+            name,
+            label_id,
+            is_loop,
+        });
+        self.labelled_statement_counter += 1;
+        label_id
+    }
+
     fn exit_loop(&mut self) {
         let item = self.breakables.pop();
         match item {
-            None | Some(Breakable::Switch { .. }) => panic!("Tried to exit loop, but no breakable was found"),
+            None
+            | Some(
+                Breakable::Switch { .. }
+                | Breakable::Labelled { .. }
+                | Breakable::Try { .. }
+                | Breakable::Iterator { .. },
+            ) => {
+                panic!("Tried to exit loop, but no breakable was found")
+            }
             Some(Breakable::Loop { .. }) => {}
         }
     }
@@ -147,11 +215,75 @@ impl FunctionLocalState {
     fn exit_switch(&mut self) {
         let item = self.breakables.pop();
         match item {
-            None | Some(Breakable::Loop { .. }) => panic!("Tried to exit switch, but no breakable was found"),
+            None
+            | Some(
+                Breakable::Loop { .. }
+                | Breakable::Labelled { .. }
+                | Breakable::Try { .. }
+                | Breakable::Iterator { .. },
+            ) => {
+                panic!("Tried to exit switch, but no breakable was found")
+            }
             Some(Breakable::Switch { .. }) => {}
         }
     }
 
+    fn exit_labelled_statement(&mut self) {
+        let item = self.breakables.pop();
+        match item {
+            None
+            | Some(
+                Breakable::Loop { .. } | Breakable::Switch { .. } | Breakable::Try { .. } | Breakable::Iterator { .. },
+            ) => {
+                panic!("Tried to exit labelled statement, but no breakable was found")
+            }
+            Some(Breakable::Labelled { .. }) => {}
+        }
+    }
+
+    fn exit_try(&mut self) {
+        let item = self.breakables.pop();
+        match item {
+            None
+            | Some(
+                Breakable::Loop { .. }
+                | Breakable::Switch { .. }
+                | Breakable::Labelled { .. }
+                | Breakable::Iterator { .. },
+            ) => {
+                panic!("Tried to exit try block, but no breakable was found")
+            }
+            Some(Breakable::Try { .. }) => {}
+        }
+    }
+
+    fn exit_iterator(&mut self) {
+        let item = self.breakables.pop();
+        match item {
+            None
+            | Some(
+                Breakable::Loop { .. } | Breakable::Switch { .. } | Breakable::Labelled { .. } | Breakable::Try { .. },
+            ) => {
+                panic!("Tried to exit iterator, but no breakable was found")
+            }
+            Some(Breakable::Iterator { .. }) => {}
+        }
+    }
+
+    /// Same as [`prepare_loop`] but for optional chains, so that optional links can jump to the end of their chain
+    fn prepare_optional_chain(&mut self) -> usize {
+        let chain_id = self.optional_chain_counter;
+        self.optional_chains.push(chain_id);
+        self.optional_chain_counter += 1;
+        chain_id
+    }
+
+    fn exit_optional_chain(&mut self) {
+        self.optional_chains
+            .pop()
+            .expect("Tried to exit optional chain, but no optional chain was found");
+    }
+
     fn add_global_label(&mut self, label: Label) {
         jump_container::add_label(&mut self.jc, label, &mut self.buf)
     }
@@ -162,9 +294,35 @@ impl FunctionLocalState {
     }
 }
 
+/// A class whose members are currently being compiled, used for resolving `super` and private names
+#[derive(Debug)]
+struct ClassState<'a> {
+    /// The function that the class is declared in
+    owner: FuncId,
+    /// The local variable (in the `owner` function) that holds the superclass, if the class has one
+    superclass: Option<u16>,
+    /// The private names declared in this class and the local variables (in the `owner` function) that hold them
+    private_names: Vec<(&'a str, u16)>,
+    /// The constructor of this class
+    constructor: FuncId,
+    /// All methods of this class, and whether they are static
+    methods: Vec<(FuncId, bool)>,
+    /// The private methods and accessors of instances and the local variables (in the `owner` function) that hold them
+    private_methods: Vec<(&'a str, PropertyDefinitionKind, u16)>,
+    /// The instance fields and whether they are private
+    fields: Vec<(ClassProperty<'a>, bool)>,
+}
+
+/// Returns whether the expression is the `super` keyword
+fn is_super(expr: &Expr<'_>) -> bool {
+    matches!(expr, Expr::Literal(LiteralExpr::Identifier(ident, _)) if ident == "super")
+}
+
 #[derive(Debug)]
 pub struct FunctionCompiler<'a> {
-    function_stack: Vec<FunctionLocalState>,
+    function_stack: Vec<FunctionLocalState<'a>>,
+    /// A stack of classes that are currently being compiled
+    class_stack: Vec<ClassState<'a>>,
     tcx: TypeInferCtx<'a>,
     /// Optimization level
     #[allow(unused)]
@@ -177,6 +335,7 @@ impl<'a> FunctionCompiler<'a> {
             opt_level,
             tcx,
             function_stack: Vec::new(),
+            class_stack: Vec::new(),
         }
     }
 
@@ -236,6 +395,7 @@ impl<'a> FunctionCompiler<'a> {
             cp: root.cp,
             locals,
             externals,
+            line_table: root.line_table,
         })
     }
 
@@ -246,11 +406,11 @@ impl<'a> FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn current_function(&self) -> &FunctionLocalState {
+    fn current_function(&self) -> &FunctionLocalState<'a> {
         self.function_stack.last().expect("Function must be present")
     }
 
-    fn current_function_mut(&mut self) -> &mut FunctionLocalState {
+    fn current_function_mut(&mut self) -> &mut FunctionLocalState<'a> {
         self.function_stack.last_mut().expect("Function must be present")
     }
 
@@ -264,6 +424,117 @@ impl<'a> FunctionCompiler<'a> {
         self.tcx.scope_mut(id)
     }
 
+    /// Emits code for jumping out of all breakables starting at `index` in the breakable stack
+    /// (for `break`, `continue` and `return`): try blocks are removed and their finally blocks are run
+    fn exit_breakables(&mut self, index: usize) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+
+        for i in (index..ib.current_function().breakables.len()).rev() {
+            let finally = match &ib.current_function().breakables[i] {
+                Breakable::Try { finally } => finally.clone(),
+                &Breakable::Iterator { iter_id, r#async } => {
+                    ib.emit_iterator_close(iter_id, r#async)?;
+                    continue;
+                }
+                _ => continue,
+            };
+
+            ib.build_try_end();
+
+            if let Some(finally) = finally {
+                // The finally block is compiled in the context of the try statement it belongs to,
+                // so that jumps in it are not affected by the statements that are being exited
+                let exited = ib.current_function_mut().breakables.split_off(i);
+                ib.accept(*finally)?;
+                ib.current_function_mut().breakables.extend(exited);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Emits code for returning the value on top of the stack from the current function,
+    /// running the finally blocks of all enclosing try statements and closing the iterators of enclosing for..of loops
+    fn emit_return(&mut self) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+        let breakables = &ib.current_function().breakables;
+        let tc_depth = match breakables
+            .iter()
+            .position(|b| matches!(b, Breakable::Try { finally: Some(..) } | Breakable::Iterator { .. }))
+        {
+            Some(index) => {
+                // Finally blocks and iterator closing need to run before returning, which may jump elsewhere or throw,
+                // so the return value is kept in a local rather than on the stack
+                let tc_depth = try_catch_depth(&breakables[..index]);
+                let id = ib
+                    .current_scope_mut()
+                    .add_local("return_value", VariableDeclarationKind::Unnameable, None)?;
+                ib.build_local_store(AssignKind::Assignment, id, false);
+                ib.build_pop();
+                ib.exit_breakables(index)?;
+                ib.build_local_load(id, false);
+                tc_depth
+            }
+            None => try_catch_depth(breakables),
+        };
+
+        ib.build_ret(tc_depth);
+        Ok(())
+    }
+
+    /// Emits code for closing the iterator stored in the given local by calling its `return()` method, if it has one
+    /// (IteratorClose and AsyncIteratorClose)
+    ///
+    /// A `return()` method that returns something other than an object throws a TypeError
+    fn emit_iterator_close(&mut self, iter_id: u16, is_async: bool) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+        let result_id =
+            ib.current_scope_mut()
+                .add_local("iterator_close_result", VariableDeclarationKind::Unnameable, None)?;
+
+        ib.build_local_load(iter_id, false);
+        ib.build_static_prop_access("return", false)?;
+        ib.build_jmpnullishp(Label::IteratorClosed, true);
+
+        ib.build_local_load(iter_id, false);
+        ib.build_static_prop_access("return", true)?;
+        ib.build_call(FunctionCallMetadata::new(false, true, false), 0);
+        if is_async {
+            ib.build_await();
+        }
+        ib.build_local_store(AssignKind::Assignment, result_id, false);
+        ib.build_pop();
+
+        // Functions are objects, and so are values of type "object" except for null
+        ib.build_local_load(result_id, false);
+        ib.build_typeof();
+        ib.build_constant(Constant::String("function".into()))?;
+        ib.build_strict_eq();
+        ib.build_jmptruep(Label::IteratorClosed, true);
+        ib.build_local_load(result_id, false);
+        ib.build_typeof();
+        ib.build_constant(Constant::String("object".into()))?;
+        ib.build_strict_ne();
+        ib.build_jmptruep(Label::IteratorCloseNotObject, true);
+        ib.build_local_load(result_id, false);
+        ib.build_jmpnullishp(Label::IteratorCloseNotObject, true);
+        ib.build_jmp(Label::IteratorClosed, true);
+
+        ib.add_local_label(Label::IteratorCloseNotObject);
+        ib.accept_expr(Expr::function_call(
+            Expr::identifier(Cow::Borrowed("TypeError"), None),
+            vec![CallArgumentKind::Normal(Expr::string_literal(Cow::Borrowed(
+                "Iterator result is not an object",
+            )))],
+            true,
+            None,
+        ))?;
+        ib.build_throw();
+
+        ib.add_local_label(Label::IteratorClosed);
+        Ok(())
+    }
+
     /// Adds an external to the current [`FunctionLocalState`] if it's not already present
     /// and returns its ID
     fn add_external_to_func(&mut self, func_id: FuncId, external_id: u16, is_nested_external: bool) -> usize {
@@ -297,6 +568,229 @@ impl<'a> FunctionCompiler<'a> {
             Some((external_id, loc, true))
         }
     }
+
+    /// Resolves the local variable `local_id` of the function `owner` from the function `func_id`,
+    /// adding it as an external to all functions in between
+    fn find_local_by_id(&mut self, local_id: u16, owner: FuncId, func_id: FuncId) -> Option<(u16, bool)> {
+        if func_id == owner {
+            return Some((local_id, false));
+        }
+
+        let parent = self.tcx.scope_node(func_id).parent()?;
+        let (id, nested_extern) = self.find_local_by_id(local_id, owner, parent.into())?;
+        let external_id = self.add_external_to_func(func_id, id, nested_extern) as u16;
+        Some((external_id, true))
+    }
+
+    /// Emits a load of the superclass of the class that is currently being compiled
+    fn build_superclass_load(&mut self, func_id: FuncId) -> Result<(), CompileError> {
+        let (owner, superclass) = match self.class_stack.last() {
+            Some(ClassState {
+                owner,
+                superclass: Some(superclass),
+                ..
+            }) => (*owner, *superclass),
+            _ => return Err(CompileError::IllegalSuper),
+        };
+
+        let (id, is_extern) = self
+            .find_local_by_id(superclass, owner, func_id)
+            .ok_or(CompileError::IllegalSuper)?;
+
+        InstructionBuilder::new(self).build_local_load(id, is_extern);
+        Ok(())
+    }
+
+    /// Emits a load of the private name `#name`, declared in the innermost class that declares it
+    fn build_private_name_load(&mut self, name: &str) -> Result<(), CompileError> {
+        let func_id = self.current_function().id;
+        let (owner, local_id) = self
+            .class_stack
+            .iter()
+            .rev()
+            .find_map(|class| {
+                class
+                    .private_names
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|&(_, id)| (class.owner, id))
+            })
+            .ok_or_else(|| CompileError::UndeclaredPrivateName(name.into()))?;
+
+        let (id, is_extern) = self
+            .find_local_by_id(local_id, owner, func_id)
+            .ok_or_else(|| CompileError::UndeclaredPrivateName(name.into()))?;
+
+        InstructionBuilder::new(self).build_local_load(id, is_extern);
+        Ok(())
+    }
+
+    /// Emits a load of the object that `super.property` lookups start at,
+    /// which is the superclass for static methods and its prototype for everything else
+    fn build_super_home_load(&mut self) -> Result<(), CompileError> {
+        let func_id = self.current_function().id;
+        let class = self.class_stack.last().ok_or(CompileError::IllegalSuper)?;
+
+        // Walk up the scopes until we reach a method of this class, to support `super` in arrow functions
+        let mut current = func_id;
+        let is_static = loop {
+            if current == class.constructor {
+                break false;
+            }
+
+            if let Some(&(_, is_static)) = class.methods.iter().find(|(id, _)| *id == current) {
+                break is_static;
+            }
+
+            match self.tcx.scope_node(current).parent() {
+                Some(parent) => current = parent.into(),
+                None => return Err(CompileError::IllegalSuper),
+            }
+        };
+
+        self.build_superclass_load(func_id)?;
+
+        if !is_static {
+            InstructionBuilder::new(self).build_static_prop_access("prototype", false)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the parameters and statements of the function that is currently being compiled,
+    /// returning the local that holds the rest parameter, if there is one.
+    ///
+    /// Constructors of base classes initialize the instance before the body runs,
+    /// derived constructors do so when `super()` returns.
+    fn visit_function_body(
+        &mut self,
+        id: FuncId,
+        arguments: &[(Parameter<'a>, Option<Expr<'a>>, Option<TypeSegment<'a>>)],
+        mut statements: Vec<Statement<'a>>,
+        initialize_instance: bool,
+    ) -> Result<Option<u16>, CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+        let mut rest_local = None;
+
+        for (index, (param, default, _ty)) in arguments.iter().enumerate() {
+            let id = match param {
+                Parameter::Identifier(ident) | Parameter::Spread(ident) => {
+                    ib.tcx
+                        .scope_mut(id)
+                        .add_local(ident, VariableDeclarationKind::Var, None)?
+                }
+                // Arguments are passed in the locals at their position, which for patterns
+                // is an unnameable local reserved by the type infer pass
+                Parameter::Pattern(..) | Parameter::SpreadPattern(..) => {
+                    index.try_into().map_err(|_| CompileError::ParameterLimitExceeded)?
+                }
+            };
+
+            if let Parameter::Spread(..) | Parameter::SpreadPattern(..) = param {
+                rest_local = Some(id);
+            }
+
+            if let Some(default) = default {
+                let mut sub_ib = InstructionBuilder::new(&mut ib);
+                // First, load parameter
+                sub_ib.build_local_load(id, false);
+                // Jump to InitParamWithDefaultValue if param is undefined
+                sub_ib.build_jmpundefinedp(Label::InitParamWithDefaultValue, true);
+                // If it isn't undefined, it won't jump to InitParamWithDefaultValue, so we jump to the end
+                sub_ib.build_jmp(Label::FinishParamDefaultValueInit, true);
+                sub_ib.add_local_label(Label::InitParamWithDefaultValue);
+                sub_ib.accept_expr(default.clone())?;
+                sub_ib.build_local_store(AssignKind::Assignment, id, false);
+
+                sub_ib.add_local_label(Label::FinishParamDefaultValueInit);
+            }
+
+            // Destructuring happens after the default value has been applied
+            if let Parameter::Pattern(pattern) | Parameter::SpreadPattern(pattern) = param {
+                let mut sub_ib = InstructionBuilder::new(&mut ib);
+                sub_ib.build_local_load(id, false);
+                sub_ib.visit_pattern(pattern.clone(), VariableDeclarationKind::Var)?;
+            }
+        }
+
+        if initialize_instance {
+            ib.build_instance_initialization()?;
+        }
+
+        transformations::ast_insert_implicit_return(&mut statements);
+        for stmt in statements {
+            ib.accept(stmt)?;
+        }
+
+        Ok(rest_local)
+    }
+
+    /// Compiles a `super(...)` call in a derived constructor, or in an arrow function inside of one
+    fn visit_super_call(&mut self, arguments: Vec<CallArgumentKind<'a>>) -> Result<(), CompileError> {
+        let func_id = self.current_function().id;
+
+        // Arrow functions don't have their own `super`, so the call belongs to the closest non-arrow function
+        let home = self
+            .function_stack
+            .iter()
+            .rev()
+            .find(|function| !matches!(function.ty, FunctionKind::Arrow))
+            .map(|function| function.id);
+
+        if !matches!(
+            self.class_stack.last(),
+            Some(ClassState { superclass: Some(_), constructor, .. }) if Some(*constructor) == home
+        ) {
+            return Err(CompileError::IllegalSuperCall);
+        }
+
+        self.build_superclass_load(func_id)?;
+        self.visit_array_literal(ArrayLiteral(arguments.into_iter().map(Into::into).collect()))?;
+        InstructionBuilder::new(self).build_super();
+
+        // The instance is initialized now, so fields can be defined on it
+        self.build_instance_initialization()
+    }
+
+    /// Defines the private methods and the fields of the class that is currently being compiled on `this`,
+    /// which happens when the constructor is called, or in derived classes right after `super()` returns
+    fn build_instance_initialization(&mut self) -> Result<(), CompileError> {
+        let func_id = self.current_function().id;
+        let class = self.class_stack.last().expect("Missing class state");
+        let owner = class.owner;
+        let private_methods = class.private_methods.clone();
+        let fields = class.fields.clone();
+
+        let mut ib = InstructionBuilder::new(self);
+
+        // Private methods are defined first, so that field initializers can use them
+        for (name, kind, local_id) in private_methods {
+            let (id, is_extern) = ib
+                .find_local_by_id(local_id, owner, func_id)
+                .expect("Private method is not reachable from the constructor");
+
+            ib.build_this();
+            ib.build_private_name_load(name)?;
+            ib.build_local_load(id, is_extern);
+            ib.build_define_property(kind);
+        }
+
+        for (ClassProperty { name, value }, private) in fields {
+            ib.build_this();
+            match private {
+                true => ib.build_private_name_load(name)?,
+                false => ib.build_constant(Constant::String(name.into()))?,
+            }
+            match value {
+                Some(value) => ib.accept_expr(value)?,
+                None => ib.build_constant(Constant::Undefined)?,
+            }
+            ib.build_define_property(PropertyDefinitionKind::Field);
+        }
+
+        Ok(())
+    }
+
     /// Tries to find a local in the current or surrounding scopes
     ///
     /// If a local variable is found in a parent scope, it is marked as an extern local
@@ -340,7 +834,13 @@ impl<'a> FunctionCompiler<'a> {
         //     console.log(x)
         // }
 
+        // For-Await-Of loops get their iterator through @@asyncIterator and await the result of `next()`
+
         let mut ib = InstructionBuilder::new(self);
+        if matches!(kind, ForEachLoopKind::ForOf { r#async: true }) && !ib.current_function().r#async {
+            return Err(CompileError::AwaitOutsideAsync);
+        }
+
         let for_of_iter_id =
             ib.current_scope_mut()
                 .add_local("for_of_iter", VariableDeclarationKind::Unnameable, None)?;
@@ -351,7 +851,8 @@ impl<'a> FunctionCompiler<'a> {
 
         ib.accept_expr(expr)?;
         match kind {
-            ForEachLoopKind::ForOf => ib.build_symbol_iterator(),
+            ForEachLoopKind::ForOf { r#async: false } => ib.build_symbol_iterator(),
+            ForEachLoopKind::ForOf { r#async: true } => ib.build_async_iterator(),
             ForEachLoopKind::ForIn => ib.build_for_in_iterator(),
         }
         ib.build_local_store(AssignKind::Assignment, for_of_iter_id, false);
@@ -374,7 +875,8 @@ impl<'a> FunctionCompiler<'a> {
                     Some(Expr::property_access(
                         false,
                         Expr::compiled(gen_step),
-                        Expr::identifier(Cow::Borrowed("value")),
+                        Expr::identifier(Cow::Borrowed("value"), None),
+                        None,
                     )),
                 )]));
 
@@ -389,37 +891,514 @@ impl<'a> FunctionCompiler<'a> {
 
         let for_of_iter_binding_bc = compile_local_load(for_of_iter_id, false);
 
+        let mut next_call = Expr::function_call(
+            Expr::property_access(
+                false,
+                Expr::compiled(for_of_iter_binding_bc),
+                Expr::identifier(Cow::Borrowed("next"), None),
+                None,
+            ),
+            Vec::new(),
+            false,
+            None,
+        );
+        if let ForEachLoopKind::ForOf { r#async: true } = kind {
+            next_call = Expr::unary(TokenType::Await, next_call);
+        }
+
         // for..of -> while loop rewrite
-        ib.visit_while_loop(WhileLoop {
+        let while_loop = WhileLoop {
             condition: Expr::unary(
                 TokenType::LogicalNot,
                 Expr::property_access(
                     false,
-                    Expr::assignment_local_space(
-                        for_of_gen_step_id,
-                        Expr::function_call(
-                            Expr::property_access(
-                                false,
-                                Expr::compiled(for_of_iter_binding_bc),
-                                Expr::identifier(Cow::Borrowed("next")),
-                            ),
-                            Vec::new(),
-                            false,
-                        ),
-                        TokenType::Assignment,
-                    ),
-                    Expr::identifier(Cow::Borrowed("done")),
+                    Expr::assignment_local_space(for_of_gen_step_id, next_call, TokenType::Assignment),
+                    Expr::identifier(Cow::Borrowed("done"), None),
+                    None,
                 ),
             ),
             body,
-        })?;
+        };
+
+        match kind {
+            ForEachLoopKind::ForOf { r#async } => ib.visit_iterator_loop(for_of_iter_id, r#async, while_loop),
+            ForEachLoopKind::ForIn => ib.visit_while_loop(while_loop),
+        }
+    }
+
+    /// Compiles the desugared while loop of a for..of statement, which closes the iterator when the loop exits early.
+    ///
+    /// Jumps out of the loop close the iterator through its [`Breakable::Iterator`],
+    /// and exceptions thrown in the loop body are caught, close the iterator and are rethrown
+    fn visit_iterator_loop(
+        &mut self,
+        iter_id: u16,
+        is_async: bool,
+        WhileLoop { condition, body }: WhileLoop<'a>,
+    ) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+
+        ib.current_function_mut().breakables.push(Breakable::Iterator {
+            iter_id,
+            r#async: is_async,
+        });
+        let loop_id = ib.current_function_mut().prepare_loop();
+
+        ib.current_function_mut()
+            .add_global_label(Label::LoopIncrement { loop_id });
+        ib.current_function_mut()
+            .add_global_label(Label::LoopCondition { loop_id });
+        ib.accept_expr(condition)?;
+        ib.build_jmpfalsep(Label::LoopEnd { loop_id }, false);
+
+        ib.build_try_block(Label::Catch);
+        ib.current_function_mut()
+            .breakables
+            .push(Breakable::Try { finally: None });
+        ib.accept(*body)?;
+        ib.current_function_mut().exit_try();
+        ib.build_try_end();
+        ib.build_jmp(Label::LoopCondition { loop_id }, false);
+
+        ib.add_local_label(Label::Catch);
+        let error_id = ib
+            .current_scope_mut()
+            .add_local("for_of_error", VariableDeclarationKind::Unnameable, None)?;
+        if error_id == u16::MAX {
+            return Err(CompileError::LocalLimitExceeded);
+        }
+        ib.writew(error_id);
+
+        // Errors thrown while closing the iterator are ignored in favor of the error thrown by the loop body
+        ib.build_try_block(Label::IteratorCloseError);
+        ib.emit_iterator_close(iter_id, is_async)?;
+        ib.build_try_end();
+        ib.build_jmp(Label::TryEnd, true);
+        ib.add_local_label(Label::IteratorCloseError);
+        ib.writew(u16::MAX);
+        ib.add_local_label(Label::TryEnd);
+        ib.build_local_load(error_id, false);
+        ib.build_throw();
+
+        ib.current_function_mut().add_global_label(Label::LoopEnd { loop_id });
+
+        ib.current_function_mut().exit_loop();
+        ib.current_function_mut().exit_iterator();
 
         Ok(())
     }
+
+    /// Compiles a `yield*` expression, which yields the values of an iterator
+    /// and forwards the values and completions that the generator is resumed with to it
+    fn visit_yield_delegate(&mut self, expr: Expr<'a>) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+        if !matches!(ib.current_function().ty, FunctionKind::Generator) {
+            return Err(CompileError::YieldOutsideGenerator);
+        }
+        let is_async = ib.current_function().r#async;
+
+        let iter_id =
+            ib.current_scope_mut()
+                .add_local("yield_delegate_iter", VariableDeclarationKind::Unnameable, None)?;
+        let received_id =
+            ib.current_scope_mut()
+                .add_local("yield_delegate_received", VariableDeclarationKind::Unnameable, None)?;
+        let result_id =
+            ib.current_scope_mut()
+                .add_local("yield_delegate_result", VariableDeclarationKind::Unnameable, None)?;
+        if received_id == u16::MAX {
+            return Err(CompileError::LocalLimitExceeded);
+        }
+
+        ib.accept_expr(expr)?;
+        match is_async {
+            true => ib.build_async_iterator(),
+            false => ib.build_symbol_iterator(),
+        }
+        ib.build_local_store(AssignKind::Assignment, iter_id, false);
+        ib.build_pop();
+        ib.build_undef();
+        ib.build_local_store(AssignKind::Assignment, received_id, false);
+        ib.build_pop();
+
+        // Calls a method of the iterator with the received value and stores the (awaited) result
+        let call_iterator_method = |ib: &mut InstructionBuilder<'_, '_>, method: &str| -> Result<(), CompileError> {
+            ib.build_local_load(iter_id, false);
+            ib.build_static_prop_access(method, true)?;
+            ib.build_local_load(received_id, false);
+            ib.build_call(FunctionCallMetadata::new(false, true, false), 1);
+            if is_async {
+                ib.build_await();
+            }
+            ib.build_local_store(AssignKind::Assignment, result_id, false);
+            ib.build_pop();
+            Ok(())
+        };
+
+        ib.add_local_label(Label::YieldDelegateNext);
+        call_iterator_method(&mut ib, "next")?;
+
+        ib.add_local_label(Label::YieldDelegateCheck);
+        ib.build_local_load(result_id, false);
+        ib.build_static_prop_access("done", false)?;
+        ib.build_jmptruep(Label::YieldDelegateEnd, true);
+
+        // The value is yielded in a try block, so that `throw()` completions can be forwarded to the iterator
+        ib.add_local_label(Label::YieldDelegateYield);
+        ib.build_local_load(result_id, false);
+        ib.build_static_prop_access("value", false)?;
+        ib.build_try_block(Label::Catch);
+        ib.build_yield();
+        ib.build_jmp(Label::YieldEnd, true);
+
+        // Resumed with `return()`: the iterator is closed with the sent value, unless it decides to continue
+        ib.build_try_end();
+        ib.build_local_store(AssignKind::Assignment, received_id, false);
+        ib.build_pop();
+        ib.build_local_load(iter_id, false);
+        ib.build_static_prop_access("return", false)?;
+        ib.build_jmpnullishp(Label::YieldDelegateReturn, true);
+        call_iterator_method(&mut ib, "return")?;
+        ib.build_local_load(result_id, false);
+        ib.build_static_prop_access("done", false)?;
+        ib.build_jmpfalsep(Label::YieldDelegateYield, true);
+        ib.build_local_load(result_id, false);
+        ib.build_static_prop_access("value", false)?;
+        ib.emit_return()?;
+
+        ib.add_local_label(Label::YieldDelegateReturn);
+        ib.build_local_load(received_id, false);
+        if is_async {
+            ib.build_await();
+        }
+        ib.emit_return()?;
+
+        // Resumed with `next()`
+        ib.add_local_label(Label::YieldEnd);
+        ib.build_try_end();
+        ib.build_local_store(AssignKind::Assignment, received_id, false);
+        ib.build_pop();
+        ib.build_jmp(Label::YieldDelegateNext, true);
+
+        // Resumed with `throw()`
+        ib.add_local_label(Label::Catch);
+        ib.writew(received_id);
+        ib.build_local_load(iter_id, false);
+        ib.build_static_prop_access("throw", false)?;
+        ib.build_jmpnullishp(Label::YieldDelegateNoThrow, true);
+        call_iterator_method(&mut ib, "throw")?;
+        ib.build_jmp(Label::YieldDelegateCheck, true);
+
+        ib.add_local_label(Label::YieldDelegateNoThrow);
+        ib.emit_iterator_close(iter_id, is_async)?;
+        ib.accept_expr(Expr::function_call(
+            Expr::identifier(Cow::Borrowed("TypeError"), None),
+            vec![CallArgumentKind::Normal(Expr::string_literal(Cow::Borrowed(
+                "The iterator does not provide a 'throw' method",
+            )))],
+            true,
+            None,
+        ))?;
+        ib.build_throw();
+
+        ib.add_local_label(Label::YieldDelegateEnd);
+        ib.build_local_load(result_id, false);
+        ib.build_static_prop_access("value", false)?;
+
+        Ok(())
+    }
+
+    /// Assigns to an identifier or a property, leaving the result of the assignment on the stack.
+    ///
+    /// `value` is the right hand side of the assignment and is `None` for increments and decrements.
+    /// The target object and the computed key are evaluated before the value, and only once.
+    fn visit_assignment_target(
+        &mut self,
+        target: Expr<'a>,
+        kind: AssignKind,
+        value: Option<Expr<'a>>,
+    ) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+
+        match target {
+            // Parenthesized targets, i.e. `(a.b) = c`
+            Expr::Grouping(GroupingExpr(mut exprs)) if exprs.len() == 1 => {
+                let target = exprs.pop().unwrap();
+                ib.visit_assignment_target(target, kind, value)?;
+            }
+            Expr::Literal(lit) => {
+                let ident = lit.to_identifier();
+
+                if let Some((id, local, is_extern)) = ib.find_local(&ident) {
+                    if matches!(local.binding().kind, VariableDeclarationKind::Const) {
+                        return Err(CompileError::ConstAssignment);
+                    }
+
+                    if let Some(value) = value {
+                        ib.accept_expr(value)?;
+                    }
+                    ib.build_local_store(kind, id, is_extern);
+                } else {
+                    if let Some(value) = value {
+                        ib.accept_expr(value)?;
+                    }
+                    ib.build_global_store(kind, &ident)?;
+                }
+            }
+            Expr::PropertyAccess(PropertyAccessExpr {
+                target,
+                property,
+                computed,
+                ..
+            }) => {
+                ib.accept_expr(*target)?;
+
+                match (*property, computed) {
+                    (Expr::Literal(lit), false) => {
+                        if let Some(value) = value {
+                            ib.accept_expr(value)?;
+                        }
+                        ib.build_static_prop_assign(kind, &lit.to_identifier())?;
+                    }
+                    // Computed properties and private names
+                    (property, _) => {
+                        ib.accept_expr(property)?;
+                        if let Some(value) = value {
+                            ib.accept_expr(value)?;
+                        }
+                        ib.build_dynamic_prop_assign(kind);
+                    }
+                }
+            }
+            _ => unimplementedc!("Assignment to non-identifier"),
+        }
+
+        Ok(())
+    }
+
+    /// Compiles a logical assignment (`a &&= b`, `a ||= b`, `a ??= b`) by desugaring it to `a && (a = b)`,
+    /// so that the value is only evaluated and stored if the left side does not short-circuit
+    fn visit_logical_assignment(
+        &mut self,
+        target: Expr<'a>,
+        operator: TokenType,
+        value: Expr<'a>,
+    ) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+
+        let target = match target {
+            Expr::Grouping(GroupingExpr(mut exprs)) if exprs.len() == 1 => {
+                let target = exprs.pop().unwrap();
+                return ib.visit_logical_assignment(target, operator, value);
+            }
+            // The target appears twice in the desugared expression,
+            // so store the object and the computed key in locals to only evaluate them once
+            Expr::PropertyAccess(PropertyAccessExpr {
+                target,
+                property,
+                computed,
+                loc,
+                ..
+            }) => {
+                let target_id = ib.current_scope_mut().add_local(
+                    "logical_assignment_target",
+                    VariableDeclarationKind::Unnameable,
+                    None,
+                )?;
+                ib.accept_expr(*target)?;
+                ib.build_local_store(AssignKind::Assignment, target_id, false);
+                ib.build_pop();
+
+                let property = if computed {
+                    let key_id = ib.current_scope_mut().add_local(
+                        "logical_assignment_key",
+                        VariableDeclarationKind::Unnameable,
+                        None,
+                    )?;
+                    ib.accept_expr(*property)?;
+                    ib.build_local_store(AssignKind::Assignment, key_id, false);
+                    ib.build_pop();
+                    Expr::compiled(compile_local_load(key_id, false))
+                } else {
+                    *property
+                };
+
+                Expr::property_access(
+                    computed,
+                    Expr::compiled(compile_local_load(target_id, false)),
+                    property,
+                    loc,
+                )
+            }
+            target => target,
+        };
+
+        let operator = match operator {
+            TokenType::LogicalAndAssignment => TokenType::LogicalAnd,
+            TokenType::LogicalOrAssignment => TokenType::LogicalOr,
+            TokenType::LogicalNullishAssignment => TokenType::NullishCoalescing,
+            _ => unreachable!("Not a logical assignment operator"),
+        };
+
+        ib.accept_expr(Expr::binary(
+            target.clone(),
+            Expr::assignment(target, value, TokenType::Assignment),
+            operator,
+            None,
+        ))
+    }
+
+    /// Destructures the value on top of the stack into the bindings of a pattern, consuming the value
+    fn visit_pattern(&mut self, pattern: Pattern<'a>, kind: VariableDeclarationKind) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+
+        match pattern {
+            Pattern::Object { fields, rest } => {
+                let source_id = ib.current_scope_mut().add_local(
+                    "destructure_source",
+                    VariableDeclarationKind::Unnameable,
+                    None,
+                )?;
+
+                ib.build_local_store(AssignKind::Assignment, source_id, false);
+                ib.build_pop();
+
+                // Keys that the rest element excludes. Computed keys are stored in a local,
+                // so that they are only evaluated once
+                let mut excluded_keys = Vec::new();
+
+                for (key, element) in fields {
+                    ib.build_local_load(source_id, false);
+
+                    match key {
+                        PatternKey::Static(name) => {
+                            excluded_keys.push(Expr::string_literal(Cow::Borrowed(name)));
+                            ib.build_static_prop_access(name, false)?;
+                        }
+                        PatternKey::Computed(expr) => {
+                            ib.accept_expr(expr)?;
+
+                            if rest.is_some() {
+                                let key_id = ib.current_scope_mut().add_local(
+                                    "destructure_key",
+                                    VariableDeclarationKind::Unnameable,
+                                    None,
+                                )?;
+                                ib.build_local_store(AssignKind::Assignment, key_id, false);
+                                excluded_keys.push(Expr::compiled(compile_local_load(key_id, false)));
+                            }
+
+                            ib.build_dynamic_prop_access(false);
+                        }
+                    }
+
+                    ib.visit_pattern_element(element, kind)?;
+                }
+
+                if let Some(rest) = rest {
+                    let excluded_count = excluded_keys
+                        .len()
+                        .try_into()
+                        .map_err(|_| CompileError::DestructureLimitExceeded)?;
+
+                    ib.build_local_load(source_id, false);
+                    for key in excluded_keys {
+                        ib.accept_expr(key)?;
+                    }
+                    ib.build_objrest(excluded_count);
+                    ib.visit_pattern_target(*rest, kind)?;
+                }
+            }
+            Pattern::Array { elements, rest } => {
+                let count = elements
+                    .len()
+                    .try_into()
+                    .map_err(|_| CompileError::DestructureLimitExceeded)?;
+
+                // Puts all values on the stack, with the first one on top
+                ib.build_symbol_iterator();
+                ib.build_iterdestruct(count, rest.is_some());
+
+                for element in elements {
+                    match element {
+                        Some(element) => ib.visit_pattern_element(element, kind)?,
+                        None => ib.build_pop(),
+                    }
+                }
+
+                if let Some(rest) = rest {
+                    ib.visit_pattern_target(*rest, kind)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies the default value of a pattern element if the value on top of the stack is undefined,
+    /// and then stores the value in its target
+    fn visit_pattern_element(
+        &mut self,
+        PatternElement { target, default }: PatternElement<'a>,
+        kind: VariableDeclarationKind,
+    ) -> Result<(), CompileError> {
+        if let Some(default) = default {
+            let mut ib = InstructionBuilder::new(self);
+            ib.build_jmpundefinednp(Label::IfBranch { branch_id: 0 }, true);
+            ib.build_jmp(Label::IfEnd, true);
+
+            ib.add_local_label(Label::IfBranch { branch_id: 0 });
+            ib.build_pop();
+            ib.accept_expr(default)?;
+
+            ib.add_local_label(Label::IfEnd);
+        }
+
+        self.visit_pattern_target(target, kind)
+    }
+
+    fn visit_pattern_target(
+        &mut self,
+        target: PatternTarget<'a>,
+        kind: VariableDeclarationKind,
+    ) -> Result<(), CompileError> {
+        match target {
+            PatternTarget::Identifier(name) => {
+                let mut ib = InstructionBuilder::new(self);
+                let id = ib.current_scope_mut().add_local(name, kind, None)?;
+                ib.build_local_store(AssignKind::Assignment, id, false);
+                ib.build_pop();
+                Ok(())
+            }
+            PatternTarget::Pattern(pattern) => self.visit_pattern(pattern, kind),
+            PatternTarget::Expr(expr) => {
+                // Move the value into a local, so that it can be used as the right side of a regular assignment
+                let mut ib = InstructionBuilder::new(self);
+                let id = ib.current_scope_mut().add_local(
+                    "destructure_target",
+                    VariableDeclarationKind::Unnameable,
+                    None,
+                )?;
+                ib.build_local_store(AssignKind::Assignment, id, false);
+                ib.build_pop();
+
+                ib.accept_expr(Expr::assignment(
+                    expr,
+                    Expr::compiled(compile_local_load(id, false)),
+                    TokenType::Assignment,
+                ))?;
+                ib.build_pop();
+                Ok(())
+            }
+        }
+    }
 }
 
 enum ForEachLoopKind {
-    ForOf,
+    ForOf {
+        /// Whether this is a `for await..of` loop
+        r#async: bool,
+    },
     ForIn,
 }
 
@@ -438,12 +1417,13 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             Statement::Loop(Loop::DoWhile(d)) => self.visit_do_while_loop(d),
             Statement::Return(r) => self.visit_return_statement(r),
             Statement::Try(t) => self.visit_try_catch(t),
-            Statement::Throw(t) => self.visit_throw(t),
+            Statement::Throw(t, loc) => self.visit_throw(t, loc),
             Statement::Import(i) => self.visit_import_statement(i),
             Statement::Export(e) => self.visit_export_statement(e),
             Statement::Class(c) => self.visit_class_declaration(c),
-            Statement::Continue => self.visit_continue(),
-            Statement::Break => self.visit_break(),
+            Statement::Continue(label) => self.visit_continue(label),
+            Statement::Break(label) => self.visit_break(label),
+            Statement::Labelled(label, stmt) => self.visit_labelled(label, stmt),
             Statement::Debugger => self.visit_debugger(),
             Statement::Empty => self.visit_empty_statement(),
             Statement::Switch(s) => self.visit_switch_statement(s),
@@ -455,7 +1435,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             Expr::Binary(e) => self.visit_binary_expression(e),
             Expr::Assignment(e) => self.visit_assignment_expression(e),
             Expr::Grouping(e) => self.visit_grouping_expression(e),
-            Expr::Literal(LiteralExpr::Identifier(i)) => self.visit_identifier_expression(&i),
+            Expr::Literal(LiteralExpr::Identifier(i, loc)) => self.visit_identifier_expression(&i, loc),
             Expr::Literal(l) => self.visit_literal_expression(l),
             Expr::Unary(e) => self.visit_unary_expression(e),
             Expr::Call(e) => self.visit_function_call(e),
@@ -467,6 +1447,9 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             Expr::Function(e) => self.visit_function_expr(e),
             Expr::Array(e) => self.visit_array_literal(e),
             Expr::Object(e) => self.visit_object_literal(e),
+            Expr::OptionalChain(e) => self.visit_optional_chain_expr(*e),
+            Expr::PrivateName(name) => self.visit_private_name(name),
+            Expr::TemplateStrings(t) => self.visit_template_strings(t),
             Expr::Compiled(mut buf) => {
                 self.current_function_mut().buf.append(&mut buf);
                 Ok(())
@@ -477,7 +1460,12 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
 
     fn visit_binary_expression(
         &mut self,
-        BinaryExpr { left, right, operator }: BinaryExpr<'a>,
+        BinaryExpr {
+            left,
+            right,
+            operator,
+            loc,
+        }: BinaryExpr<'a>,
     ) -> Result<(), CompileError> {
         let func_id = self.current_function().id;
         let left_type = self.tcx.visit(&left, func_id);
@@ -489,6 +1477,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         macro_rules! generic_bin {
             ($gen:expr) => {{
                 ib.accept_expr(*right)?;
+                ib.add_source_location(loc);
                 $gen(&mut ib);
             }};
         }
@@ -502,6 +1491,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                         ib.build_intrinsic_op($spec);
                     }
                     _ => {
+                        ib.add_source_location(loc);
                         $gen(&mut ib);
                     }
                 }
@@ -538,6 +1528,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                     }
                     _ => {
                         ib.accept_expr(*right)?;
+                        ib.add_source_location(loc);
                         $gen(&mut ib);
                     }
                 }
@@ -642,18 +1633,21 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn visit_identifier_expression(&mut self, ident: &str) -> Result<(), CompileError> {
+    fn visit_identifier_expression(&mut self, ident: &str, loc: Option<Location>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
         match ident {
             "this" => ib.build_this(),
-            "super" => ib.build_super(),
+            "super" => return Err(CompileError::IllegalSuper),
             "globalThis" => ib.build_global(),
             "Infinity" => ib.build_infinity(),
             "NaN" => ib.build_nan(),
             ident => match ib.find_local(ident) {
                 Some((index, _, is_extern)) => ib.build_local_load(index, is_extern),
-                _ => ib.build_global_load(ident)?,
+                _ => {
+                    ib.add_source_location(loc);
+                    ib.build_global_load(ident)?
+                }
             },
         };
 
@@ -663,6 +1657,10 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
     fn visit_unary_expression(&mut self, UnaryExpr { operator, expr }: UnaryExpr<'a>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
+        if let TokenType::YieldStar = operator {
+            return ib.visit_yield_delegate(*expr);
+        }
+
         // Special case delete operator, as it works different from other unary operators
         if let TokenType::Delete = operator {
             match *expr {
@@ -670,6 +1668,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                     computed,
                     property,
                     target,
+                    ..
                 }) => match (*property, computed) {
                     (Expr::Literal(lit), false) => {
                         ib.accept_expr(*target)?;
@@ -677,6 +1676,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                         let id = ib.current_function_mut().cp.add(Constant::Identifier(ident.into()))?;
                         ib.build_static_delete(id);
                     }
+                    (Expr::PrivateName(..), _) => return Err(CompileError::IllegalPrivateNameDelete),
                     (expr, _) => {
                         ib.accept_expr(expr)?;
                         ib.accept_expr(*target)?;
@@ -713,7 +1713,23 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                     return Err(CompileError::YieldOutsideGenerator);
                 }
 
+                let is_async = ib.current_function().r#async;
+                if is_async {
+                    // Async generators await values before yielding them
+                    ib.build_await();
+                }
+
                 ib.build_yield();
+                ib.build_jmp(Label::YieldEnd, true);
+
+                // Generators resumed with `return()` continue here (see `Instruction::Yield`)
+                // and return the sent value, running any finally blocks
+                if is_async {
+                    ib.build_await();
+                }
+                ib.emit_return()?;
+
+                ib.add_local_label(Label::YieldEnd);
             }
             TokenType::Await => {
                 if !ib.current_function().r#async {
@@ -746,54 +1762,10 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                         ib.build_pop();
                     }
                 }
-                VariableDeclarationName::ObjectDestructuring { fields, rest } => {
-                    if rest.is_some() {
-                        unimplementedc!("Rest operator in object destructuring");
-                    }
-
-                    let field_count = fields
-                        .len()
-                        .try_into()
-                        .map_err(|_| CompileError::DestructureLimitExceeded)?;
-
-                    // Unwrap ok; checked at parse time
+                VariableDeclarationName::Pattern(pattern) => {
                     let value = value.ok_or(CompileError::MissingInitializerInDestructuring)?;
                     ib.accept_expr(value)?;
-
-                    ib.build_objdestruct(field_count);
-
-                    for (name, alias) in fields {
-                        let name = alias.unwrap_or(name);
-                        let id = ib.current_scope_mut().add_local(name, binding.kind, None)?;
-
-                        let var_id = ib.current_function_mut().cp.add(Constant::Number(id as f64))?;
-                        let ident_id = ib.current_function_mut().cp.add(Constant::Identifier(name.into()))?;
-                        ib.writew(var_id);
-                        ib.writew(ident_id);
-                    }
-                }
-                VariableDeclarationName::ArrayDestructuring { fields, rest } => {
-                    if rest.is_some() {
-                        unimplementedc!("Rest operator in array destructuring");
-                    }
-
-                    let field_count = fields
-                        .len()
-                        .try_into()
-                        .map_err(|_| CompileError::DestructureLimitExceeded)?;
-
-                    // Unwrap ok; checked at parse time
-                    let value = value.expect("Array destructuring requires a value");
-                    ib.accept_expr(value)?;
-
-                    ib.build_arraydestruct(field_count);
-
-                    for name in fields {
-                        let id = ib.current_scope_mut().add_local(name, binding.kind, None)?;
-
-                        let var_id = ib.current_function_mut().cp.add(Constant::Number(id as f64))?;
-                        ib.writew(var_id);
-                    }
+                    ib.visit_pattern(pattern, binding.kind)?;
                 }
             }
         }
@@ -888,6 +1860,9 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
 
         let loop_id = ib.current_function_mut().prepare_loop();
 
+        // While loops have no increment step, so `continue` jumps straight to the condition
+        ib.current_function_mut()
+            .add_global_label(Label::LoopIncrement { loop_id });
         ib.current_function_mut()
             .add_global_label(Label::LoopCondition { loop_id });
         ib.accept_expr(condition)?;
@@ -913,6 +1888,8 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
 
         ib.accept(*body)?;
 
+        ib.current_function_mut()
+            .add_global_label(Label::LoopIncrement { loop_id });
         ib.accept_expr(condition)?;
         ib.build_jmptruep(Label::LoopCondition { loop_id }, false);
 
@@ -929,169 +1906,48 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         let mut ib = InstructionBuilder::new(self);
 
         match left {
-            AssignmentTarget::Expr(left) => match *left {
-                Expr::Literal(lit) => {
-                    let ident = lit.to_identifier();
-                    let local = ib.find_local(&ident);
-
-                    if let Some((id, local, is_extern)) = local {
-                        if matches!(local.binding().kind, VariableDeclarationKind::Const) {
-                            return Err(CompileError::ConstAssignment);
-                        }
-
-                        macro_rules! assign {
-                            ($kind:expr) => {{
-                                ib.accept_expr(*right)?;
-                                ib.build_local_store($kind, id, is_extern);
-                            }};
-                        }
-
-                        match operator {
-                            TokenType::Assignment => assign!(AssignKind::Assignment),
-                            TokenType::AdditionAssignment => assign!(AssignKind::AddAssignment),
-                            TokenType::SubtractionAssignment => assign!(AssignKind::SubAssignment),
-                            TokenType::MultiplicationAssignment => assign!(AssignKind::MulAssignment),
-                            TokenType::DivisionAssignment => assign!(AssignKind::DivAssignment),
-                            TokenType::RemainderAssignment => assign!(AssignKind::RemAssignment),
-                            TokenType::ExponentiationAssignment => assign!(AssignKind::PowAssignment),
-                            TokenType::LeftShiftAssignment => assign!(AssignKind::ShlAssignment),
-                            TokenType::RightShiftAssignment => assign!(AssignKind::ShrAssignment),
-                            TokenType::UnsignedRightShiftAssignment => assign!(AssignKind::UshrAssignment),
-                            TokenType::BitwiseAndAssignment => assign!(AssignKind::BitAndAssignment),
-                            TokenType::BitwiseOrAssignment => assign!(AssignKind::BitOrAssignment),
-                            TokenType::BitwiseXorAssignment => assign!(AssignKind::BitXorAssignment),
-                            _ => unimplementedc!("Unknown operator"),
-                        }
-                    } else {
-                        macro_rules! assign {
-                            ($kind:expr) => {{
-                                ib.accept_expr(*right)?;
-                                ib.build_global_store($kind, &ident)?;
-                            }};
-                        }
-
-                        match operator {
-                            TokenType::Assignment => assign!(AssignKind::Assignment),
-                            TokenType::AdditionAssignment => assign!(AssignKind::AddAssignment),
-                            TokenType::SubtractionAssignment => assign!(AssignKind::SubAssignment),
-                            TokenType::MultiplicationAssignment => assign!(AssignKind::MulAssignment),
-                            TokenType::DivisionAssignment => assign!(AssignKind::DivAssignment),
-                            TokenType::RemainderAssignment => assign!(AssignKind::RemAssignment),
-                            TokenType::ExponentiationAssignment => assign!(AssignKind::PowAssignment),
-                            TokenType::LeftShiftAssignment => assign!(AssignKind::ShlAssignment),
-                            TokenType::RightShiftAssignment => assign!(AssignKind::ShrAssignment),
-                            TokenType::UnsignedRightShiftAssignment => assign!(AssignKind::UshrAssignment),
-                            TokenType::BitwiseAndAssignment => assign!(AssignKind::BitAndAssignment),
-                            TokenType::BitwiseOrAssignment => assign!(AssignKind::BitOrAssignment),
-                            TokenType::BitwiseXorAssignment => assign!(AssignKind::BitXorAssignment),
-                            _ => unimplementedc!("Unknown operator"),
-                        }
+            AssignmentTarget::Expr(left) => {
+                let kind = match operator {
+                    TokenType::Assignment => AssignKind::Assignment,
+                    TokenType::AdditionAssignment => AssignKind::AddAssignment,
+                    TokenType::SubtractionAssignment => AssignKind::SubAssignment,
+                    TokenType::MultiplicationAssignment => AssignKind::MulAssignment,
+                    TokenType::DivisionAssignment => AssignKind::DivAssignment,
+                    TokenType::RemainderAssignment => AssignKind::RemAssignment,
+                    TokenType::ExponentiationAssignment => AssignKind::PowAssignment,
+                    TokenType::LeftShiftAssignment => AssignKind::ShlAssignment,
+                    TokenType::RightShiftAssignment => AssignKind::ShrAssignment,
+                    TokenType::UnsignedRightShiftAssignment => AssignKind::UshrAssignment,
+                    TokenType::BitwiseAndAssignment => AssignKind::BitAndAssignment,
+                    TokenType::BitwiseOrAssignment => AssignKind::BitOrAssignment,
+                    TokenType::BitwiseXorAssignment => AssignKind::BitXorAssignment,
+                    TokenType::LogicalAndAssignment
+                    | TokenType::LogicalOrAssignment
+                    | TokenType::LogicalNullishAssignment => {
+                        return ib.visit_logical_assignment(*left, operator, *right);
                     }
-                }
-                Expr::PropertyAccess(prop) => {
-                    ib.accept_expr(*prop.target)?;
+                    _ => unimplementedc!("Unknown operator"),
+                };
 
-                    macro_rules! staticassign {
-                        ($lit:expr, $kind:expr) => {{
-                            ib.accept_expr(*right)?;
-                            let ident = $lit.to_identifier();
-                            ib.build_static_prop_assign($kind, &ident)?;
-                        }};
-                    }
-                    macro_rules! dynamicassign {
-                        ($prop:expr, $kind:expr) => {{
-                            ib.accept_expr(*right)?;
-                            ib.accept_expr($prop)?;
-                            ib.build_dynamic_prop_assign($kind);
-                        }};
-                    }
-
-                    match (*prop.property, prop.computed, operator) {
-                        (Expr::Literal(lit), false, TokenType::Assignment) => {
-                            staticassign!(lit, AssignKind::Assignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::AdditionAssignment) => {
-                            staticassign!(lit, AssignKind::AddAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::SubtractionAssignment) => {
-                            staticassign!(lit, AssignKind::SubAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::MultiplicationAssignment) => {
-                            staticassign!(lit, AssignKind::MulAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::DivisionAssignment) => {
-                            staticassign!(lit, AssignKind::DivAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::RemainderAssignment) => {
-                            staticassign!(lit, AssignKind::RemAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::ExponentiationAssignment) => {
-                            staticassign!(lit, AssignKind::PowAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::LeftShiftAssignment) => {
-                            staticassign!(lit, AssignKind::ShlAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::RightShiftAssignment) => {
-                            staticassign!(lit, AssignKind::ShrAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::UnsignedRightShiftAssignment) => {
-                            staticassign!(lit, AssignKind::UshrAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::BitwiseAndAssignment) => {
-                            staticassign!(lit, AssignKind::BitAndAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::BitwiseOrAssignment) => {
-                            staticassign!(lit, AssignKind::BitOrAssignment)
-                        }
-                        (Expr::Literal(lit), false, TokenType::BitwiseXorAssignment) => {
-                            staticassign!(lit, AssignKind::BitXorAssignment)
-                        }
-                        (prop, true, TokenType::Assignment) => dynamicassign!(prop, AssignKind::Assignment),
-                        (prop, true, TokenType::AdditionAssignment) => {
-                            dynamicassign!(prop, AssignKind::AddAssignment)
-                        }
-                        (prop, true, TokenType::SubtractionAssignment) => {
-                            dynamicassign!(prop, AssignKind::SubAssignment)
-                        }
-                        (prop, true, TokenType::MultiplicationAssignment) => {
-                            dynamicassign!(prop, AssignKind::MulAssignment)
-                        }
-                        (prop, true, TokenType::DivisionAssignment) => {
-                            dynamicassign!(prop, AssignKind::DivAssignment)
-                        }
-                        (prop, true, TokenType::RemainderAssignment) => {
-                            dynamicassign!(prop, AssignKind::RemAssignment)
-                        }
-                        (prop, true, TokenType::ExponentiationAssignment) => {
-                            dynamicassign!(prop, AssignKind::PowAssignment)
-                        }
-                        (prop, true, TokenType::LeftShiftAssignment) => {
-                            dynamicassign!(prop, AssignKind::ShlAssignment)
-                        }
-                        (prop, true, TokenType::RightShiftAssignment) => {
-                            dynamicassign!(prop, AssignKind::ShrAssignment)
-                        }
-                        (prop, true, TokenType::UnsignedRightShiftAssignment) => {
-                            dynamicassign!(prop, AssignKind::UshrAssignment)
-                        }
-                        (prop, true, TokenType::BitwiseAndAssignment) => {
-                            dynamicassign!(prop, AssignKind::BitAndAssignment)
-                        }
-                        (prop, true, TokenType::BitwiseOrAssignment) => {
-                            dynamicassign!(prop, AssignKind::BitOrAssignment)
-                        }
-                        (prop, true, TokenType::BitwiseXorAssignment) => {
-                            dynamicassign!(prop, AssignKind::BitXorAssignment)
-                        }
-                        _ => unimplementedc!("Assignment to computed property"),
-                    }
-                }
-                _ => unimplementedc!("Assignment to non-identifier"),
-            },
+                ib.visit_assignment_target(*left, kind, Some(*right))?;
+            }
             AssignmentTarget::LocalId(id) => {
                 ib.accept_expr(*right)?;
                 ib.build_local_store(AssignKind::Assignment, id, false);
             }
+            AssignmentTarget::Pattern(pattern) => {
+                // The assigned value is also the result of the expression, so keep a copy of it on the stack
+                let value_id =
+                    ib.current_scope_mut()
+                        .add_local("destructure_value", VariableDeclarationKind::Unnameable, None)?;
+
+                ib.accept_expr(*right)?;
+                ib.build_local_store(AssignKind::Assignment, value_id, false);
+                ib.build_local_load(value_id, false);
+
+                // Assignment patterns only have expression targets, so the declaration kind is unused
+                ib.visit_pattern(pattern, VariableDeclarationKind::Var)?;
+            }
         }
 
         Ok(())
@@ -1103,6 +1959,8 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             constructor_call,
             target,
             arguments,
+            loc,
+            optional,
         }: FunctionCall<'a>,
     ) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
@@ -1122,7 +1980,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         fn try_spec_function_call<'a>(
             ib: &mut InstructionBuilder<'_, 'a>,
             target: &Expr<'a>,
-            arguments: &[Expr<'a>],
+            arguments: &[CallArgumentKind<'a>],
         ) -> Result<bool, CompileError> {
             if let Expr::PropertyAccess(PropertyAccessExpr { target, property, .. }) = target {
                 let Some(target) = target.as_identifier() else {
@@ -1137,6 +1995,18 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                         return Ok(false);
                     };
 
+                // Spread arguments have an unknown length, so they cannot be specialized
+                let Some(arguments) = arguments
+                    .iter()
+                    .map(|arg| match arg {
+                        CallArgumentKind::Normal(expr) => Some(expr),
+                        CallArgumentKind::Spread(..) => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return Ok(false);
+                };
+
                 macro_rules! emit_spec {
                     ($spec:expr) => {{
                         for arg in arguments {
@@ -1186,7 +2056,12 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             return Ok(());
         }
 
+        if is_super(&target) {
+            return ib.visit_super_call(arguments);
+        }
+
         let has_this = if let Expr::PropertyAccess(p) = *target {
+            // `super.method()` calls the method of the superclass with the current `this`
             ib.visit_property_access_expr(p, true)?;
             true
         } else {
@@ -1194,28 +2069,48 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             false
         };
 
-        let argc = arguments
-            .len()
-            .try_into()
-            .map_err(|_| CompileError::ParameterLimitExceeded)?;
+        if optional {
+            ib.build_optional_chain_jmp(has_this);
+        }
+
+        let has_spread = arguments.iter().any(|arg| matches!(arg, CallArgumentKind::Spread(..)));
+
+        let argc = if has_spread {
+            // Spread arguments are collected into an array, which the VM unpacks when calling the function
+            ib.visit_array_literal(ArrayLiteral(arguments.into_iter().map(Into::into).collect()))?;
+            0
+        } else {
+            let argc = arguments
+                .len()
+                .try_into()
+                .map_err(|_| CompileError::ParameterLimitExceeded)?;
+
+            for arg in arguments {
+                ib.accept_expr(arg.into_expr())?;
+            }
 
-        for arg in arguments {
-            ib.accept_expr(arg)?;
-        }
+            argc
+        };
 
-        let meta = FunctionCallMetadata::new_checked(argc, constructor_call, has_this)
-            .ok_or(CompileError::ParameterLimitExceeded)?;
+        let meta = FunctionCallMetadata::new(constructor_call, has_this, has_spread);
 
-        ib.build_call(meta);
+        ib.add_source_location(loc);
+        ib.build_call(meta, argc);
 
         Ok(())
     }
 
     fn visit_return_statement(&mut self, ReturnStatement(stmt): ReturnStatement<'a>) -> Result<(), CompileError> {
-        let tc_depth = self.current_function().try_catch_depth;
-        self.accept_expr(stmt)?;
-        InstructionBuilder::new(self).build_ret(tc_depth);
-        Ok(())
+        let mut ib = InstructionBuilder::new(self);
+        ib.accept_expr(stmt)?;
+
+        let function = ib.current_function();
+        if function.r#async && matches!(function.ty, FunctionKind::Generator) {
+            // Async generators await their return value
+            ib.build_await();
+        }
+
+        ib.emit_return()
     }
 
     fn visit_conditional_expr(
@@ -1243,20 +2138,46 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             computed,
             target,
             property,
+            loc,
+            optional,
         }: PropertyAccessExpr<'a>,
         preserve_this: bool,
     ) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
+        if is_super(&target) {
+            // Accessors reached through `super.property` are called with the current `this`, not the home object
+            if preserve_this {
+                ib.build_this();
+            }
+            ib.build_this();
+            ib.build_super_home_load()?;
+            match (*property, computed) {
+                (Expr::Literal(lit), false) => {
+                    ib.build_constant(Constant::String(lit.to_identifier().as_ref().into()))?;
+                }
+                (e, _) => ib.accept_expr(e)?,
+            }
+            ib.add_source_location(loc);
+            ib.build_super_prop_access();
+            return Ok(());
+        }
+
         ib.accept_expr(*target)?;
 
+        if optional {
+            ib.build_optional_chain_jmp(false);
+        }
+
         match (*property, computed) {
             (Expr::Literal(lit), false) => {
                 let ident = lit.to_identifier();
+                ib.add_source_location(loc);
                 ib.build_static_prop_access(&ident, preserve_this)?;
             }
             (e, _) => {
                 ib.accept_expr(e)?;
+                ib.add_source_location(loc);
                 ib.build_dynamic_prop_access(preserve_this);
             }
         }
@@ -1275,129 +2196,53 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
     fn visit_postfix_expr(&mut self, (tt, expr): Postfix<'a>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
-        match *expr {
-            Expr::Literal(lit) => {
-                let ident = lit.to_identifier();
-
-                if let Some((id, loc, is_extern)) = ib.find_local(&ident) {
-                    let ty = loc.inferred_type().borrow();
-
-                    // Specialize guaranteed local number increment
-                    if let (Ok(id), Some(CompileValueType::Number), false) = (u8::try_from(id), &*ty, is_extern) {
-                        match tt {
-                            TokenType::Increment => ib.build_postfix_inc_local_num(id),
-                            TokenType::Decrement => ib.build_postfix_dec_local_num(id),
-                            _ => unreachable!("Token never emitted"),
-                        }
-                        return Ok(());
-                    }
-
-                    match tt {
-                        TokenType::Increment => ib.build_local_store(AssignKind::PostfixIncrement, id, is_extern),
-                        TokenType::Decrement => ib.build_local_store(AssignKind::PostfixDecrement, id, is_extern),
-                        _ => unreachable!("Token never emitted"),
-                    }
-                } else {
+        if let Expr::Literal(lit) = &*expr {
+            if let Some((id, loc, false)) = ib.find_local(&lit.to_identifier()) {
+                // Specialize guaranteed local number increment
+                if let (Ok(id), Some(CompileValueType::Number)) = (u8::try_from(id), &*loc.inferred_type().borrow()) {
                     match tt {
-                        TokenType::Increment => ib.build_global_store(AssignKind::PostfixIncrement, &ident)?,
-                        TokenType::Decrement => ib.build_global_store(AssignKind::PostfixDecrement, &ident)?,
+                        TokenType::Increment => ib.build_postfix_inc_local_num(id),
+                        TokenType::Decrement => ib.build_postfix_dec_local_num(id),
                         _ => unreachable!("Token never emitted"),
                     }
+                    return Ok(());
                 }
             }
-            Expr::PropertyAccess(prop) => {
-                ib.accept_expr(*prop.target)?;
-
-                match (*prop.property, prop.computed) {
-                    (Expr::Literal(lit), false) => {
-                        let ident = lit.to_identifier();
-                        match tt {
-                            TokenType::Increment => {
-                                ib.build_static_prop_assign(AssignKind::PostfixIncrement, &ident)?
-                            }
-                            TokenType::Decrement => {
-                                ib.build_static_prop_assign(AssignKind::PostfixDecrement, &ident)?
-                            }
-                            _ => unreachable!("Token never emitted"),
-                        }
-                    }
-                    (prop, true) => {
-                        ib.accept_expr(prop)?;
-                        match tt {
-                            TokenType::Increment => ib.build_dynamic_prop_assign(AssignKind::PostfixIncrement),
-                            TokenType::Decrement => ib.build_dynamic_prop_assign(AssignKind::PostfixDecrement),
-                            _ => unreachable!("Token never emitted"),
-                        }
-                    }
-                    _ => unreachable!("Static assignment was not a literal"),
-                }
-            }
-            _ => unimplementedc!("Non-identifier postfix expression"),
         }
 
-        Ok(())
+        let kind = match tt {
+            TokenType::Increment => AssignKind::PostfixIncrement,
+            TokenType::Decrement => AssignKind::PostfixDecrement,
+            _ => unreachable!("Token never emitted"),
+        };
+
+        ib.visit_assignment_target(*expr, kind, None)
     }
 
     fn visit_prefix_expr(&mut self, (tt, expr): Postfix<'a>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
-        match *expr {
-            Expr::Literal(lit) => {
-                let ident = lit.to_identifier();
-
-                if let Some((id, loc, is_extern)) = ib.find_local(&ident) {
-                    let ty = loc.inferred_type().borrow();
-
-                    // Specialize guaranteed local number increment
-                    if let (Ok(id), Some(CompileValueType::Number), false) = (u8::try_from(id), &*ty, is_extern) {
-                        match tt {
-                            TokenType::Increment => ib.build_prefix_inc_local_num(id),
-                            TokenType::Decrement => ib.build_prefix_dec_local_num(id),
-                            _ => unreachable!("Token never emitted"),
-                        }
-                        return Ok(());
-                    }
-
+        if let Expr::Literal(lit) = &*expr {
+            if let Some((id, loc, false)) = ib.find_local(&lit.to_identifier()) {
+                // Specialize guaranteed local number increment
+                if let (Ok(id), Some(CompileValueType::Number)) = (u8::try_from(id), &*loc.inferred_type().borrow()) {
                     match tt {
-                        TokenType::Increment => ib.build_local_store(AssignKind::PrefixIncrement, id, is_extern),
-                        TokenType::Decrement => ib.build_local_store(AssignKind::PrefixDecrement, id, is_extern),
+                        TokenType::Increment => ib.build_prefix_inc_local_num(id),
+                        TokenType::Decrement => ib.build_prefix_dec_local_num(id),
                         _ => unreachable!("Token never emitted"),
                     }
-                } else {
-                    match tt {
-                        TokenType::Increment => ib.build_global_store(AssignKind::PrefixIncrement, &ident)?,
-                        TokenType::Decrement => ib.build_global_store(AssignKind::PrefixDecrement, &ident)?,
-                        _ => unreachable!("Token never emitted"),
-                    }
-                }
-            }
-            Expr::PropertyAccess(prop) => {
-                ib.accept_expr(*prop.target)?;
-
-                match (*prop.property, prop.computed) {
-                    (Expr::Literal(lit), false) => {
-                        let ident = lit.to_identifier();
-                        match tt {
-                            TokenType::Increment => ib.build_static_prop_assign(AssignKind::PrefixIncrement, &ident)?,
-                            TokenType::Decrement => ib.build_static_prop_assign(AssignKind::PrefixDecrement, &ident)?,
-                            _ => unreachable!("Token never emitted"),
-                        }
-                    }
-                    (prop, true) => {
-                        ib.accept_expr(prop)?;
-                        match tt {
-                            TokenType::Increment => ib.build_dynamic_prop_assign(AssignKind::PrefixIncrement),
-                            TokenType::Decrement => ib.build_dynamic_prop_assign(AssignKind::PrefixDecrement),
-                            _ => unreachable!("Token never emitted"),
-                        }
-                    }
-                    _ => unreachable!("Static assignment was not a literal"),
+                    return Ok(());
                 }
             }
-            _ => unimplementedc!("Non-identifier postfix expression"),
         }
 
-        Ok(())
+        let kind = match tt {
+            TokenType::Increment => AssignKind::PrefixIncrement,
+            TokenType::Decrement => AssignKind::PrefixDecrement,
+            _ => unreachable!("Token never emitted"),
+        };
+
+        ib.visit_assignment_target(*expr, kind, None)
     }
 
     fn visit_function_expr(
@@ -1406,53 +2251,29 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             id,
             name,
             parameters: arguments,
-            mut statements,
+            statements,
             ty,
             r#async,
         }: FunctionDeclaration<'a>,
     ) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
+        let (is_ctor, is_derived_ctor) = match ib.class_stack.last() {
+            Some(ClassState {
+                superclass,
+                constructor,
+                ..
+            }) if *constructor == id => (true, superclass.is_some()),
+            _ => (false, false),
+        };
         ib.function_stack.push(FunctionLocalState::new(ty, id));
+        ib.current_function_mut().r#async = r#async;
 
-        let mut rest_local = None;
-
-        for (param, default, _ty) in &arguments {
-            let name = match param {
-                Parameter::Identifier(ident) => ident,
-                Parameter::Spread(ident) => ident,
-            };
-
-            let id = ib
-                .tcx
-                .scope_mut(id)
-                .add_local(name, VariableDeclarationKind::Var, None)?;
-
-            if let Parameter::Spread(..) = param {
-                rest_local = Some(id);
-            }
-
-            if let Some(default) = default {
-                let mut sub_ib = InstructionBuilder::new(&mut ib);
-                // First, load parameter
-                sub_ib.build_local_load(id, false);
-                // Jump to InitParamWithDefaultValue if param is undefined
-                sub_ib.build_jmpundefinedp(Label::InitParamWithDefaultValue, true);
-                // If it isn't undefined, it won't jump to InitParamWithDefaultValue, so we jump to the end
-                sub_ib.build_jmp(Label::FinishParamDefaultValueInit, true);
-                sub_ib.add_local_label(Label::InitParamWithDefaultValue);
-                sub_ib.accept_expr(default.clone())?;
-                sub_ib.build_local_store(AssignKind::Assignment, id, false);
-
-                sub_ib.add_local_label(Label::FinishParamDefaultValueInit);
-            }
-        }
-
-        transformations::ast_insert_implicit_return(&mut statements);
-        for stmt in statements {
-            ib.accept(stmt)?;
-        }
-
+        // Note: No `?` here because we need to always pop the function state,
+        // otherwise enclosing blocks would exit the scope of this function
+        let rest_local = ib.visit_function_body(id, &arguments, statements, is_ctor && !is_derived_ctor);
         let cmp = ib.function_stack.pop().expect("Missing function state");
+        let rest_local = rest_local?;
+
         let scope = ib.tcx.scope(id);
         let externals = scope.externals();
         let locals = scope.locals().len();
@@ -1464,12 +2285,14 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             name: name.map(ToOwned::to_owned),
             ty,
             params: match arguments.last() {
-                Some((Parameter::Spread(..), ..)) => arguments.len() - 1,
+                Some((Parameter::Spread(..) | Parameter::SpreadPattern(..), ..)) => arguments.len() - 1,
                 _ => arguments.len(),
             },
             externals: externals.into(),
             r#async,
             rest_local,
+            is_derived_ctor,
+            line_table: cmp.line_table,
             poison_ips: RefCell::new(HashSet::new()),
         };
         ib.build_constant(Constant::Function(Rc::new(function)))?;
@@ -1477,19 +2300,40 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn visit_array_literal(&mut self, ArrayLiteral(exprs): ArrayLiteral<'a>) -> Result<(), CompileError> {
+    fn visit_array_literal(&mut self, ArrayLiteral(members): ArrayLiteral<'a>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
-        let len = exprs
-            .len()
-            .try_into()
-            .map_err(|_| CompileError::ArrayLitLimitExceeded)?;
+        // All elements up to the first spread element are known and can be put in the array literal directly,
+        // the remaining ones are appended one by one
+        let known_len = members
+            .iter()
+            .position(|member| matches!(member, ArrayMemberKind::Spread(..)))
+            .unwrap_or(members.len());
 
-        for expr in exprs {
-            ib.accept_expr(expr)?;
+        let len = known_len.try_into().map_err(|_| CompileError::ArrayLitLimitExceeded)?;
+
+        let mut members = members.into_iter();
+
+        for member in members.by_ref().take(known_len) {
+            ib.accept_expr(member.into_expr())?;
         }
 
         ib.build_arraylit(len);
+
+        for member in members {
+            match member {
+                ArrayMemberKind::Item(expr) => {
+                    ib.accept_expr(expr)?;
+                    ib.build_array_push();
+                }
+                ArrayMemberKind::Spread(expr) => {
+                    ib.accept_expr(expr)?;
+                    ib.build_symbol_iterator();
+                    ib.build_array_spread();
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -1513,27 +2357,156 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn visit_try_catch(&mut self, TryCatch { try_, catch, .. }: TryCatch<'a>) -> Result<(), CompileError> {
+    fn visit_private_name(&mut self, name: &'a str) -> Result<(), CompileError> {
+        self.build_private_name_load(name)
+    }
+
+    fn visit_template_strings(
+        &mut self,
+        TemplateStrings { cooked, raw }: TemplateStrings<'a>,
+    ) -> Result<(), CompileError> {
+        // The strings array is created once per template literal and cached in a local of the root function,
+        // which is shared by all functions that the template literal may end up being evaluated in
+        let func_id = self.current_function().id;
+        let cache_id = self.tcx.scope_mut(FuncId::ROOT).add_local(
+            "template_strings",
+            VariableDeclarationKind::Unnameable,
+            None,
+        )?;
+        let (id, is_extern) = self
+            .find_local_by_id(cache_id, FuncId::ROOT, func_id)
+            .expect("Root function must be reachable");
+
+        let len = cooked
+            .len()
+            .try_into()
+            .map_err(|_| CompileError::ArrayLitLimitExceeded)?;
+
+        let mut ib = InstructionBuilder::new(self);
+        ib.build_local_load(id, is_extern);
+        ib.build_jmpundefinednp(Label::InitTemplateStrings, true);
+        ib.build_jmp(Label::IfEnd, true);
+
+        ib.add_local_label(Label::InitTemplateStrings);
+        ib.build_pop();
+        for string in cooked.into_iter().chain(raw.into_iter().map(Some)) {
+            match string {
+                Some(string) => ib.build_constant(Constant::String(string.as_ref().into()))?,
+                // Segments with invalid escape sequences have an undefined cooked value
+                None => ib.build_constant(Constant::Undefined)?,
+            }
+        }
+        ib.build_template_strings(len);
+        ib.build_local_store(AssignKind::Assignment, id, is_extern);
+
+        ib.add_local_label(Label::IfEnd);
+        Ok(())
+    }
+
+    fn visit_optional_chain_expr(&mut self, expr: Expr<'a>) -> Result<(), CompileError> {
+        let mut ib = InstructionBuilder::new(self);
+
+        let chain_id = ib.current_function_mut().prepare_optional_chain();
+        ib.accept_expr(expr)?;
+        ib.current_function_mut().exit_optional_chain();
+        ib.build_jmp(Label::IfEnd, true);
+
+        // Optional links jump here with the nullish value (and possibly `this`) still on the stack
+        let pop_this = Label::OptionalChainShortCircuit {
+            chain_id,
+            pop_this: true,
+        };
+        ib.current_function_mut().add_global_label(pop_this);
+        ib.build_pop();
+        let pop_value = Label::OptionalChainShortCircuit {
+            chain_id,
+            pop_this: false,
+        };
+        ib.current_function_mut().add_global_label(pop_value);
+        ib.build_pop();
+        ib.build_undef();
+
+        ib.add_local_label(Label::IfEnd);
+        Ok(())
+    }
+
+    fn visit_try_catch(&mut self, TryCatch { try_, catch, finally }: TryCatch<'a>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
 
-        ib.build_try_block();
+        if let Some(finally) = finally {
+            // try { ... } catch { ... } finally { ... } is compiled as a try-catch nested in a try-finally.
+            // When leaving normally or through a jump, the finally block is run inline (see `exit_breakables`).
+            // When an exception is thrown, the finally handler stores it, runs the finally block and rethrows it
+            ib.build_try_block(Label::Finally);
+            ib.current_function_mut().breakables.push(Breakable::Try {
+                finally: Some(finally.clone()),
+            });
+
+            match catch {
+                Some(catch) => ib.visit_try_catch(TryCatch::new(*try_, Some(catch), None))?,
+                None => {
+                    ib.current_scope_mut().enter();
+                    ib.accept(*try_)?;
+                    ib.current_scope_mut().exit();
+                }
+            }
+
+            ib.current_function_mut().exit_try();
+            ib.build_try_end();
+            ib.accept(*finally.clone())?;
+            ib.build_jmp(Label::TryEnd, true);
+
+            ib.add_local_label(Label::Finally);
+            let id = ib
+                .current_scope_mut()
+                .add_local("finally_error", VariableDeclarationKind::Unnameable, None)?;
+            if id == u16::MAX {
+                return Err(CompileError::LocalLimitExceeded);
+            }
+            ib.writew(id);
+            ib.accept(*finally)?;
+            ib.build_local_load(id, false);
+            ib.build_throw();
+
+            ib.add_local_label(Label::TryEnd);
+            return Ok(());
+        }
+
+        let Some(catch) = catch else {
+            unreachable!("Try statement without catch or finally block")
+        };
+
+        ib.build_try_block(Label::Catch);
 
-        ib.current_function_mut().try_catch_depth += 1;
+        ib.current_function_mut()
+            .breakables
+            .push(Breakable::Try { finally: None });
         ib.current_scope_mut().enter();
         ib.accept(*try_)?;
         ib.current_scope_mut().exit();
-        ib.current_function_mut().try_catch_depth -= 1;
+        ib.current_function_mut().exit_try();
 
+        // The try block is removed by the VM when an exception is caught, so it only needs to be removed here
+        ib.build_try_end();
         ib.build_jmp(Label::TryEnd, true);
 
         ib.add_local_label(Label::Catch);
 
         ib.current_scope_mut().enter();
 
-        if let Some(ident) = catch.ident {
-            let id = ib
-                .current_scope_mut()
-                .add_local(ident, VariableDeclarationKind::Var, None)?;
+        if let Some(binding) = catch.binding {
+            let id = match binding {
+                PatternTarget::Identifier(ident) => {
+                    ib.current_scope_mut()
+                        .add_local(ident, VariableDeclarationKind::Var, None)?
+                }
+                // Patterns receive the error in an unnameable local and destructure it from there
+                PatternTarget::Pattern(..) => {
+                    ib.current_scope_mut()
+                        .add_local("catch_binding", VariableDeclarationKind::Unnameable, None)?
+                }
+                PatternTarget::Expr(..) => unreachable!("Catch bindings are never assignment patterns"),
+            };
 
             if id == u16::MAX {
                 // Max u16 value is reserved for "no binding"
@@ -1541,6 +2514,11 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             }
 
             ib.writew(id);
+
+            if let PatternTarget::Pattern(pattern) = binding {
+                ib.build_local_load(id, false);
+                ib.visit_pattern(pattern, VariableDeclarationKind::Var)?;
+            }
         } else {
             ib.writew(u16::MAX);
         }
@@ -1549,14 +2527,14 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         ib.current_scope_mut().exit();
 
         ib.add_local_label(Label::TryEnd);
-        ib.build_try_end();
 
         Ok(())
     }
 
-    fn visit_throw(&mut self, expr: Expr<'a>) -> Result<(), CompileError> {
+    fn visit_throw(&mut self, expr: Expr<'a>, loc: Location) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
         ib.accept_expr(expr)?;
+        ib.add_source_location(Some(loc));
         ib.build_throw();
         Ok(())
     }
@@ -1607,8 +2585,16 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn visit_for_of_loop(&mut self, ForOfLoop { binding, expr, body }: ForOfLoop<'a>) -> Result<(), CompileError> {
-        self.visit_for_each_kinded_loop(ForEachLoopKind::ForOf, binding, expr, body)
+    fn visit_for_of_loop(
+        &mut self,
+        ForOfLoop {
+            binding,
+            expr,
+            body,
+            r#async,
+        }: ForOfLoop<'a>,
+    ) -> Result<(), CompileError> {
+        self.visit_for_each_kinded_loop(ForEachLoopKind::ForOf { r#async }, binding, expr, body)
     }
 
     fn visit_for_in_loop(&mut self, ForInLoop { binding, expr, body }: ForInLoop<'a>) -> Result<(), CompileError> {
@@ -1687,14 +2673,7 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                 for var in &vars {
                     match &var.binding.name {
                         VariableDeclarationName::Identifier(ident) => it.push(ident),
-                        VariableDeclarationName::ArrayDestructuring { fields, rest } => {
-                            it.extend(fields.iter());
-                            it.extend(rest);
-                        }
-                        VariableDeclarationName::ObjectDestructuring { fields, rest } => {
-                            it.extend(fields.iter().map(|(name, ident)| ident.unwrap_or(name)));
-                            it.extend(rest);
-                        }
+                        VariableDeclarationName::Pattern(pattern) => it.extend(pattern.bindings()),
                     }
                 }
 
@@ -1709,40 +2688,97 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn visit_break(&mut self) -> Result<(), CompileError> {
-        let mut ib = InstructionBuilder::new(self);
-        let breakable = *ib
-            .current_function_mut()
-            .breakables
-            .last()
-            .ok_or(CompileError::IllegalBreak)?;
-        match breakable {
-            Breakable::Loop { loop_id } => {
-                ib.build_jmp(Label::LoopEnd { loop_id }, false);
+    fn visit_break(&mut self, label: Option<&'a str>) -> Result<(), CompileError> {
+        let breakables = &self.current_function().breakables;
+        let index = match label {
+            Some(label) => breakables
+                .iter()
+                .rposition(|b| matches!(b, Breakable::Labelled { name, .. } if *name == label))
+                .ok_or_else(|| CompileError::UndefinedLabel(label.into()))?,
+            // Unlabelled breaks always refer to the innermost loop or switch, even if there's a labelled block in between
+            None => breakables
+                .iter()
+                .rposition(|b| matches!(b, Breakable::Loop { .. } | Breakable::Switch { .. }))
+                .ok_or(CompileError::IllegalBreak)?,
+        };
+        let target = match breakables[index] {
+            Breakable::Loop { loop_id } => Label::LoopEnd { loop_id },
+            Breakable::Switch { switch_id } => Label::SwitchEnd { switch_id },
+            Breakable::Labelled { label_id, .. } => Label::LabelledStatementEnd { label_id },
+            Breakable::Try { .. } | Breakable::Iterator { .. } => {
+                unreachable!("Try blocks and iterators cannot be the target of a break")
             }
-            Breakable::Switch { switch_id } => {
-                ib.build_jmp(Label::SwitchEnd { switch_id }, false);
+        };
+
+        // Breaking out of a for..of loop also closes its iterator, which is registered right before the loop
+        let exit_index = match index.checked_sub(1).map(|i| &breakables[i]) {
+            Some(Breakable::Iterator { .. }) => index - 1,
+            _ => index + 1,
+        };
+        self.exit_breakables(exit_index)?;
+        InstructionBuilder::new(self).build_jmp(target, false);
+        Ok(())
+    }
+
+    fn visit_continue(&mut self, label: Option<&'a str>) -> Result<(), CompileError> {
+        let breakables = &self.current_function().breakables;
+        let is_loop = |b: &Breakable<'_>| matches!(b, Breakable::Loop { .. });
+
+        let index = match label {
+            // Only loops can be continued, so a labelled continue refers to the first loop following the label.
+            // Anything in between can only be other labels of that same loop.
+            Some(label) => {
+                let index = breakables
+                    .iter()
+                    .rposition(|b| matches!(b, Breakable::Labelled { name, .. } if *name == label))
+                    .ok_or_else(|| CompileError::UndefinedLabel(label.into()))?;
+
+                match breakables[index] {
+                    Breakable::Labelled { is_loop: true, .. } => {
+                        breakables[index..].iter().position(is_loop).map(|i| index + i)
+                    }
+                    _ => None,
+                }
             }
+            // Unlabelled continues skip over switch statements and labelled blocks
+            None => breakables.iter().rposition(is_loop),
         }
+        .ok_or(CompileError::IllegalContinue)?;
+
+        let Breakable::Loop { loop_id } = breakables[index] else {
+            unreachable!("Continue target must be a loop")
+        };
+
+        self.exit_breakables(index + 1)?;
+        InstructionBuilder::new(self).build_jmp(Label::LoopIncrement { loop_id }, false);
         Ok(())
     }
 
-    fn visit_continue(&mut self) -> Result<(), CompileError> {
+    fn visit_labelled(&mut self, label: &'a str, stmt: Box<Statement<'a>>) -> Result<(), CompileError> {
         let mut ib = InstructionBuilder::new(self);
-        let breakable = *ib
-            .current_function_mut()
+
+        // A label cannot be nested in a statement with the same label
+        if ib
+            .current_function()
             .breakables
-            .last()
-            .ok_or(CompileError::IllegalBreak)?;
-        match breakable {
-            Breakable::Loop { loop_id } => {
-                ib.build_jmp(Label::LoopIncrement { loop_id }, false);
-            }
-            Breakable::Switch { .. } => {
-                // TODO: make it possible to use `continue` in loops even if its used in a switch
-                unimplementedc!("`continue` used inside of a switch statement");
-            }
+            .iter()
+            .any(|b| matches!(b, Breakable::Labelled { name, .. } if *name == label))
+        {
+            return Err(CompileError::DuplicateLabel(label.into()));
+        }
+
+        let mut inner = &*stmt;
+        while let Statement::Labelled(_, stmt) = inner {
+            inner = stmt;
         }
+        let is_loop = matches!(inner, Statement::Loop(..));
+
+        let label_id = ib.current_function_mut().prepare_labelled_statement(label, is_loop);
+        ib.accept(*stmt)?;
+        ib.current_function_mut()
+            .add_global_label(Label::LabelledStatementEnd { label_id });
+        ib.current_function_mut().exit_labelled_statement();
+
         Ok(())
     }
 
@@ -1756,10 +2792,6 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
     }
 
     fn visit_class_declaration(&mut self, class: Class<'a>) -> Result<(), CompileError> {
-        if class.extends.is_some() {
-            unimplementedc!("Extending class");
-        }
-
         let mut ib = InstructionBuilder::new(self);
 
         let constructor = class.members.iter().find_map(|member| {
@@ -1781,7 +2813,79 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
                 .add_local("DesugaredClass", VariableDeclarationKind::Unnameable, None)?,
         };
 
-        let (parameters, mut statements, id) = match constructor {
+        // The superclass is evaluated before the class binding is initialized
+        let superclass = match class.extends.clone() {
+            Some(extends) => {
+                let id = ib
+                    .current_scope_mut()
+                    .add_local("superclass", VariableDeclarationKind::Unnameable, None)?;
+
+                ib.accept_expr(extends)?;
+                ib.build_local_store(AssignKind::Assignment, id, false);
+                ib.build_pop();
+                Some(id)
+            }
+            None => None,
+        };
+
+        // Every evaluation of a class creates new private names, so that instances of different evaluations
+        // of the same class cannot access each others private members
+        let mut private_names: Vec<(&str, u16)> = Vec::new();
+        for member in &class.members {
+            let name = match member.name() {
+                Some(name) if member.private => name,
+                _ => continue,
+            };
+
+            // A getter and a setter share the same private name
+            if private_names.iter().any(|&(n, _)| n == name) {
+                continue;
+            }
+
+            let id = ib
+                .current_scope_mut()
+                .add_local("private_name", VariableDeclarationKind::Unnameable, None)?;
+
+            ib.build_private_name(&format!("#{name}"))?;
+            ib.build_local_store(AssignKind::Assignment, id, false);
+            ib.build_pop();
+            private_names.push((name, id));
+        }
+
+        // Private methods of instances are created once and defined on every instance by the constructor
+        let mut private_methods = Vec::new();
+        for member in &class.members {
+            if let ClassMember {
+                private: true,
+                static_: false,
+                kind,
+            } = member
+            {
+                let (name, kind) = match kind {
+                    ClassMemberKind::Method(method) => (method.name, PropertyDefinitionKind::Method),
+                    ClassMemberKind::Getter(method) => (method.name, PropertyDefinitionKind::Getter),
+                    ClassMemberKind::Setter(method) => (method.name, PropertyDefinitionKind::Setter),
+                    _ => continue,
+                };
+
+                let id =
+                    ib.current_scope_mut()
+                        .add_local("private_method", VariableDeclarationKind::Unnameable, None)?;
+                private_methods.push((name.expect("Class method did not have a name"), kind, id));
+            }
+        }
+
+        let fields = class
+            .members
+            .iter()
+            .filter_map(|member| match &member.kind {
+                ClassMemberKind::Property(property) if !member.static_ => Some((property.clone(), member.private)),
+                _ => None,
+            })
+            .collect();
+
+        let has_constructor = constructor.is_some();
+        let (mut parameters, mut statements, id) = match constructor {
             Some(fun) => (fun.parameters, fun.statements, fun.id),
             None => {
                 let parent = ib.current_function().id;
@@ -1789,7 +2893,49 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
             }
         };
 
-        transformations::insert_initializer_in_constructor(&class, &mut statements);
+        let methods = class
+            .members
+            .iter()
+            .filter_map(|member| match &member.kind {
+                ClassMemberKind::Method(method)
+                | ClassMemberKind::Getter(method)
+                | ClassMemberKind::Setter(method)
+                | ClassMemberKind::StaticBlock(method) => Some((method.id, member.static_)),
+                // Initializers of static fields are functions too
+                ClassMemberKind::Property(ClassProperty {
+                    value: Some(Expr::Function(init)),
+                    ..
+                }) if member.static_ => Some((init.id, true)),
+                ClassMemberKind::Property(_) => None,
+            })
+            .collect();
+
+        let owner = ib.current_function().id;
+        ib.class_stack.push(ClassState {
+            owner,
+            superclass,
+            private_names,
+            constructor: id,
+            methods,
+            private_methods: private_methods.clone(),
+            fields,
+        });
+
+        if superclass.is_some() && !has_constructor {
+            // The implicit constructor forwards all arguments: `constructor(...args) { super(...args); }`
+            ib.tcx
+                .scope_mut(id)
+                .add_local("args", VariableDeclarationKind::Var, None)?;
+
+            parameters.push((Parameter::Spread("args"), None, None));
+            statements.push(Statement::Expression(Expr::Call(FunctionCall {
+                constructor_call: false,
+                target: Box::new(Expr::identifier(Cow::Borrowed("super"), None)),
+                arguments: vec![CallArgumentKind::Spread(Expr::identifier(Cow::Borrowed("args"), None))],
+                loc: None,
+                optional: false,
+            })));
+        }
 
         let desugared_class = FunctionDeclaration {
             id,
@@ -1807,33 +2953,90 @@ impl<'a> Visitor<'a, Result<(), CompileError>> for FunctionCompiler<'a> {
         ))?;
         let load_class_binding = Expr::Compiled(compile_local_load(binding_id, false));
 
+        if superclass.is_some() {
+            ib.accept_expr(load_class_binding.clone())?;
+            ib.build_superclass_load(owner)?;
+            ib.build_extends();
+            ib.build_pop();
+        }
+
+        // Methods and accessors are defined first, static fields and static blocks are evaluated afterwards in order
+        let mut private_methods = private_methods.into_iter();
+        let mut static_elements = Vec::new();
         for member in class.members {
-            if let ClassMemberKind::Method(method) = member.kind {
-                let name = method.name.expect("Class method did not have a name");
-
-                ib.accept(Statement::Expression(Expr::Assignment(AssignmentExpr {
-                    left: AssignmentTarget::Expr(match member.static_ {
-                        true => Box::new(Expr::PropertyAccess(PropertyAccessExpr {
-                            computed: false,
-                            property: Box::new(Expr::string_literal(Cow::Borrowed(name))),
-                            target: Box::new(load_class_binding.clone()),
-                        })),
-                        false => Box::new(Expr::PropertyAccess(PropertyAccessExpr {
-                            computed: false,
-                            property: Box::new(Expr::string_literal(Cow::Borrowed(name))),
-                            target: Box::new(Expr::PropertyAccess(PropertyAccessExpr {
-                                computed: false,
-                                property: Box::new(Expr::string_literal(Cow::Borrowed("prototype"))),
-                                target: Box::new(load_class_binding.clone()),
-                            })),
-                        })),
-                    }),
-                    operator: TokenType::Assignment,
-                    right: Box::new(Expr::Function(method)),
-                })))?
+            if member.as_constructor().is_some() {
+                continue;
+            }
+
+            let (method, kind) = match member.kind {
+                ClassMemberKind::Method(method) => (method, PropertyDefinitionKind::Method),
+                ClassMemberKind::Getter(method) => (method, PropertyDefinitionKind::Getter),
+                ClassMemberKind::Setter(method) => (method, PropertyDefinitionKind::Setter),
+                ClassMemberKind::StaticBlock(..) => {
+                    static_elements.push(member);
+                    continue;
+                }
+                ClassMemberKind::Property(..) => {
+                    // Instance fields are defined by the constructor
+                    if member.static_ {
+                        static_elements.push(member);
+                    }
+                    continue;
+                }
+            };
+            let name = method.name.expect("Class method did not have a name");
+
+            if member.private && !member.static_ {
+                let (_, _, id) = private_methods.next().expect("Missing private method local");
+                ib.accept_expr(Expr::Function(method))?;
+                ib.build_local_store(AssignKind::Assignment, id, false);
+                ib.build_pop();
+                continue;
+            }
+
+            ib.accept_expr(load_class_binding.clone())?;
+            if !member.static_ {
+                ib.build_static_prop_access("prototype", false)?;
+            }
+            match member.private {
+                true => ib.build_private_name_load(name)?,
+                false => ib.build_constant(Constant::String(name.into()))?,
+            }
+            ib.accept_expr(Expr::Function(method))?;
+            ib.build_define_property(kind);
+        }
+
+        for member in static_elements {
+            match member.kind {
+                ClassMemberKind::Property(ClassProperty { name, value }) => {
+                    ib.accept_expr(load_class_binding.clone())?;
+                    match member.private {
+                        true => ib.build_private_name_load(name)?,
+                        false => ib.build_constant(Constant::String(name.into()))?,
+                    }
+                    match value {
+                        // The initializer is wrapped in a function, which is called with the class as `this`
+                        Some(init) => {
+                            ib.accept_expr(load_class_binding.clone())?;
+                            ib.accept_expr(init)?;
+                            ib.build_call(FunctionCallMetadata::new(false, true, false), 0);
+                        }
+                        None => ib.build_constant(Constant::Undefined)?,
+                    }
+                    ib.build_define_property(PropertyDefinitionKind::Field);
+                }
+                ClassMemberKind::StaticBlock(block) => {
+                    ib.accept_expr(load_class_binding.clone())?;
+                    ib.accept_expr(Expr::Function(block))?;
+                    ib.build_call(FunctionCallMetadata::new(false, true, false), 0);
+                    ib.build_pop();
+                }
+                _ => unreachable!("Not a static element"),
             }
         }
 
+        ib.class_stack.pop();
+
         Ok(())
     }
 
@@ -190,11 +190,13 @@ impl<'buf> FunctionDecompiler<'buf> {
                 Instruction::StoreLocalW => self.handle_inc_op_instr2("storelocalw")?,
                 Instruction::Call => {
                     let meta = FunctionCallMetadata::from(self.read()?);
+                    let argc = self.read_u16()?;
                     self.handle_op_map_instr(
                         "call",
                         &[
-                            ("argc", &meta.value()),
+                            ("argc", &argc),
                             ("is_constructor_call", &meta.is_constructor_call()),
+                            ("is_spread_call", &meta.is_spread_call()),
                         ],
                     );
                 }
@@ -244,6 +246,9 @@ impl<'buf> FunctionDecompiler<'buf> {
                             ObjectMemberKind::Dynamic => {
                                 props.push(String::from("<dynamic>"));
                             }
+                            ObjectMemberKind::Spread => {
+                                props.push(String::from("<spread>"));
+                            }
                             ObjectMemberKind::Static | ObjectMemberKind::Getter | ObjectMemberKind::Setter => {
                                 let cid = self.read()?;
                                 props.push(DisplayConstant(&self.constants[cid as usize]).to_string());
@@ -294,6 +299,19 @@ impl<'buf> FunctionDecompiler<'buf> {
                 Instruction::Debugger => self.handle_opless_instr("debugger"),
                 Instruction::Global => self.handle_opless_instr("global"),
                 Instruction::Super => self.handle_opless_instr("super"),
+                Instruction::Extends => self.handle_opless_instr("extends"),
+                Instruction::ArrayPush => self.handle_opless_instr("arraypush"),
+                Instruction::ArraySpread => self.handle_opless_instr("arrayspread"),
+                Instruction::ObjRest => self.handle_incw_op_instr("objrest")?,
+                Instruction::IterDestruct => {
+                    let count = self.read_u16()?;
+                    let has_rest = self.read()? == 1;
+                    self.handle_op_map_instr("iterdestruct", &[("count", &count), ("has_rest", &has_rest)])
+                }
+                Instruction::PrivateName => self.handle_incw_op_instr("privatename")?,
+                Instruction::DefineProperty => self.handle_inc_op_instr("defineproperty")?,
+                Instruction::TemplateStrings => self.handle_incw_op_instr("templatestrings")?,
+                Instruction::SuperPropAccess => self.handle_opless_instr("superpropaccess"),
                 Instruction::Undef => self.handle_opless_instr("undef"),
                 Instruction::Break => self.handle_opless_instr("break"),
                 Instruction::Await => self.handle_opless_instr("await"),
@@ -378,6 +396,7 @@ impl<'buf> FunctionDecompiler<'buf> {
                 }
                 Instruction::CallSymbolIterator => self.handle_opless_instr("@@iterator"),
                 Instruction::CallForInIterator => self.handle_opless_instr("@@forInIterator"),
+                Instruction::CallAsyncIterator => self.handle_opless_instr("@@asyncIterator"),
                 Instruction::DeletePropertyStatic => self.handle_incw_op_instr("deletepropertystatic")?,
                 Instruction::DeletePropertyDynamic => self.handle_opless_instr("deletepropertydynamic"),
                 Instruction::Switch => {
@@ -394,21 +413,6 @@ impl<'buf> FunctionDecompiler<'buf> {
 
                     self.handle_op_map_instr("switch", &[("case_count", &case_count), ("has_default", &has_default)])
                 }
-                Instruction::ObjDestruct => {
-                    let count = self.read_u16()?;
-                    for _ in 0..count {
-                        self.read_u16()?; // discard var id
-                        self.read_u16()?; // discard property name id
-                    }
-                    self.handle_op_map_instr("objdestruct", &[("count", &count)])
-                }
-                Instruction::ArrayDestruct => {
-                    let count = self.read_u16()?;
-                    for _ in 0..count {
-                        self.read_u16()?; // discard var id
-                    }
-                    self.handle_op_map_instr("arraydestruct", &[("count", &count)])
-                }
                 Instruction::Nop => self.handle_opless_instr("nop"),
             }
         }
@@ -435,13 +439,14 @@ impl fmt::Display for DisplayConstant<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0 {
             Constant::Number(n) => write!(f, "{n}"),
+            Constant::BigInt(n) => write!(f, "{n}n"),
             Constant::String(s) => write!(f, "\"{s}\""),
             Constant::Boolean(b) => write!(f, "{b}"),
             Constant::Identifier(ident) => write!(f, "{ident}"),
             Constant::Function(fun) => write!(f, "<function {}>", fun.name.as_deref().unwrap_or("<anonymous>")),
             Constant::Null => f.write_str("null"),
             Constant::Undefined => f.write_str("undefined"),
-            Constant::Regex(_, source) => write!(f, "{source}"),
+            Constant::Regex(_, flags, source) => write!(f, "/{source}/{flags}"),
         }
     }
 }
@@ -295,6 +295,11 @@ impl<'a> Lexer<'a> {
             }
         }
 
+        if self.current() == Some(b'n') {
+            self.advance();
+            return self.create_contextified_token(TokenType::NumberBigInt);
+        }
+
         self.create_contextified_token(ty);
     }
 
@@ -336,6 +341,12 @@ impl<'a> Lexer<'a> {
             self.advance();
         }
 
+        // BigInt literals cannot have a fraction or an exponent
+        if !is_float && !is_exp && self.current() == Some(b'n') {
+            self.advance();
+            return self.create_contextified_token(TokenType::NumberBigInt);
+        }
+
         self.create_contextified_token(TokenType::NumberDec)
     }
 
@@ -361,6 +372,12 @@ impl<'a> Lexer<'a> {
                 }
             }
 
+            // The escaped character is kept in the raw lexeme, but must not end the segment
+            if cur == b'\\' && self.peek().is_some() {
+                self.advance();
+            }
+
+            let cur = self.current_real();
             if cur == b'\n' {
                 self.line += 1;
                 self.line_idx = self.idx;
@@ -401,17 +418,25 @@ impl<'a> Lexer<'a> {
     /// Reads a regex literal, assuming the current cursor is one byte ahead of the `/`
     fn read_regex_literal(&mut self) {
         // No real regex parsing here, we only skip to the end of the regex literal here.
+        let mut in_class = false;
         while !self.is_eof() {
             let c = self.next_char().unwrap();
-            if c == b'/' {
-                // End of regex literal
-                break;
-            } else if c == b'\\' {
+            match c {
+                // End of regex literal. A slash inside of a character class does not end it
+                b'/' if !in_class => break,
+                b'[' => in_class = true,
+                b']' => in_class = false,
                 // Skip escaped character
-                self.advance();
+                b'\\' => self.advance(),
+                _ => {}
             }
         }
 
+        // Flags following the literal are part of the token
+        while self.current().is_some_and(util::is_alpha) {
+            self.advance();
+        }
+
         let lexeme = self.get_lexeme();
         self.create_contextified_token_with_lexeme(TokenType::RegexLiteral, Cow::Borrowed(lexeme));
     }
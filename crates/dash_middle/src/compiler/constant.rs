@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
 #[cfg(feature = "format")]
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,7 @@ use crate::parser::expr::LiteralExpr;
 use crate::parser::statement::FunctionKind;
 
 use super::external::External;
+use super::line_table::LineTable;
 
 #[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -24,6 +26,11 @@ pub struct Function {
     pub r#async: bool,
     /// If the parameter list uses the rest operator ..., then this will be Some(local_id)
     pub rest_local: Option<u16>,
+    /// Whether this function is the constructor of a derived class,
+    /// in which case `this` is uninitialized until `super()` is called
+    pub is_derived_ctor: bool,
+    /// Source positions of the instructions in `buffer`
+    pub line_table: LineTable,
     // JIT-poisoned code regions (instruction pointers)
     // TODO: refactor this a bit so this isn't "visible" to e.g. the bytecode compiler with builder pattern
     pub poison_ips: RefCell<HashSet<usize>>,
@@ -43,11 +50,12 @@ impl Function {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Constant {
     Number(f64),
+    BigInt(Rc<BigInt>),
     String(Rc<str>),
     Identifier(Rc<str>),
     Boolean(bool),
     Function(Rc<Function>),
-    Regex(dash_regex::Regex, Rc<str>),
+    Regex(dash_regex::ParsedRegex, dash_regex::Flags, Rc<str>),
     Null,
     Undefined,
 }
@@ -84,12 +92,13 @@ impl Constant {
     pub fn from_literal(expr: &LiteralExpr<'_>) -> Self {
         match expr {
             LiteralExpr::Number(n) => Self::Number(*n),
-            LiteralExpr::Identifier(s) => Self::Identifier(s.as_ref().into()),
+            LiteralExpr::BigInt(n) => Self::BigInt(Rc::new(n.clone())),
+            LiteralExpr::Identifier(s, _) => Self::Identifier(s.as_ref().into()),
             LiteralExpr::String(s) => Self::String(s.as_ref().into()),
             LiteralExpr::Boolean(b) => Self::Boolean(*b),
             LiteralExpr::Null => Self::Null,
             LiteralExpr::Undefined => Self::Undefined,
-            LiteralExpr::Regex(regex, source) => Self::Regex(regex.clone(), (*source).into()),
+            LiteralExpr::Regex(regex, flags, source) => Self::Regex(regex.clone(), *flags, (*source).into()),
         }
     }
 }
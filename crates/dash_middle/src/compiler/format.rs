@@ -1,6 +1,6 @@
 use super::CompileResult;
 
-const BYTECODE_VERSION: u32 = 2;
+const BYTECODE_VERSION: u32 = 11;
 
 pub fn serialize(cr: CompileResult) -> bincode::Result<Vec<u8>> {
     let mut buffer = BYTECODE_VERSION.to_le_bytes().to_vec();
@@ -15,7 +15,9 @@ pub enum DeserializeError {
 }
 
 pub fn deserialize(buf: &[u8]) -> Result<CompileResult, DeserializeError> {
-    let bytes = buf.try_into().map_err(|_| DeserializeError::InvalidVersion)?;
+    let bytes = buf[..buf.len().min(4)]
+        .try_into()
+        .map_err(|_| DeserializeError::InvalidVersion)?;
     let version = u32::from_le_bytes(bytes);
 
     if version != BYTECODE_VERSION {
@@ -47,6 +47,9 @@ pub enum Instruction {
     ObjLitW,
     This,
     StaticPropAssign,
+    /// Assigns to a computed property: `[target, key, value] -> [result]`
+    ///
+    /// Increments and decrements have no value operand
     DynamicPropAssign,
     /// Loads an external variable
     LdLocalExt,
@@ -59,6 +62,10 @@ pub enum Instruction {
     Try,
     TryEnd,
     Throw,
+    /// Suspends the current generator: `[value] -> [sent value]`
+    ///
+    /// The compiler always emits a `Jmp` right after it, which skips the code that handles generators being
+    /// resumed with a return completion. Such a resumption continues [`YIELD_RETURN_OFFSET`] bytes after the yield.
     Yield,
     JmpFalseNP,
     JmpTrueP,
@@ -81,6 +88,8 @@ pub enum Instruction {
     ExportNamed,
     Debugger,
     Global,
+    /// Calls the super constructor of a derived class with an array of arguments,
+    /// initializing the `this` binding of the current frame
     Super,
     Undef,
     Break,
@@ -90,11 +99,36 @@ pub enum Instruction {
     IntrinsicOp,
     CallSymbolIterator,
     CallForInIterator,
+    /// Gets the async iterator of a value for `for await..of` loops, falling back to
+    /// an async wrapper around its sync iterator: `[value] -> [iterator]`
+    CallAsyncIterator,
     DeletePropertyStatic,
     DeletePropertyDynamic,
     Switch,
-    ObjDestruct,
-    ArrayDestruct,
+    /// Sets up the prototype chain of a derived class: `[class, superclass] -> [class]`
+    Extends,
+    /// Appends a value to an array literal: `[array, value] -> [array]`
+    ArrayPush,
+    /// Appends all values of an iterator to an array literal: `[array, iterator] -> [array]`
+    ArraySpread,
+    /// Copies all properties of an object except for the given keys into a new object,
+    /// used for the rest element of object patterns: `[object, key1, ..., keyN] -> [rest]`
+    ObjRest,
+    /// Takes the values for an array pattern from an iterator, pushing them in reverse order
+    /// so that the first value is on top: `[iterator] -> [rest?, valueN, ..., value1]`
+    IterDestruct,
+    /// Creates a new private name for a `#name` declared in a class, followed by the u16 index
+    /// of its name in the constant pool: `[] -> [private name]`
+    PrivateName,
+    /// Defines a member of a class on an object, followed by a u8 [`PropertyDefinitionKind`](super::PropertyDefinitionKind):
+    /// `[object, key, value] -> []`
+    DefineProperty,
+    /// Creates the frozen strings array of a tagged template literal with its `raw` property,
+    /// followed by the u16 number of strings: `[cooked1, ..., cookedN, raw1, ..., rawN] -> [strings]`
+    TemplateStrings,
+    /// Reads a property of the home object of a method, used for `super.property`.
+    /// Accessors are called with the given `this` value instead of the home object: `[this, home, key] -> [value]`
+    SuperPropAccess,
     // Nop exists solely for the sake of benchmarking the raw throughput of the VM dispatch loop
     Nop,
 }
@@ -104,6 +138,10 @@ pub enum Instruction {
 pub const POP: u8 = Instruction::Pop as u8;
 pub const RET: u8 = Instruction::Ret as u8;
 
+/// The number of bytes between a `Yield` and the code that handles a generator being resumed with a return completion,
+/// i.e. the size of the `Jmp` instruction that skips it
+pub const YIELD_RETURN_OFFSET: usize = 3;
+
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromRepr)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -0,0 +1,56 @@
+#[cfg(feature = "format")]
+use serde::{Deserialize, Serialize};
+
+use crate::lexer::token::Location;
+
+/// A position in the source code, with a 1-based line and column
+#[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl From<Location> for SourcePosition {
+    fn from(loc: Location) -> Self {
+        Self {
+            line: loc.line as u32,
+            column: loc.column() as u32,
+        }
+    }
+}
+
+/// Maps instruction pointers of a function to the source position they were compiled from
+///
+/// Entries are sorted by instruction pointer and only added when the position changes,
+/// so an instruction belongs to the closest entry at or before its instruction pointer.
+#[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LineTable(Vec<(u32, SourcePosition)>);
+
+impl LineTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the instructions starting at `ip` were compiled from `position`
+    pub fn add(&mut self, ip: usize, position: SourcePosition) {
+        let ip = ip as u32;
+
+        match self.0.last_mut() {
+            Some((_, last)) if *last == position => {}
+            Some((last_ip, last)) if *last_ip == ip => *last = position,
+            _ => self.0.push((ip, position)),
+        }
+    }
+
+    /// Returns the source position of the instruction at `ip`
+    pub fn lookup(&self, ip: usize) -> Option<SourcePosition> {
+        let index = self.0.partition_point(|&(entry_ip, _)| entry_ip as usize <= ip);
+        index.checked_sub(1).map(|index| self.0[index].1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -4,6 +4,7 @@ use crate::parser;
 
 use self::constant::ConstantPool;
 use self::external::External;
+use self::line_table::LineTable;
 
 #[cfg(feature = "format")]
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,7 @@ pub mod external;
 pub mod format;
 pub mod instruction;
 pub mod instruction_iter;
+pub mod line_table;
 pub mod scope;
 
 #[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
@@ -22,13 +24,16 @@ pub struct CompileResult {
     pub cp: ConstantPool,
     pub locals: usize,
     pub externals: Vec<External>,
+    pub line_table: LineTable,
 }
 
 /// Function call metadata
 ///
 /// Highest bit = set if constructor call
 /// 2nd highest bit = set if object call
-/// remaining 6 bits = number of arguments
+/// 3rd highest bit = set if the arguments are passed as a single array (i.e. the call has spread arguments)
+///
+/// The argument count is not part of the metadata, and is encoded separately as a u16.
 #[repr(transparent)]
 pub struct FunctionCallMetadata(u8);
 
@@ -45,24 +50,22 @@ impl From<FunctionCallMetadata> for u8 {
 }
 
 impl FunctionCallMetadata {
-    pub fn new_checked(mut value: u8, constructor: bool, object: bool) -> Option<Self> {
-        if value & 0b11000000 == 0 {
-            if constructor {
-                value |= 0b10000000;
-            }
-
-            if object {
-                value |= 0b01000000;
-            }
-
-            Some(Self(value))
-        } else {
-            None
+    pub fn new(constructor: bool, object: bool, spread: bool) -> Self {
+        let mut value = 0;
+
+        if constructor {
+            value |= 0b10000000;
+        }
+
+        if object {
+            value |= 0b01000000;
+        }
+
+        if spread {
+            value |= 0b00100000;
         }
-    }
 
-    pub fn value(&self) -> u8 {
-        self.0 & !0b11000000
+        Self(value)
     }
 
     pub fn is_constructor_call(&self) -> bool {
@@ -72,6 +75,10 @@ impl FunctionCallMetadata {
     pub fn is_object_call(&self) -> bool {
         self.0 & (1 << 6) != 0
     }
+
+    pub fn is_spread_call(&self) -> bool {
+        self.0 & (1 << 5) != 0
+    }
 }
 
 #[repr(u8)]
@@ -88,6 +95,17 @@ pub enum ObjectMemberKind {
     Setter,
     Static,
     Dynamic,
+    Spread,
+}
+
+/// The kind of class member that is defined by [`Instruction::DefineProperty`](instruction::Instruction::DefineProperty)
+#[repr(u8)]
+#[derive(Debug, FromRepr, Clone, Copy)]
+pub enum PropertyDefinitionKind {
+    Field,
+    Method,
+    Getter,
+    Setter,
 }
 
 use parser::expr::ObjectMemberKind as ParserObjectMemberKind;
@@ -99,6 +117,7 @@ impl From<&ParserObjectMemberKind<'_>> for ObjectMemberKind {
             ParserObjectMemberKind::Getter(..) => Self::Getter,
             ParserObjectMemberKind::Setter(..) => Self::Setter,
             ParserObjectMemberKind::Static(..) => Self::Static,
+            ParserObjectMemberKind::Spread => Self::Spread,
         }
     }
 }
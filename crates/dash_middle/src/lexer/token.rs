@@ -202,6 +202,9 @@ pub enum TokenType {
     /// Number: 42
     NumberDec,
 
+    /// BigInt: 42n, 0xffn
+    NumberBigInt,
+
     /// Regex literal: /a+b/g
     RegexLiteral,
 
@@ -271,6 +274,10 @@ pub enum TokenType {
     #[display(fmt = "yield")]
     Yield,
 
+    /// Delegating yield: yield* foo
+    #[display(fmt = "yield*")]
+    YieldStar,
+
     #[display(fmt = "new")]
     New,
 
@@ -470,6 +477,20 @@ pub struct Location {
     pub line_offset: usize,
 }
 
+impl Location {
+    /// Returns the 1-based column of this location
+    pub fn column(&self) -> usize {
+        // `line_offset` points at the newline character of the previous line, except for the first line
+        let line_start = if self.line <= 1 {
+            self.line_offset
+        } else {
+            self.line_offset + 1
+        };
+
+        self.offset - line_start + 1
+    }
+}
+
 pub struct FormattableError<'a, 'b> {
     pub loc: &'a Location,
     pub source: &'a [u8],
@@ -487,10 +508,8 @@ impl<'a, 'b> fmt::Display for FormattableError<'a, 'b> {
             self.loc.line_offset + 1
         };
 
-        let column = self.loc.offset - offset;
-
         writeln!(f, "error: {}", self.message)?;
-        write!(f, "--> script.js:{}:{}\n\n", self.loc.line, column)?;
+        write!(f, "--> script.js:{}:{}\n\n", self.loc.line, self.loc.column())?;
 
         let line = {
             let partial = &self.source[offset..];
@@ -3,6 +3,7 @@ use std::fmt;
 use std::num::ParseIntError;
 
 use either::Either;
+use num_bigint::ParseBigIntError;
 
 use crate::lexer::token::FormattableError;
 use crate::lexer::token::Location;
@@ -22,6 +23,8 @@ pub enum ErrorKind<'a> {
     UnexpectedEof,
     /// Integer parsing failed
     ParseIntError(Token<'a>, ParseIntError),
+    /// BigInt literal parsing failed
+    ParseBigIntError(Token<'a>, ParseBigIntError),
     /// More than one default clause in a switch statement
     MultipleDefaultInSwitch(Token<'a>),
     InvalidAccessorParams {
@@ -31,6 +34,8 @@ pub enum ErrorKind<'a> {
     },
     MultipleRestInDestructuring(Token<'a>),
     RegexSyntaxError(Token<'a>, dash_regex::Error),
+    /// An invalid escape sequence in an untagged template literal
+    InvalidEscapeSequence(Token<'a>),
 }
 
 /// An error that occurred during parsing
@@ -60,6 +65,7 @@ impl fmt::Display for Error<'_> {
                 ))),
             ),
             ErrorKind::ParseIntError(tok, err) => (tok, "int parsing failed", Some(Box::new(err))),
+            ErrorKind::ParseBigIntError(tok, err) => (tok, "bigint parsing failed", Some(Box::new(err))),
             ErrorKind::UnexpectedEof => (
                 &Token {
                     full: Cow::Borrowed(""),
@@ -87,6 +93,13 @@ impl fmt::Display for Error<'_> {
                 (tok, "a rest element must be last in a destructuring pattern", None)
             }
             ErrorKind::RegexSyntaxError(tok, err) => (tok, "regex parse error", Some(Box::new(err))),
+            ErrorKind::InvalidEscapeSequence(tok) => (
+                tok,
+                "invalid escape sequence in template literal",
+                Some(Box::new(
+                    "invalid escape sequences are only allowed in tagged templates",
+                )),
+            ),
         };
 
         let format_err = FormattableError {
@@ -4,10 +4,11 @@ use std::{
 };
 
 use derive_more::Display;
+use num_bigint::{BigInt, Sign};
 
-use crate::lexer::token::TokenType;
+use crate::lexer::token::{Location, TokenType};
 
-use super::statement::{fmt_list, FunctionDeclaration};
+use super::statement::{fmt_list, FunctionDeclaration, Pattern};
 
 /// The sequence operator (`expr, expr`)
 pub type Seq<'a> = (Box<Expr<'a>>, Box<Expr<'a>>);
@@ -52,6 +53,17 @@ pub enum Expr<'a> {
     Array(ArrayLiteral<'a>),
     /// An object literal expression
     Object(ObjectLiteral<'a>),
+    /// An optional chain, i.e. `foo?.bar.baz`
+    ///
+    /// Evaluates to undefined if any of its optional links (`?.`) is applied to a nullish value
+    OptionalChain(Box<Expr<'a>>),
+    /// A private name, i.e. `#foo`
+    ///
+    /// This is only valid as the property of a property access expression or on the left side of `in`
+    #[display(fmt = "#{_0}")]
+    PrivateName(&'a str),
+    /// The strings array of a tagged template literal, which is passed to the tag function as its first argument
+    TemplateStrings(TemplateStrings<'a>),
     /// Compiled bytecode
     #[display(fmt = "<compiled>")]
     Compiled(Vec<u8>),
@@ -61,8 +73,8 @@ pub enum Expr<'a> {
 
 impl<'a> Expr<'a> {
     /// Creates a binary expression
-    pub fn binary(l: Expr<'a>, r: Expr<'a>, op: TokenType) -> Self {
-        Self::Binary(BinaryExpr::new(l, r, op))
+    pub fn binary(l: Expr<'a>, r: Expr<'a>, op: TokenType, loc: Option<Location>) -> Self {
+        Self::Binary(BinaryExpr::new(l, r, op, loc))
     }
 
     /// Creates a grouping expression
@@ -90,12 +102,17 @@ impl<'a> Expr<'a> {
         Self::Literal(LiteralExpr::Number(n))
     }
 
+    /// Creates a BigInt literal expression
+    pub fn bigint_literal(n: BigInt) -> Self {
+        Self::Literal(LiteralExpr::BigInt(n))
+    }
+
     /// Creates a string literal expression
     pub fn string_literal(s: Cow<'a, str>) -> Self {
         Self::Literal(LiteralExpr::String(s))
     }
 
-    pub fn array_literal(a: Vec<Expr<'a>>) -> Self {
+    pub fn array_literal(a: Vec<ArrayMemberKind<'a>>) -> Self {
         Self::Array(ArrayLiteral(a))
     }
 
@@ -104,8 +121,8 @@ impl<'a> Expr<'a> {
     }
 
     /// Creates an identifier literal expression
-    pub fn identifier(s: Cow<'a, str>) -> Self {
-        Self::Literal(LiteralExpr::Identifier(s))
+    pub fn identifier(s: Cow<'a, str>, loc: Option<Location>) -> Self {
+        Self::Literal(LiteralExpr::Identifier(s, loc))
     }
 
     /// Creates a null literal expression
@@ -118,16 +135,23 @@ impl<'a> Expr<'a> {
         Self::Literal(LiteralExpr::Undefined)
     }
 
-    pub fn regex_literal(regex: dash_regex::Regex, source: &'a str) -> Self {
-        Self::Literal(LiteralExpr::Regex(regex, source))
+    pub fn regex_literal(regex: dash_regex::ParsedRegex, flags: dash_regex::Flags, source: &'a str) -> Self {
+        Self::Literal(LiteralExpr::Regex(regex, flags, source))
     }
 
     /// Creates a function call expression
-    pub fn function_call(target: Expr<'a>, arguments: Vec<Expr<'a>>, constructor_call: bool) -> Self {
+    pub fn function_call(
+        target: Expr<'a>,
+        arguments: Vec<CallArgumentKind<'a>>,
+        constructor_call: bool,
+        loc: Option<Location>,
+    ) -> Self {
         Self::Call(FunctionCall {
             constructor_call,
             target: Box::new(target),
             arguments,
+            loc,
+            optional: false,
         })
     }
 
@@ -141,11 +165,13 @@ impl<'a> Expr<'a> {
     }
 
     /// Creates a property access expression
-    pub fn property_access(computed: bool, target: Expr<'a>, property: Expr<'a>) -> Self {
+    pub fn property_access(computed: bool, target: Expr<'a>, property: Expr<'a>, loc: Option<Location>) -> Self {
         Self::PropertyAccess(PropertyAccessExpr {
             computed,
             target: Box::new(target),
             property: Box::new(property),
+            loc,
+            optional: false,
         })
     }
 
@@ -203,9 +229,55 @@ impl<'a> Expr<'a> {
         }
     }
 }
+/// An element of an array literal
+#[derive(Debug, Clone)]
+pub enum ArrayMemberKind<'a> {
+    /// A single element, i.e. `expr`
+    Item(Expr<'a>),
+    /// An iterable whose values are spread into the array, i.e. `...expr`
+    Spread(Expr<'a>),
+}
+
+impl<'a> ArrayMemberKind<'a> {
+    pub fn expr(&self) -> &Expr<'a> {
+        match self {
+            Self::Item(expr) | Self::Spread(expr) => expr,
+        }
+    }
+
+    pub fn expr_mut(&mut self) -> &mut Expr<'a> {
+        match self {
+            Self::Item(expr) | Self::Spread(expr) => expr,
+        }
+    }
+
+    pub fn into_expr(self) -> Expr<'a> {
+        match self {
+            Self::Item(expr) | Self::Spread(expr) => expr,
+        }
+    }
+}
+
+impl<'a> fmt::Display for ArrayMemberKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Item(expr) => write!(f, "{expr}"),
+            Self::Spread(expr) => write!(f, "...{expr}"),
+        }
+    }
+}
+
 /// An array literal expression (`[expr, expr]`)
 #[derive(Debug, Clone)]
-pub struct ArrayLiteral<'a>(pub Vec<Expr<'a>>);
+pub struct ArrayLiteral<'a>(pub Vec<ArrayMemberKind<'a>>);
+
+impl<'a> ArrayLiteral<'a> {
+    pub fn has_spread(&self) -> bool {
+        self.0
+            .iter()
+            .any(|member| matches!(member, ArrayMemberKind::Spread(..)))
+    }
+}
 
 impl<'a> fmt::Display for ArrayLiteral<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -215,12 +287,38 @@ impl<'a> fmt::Display for ArrayLiteral<'a> {
     }
 }
 
+/// The strings of a tagged template literal (``tag`a${b}c` ``), in both cooked and raw form
+///
+/// Evaluating this expression multiple times always yields the same (frozen) array
+#[derive(Debug, Clone)]
+pub struct TemplateStrings<'a> {
+    /// The segments with their escape sequences resolved, or `None` if a segment contains an invalid escape sequence
+    pub cooked: Vec<Option<Cow<'a, str>>>,
+    /// The segments as they appear in the source code
+    pub raw: Vec<Cow<'a, str>>,
+}
+
+impl<'a> fmt::Display for TemplateStrings<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (index, raw) in self.raw.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "`{raw}`")?;
+        }
+        write!(f, "]")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ObjectMemberKind<'a> {
     Getter(Cow<'a, str>),
     Setter(Cow<'a, str>),
     Static(&'a str),
     Dynamic(Expr<'a>),
+    /// Copies the own enumerable properties of the value: `{ ...value }`
+    Spread,
 }
 
 impl<'a> fmt::Display for ObjectMemberKind<'a> {
@@ -230,6 +328,7 @@ impl<'a> fmt::Display for ObjectMemberKind<'a> {
             Self::Setter(name) => write!(f, "set {name}"),
             Self::Static(name) => f.write_str(name),
             Self::Dynamic(expr) => write!(f, "[{expr}]"),
+            Self::Spread => f.write_str("..."),
         }
     }
 }
@@ -246,7 +345,10 @@ impl<'a> fmt::Display for ObjectLiteral<'a> {
             if i > 0 {
                 write!(f, ", ")?;
             }
-            write!(f, "{k}: ")?;
+            match k {
+                ObjectMemberKind::Spread => write!(f, "{k}")?,
+                _ => write!(f, "{k}: ")?,
+            }
             write!(f, "{v}")?;
         }
 
@@ -263,14 +365,24 @@ pub struct PropertyAccessExpr<'a> {
     pub target: Box<Expr<'a>>,
     /// The property of the object that is accessed
     pub property: Box<Expr<'a>>,
+    /// Location of the accessed property in the source code, if this expression was parsed from source
+    pub loc: Option<Location>,
+    /// Whether this is an optional property access (`foo?.bar`), which short-circuits the chain if the target is nullish
+    pub optional: bool,
 }
 
 impl<'a> fmt::Display for PropertyAccessExpr<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.target)?;
 
+        if self.optional {
+            write!(f, "?.")?;
+        }
+
         if self.computed {
             write!(f, "[{}]", self.property)?;
+        } else if self.optional {
+            write!(f, "{}", self.property)?;
         } else {
             write!(f, ".{}", self.property)?;
         }
@@ -291,6 +403,53 @@ pub struct ConditionalExpr<'a> {
     pub el: Box<Expr<'a>>,
 }
 
+/// An argument of a function call
+#[derive(Debug, Clone)]
+pub enum CallArgumentKind<'a> {
+    /// A single argument, i.e. `expr`
+    Normal(Expr<'a>),
+    /// An iterable whose values are spread into the argument list, i.e. `...expr`
+    Spread(Expr<'a>),
+}
+
+impl<'a> CallArgumentKind<'a> {
+    pub fn expr(&self) -> &Expr<'a> {
+        match self {
+            Self::Normal(expr) | Self::Spread(expr) => expr,
+        }
+    }
+
+    pub fn expr_mut(&mut self) -> &mut Expr<'a> {
+        match self {
+            Self::Normal(expr) | Self::Spread(expr) => expr,
+        }
+    }
+
+    pub fn into_expr(self) -> Expr<'a> {
+        match self {
+            Self::Normal(expr) | Self::Spread(expr) => expr,
+        }
+    }
+}
+
+impl<'a> From<CallArgumentKind<'a>> for ArrayMemberKind<'a> {
+    fn from(argument: CallArgumentKind<'a>) -> Self {
+        match argument {
+            CallArgumentKind::Normal(expr) => Self::Item(expr),
+            CallArgumentKind::Spread(expr) => Self::Spread(expr),
+        }
+    }
+}
+
+impl<'a> fmt::Display for CallArgumentKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal(expr) => write!(f, "{expr}"),
+            Self::Spread(expr) => write!(f, "...{expr}"),
+        }
+    }
+}
+
 /// A function call expression
 #[derive(Debug, Clone)]
 pub struct FunctionCall<'a> {
@@ -299,12 +458,20 @@ pub struct FunctionCall<'a> {
     /// The target (callee)
     pub target: Box<Expr<'a>>,
     /// Function call arguments
-    pub arguments: Vec<Expr<'a>>,
+    pub arguments: Vec<CallArgumentKind<'a>>,
+    /// Location of the callee in the source code, if this expression was parsed from source
+    pub loc: Option<Location>,
+    /// Whether this is an optional call (`foo?.()`), which short-circuits the chain if the callee is nullish
+    pub optional: bool,
 }
 
 impl<'a> fmt::Display for FunctionCall<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}(", self.target)?;
+        write!(f, "{}", self.target)?;
+        if self.optional {
+            write!(f, "?.")?;
+        }
+        write!(f, "(")?;
         fmt_list(f, &self.arguments, ",")?;
         write!(f, ")")
     }
@@ -317,6 +484,8 @@ pub enum AssignmentTarget<'a> {
     Expr(Box<Expr<'a>>),
     /// Assignment to a local id (i.e. previously allocated stack space)
     LocalId(u16),
+    /// Destructuring assignment: `[a, b] = [b, a]`
+    Pattern(Pattern<'a>),
 }
 
 impl<'a> AssignmentTarget<'a> {
@@ -377,15 +546,18 @@ pub struct BinaryExpr<'a> {
     pub right: Box<Expr<'a>>,
     /// Operator
     pub operator: TokenType,
+    /// Location of the operator in the source code, if this expression was parsed from source
+    pub loc: Option<Location>,
 }
 
 impl<'a> BinaryExpr<'a> {
     /// Creates a new binary expression
-    pub fn new(l: Expr<'a>, r: Expr<'a>, op: TokenType) -> Self {
+    pub fn new(l: Expr<'a>, r: Expr<'a>, op: TokenType, loc: Option<Location>) -> Self {
         Self {
             left: Box::new(l),
             right: Box::new(r),
             operator: op,
+            loc,
         }
     }
 }
@@ -408,16 +580,20 @@ pub enum LiteralExpr<'a> {
     /// Boolean literal
     Boolean(bool),
     // Binding(VariableBinding<'a>),
-    /// Identifier literal (variable lookup)
-    Identifier(Cow<'a, str>),
+    /// Identifier literal (variable lookup), with its location in the source code if parsed from source
+    #[display(fmt = "{_0}")]
+    Identifier(Cow<'a, str>, Option<Location>),
     /// Number literal
     Number(f64),
+    /// BigInt literal
+    #[display(fmt = "{_0}n")]
+    BigInt(BigInt),
     /// String literal, borrowed from input string
     #[display(fmt = "\"{_0}\"")]
     String(Cow<'a, str>),
 
-    #[display(fmt = "/{_1}/")]
-    Regex(dash_regex::Regex, &'a str),
+    #[display(fmt = "/{_2}/{_1}")]
+    Regex(dash_regex::ParsedRegex, dash_regex::Flags, &'a str),
 
     #[display(fmt = "null")]
     Null,
@@ -431,7 +607,7 @@ impl<'a> LiteralExpr<'a> {
     pub fn as_identifier_borrowed(&self) -> Option<&'a str> {
         match self {
             Self::Boolean(b) => Some(b.then(|| "true").unwrap_or("false")),
-            Self::Identifier(Cow::Borrowed(i)) => Some(i),
+            Self::Identifier(Cow::Borrowed(i), _) => Some(i),
             Self::Undefined => Some("undefined"),
             Self::Null => Some("null"),
             Self::String(Cow::Borrowed(s)) => Some(s),
@@ -443,12 +619,13 @@ impl<'a> LiteralExpr<'a> {
     pub fn to_identifier(&self) -> Cow<'a, str> {
         match self {
             Self::Boolean(b) => Cow::Borrowed(b.then(|| "true").unwrap_or("false")),
-            Self::Identifier(ident) => ident.clone(),
+            Self::Identifier(ident, _) => ident.clone(),
             Self::Undefined => Cow::Borrowed("undefined"),
             Self::Null => Cow::Borrowed("null"),
             Self::Number(n) => Cow::Owned(n.to_string()),
+            Self::BigInt(n) => Cow::Owned(n.to_string()),
             Self::String(s) => s.clone(),
-            Self::Regex(_, s) => Cow::Borrowed(*s),
+            Self::Regex(_, _, s) => Cow::Borrowed(*s),
         }
     }
 
@@ -458,8 +635,9 @@ impl<'a> LiteralExpr<'a> {
     pub fn is_truthy(&self) -> Option<bool> {
         match self {
             Self::Boolean(b) => Some(*b),
-            Self::Identifier(_) => None,
+            Self::Identifier(..) => None,
             Self::Number(n) => Some(*n != 0.0),
+            Self::BigInt(n) => Some(n.sign() != Sign::NoSign),
             Self::String(s) => Some(!s.is_empty()),
             Self::Null => Some(false),
             Self::Undefined => Some(false),
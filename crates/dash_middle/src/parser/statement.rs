@@ -4,7 +4,10 @@ use derive_more::Display;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{lexer::token::TokenType, tree::TreeToken};
+use crate::{
+    lexer::token::{Location, TokenType},
+    tree::TreeToken,
+};
 
 use super::{expr::Expr, types::TypeSegment};
 
@@ -28,8 +31,9 @@ pub enum Statement<'a> {
     Return(ReturnStatement<'a>),
     /// Try catch block
     Try(TryCatch<'a>),
-    /// Throw statement
-    Throw(Expr<'a>),
+    /// Throw statement, with the location of the `throw` keyword
+    #[display(fmt = "{_0}")]
+    Throw(Expr<'a>, Location),
     /// Import statement
     Import(ImportKind<'a>),
     /// Export statement
@@ -38,12 +42,15 @@ pub enum Statement<'a> {
     Class(Class<'a>),
     /// A switch statement
     Switch(SwitchStatement<'a>),
-    /// Continue loop statement
-    #[display(fmt = "continue;")]
-    Continue,
-    /// Break loop statement
-    #[display(fmt = "break;")]
-    Break,
+    /// Continue loop statement, optionally with the label of the loop to continue
+    #[display(fmt = "continue{};", "fmt_label(_0)")]
+    Continue(Option<&'a str>),
+    /// Break loop statement, optionally with the label of the statement to break out of
+    #[display(fmt = "break{};", "fmt_label(_0)")]
+    Break(Option<&'a str>),
+    /// A labelled statement: `label: for (;;) {}`
+    #[display(fmt = "{_0}: {_1}")]
+    Labelled(&'a str, Box<Statement<'a>>),
     /// Debugger statement
     #[display(fmt = "debugger;")]
     Debugger,
@@ -55,6 +62,10 @@ pub enum Statement<'a> {
     Empty,
 }
 
+fn fmt_label(label: &Option<&str>) -> String {
+    label.map(|label| format!(" {label}")).unwrap_or_default()
+}
+
 impl<'a> Statement<'a> {
     pub fn enters_scope(&self) -> bool {
         matches!(
@@ -138,21 +149,29 @@ impl<'a> ImportKind<'a> {
 }
 
 /// A catch statement
-#[derive(Debug, Clone, Display)]
-#[display(fmt = "catch ({}) {{ {} }}", "ident.unwrap_or(\"_\")", "body")]
+#[derive(Debug, Clone)]
 pub struct Catch<'a> {
     /// The body of a catch statement
     pub body: Box<Statement<'a>>,
-    /// The identifier of the variable that receives the thrown error
-    pub ident: Option<&'a str>,
+    /// The binding that receives the thrown error, either an identifier or a destructuring pattern
+    pub binding: Option<PatternTarget<'a>>,
 }
 
 impl<'a> Catch<'a> {
     /// Creates a new catch statement
-    pub fn new(body: Statement<'a>, ident: Option<&'a str>) -> Self {
+    pub fn new(body: Statement<'a>, binding: Option<PatternTarget<'a>>) -> Self {
         Self {
             body: Box::new(body),
-            ident,
+            binding,
+        }
+    }
+}
+
+impl<'a> fmt::Display for Catch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.binding {
+            Some(binding) => write!(f, "catch ({binding}) {{ {} }}", self.body),
+            None => write!(f, "catch {{ {} }}", self.body),
         }
     }
 }
@@ -162,16 +181,19 @@ impl<'a> Catch<'a> {
 pub struct TryCatch<'a> {
     /// The body of the try statement
     pub try_: Box<Statement<'a>>,
-    /// Catch statement
-    // TODO: make this optional. a try can exist without catch (try finally)
-    pub catch: Catch<'a>,
+    /// Optional catch statement. A try statement without a catch must have a finally block
+    pub catch: Option<Catch<'a>>,
     /// Optional finally block
     pub finally: Option<Box<Statement<'a>>>,
 }
 
 impl<'a> fmt::Display for TryCatch<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "try {{ {} }} {}", self.try_, self.catch)?;
+        write!(f, "try {{ {} }}", self.try_)?;
+
+        if let Some(catch) = &self.catch {
+            write!(f, " {catch}")?;
+        }
 
         if let Some(finally) = &self.finally {
             write!(f, " finally {{ {finally} }}")?;
@@ -183,7 +205,7 @@ impl<'a> fmt::Display for TryCatch<'a> {
 
 impl<'a> TryCatch<'a> {
     /// Creates a new try catch block
-    pub fn new(try_: Statement<'a>, catch: Catch<'a>, finally: Option<Statement<'a>>) -> Self {
+    pub fn new(try_: Statement<'a>, catch: Option<Catch<'a>>, finally: Option<Statement<'a>>) -> Self {
         Self {
             try_: Box::new(try_),
             catch,
@@ -254,8 +276,7 @@ impl<'a> DoWhileLoop<'a> {
 }
 
 /// A for..of loop
-#[derive(Debug, Clone, Display)]
-#[display(fmt = "for ({binding} of {expr}) {{ {body} }}")]
+#[derive(Debug, Clone)]
 pub struct ForOfLoop<'a> {
     /// The binding of this loop
     pub binding: VariableBinding<'a>,
@@ -263,6 +284,18 @@ pub struct ForOfLoop<'a> {
     pub expr: Expr<'a>,
     /// The body of this loop
     pub body: Box<Statement<'a>>,
+    /// Whether this is a `for await..of` loop
+    pub r#async: bool,
+}
+
+impl<'a> fmt::Display for ForOfLoop<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.r#async {
+            write!(f, "for await ({} of {}) {{ {} }}", self.binding, self.expr, self.body)
+        } else {
+            write!(f, "for ({} of {}) {{ {} }}", self.binding, self.expr, self.body)
+        }
+    }
 }
 
 /// A for loop
@@ -621,69 +654,149 @@ pub enum VariableDeclarationKind {
     Unnameable,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Display)]
 pub enum VariableDeclarationName<'a> {
     /// Normal identifier
     Identifier(&'a str),
-    /// Object destructuring: { a } = { a: 1 }
-    ObjectDestructuring {
-        /// Fields to destructure
-        ///
-        /// Destructured fields can also be aliased with ` { a: b } = { a: 3 } `
-        fields: Vec<(&'a str, Option<&'a str>)>,
+    /// Destructuring: `{ a, b: [c] } = d`
+    Pattern(Pattern<'a>),
+}
+
+/// A destructuring pattern, i.e. `{ a, b: [c] }` or `[a, b = 1, ...c]`
+#[derive(Debug, Clone)]
+pub enum Pattern<'a> {
+    /// Object destructuring: `{ a, b: c = 1, [d]: e, ...rest }`
+    Object {
+        /// Properties to destructure
+        fields: Vec<(PatternKey<'a>, PatternElement<'a>)>,
         /// The rest element, if present
-        rest: Option<&'a str>,
+        rest: Option<Box<PatternTarget<'a>>>,
     },
-    /// Array destructuring: [ a ] = [ 1 ]
-    ArrayDestructuring {
-        /// Elements to destructure
-        fields: Vec<&'a str>,
+    /// Array destructuring: `[a, , b = 1, ...rest]`
+    Array {
+        /// Elements to destructure, or `None` for holes
+        elements: Vec<Option<PatternElement<'a>>>,
         /// The rest element, if present
-        rest: Option<&'a str>,
+        rest: Option<Box<PatternTarget<'a>>>,
     },
 }
 
-impl<'a> fmt::Display for VariableDeclarationName<'a> {
+impl<'a> fmt::Display for Pattern<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            VariableDeclarationName::Identifier(name) => write!(f, "{name}"),
-            VariableDeclarationName::ObjectDestructuring { fields, rest } => {
+            Pattern::Object { fields, rest } => {
                 write!(f, "{{ ")?;
 
-                for (i, (name, alias)) in fields.iter().enumerate() {
+                for (i, (key, element)) in fields.iter().enumerate() {
                     if i > 0 {
                         write!(f, ", ")?;
                     }
 
-                    if let Some(alias) = alias {
-                        write!(f, "{name}: {alias}")?;
-                    } else {
-                        write!(f, "{name}")?;
-                    }
+                    write!(f, "{key}: {element}")?;
                 }
 
                 if let Some(rest) = rest {
-                    write!(f, ", ...{rest}")?;
+                    if !fields.is_empty() {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "...{rest}")?;
                 }
 
                 write!(f, " }}")
             }
-            VariableDeclarationName::ArrayDestructuring { fields, rest } => {
-                write!(f, "[ ")?;
+            Pattern::Array { elements, rest } => {
+                write!(f, "[")?;
 
-                for (i, name) in fields.iter().enumerate() {
+                for (i, element) in elements.iter().enumerate() {
                     if i > 0 {
                         write!(f, ", ")?;
                     }
 
-                    write!(f, "{name}")?;
+                    if let Some(element) = element {
+                        write!(f, "{element}")?;
+                    }
                 }
 
                 if let Some(rest) = rest {
-                    write!(f, ", ...{rest}")?;
+                    if !elements.is_empty() {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "...{rest}")?;
                 }
 
-                write!(f, " ]")
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// The key of a property in an object pattern
+#[derive(Debug, Clone, Display)]
+pub enum PatternKey<'a> {
+    /// A static property name: `{ a }`
+    Static(&'a str),
+    /// A computed property name: `{ [a]: b }`
+    #[display(fmt = "[{_0}]")]
+    Computed(Expr<'a>),
+}
+
+/// An element of a pattern, i.e. the `b = 1` in `{ a: b = 1 }`
+#[derive(Debug, Clone)]
+pub struct PatternElement<'a> {
+    /// Where the destructured value is stored
+    pub target: PatternTarget<'a>,
+    /// The value to use if the destructured value is undefined
+    pub default: Option<Expr<'a>>,
+}
+
+impl<'a> fmt::Display for PatternElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+
+        if let Some(default) = &self.default {
+            write!(f, " = {default}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The target of a destructured value
+#[derive(Debug, Clone, Display)]
+pub enum PatternTarget<'a> {
+    /// Binds the value to a variable
+    Identifier(&'a str),
+    /// Destructures the value further
+    Pattern(Pattern<'a>),
+    /// Assigns the value to a place expression, i.e. `a.b` in `[a.b] = c`
+    ///
+    /// Only destructuring assignments have these targets
+    Expr(Expr<'a>),
+}
+
+impl<'a> Pattern<'a> {
+    /// Returns the names of all variables that this pattern binds
+    pub fn bindings(&self) -> Vec<&'a str> {
+        let mut bindings = Vec::new();
+        self.collect_bindings(&mut bindings);
+        bindings
+    }
+
+    fn collect_bindings(&self, bindings: &mut Vec<&'a str>) {
+        let (targets, rest): (Vec<_>, _) = match self {
+            Pattern::Object { fields, rest } => (fields.iter().map(|(_, element)| &element.target).collect(), rest),
+            Pattern::Array { elements, rest } => {
+                (elements.iter().flatten().map(|element| &element.target).collect(), rest)
+            }
+        };
+
+        for target in targets.into_iter().chain(rest.as_deref()) {
+            match target {
+                PatternTarget::Identifier(ident) => bindings.push(ident),
+                PatternTarget::Pattern(pattern) => pattern.collect_bindings(bindings),
+                PatternTarget::Expr(..) => {}
             }
         }
     }
@@ -707,7 +820,7 @@ impl From<TokenType> for VariableDeclarationKind {
 }
 
 /// A variable binding
-#[derive(Debug, Clone, Display, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Display)]
 #[display(fmt = "{kind} {name}")]
 pub struct VariableBinding<'a> {
     /// The name/identifier of this variable
@@ -841,12 +954,12 @@ impl<'a> ClassMember<'a> {
         }
     }
 
-    /// Returns the identifier of this class member
-    pub fn name(&self) -> &'a str {
+    /// Returns the identifier of this class member, if it has one
+    pub fn name(&self) -> Option<&'a str> {
         match &self.kind {
-            ClassMemberKind::Property(p) => p.name,
-            // Methods *always* have names, so unwrapping is OK here
-            ClassMemberKind::Method(m) => m.name.unwrap(),
+            ClassMemberKind::Property(p) => Some(p.name),
+            ClassMemberKind::Method(m) | ClassMemberKind::Getter(m) | ClassMemberKind::Setter(m) => m.name,
+            ClassMemberKind::StaticBlock(..) => None,
         }
     }
 }
@@ -858,6 +971,17 @@ pub enum ClassMemberKind<'a> {
     Method(FunctionDeclaration<'a>),
     /// A class property
     Property(ClassProperty<'a>),
+    /// A getter, i.e. `get foo() {}`
+    #[display(fmt = "get {_0}")]
+    Getter(FunctionDeclaration<'a>),
+    /// A setter, i.e. `set foo(v) {}`
+    #[display(fmt = "set {_0}")]
+    Setter(FunctionDeclaration<'a>),
+    /// A static initialization block, i.e. `static { ... }`
+    ///
+    /// The block is represented as a function that is called with the class as `this`
+    #[display(fmt = "{{ {_0} }}")]
+    StaticBlock(FunctionDeclaration<'a>),
 }
 
 /// A class property
@@ -866,6 +990,8 @@ pub struct ClassProperty<'a> {
     /// The name of this property
     pub name: &'a str,
     /// The default value of this property, set when its constructor is called
+    ///
+    /// Initializers of static properties are wrapped in a function that is called with the class as `this`
     pub value: Option<Expr<'a>>,
 }
 
@@ -886,4 +1012,19 @@ impl<'a> fmt::Display for ClassProperty<'a> {
 pub enum Parameter<'a> {
     Identifier(&'a str),
     Spread(&'a str),
+    /// A destructuring pattern, i.e. `{ a, b }` in `function f({ a, b }) {}`
+    Pattern(Pattern<'a>),
+    /// A destructuring rest parameter, i.e. `[b, c]` in `function f(...[b, c]) {}`
+    SpreadPattern(Pattern<'a>),
+}
+
+impl<'a> Parameter<'a> {
+    /// Creates the rest parameter that collects the remaining arguments into the given binding target
+    pub fn rest(target: PatternTarget<'a>) -> Self {
+        match target {
+            PatternTarget::Identifier(ident) => Self::Spread(ident),
+            PatternTarget::Pattern(pattern) => Self::SpreadPattern(pattern),
+            PatternTarget::Expr(..) => unreachable!("Parameters are never assignment patterns"),
+        }
+    }
 }
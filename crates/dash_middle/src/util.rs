@@ -1,9 +1,12 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::fmt;
 use std::io::Read;
+use std::iter::Peekable;
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::ops::RangeInclusive;
+use std::str::Chars;
 use std::thread::ThreadId;
 
 use smallvec::SmallVec;
@@ -79,6 +82,94 @@ pub fn force_utf8_borrowed(s: &[u8]) -> &str {
     std::str::from_utf8(s).expect("Invalid UTF8")
 }
 
+/// Resolves the escape sequences of the raw text of a template literal segment,
+/// returning the input unchanged if it contains none
+///
+/// Returns `None` if the segment contains an invalid escape sequence (i.e. `\unicode` or `\1`),
+/// which is a syntax error in untagged templates and has an `undefined` cooked value in tagged templates
+pub fn unescape(s: &str) -> Option<Cow<'_, str>> {
+    if !s.contains('\\') {
+        return Some(Cow::Borrowed(s));
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'r' => out.push('\r'),
+            'b' => out.push('\x08'),
+            'f' => out.push('\x0C'),
+            'v' => out.push('\x0B'),
+            '0' if !chars.peek().is_some_and(char::is_ascii_digit) => out.push('\0'),
+            // Legacy octal escapes are not allowed in templates
+            '0'..='9' => return None,
+            'x' => {
+                let hi = chars.next()?.to_digit(16)?;
+                let lo = chars.next()?.to_digit(16)?;
+                out.push(char::from_u32(hi << 4 | lo)?);
+            }
+            'u' => {
+                let mut cp = unescape_code_point(&mut chars)?;
+
+                // A surrogate pair spelled out as two escapes, i.e. `\uD83D\uDE00`
+                if (0xD800..0xDC00).contains(&cp) {
+                    let mut lookahead = chars.clone();
+                    if lookahead.next() == Some('\\') && lookahead.next() == Some('u') {
+                        if let Some(lo @ 0xDC00..=0xDFFF) = unescape_code_point(&mut lookahead) {
+                            cp = 0x10000 + ((cp - 0xD800) << 10) + (lo - 0xDC00);
+                            chars = lookahead;
+                        }
+                    }
+                }
+
+                // Lone surrogates cannot be represented in a Rust string
+                out.push(char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            // Line continuation
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+            }
+            other => out.push(other),
+        }
+    }
+
+    Some(Cow::Owned(out))
+}
+
+/// Parses the code point of a `\u` escape sequence, either as exactly four hex digits or as `{X...}`
+fn unescape_code_point(chars: &mut Peekable<Chars<'_>>) -> Option<u32> {
+    if chars.peek() == Some(&'{') {
+        chars.next();
+
+        let mut cp = 0u32;
+        let mut digits = 0;
+        loop {
+            match chars.next()? {
+                '}' if digits > 0 => break,
+                c => {
+                    cp = cp.checked_mul(16)?.checked_add(c.to_digit(16)?)?;
+                    digits += 1;
+                }
+            }
+        }
+
+        (cp <= 0x10FFFF).then_some(cp)
+    } else {
+        (0..4).try_fold(0, |cp, _| Some(cp << 4 | chars.next()?.to_digit(16)?))
+    }
+}
+
 pub fn fmt_group<D: fmt::Display>(formatter: &mut fmt::Formatter<'_>, items: &[D], delim: &str) -> fmt::Result {
     for (index, item) in items.iter().enumerate() {
         if index > 0 {
@@ -1,3 +1,4 @@
+use crate::lexer::token::Location;
 use crate::parser::expr::ArrayLiteral;
 use crate::parser::expr::AssignmentExpr;
 use crate::parser::expr::BinaryExpr;
@@ -11,6 +12,7 @@ use crate::parser::expr::Postfix;
 use crate::parser::expr::Prefix;
 use crate::parser::expr::PropertyAccessExpr;
 use crate::parser::expr::Seq;
+use crate::parser::expr::TemplateStrings;
 use crate::parser::expr::UnaryExpr;
 use crate::parser::statement::BlockStatement;
 use crate::parser::statement::Class;
@@ -56,7 +58,7 @@ pub trait Visitor<'a, V> {
     fn visit_literal_expression(&mut self, e: LiteralExpr<'a>) -> V;
 
     /// Visits an identifier
-    fn visit_identifier_expression(&mut self, i: &str) -> V;
+    fn visit_identifier_expression(&mut self, i: &str, loc: Option<Location>) -> V;
 
     /// Visits an unary expression
     fn visit_unary_expression(&mut self, e: UnaryExpr<'a>) -> V;
@@ -114,11 +116,20 @@ pub trait Visitor<'a, V> {
     /// Visits an object literal
     fn visit_object_literal(&mut self, o: ObjectLiteral<'a>) -> V;
 
+    /// Visits an optional chain
+    fn visit_optional_chain_expr(&mut self, e: Expr<'a>) -> V;
+
+    /// Visits a private name
+    fn visit_private_name(&mut self, name: &'a str) -> V;
+
+    /// Visits the strings array of a tagged template literal
+    fn visit_template_strings(&mut self, t: TemplateStrings<'a>) -> V;
+
     /// Visits a try catch statement
     fn visit_try_catch(&mut self, t: TryCatch<'a>) -> V;
 
     /// Visits a throw statement
-    fn visit_throw(&mut self, e: Expr<'a>) -> V;
+    fn visit_throw(&mut self, e: Expr<'a>, loc: Location) -> V;
 
     /// Visits a for loop
     fn visit_for_loop(&mut self, f: ForLoop<'a>) -> V;
@@ -139,10 +150,13 @@ pub trait Visitor<'a, V> {
     fn visit_empty_statement(&mut self) -> V;
 
     /// Visits a break statement
-    fn visit_break(&mut self) -> V;
+    fn visit_break(&mut self, label: Option<&'a str>) -> V;
 
     /// Visits a continue statement
-    fn visit_continue(&mut self) -> V;
+    fn visit_continue(&mut self, label: Option<&'a str>) -> V;
+
+    /// Visits a labelled statement
+    fn visit_labelled(&mut self, label: &'a str, stmt: Box<Statement<'a>>) -> V;
 
     /// Visits a debugger statement
     fn visit_debugger(&mut self) -> V;
@@ -171,12 +185,13 @@ pub fn accept_default<'a, T, V: Visitor<'a, T>>(this: &mut V, s: Statement<'a>)
         Statement::Loop(Loop::DoWhile(d)) => this.visit_do_while_loop(d),
         Statement::Return(r) => this.visit_return_statement(r),
         Statement::Try(t) => this.visit_try_catch(t),
-        Statement::Throw(t) => this.visit_throw(t),
+        Statement::Throw(t, loc) => this.visit_throw(t, loc),
         Statement::Import(i) => this.visit_import_statement(i),
         Statement::Export(e) => this.visit_export_statement(e),
         Statement::Class(c) => this.visit_class_declaration(c),
-        Statement::Continue => this.visit_continue(),
-        Statement::Break => this.visit_break(),
+        Statement::Continue(label) => this.visit_continue(label),
+        Statement::Break(label) => this.visit_break(label),
+        Statement::Labelled(label, stmt) => this.visit_labelled(label, stmt),
         Statement::Debugger => this.visit_debugger(),
         Statement::Empty => this.visit_empty_statement(),
         Statement::Switch(s) => this.visit_switch_statement(s),
@@ -191,7 +206,7 @@ where
         Expr::Binary(e) => this.visit_binary_expression(e),
         Expr::Assignment(e) => this.visit_assignment_expression(e),
         Expr::Grouping(e) => this.visit_grouping_expression(e),
-        Expr::Literal(LiteralExpr::Identifier(i)) => this.visit_identifier_expression(&i),
+        Expr::Literal(LiteralExpr::Identifier(i, loc)) => this.visit_identifier_expression(&i, loc),
         Expr::Literal(l) => this.visit_literal_expression(l),
         Expr::Unary(e) => this.visit_unary_expression(e),
         Expr::Call(e) => this.visit_function_call(e),
@@ -203,6 +218,9 @@ where
         Expr::Function(e) => this.visit_function_expr(e),
         Expr::Array(e) => this.visit_array_literal(e),
         Expr::Object(e) => this.visit_object_literal(e),
+        Expr::OptionalChain(e) => this.visit_optional_chain_expr(*e),
+        Expr::PrivateName(name) => this.visit_private_name(name),
+        Expr::TemplateStrings(t) => this.visit_template_strings(t),
         Expr::Compiled(..) => on_empty(this),
         Expr::Empty => this.visit_empty_expr(),
     }
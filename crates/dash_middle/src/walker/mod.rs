@@ -1,7 +1,10 @@
+use crate::lexer::token::Location;
 use crate::parser::expr::ArrayLiteral;
+use crate::parser::expr::ArrayMemberKind;
 use crate::parser::expr::AssignmentExpr;
 use crate::parser::expr::AssignmentTarget;
 use crate::parser::expr::BinaryExpr;
+use crate::parser::expr::CallArgumentKind;
 use crate::parser::expr::ConditionalExpr;
 use crate::parser::expr::Expr;
 use crate::parser::expr::FunctionCall;
@@ -13,6 +16,7 @@ use crate::parser::expr::Postfix;
 use crate::parser::expr::Prefix;
 use crate::parser::expr::PropertyAccessExpr;
 use crate::parser::expr::Seq;
+use crate::parser::expr::TemplateStrings;
 use crate::parser::expr::UnaryExpr;
 use crate::parser::statement::BlockStatement;
 use crate::parser::statement::Class;
@@ -58,7 +62,7 @@ pub trait AstWalker<'a> {
 
     fn visit_literal_expression(&mut self, _e: LiteralExpr<'a>) {}
 
-    fn visit_identifier_expression(&mut self, _i: &str) {}
+    fn visit_identifier_expression(&mut self, _i: &str, _loc: Option<Location>) {}
 
     fn visit_binding_expression(&mut self, _b: VariableBinding<'a>) {}
 
@@ -100,14 +104,14 @@ pub trait AstWalker<'a> {
     fn visit_assignment_expression(&mut self, e: AssignmentExpr<'a>) -> () {
         match e.left {
             AssignmentTarget::Expr(e) => self.accept_expr(*e),
-            AssignmentTarget::LocalId(..) => {}
+            AssignmentTarget::LocalId(..) | AssignmentTarget::Pattern(..) => {}
         };
         self.accept_expr(*e.right);
     }
 
     fn visit_function_call(&mut self, c: FunctionCall<'a>) -> () {
         self.accept_expr(*c.target);
-        accept_expr_many(self, c.arguments);
+        accept_expr_many(self, c.arguments.into_iter().map(CallArgumentKind::into_expr));
     }
 
     fn visit_return_statement(&mut self, s: ReturnStatement<'a>) -> () {
@@ -143,7 +147,7 @@ pub trait AstWalker<'a> {
     }
 
     fn visit_array_literal(&mut self, a: ArrayLiteral<'a>) -> () {
-        accept_expr_many(self, a.0);
+        accept_expr_many(self, a.0.into_iter().map(ArrayMemberKind::into_expr));
     }
 
     fn visit_object_literal(&mut self, o: ObjectLiteral<'a>) -> () {
@@ -156,13 +160,23 @@ pub trait AstWalker<'a> {
         }
     }
 
+    fn visit_optional_chain_expr(&mut self, e: Expr<'a>) -> () {
+        self.accept_expr(e);
+    }
+
+    fn visit_private_name(&mut self, _name: &'a str) -> () {}
+
+    fn visit_template_strings(&mut self, _t: TemplateStrings<'a>) -> () {}
+
     fn visit_try_catch(&mut self, t: TryCatch<'a>) -> () {
         self.accept(*t.try_);
-        self.accept(*t.catch.body);
+        if let Some(catch) = t.catch {
+            self.accept(*catch.body);
+        }
         accept_maybe_box(self, t.finally);
     }
 
-    fn visit_throw(&mut self, e: Expr<'a>) -> () {
+    fn visit_throw(&mut self, e: Expr<'a>, _loc: Location) -> () {
         self.accept_expr(e);
     }
 
@@ -201,9 +215,13 @@ pub trait AstWalker<'a> {
 
     fn visit_empty_statement(&mut self) -> () {}
 
-    fn visit_break(&mut self) -> () {}
+    fn visit_break(&mut self, _label: Option<&'a str>) -> () {}
 
-    fn visit_continue(&mut self) -> () {}
+    fn visit_continue(&mut self, _label: Option<&'a str>) -> () {}
+
+    fn visit_labelled(&mut self, _label: &'a str, stmt: Box<Statement<'a>>) -> () {
+        self.accept(*stmt);
+    }
 
     fn visit_debugger(&mut self) -> () {}
 
@@ -213,7 +231,10 @@ pub trait AstWalker<'a> {
         accept_maybe_expr(self, c.extends);
         for member in c.members {
             match member.kind {
-                ClassMemberKind::Method(m) => self.accept(Statement::Function(m)),
+                ClassMemberKind::Method(m)
+                | ClassMemberKind::Getter(m)
+                | ClassMemberKind::Setter(m)
+                | ClassMemberKind::StaticBlock(m) => self.accept(Statement::Function(m)),
                 ClassMemberKind::Property(ClassProperty { value, .. }) => accept_maybe_expr(self, value),
             }
         }
@@ -244,12 +265,13 @@ pub fn accept_default<'a, V: AstWalker<'a> + ?Sized>(this: &mut V, s: Statement<
         Statement::Loop(Loop::ForIn(f)) => this.visit_for_in_loop(f),
         Statement::Return(r) => this.visit_return_statement(r),
         Statement::Try(t) => this.visit_try_catch(t),
-        Statement::Throw(t) => this.visit_throw(t),
+        Statement::Throw(t, loc) => this.visit_throw(t, loc),
         Statement::Import(i) => this.visit_import_statement(i),
         Statement::Export(e) => this.visit_export_statement(e),
         Statement::Class(c) => this.visit_class_declaration(c),
-        Statement::Continue => this.visit_continue(),
-        Statement::Break => this.visit_break(),
+        Statement::Continue(label) => this.visit_continue(label),
+        Statement::Break(label) => this.visit_break(label),
+        Statement::Labelled(label, stmt) => this.visit_labelled(label, stmt),
         Statement::Debugger => this.visit_debugger(),
         Statement::Empty => this.visit_empty_statement(),
         Statement::Switch(s) => this.visit_switch_statement(s),
@@ -261,7 +283,7 @@ pub fn accept_expr_default<'a, V: AstWalker<'a> + ?Sized>(this: &mut V, e: Expr<
         Expr::Binary(e) => this.visit_binary_expression(e),
         Expr::Assignment(e) => this.visit_assignment_expression(e),
         Expr::Grouping(e) => this.visit_grouping_expression(e),
-        Expr::Literal(LiteralExpr::Identifier(i)) => this.visit_identifier_expression(&i),
+        Expr::Literal(LiteralExpr::Identifier(i, loc)) => this.visit_identifier_expression(&i, loc),
         Expr::Literal(l) => this.visit_literal_expression(l),
         Expr::Unary(e) => this.visit_unary_expression(e),
         Expr::Call(e) => this.visit_function_call(e),
@@ -273,6 +295,9 @@ pub fn accept_expr_default<'a, V: AstWalker<'a> + ?Sized>(this: &mut V, e: Expr<
         Expr::Function(e) => this.visit_function_expr(e),
         Expr::Array(e) => this.visit_array_literal(e),
         Expr::Object(e) => this.visit_object_literal(e),
+        Expr::OptionalChain(e) => this.visit_optional_chain_expr(*e),
+        Expr::PrivateName(name) => this.visit_private_name(name),
+        Expr::TemplateStrings(t) => this.visit_template_strings(t),
         Expr::Compiled(..) => (),
         Expr::Empty => this.visit_empty_expr(),
     }
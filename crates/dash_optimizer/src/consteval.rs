@@ -1,6 +1,7 @@
 use dash_log::debug;
 use dash_middle::lexer::token::TokenType;
 use dash_middle::parser::expr::ArrayLiteral;
+use dash_middle::parser::expr::ArrayMemberKind;
 use dash_middle::parser::expr::AssignmentExpr;
 use dash_middle::parser::expr::AssignmentTarget;
 use dash_middle::parser::expr::BinaryExpr;
@@ -28,6 +29,10 @@ use dash_middle::parser::statement::IfStatement;
 use dash_middle::parser::statement::ImportKind;
 use dash_middle::parser::statement::Loop;
 use dash_middle::parser::statement::Parameter;
+use dash_middle::parser::statement::Pattern;
+use dash_middle::parser::statement::PatternElement;
+use dash_middle::parser::statement::PatternKey;
+use dash_middle::parser::statement::PatternTarget;
 use dash_middle::parser::statement::ReturnStatement;
 use dash_middle::parser::statement::SpecifierKind;
 use dash_middle::parser::statement::Statement;
@@ -37,6 +42,7 @@ use dash_middle::parser::statement::TryCatch;
 use dash_middle::parser::statement::VariableBinding;
 use dash_middle::parser::statement::VariableDeclaration;
 use dash_middle::parser::statement::VariableDeclarationKind;
+use dash_middle::parser::statement::VariableDeclarationName;
 use dash_middle::parser::statement::VariableDeclarations;
 use dash_middle::parser::statement::WhileLoop;
 
@@ -75,7 +81,7 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
             Statement::Loop(expr) => self.visit_loop_statement(expr, func_id),
             Statement::Return(stmt) => self.visit_return_statement(stmt, func_id),
             Statement::Try(stmt) => self.visit_try_statement(stmt, func_id),
-            Statement::Throw(expr) => {
+            Statement::Throw(expr, _) => {
                 self.visit(expr, func_id);
             }
             Statement::Import(ImportKind::AllAs(SpecifierKind::Ident(..), ..)) => {}
@@ -90,8 +96,9 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
             Statement::Export(ExportKind::NamedVar(stmt)) => self.visit_variable_declaration(stmt, func_id),
             Statement::Class(stmt) => self.visit_class_statement(stmt, func_id),
             Statement::Switch(stmt) => self.visit_switch_statement(stmt, func_id),
-            Statement::Continue => {}
-            Statement::Break => {}
+            Statement::Labelled(_, stmt) => self.visit_statement(stmt, func_id),
+            Statement::Continue(..) => {}
+            Statement::Break(..) => {}
             Statement::Debugger => {}
             Statement::Empty => {}
         };
@@ -131,7 +138,12 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
 
     pub fn visit_try_statement(&mut self, TryCatch { try_, catch, finally }: &mut TryCatch<'a>, func_id: FuncId) {
         self.visit_statement(try_, func_id);
-        self.visit_statement(&mut catch.body, func_id);
+        if let Some(catch) = catch {
+            if let Some(PatternTarget::Pattern(pattern)) = &mut catch.binding {
+                self.visit_pattern(pattern, func_id);
+            }
+            self.visit_statement(&mut catch.body, func_id);
+        }
         self.visit_maybe_statement(finally.as_deref_mut(), func_id);
     }
 
@@ -139,7 +151,10 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
         self.visit_maybe_expr(extends.as_mut(), func_id);
         for member in members {
             match &mut member.kind {
-                ClassMemberKind::Method(method) => {
+                ClassMemberKind::Method(method)
+                | ClassMemberKind::Getter(method)
+                | ClassMemberKind::Setter(method)
+                | ClassMemberKind::StaticBlock(method) => {
                     self.visit_function_expression(method, func_id);
                 }
                 ClassMemberKind::Property(ClassProperty { value, .. }) => {
@@ -179,7 +194,9 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
                 self.visit_maybe_expr(finalizer.as_mut(), func_id);
                 self.visit_statement(body, func_id);
             }
-            Loop::ForOf(ForOfLoop { expr, body, binding }) => {
+            Loop::ForOf(ForOfLoop {
+                expr, body, binding, ..
+            }) => {
                 self.visit_variable_binding(binding, None, func_id);
                 self.visit(expr, func_id);
                 self.visit_statement(body, func_id);
@@ -202,13 +219,17 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
 
     fn visit_variable_binding(
         &mut self,
-        _binding: &VariableBinding<'a>,
+        binding: &mut VariableBinding<'a>,
         value: Option<&mut Expr<'a>>,
         func_id: FuncId,
     ) {
         if let Some(value) = value {
             self.visit(value, func_id);
         }
+
+        if let VariableDeclarationName::Pattern(pattern) = &mut binding.name {
+            self.visit_pattern(pattern, func_id);
+        }
     }
 
     pub fn visit_variable_declaration(
@@ -259,6 +280,9 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
             Expr::Function(expr) => self.visit_function_expression(expr, func_id),
             Expr::Array(..) => self.visit_array_expression(expression, func_id),
             Expr::Object(..) => self.visit_object_expression(expression, func_id),
+            Expr::OptionalChain(expr) => self.visit(expr, func_id),
+            Expr::PrivateName(..) => {}
+            Expr::TemplateStrings(..) => {}
             Expr::Compiled(..) => {}
             Expr::Empty => {}
         }
@@ -269,7 +293,9 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
             unreachable!()
         };
 
-        self.visit_many_exprs(array, func_id);
+        for member in array {
+            self.visit(member.expr_mut(), func_id);
+        }
     }
 
     fn visit_object_expression(&mut self, object_expr: &mut Expr<'a>, func_id: FuncId) {
@@ -350,7 +376,9 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
         };
 
         self.visit(target, func_id);
-        self.visit_many_exprs(arguments, func_id);
+        for argument in arguments {
+            self.visit(argument.expr_mut(), func_id);
+        }
     }
 
     fn visit_assignment_expression(&mut self, assignment_expr: &mut Expr<'a>, func_id: FuncId) {
@@ -358,8 +386,10 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
             unreachable!()
         };
 
-        if let AssignmentTarget::Expr(left) = left {
-            self.visit(left, func_id);
+        match left {
+            AssignmentTarget::Expr(left) => self.visit(left, func_id),
+            AssignmentTarget::Pattern(pattern) => self.visit_pattern(pattern, func_id),
+            AssignmentTarget::LocalId(..) => {}
         }
         self.visit(right, func_id);
     }
@@ -383,7 +413,10 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
     }
 
     fn visit_binary_expression(&mut self, binary_expr: &mut Expr<'a>, func_id: FuncId) {
-        let Expr::Binary(BinaryExpr { left, right, operator }) = binary_expr else {
+        let Expr::Binary(BinaryExpr {
+            left, right, operator, ..
+        }) = binary_expr
+        else {
             unreachable!()
         };
         debug!("reduce binary: {:?} {:?}", left, right);
@@ -469,6 +502,44 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
         }
     }
 
+    /// Visits the computed keys and default values of a destructuring pattern
+    pub fn visit_pattern(&mut self, pattern: &mut Pattern<'a>, func_id: FuncId) {
+        let rest = match pattern {
+            Pattern::Object { fields, rest } => {
+                for (key, element) in fields {
+                    if let PatternKey::Computed(expr) = key {
+                        self.visit(expr, func_id);
+                    }
+                    self.visit_pattern_element(element, func_id);
+                }
+                rest
+            }
+            Pattern::Array { elements, rest } => {
+                for element in elements.iter_mut().flatten() {
+                    self.visit_pattern_element(element, func_id);
+                }
+                rest
+            }
+        };
+
+        if let Some(rest) = rest {
+            self.visit_pattern_target(rest, func_id);
+        }
+    }
+
+    fn visit_pattern_element(&mut self, PatternElement { target, default }: &mut PatternElement<'a>, func_id: FuncId) {
+        self.visit_maybe_expr(default.as_mut(), func_id);
+        self.visit_pattern_target(target, func_id);
+    }
+
+    fn visit_pattern_target(&mut self, target: &mut PatternTarget<'a>, func_id: FuncId) {
+        match target {
+            PatternTarget::Identifier(..) => {}
+            PatternTarget::Pattern(pattern) => self.visit_pattern(pattern, func_id),
+            PatternTarget::Expr(expr) => self.visit(expr, func_id),
+        }
+    }
+
     pub fn visit_function_expression(
         &mut self,
         FunctionDeclaration {
@@ -490,6 +561,9 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
                         .scope_mut(sub_func_id)
                         .add_local(ident, VariableDeclarationKind::Var, None);
                 }
+                Parameter::Pattern(pattern) | Parameter::SpreadPattern(pattern) => {
+                    self.visit_pattern(pattern, sub_func_id)
+                }
             }
 
             if let Some(expr) = expr {
@@ -506,7 +580,7 @@ impl<'a, 'b> ConstFunctionEvalCtx<'a, 'b> {
 fn stmt_has_side_effects(stmt: &Statement<'_>) -> bool {
     match stmt {
         Statement::Block(BlockStatement(block)) => block.iter().any(stmt_has_side_effects),
-        Statement::Break => true,
+        Statement::Break(..) => true,
         Statement::Class(Class { .. }) => true, // TODO: can possibly be SE-free
         Statement::Empty => false,
         Statement::Expression(expr) => expr_has_side_effects(expr),
@@ -521,7 +595,16 @@ fn stmt_has_side_effects(stmt: &Statement<'_>) -> bool {
 
 fn expr_has_side_effects(expr: &Expr<'_>) -> bool {
     match expr {
-        Expr::Array(ArrayLiteral(array)) => array.iter().any(expr_has_side_effects),
+        Expr::Array(ArrayLiteral(array)) => array.iter().any(|member| match member {
+            ArrayMemberKind::Item(expr) => expr_has_side_effects(expr),
+            // Spreading invokes the iterator protocol, which can run arbitrary code
+            ArrayMemberKind::Spread(..) => true,
+        }),
+        // `in` throws if the right hand side is not an object
+        Expr::Binary(BinaryExpr {
+            operator: TokenType::In,
+            ..
+        }) => true,
         Expr::Binary(BinaryExpr { left, right, .. }) => expr_has_side_effects(left) || expr_has_side_effects(right),
         Expr::Conditional(ConditionalExpr { condition, then, el }) => {
             expr_has_side_effects(condition) || expr_has_side_effects(then) || expr_has_side_effects(el)
@@ -534,13 +617,16 @@ fn expr_has_side_effects(expr: &Expr<'_>) -> bool {
         Expr::Literal(LiteralExpr::Null) => false,
         Expr::Literal(LiteralExpr::Undefined) => false,
         Expr::Literal(LiteralExpr::Number(..)) => false,
+        // Arithmetic on BigInts throws when mixed with numbers, so expressions involving them cannot be removed
+        Expr::Literal(LiteralExpr::BigInt(..)) => true,
         Expr::Literal(LiteralExpr::Regex(..)) => false,
         Expr::Literal(LiteralExpr::String(..)) => false,
         Expr::Object(ObjectLiteral(object)) => object.iter().any(|(kind, expr)| {
-            if let ObjectMemberKind::Dynamic(dynamic) = kind {
-                if expr_has_side_effects(dynamic) {
-                    return true;
-                }
+            match kind {
+                ObjectMemberKind::Dynamic(dynamic) if expr_has_side_effects(dynamic) => return true,
+                // Spreading an object invokes its getters
+                ObjectMemberKind::Spread => return true,
+                _ => {}
             };
             expr_has_side_effects(expr)
         }),
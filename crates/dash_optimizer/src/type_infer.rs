@@ -32,6 +32,10 @@ use dash_middle::parser::statement::IfStatement;
 use dash_middle::parser::statement::ImportKind;
 use dash_middle::parser::statement::Loop;
 use dash_middle::parser::statement::Parameter;
+use dash_middle::parser::statement::Pattern;
+use dash_middle::parser::statement::PatternElement;
+use dash_middle::parser::statement::PatternKey;
+use dash_middle::parser::statement::PatternTarget;
 use dash_middle::parser::statement::ReturnStatement;
 use dash_middle::parser::statement::SpecifierKind;
 use dash_middle::parser::statement::Statement;
@@ -104,7 +108,7 @@ impl<'a> TypeInferCtx<'a> {
             Statement::Loop(expr) => self.visit_loop_statement(expr, func_id),
             Statement::Return(stmt) => self.visit_return_statement(stmt, func_id),
             Statement::Try(stmt) => self.visit_try_statement(stmt, func_id),
-            Statement::Throw(expr) => drop(self.visit(expr, func_id)),
+            Statement::Throw(expr, _) => drop(self.visit(expr, func_id)),
             Statement::Import(ImportKind::AllAs(SpecifierKind::Ident(..), ..)) => {}
             Statement::Import(ImportKind::Dynamic(expr)) => drop(self.visit(expr, func_id)),
             Statement::Import(ImportKind::DefaultAs(SpecifierKind::Ident(..), ..)) => {}
@@ -113,8 +117,9 @@ impl<'a> TypeInferCtx<'a> {
             Statement::Export(ExportKind::NamedVar(stmt)) => self.visit_variable_declaration(stmt, func_id),
             Statement::Class(stmt) => self.visit_class_statement(stmt, func_id),
             Statement::Switch(stmt) => self.visit_switch_statement(stmt, func_id),
-            Statement::Continue => {}
-            Statement::Break => {}
+            Statement::Labelled(_, stmt) => self.visit_statement(stmt, func_id),
+            Statement::Continue(..) => {}
+            Statement::Break(..) => {}
             Statement::Debugger => {}
             Statement::Empty => {}
         }
@@ -146,7 +151,12 @@ impl<'a> TypeInferCtx<'a> {
 
     pub fn visit_try_statement(&mut self, TryCatch { try_, catch, finally }: &TryCatch<'a>, func_id: FuncId) {
         self.visit_statement(try_, func_id);
-        self.visit_statement(&catch.body, func_id);
+        if let Some(catch) = catch {
+            if let Some(PatternTarget::Pattern(pattern)) = &catch.binding {
+                self.visit_pattern(pattern, func_id);
+            }
+            self.visit_statement(&catch.body, func_id);
+        }
         self.visit_maybe_statement(finally.as_deref(), func_id);
     }
 
@@ -154,7 +164,10 @@ impl<'a> TypeInferCtx<'a> {
         self.visit_maybe_expr(extends.as_ref(), func_id);
         for member in members {
             match &member.kind {
-                ClassMemberKind::Method(method) => drop(self.visit_function_expression(method, func_id)),
+                ClassMemberKind::Method(method)
+                | ClassMemberKind::Getter(method)
+                | ClassMemberKind::Setter(method)
+                | ClassMemberKind::StaticBlock(method) => drop(self.visit_function_expression(method, func_id)),
                 ClassMemberKind::Property(ClassProperty { value, .. }) => {
                     drop(self.visit_maybe_expr(value.as_ref(), func_id))
                 }
@@ -192,7 +205,9 @@ impl<'a> TypeInferCtx<'a> {
                 self.visit_maybe_expr(finalizer.as_ref(), func_id);
                 self.visit_statement(body, func_id);
             }
-            Loop::ForOf(ForOfLoop { expr, body, binding }) => {
+            Loop::ForOf(ForOfLoop {
+                expr, body, binding, ..
+            }) => {
                 self.visit_variable_binding(binding, None, func_id);
                 self.visit(expr, func_id);
                 self.visit_statement(body, func_id);
@@ -214,16 +229,31 @@ impl<'a> TypeInferCtx<'a> {
     }
 
     fn visit_variable_binding(&mut self, binding: &VariableBinding<'a>, value: Option<&Expr<'a>>, func_id: FuncId) {
-        if let VariableDeclarationName::Identifier(ident) = binding.name {
-            let ty = match value {
-                Some(expr) => self.visit(expr, func_id),
-                None => Some(CompileValueType::Uninit),
-            };
+        match &binding.name {
+            VariableDeclarationName::Identifier(ident) => {
+                let ty = match value {
+                    Some(expr) => self.visit(expr, func_id),
+                    None => Some(CompileValueType::Uninit),
+                };
 
-            debug!("discovered new variable {} of type {:?}", ident, ty);
+                debug!("discovered new variable {} of type {:?}", ident, ty);
 
-            if let Err(..) = self.scope_mut(func_id).add_local(ident, binding.kind, ty) {
-                error!("failed to add variable");
+                if let Err(..) = self.scope_mut(func_id).add_local(ident, binding.kind, ty) {
+                    error!("failed to add variable");
+                }
+            }
+            VariableDeclarationName::Pattern(pattern) => {
+                self.visit_maybe_expr(value, func_id);
+                self.visit_pattern(pattern, func_id);
+
+                // The types of destructured values are unknown
+                for ident in pattern.bindings() {
+                    debug!("discovered new destructured variable {}", ident);
+
+                    if self.scope_mut(func_id).add_local(ident, binding.kind, None).is_err() {
+                        error!("failed to add variable");
+                    }
+                }
             }
         }
     }
@@ -275,6 +305,12 @@ impl<'a> TypeInferCtx<'a> {
             Expr::Function(expr) => self.visit_function_expression(expr, func_id),
             Expr::Array(expr) => self.visit_array_expression(expr, func_id),
             Expr::Object(expr) => self.visit_object_expression(expr, func_id),
+            Expr::OptionalChain(expr) => {
+                self.visit(expr, func_id);
+                None
+            }
+            Expr::PrivateName(..) => None,
+            Expr::TemplateStrings(..) => Some(CompileValueType::Array),
             Expr::Compiled(..) => None,
             Expr::Empty => None,
         }
@@ -282,7 +318,9 @@ impl<'a> TypeInferCtx<'a> {
 
     pub fn visit_binary_expression(
         &mut self,
-        BinaryExpr { left, right, operator }: &BinaryExpr<'a>,
+        BinaryExpr {
+            left, right, operator, ..
+        }: &BinaryExpr<'a>,
         func_id: FuncId,
     ) -> Option<CompileValueType> {
         let left = self.visit(left, func_id);
@@ -300,7 +338,12 @@ impl<'a> TypeInferCtx<'a> {
             (_, _, TokenType::StrictEquality) => Some(CompileValueType::Boolean),
             (_, _, TokenType::StrictInequality) => Some(CompileValueType::Boolean),
             (Some(CompileValueType::Number), Some(CompileValueType::Number), _) => Some(CompileValueType::Number),
-            (_, _, TokenType::Minus | TokenType::Star | TokenType::Slash) => Some(CompileValueType::Number),
+            // Arithmetic on two BigInts yields a BigInt, so only known non-BigInt operands produce a number
+            (Some(left), Some(right), TokenType::Minus | TokenType::Star | TokenType::Slash)
+                if is_never_bigint(&left) && is_never_bigint(&right) =>
+            {
+                Some(CompileValueType::Number)
+            }
             _ => None,
         }
     }
@@ -335,11 +378,12 @@ impl<'a> TypeInferCtx<'a> {
     ) -> Option<CompileValueType> {
         match expression {
             LiteralExpr::Boolean(..) => Some(CompileValueType::Boolean),
-            LiteralExpr::Identifier(identifier) => match self.find_local(identifier, func_id) {
+            LiteralExpr::Identifier(identifier, _) => match self.find_local(identifier, func_id) {
                 Some(local) => local.inferred_type().borrow().clone(),
                 _ => None,
             },
             LiteralExpr::Number(..) => Some(CompileValueType::Number),
+            LiteralExpr::BigInt(..) => None,
             LiteralExpr::String(..) => Some(CompileValueType::String),
             LiteralExpr::Regex(..) => None,
             LiteralExpr::Null => Some(CompileValueType::Null),
@@ -352,9 +396,10 @@ impl<'a> TypeInferCtx<'a> {
         UnaryExpr { expr, operator }: &UnaryExpr<'a>,
         func_id: FuncId,
     ) -> Option<CompileValueType> {
-        self.visit(expr, func_id);
+        let ty = self.visit(expr, func_id);
         match operator {
-            TokenType::Plus | TokenType::Minus => Some(CompileValueType::Number),
+            TokenType::Plus => Some(CompileValueType::Number),
+            TokenType::Minus if ty.as_ref().is_some_and(is_never_bigint) => Some(CompileValueType::Number),
             TokenType::Typeof => Some(CompileValueType::String),
             _ => None,
         }
@@ -365,15 +410,20 @@ impl<'a> TypeInferCtx<'a> {
         AssignmentExpr { left, right, .. }: &AssignmentExpr<'a>,
         func_id: FuncId,
     ) -> Option<CompileValueType> {
-        let AssignmentTarget::Expr(left) = left else {
-            panic!("Cannot infer type for assignment place LocalId");
+        let left = match left {
+            AssignmentTarget::Expr(left) => left,
+            AssignmentTarget::Pattern(pattern) => {
+                self.visit_pattern(pattern, func_id);
+                return self.visit(right, func_id);
+            }
+            AssignmentTarget::LocalId(..) => panic!("Cannot infer type for assignment place LocalId"),
         };
 
         self.visit(left, func_id);
         let right_type = self.visit(right, func_id);
 
         // Also propagate assignment to target
-        if let Expr::Literal(LiteralExpr::Identifier(ident)) = &**left {
+        if let Expr::Literal(LiteralExpr::Identifier(ident, _)) = &**left {
             if let Some(local) = self.find_local(ident, func_id) {
                 let left_type = local.inferred_type();
                 let left_type_ref = left_type.borrow();
@@ -416,7 +466,7 @@ impl<'a> TypeInferCtx<'a> {
     ) -> Option<CompileValueType> {
         self.visit(target, func_id);
         for argument in arguments {
-            self.visit(argument, func_id);
+            self.visit(argument.expr(), func_id);
         }
         None
     }
@@ -504,6 +554,18 @@ impl<'a> TypeInferCtx<'a> {
                         error!("failed to reserve space for parameter")
                     }
                 }
+                Parameter::Pattern(pattern) | Parameter::SpreadPattern(pattern) => {
+                    // The argument is stored in an unnameable local and destructured by the compiler
+                    if self
+                        .scope_mut(sub_func_id)
+                        .add_local("destructured_parameter", VariableDeclarationKind::Unnameable, None)
+                        .is_err()
+                    {
+                        error!("failed to reserve space for parameter")
+                    }
+
+                    self.visit_pattern(pattern, sub_func_id);
+                }
             }
 
             if let Some(expr) = expr {
@@ -517,13 +579,53 @@ impl<'a> TypeInferCtx<'a> {
         None
     }
 
+    /// Visits the computed keys and default values of a destructuring pattern
+    pub fn visit_pattern(&mut self, pattern: &Pattern<'a>, func_id: FuncId) {
+        let rest = match pattern {
+            Pattern::Object { fields, rest } => {
+                for (key, element) in fields {
+                    if let PatternKey::Computed(expr) = key {
+                        self.visit(expr, func_id);
+                    }
+                    self.visit_pattern_element(element, func_id);
+                }
+                rest
+            }
+            Pattern::Array { elements, rest } => {
+                for element in elements.iter().flatten() {
+                    self.visit_pattern_element(element, func_id);
+                }
+                rest
+            }
+        };
+
+        if let Some(rest) = rest {
+            self.visit_pattern_target(rest, func_id);
+        }
+    }
+
+    fn visit_pattern_element(&mut self, PatternElement { target, default }: &PatternElement<'a>, func_id: FuncId) {
+        self.visit_maybe_expr(default.as_ref(), func_id);
+        self.visit_pattern_target(target, func_id);
+    }
+
+    fn visit_pattern_target(&mut self, target: &PatternTarget<'a>, func_id: FuncId) {
+        match target {
+            PatternTarget::Identifier(..) => {}
+            PatternTarget::Pattern(pattern) => self.visit_pattern(pattern, func_id),
+            PatternTarget::Expr(expr) => {
+                self.visit(expr, func_id);
+            }
+        }
+    }
+
     pub fn visit_array_expression(
         &mut self,
         ArrayLiteral(expr): &ArrayLiteral<'a>,
         func_id: FuncId,
     ) -> Option<CompileValueType> {
-        for expr in expr {
-            self.visit(expr, func_id);
+        for member in expr {
+            self.visit(member.expr(), func_id);
         }
         Some(CompileValueType::Array)
     }
@@ -542,3 +644,18 @@ impl<'a> TypeInferCtx<'a> {
         None
     }
 }
+
+/// Checks whether a value of the given type can never be a BigInt
+fn is_never_bigint(ty: &CompileValueType) -> bool {
+    match ty {
+        CompileValueType::Boolean
+        | CompileValueType::Null
+        | CompileValueType::Undefined
+        | CompileValueType::Number
+        | CompileValueType::String
+        | CompileValueType::Array => true,
+        CompileValueType::Either(left, right) => is_never_bigint(left) && is_never_bigint(right),
+        CompileValueType::Maybe(ty) => is_never_bigint(ty),
+        CompileValueType::Uninit | CompileValueType::Extern => false,
+    }
+}
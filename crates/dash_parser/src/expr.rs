@@ -3,14 +3,29 @@ use std::borrow::Cow;
 use dash_middle::lexer::token::TokenType;
 use dash_middle::lexer::token::ASSIGNMENT_TYPES;
 use dash_middle::parser::error::ErrorKind;
+use dash_middle::parser::expr::ArrayLiteral;
+use dash_middle::parser::expr::ArrayMemberKind;
+use dash_middle::parser::expr::AssignmentExpr;
+use dash_middle::parser::expr::AssignmentTarget;
+use dash_middle::parser::expr::CallArgumentKind;
 use dash_middle::parser::expr::Expr;
+use dash_middle::parser::expr::FunctionCall;
+use dash_middle::parser::expr::LiteralExpr;
+use dash_middle::parser::expr::ObjectLiteral;
 use dash_middle::parser::expr::ObjectMemberKind;
+use dash_middle::parser::expr::PropertyAccessExpr;
+use dash_middle::parser::expr::TemplateStrings;
 use dash_middle::parser::statement::BlockStatement;
 use dash_middle::parser::statement::FunctionDeclaration;
 use dash_middle::parser::statement::FunctionKind;
 use dash_middle::parser::statement::Parameter;
+use dash_middle::parser::statement::Pattern;
+use dash_middle::parser::statement::PatternElement;
+use dash_middle::parser::statement::PatternKey;
+use dash_middle::parser::statement::PatternTarget;
 use dash_middle::parser::statement::ReturnStatement;
 use dash_middle::parser::statement::Statement;
+use dash_middle::util;
 
 use crate::must_borrow_lexeme;
 use crate::stmt::StatementParser;
@@ -48,7 +63,18 @@ pub trait ExpressionParser<'a> {
     ///
     /// Calling this will turn all parameters, which were parsed as if they were part of the grouping operator
     /// into their arrow function parameter equivalent
-    fn parse_arrow_function_end(&mut self, prec: Vec<Expr<'a>>) -> Option<FunctionDeclaration<'a>>;
+    ///
+    /// `rest` is the binding target of a trailing rest parameter, i.e. `r` in `(a, ...r) => {}`
+    fn parse_arrow_function_end(
+        &mut self,
+        prec: Vec<Expr<'a>>,
+        rest: Option<PatternTarget<'a>>,
+    ) -> Option<FunctionDeclaration<'a>>;
+    /// Skips the `...` of a spread element, and returns whether there was one
+    fn parse_spread_operator(&mut self) -> bool;
+    /// Parses the rest of a template literal whose first segment has already been consumed,
+    /// and returns its raw segments along with the substituted expressions in between them
+    fn parse_template_literal(&mut self, first: Cow<'a, str>) -> Option<(Vec<Cow<'a, str>>, Vec<Expr<'a>>)>;
 }
 
 impl<'a> ExpressionParser<'a> for Parser<'a> {
@@ -64,8 +90,12 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
 
     fn parse_yield(&mut self) -> Option<Expr<'a>> {
         if self.expect_and_skip(&[TokenType::Yield], false) {
+            let operator = match self.expect_and_skip(&[TokenType::Star], false) {
+                true => TokenType::YieldStar,
+                false => TokenType::Yield,
+            };
             let right = self.parse_yield()?;
-            return Some(Expr::unary(TokenType::Yield, right));
+            return Some(Expr::unary(operator, right));
         }
 
         self.parse_assignment()
@@ -76,8 +106,20 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
 
         if self.expect_and_skip(ASSIGNMENT_TYPES, false) {
             let operator = self.previous()?.ty;
-            let rval = self.parse_assignment()?;
-            expr = Expr::assignment(expr, rval, operator);
+            let rval = self.parse_yield()?;
+
+            expr = match expr {
+                // Array and object literals on the left side are destructuring assignments
+                Expr::Array(..) | Expr::Object(..) if operator == TokenType::Assignment => {
+                    match expr_into_pattern_target(expr.clone(), true) {
+                        Some(PatternTarget::Pattern(pattern)) => {
+                            Expr::Assignment(AssignmentExpr::new(AssignmentTarget::Pattern(pattern), rval, operator))
+                        }
+                        _ => Expr::assignment(expr, rval, operator),
+                    }
+                }
+                _ => Expr::assignment(expr, rval, operator),
+            };
         }
 
         Some(expr)
@@ -102,9 +144,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_logical_or()?;
 
         while self.expect_and_skip(&[TokenType::NullishCoalescing], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_logical_or()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -114,9 +156,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_logical_and()?;
 
         while self.expect_and_skip(&[TokenType::LogicalOr], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_logical_and()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -126,9 +168,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_bitwise_or()?;
 
         while self.expect_and_skip(&[TokenType::LogicalAnd], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_bitwise_or()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -138,9 +180,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_bitwise_xor()?;
 
         while self.expect_and_skip(&[TokenType::BitwiseOr], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_bitwise_xor()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -150,9 +192,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_bitwise_and()?;
 
         while self.expect_and_skip(&[TokenType::BitwiseXor], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_bitwise_and()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -162,9 +204,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_equality()?;
 
         while self.expect_and_skip(&[TokenType::BitwiseAnd], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_equality()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -182,9 +224,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
             ],
             false,
         ) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_comparison()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -204,9 +246,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
             ],
             false,
         ) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_bitwise_shift()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -223,9 +265,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
             ],
             false,
         ) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_term()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -235,9 +277,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_factor()?;
 
         while self.expect_and_skip(&[TokenType::Plus, TokenType::Minus], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_factor()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -247,9 +289,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_pow()?;
 
         while self.expect_and_skip(&[TokenType::Star, TokenType::Slash, TokenType::Remainder], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_pow()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -259,9 +301,9 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         let mut expr = self.parse_unary()?;
 
         while self.expect_and_skip(&[TokenType::Exponentiation], false) {
-            let operator = self.previous()?.ty;
+            let (operator, loc) = self.previous().map(|tok| (tok.ty, tok.loc))?;
             let rval = self.parse_unary()?;
-            expr = Expr::binary(expr, rval, operator);
+            expr = Expr::binary(expr, rval, operator, Some(loc));
         }
 
         Some(expr)
@@ -317,23 +359,58 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         }
 
         let mut expr = self.parse_primary_expr()?;
+        let mut is_optional_chain = false;
 
         while self.expect_and_skip(
-            &[TokenType::LeftParen, TokenType::Dot, TokenType::LeftSquareBrace],
+            &[
+                TokenType::LeftParen,
+                TokenType::Dot,
+                TokenType::LeftSquareBrace,
+                TokenType::OptionalChaining,
+                TokenType::TemplateLiteral,
+            ],
             false,
         ) {
-            let previous = self.previous()?.ty;
+            let mut previous = self.previous()?.ty;
+            let optional = previous == TokenType::OptionalChaining;
+
+            if optional {
+                is_optional_chain = true;
+
+                // `a?.[k]` and `f?.()` continue with the bracket or paren, `a?.b` with the property name
+                previous = match self.current()?.ty {
+                    ty @ (TokenType::LeftParen | TokenType::LeftSquareBrace) => {
+                        self.advance();
+                        ty
+                    }
+                    _ => TokenType::Dot,
+                };
+            }
 
             match previous {
                 TokenType::LeftParen => {
+                    // Calls are attributed to the token right before the opening paren, i.e. the callee name in `a.b()`
+                    let callee_idx = self.idx - if optional { 3 } else { 2 };
+                    let loc = self.tokens.get(callee_idx).map(|tok| tok.loc);
+
                     let mut arguments = Vec::new();
 
+                    // Arguments get their own `new` level, so that calls in the argument list
+                    // don't consume the level of an outer `new` expression
+                    self.new_level_stack.add_level();
+
                     // TODO: refactor to `parse_expr_list`
                     while !self.expect_and_skip(&[TokenType::RightParen], false) {
                         self.expect_and_skip(&[TokenType::Comma], false);
-                        arguments.push(self.parse_expression()?);
+                        let argument = match self.parse_spread_operator() {
+                            true => CallArgumentKind::Spread(self.parse_expression()?),
+                            false => CallArgumentKind::Normal(self.parse_expression()?),
+                        };
+                        arguments.push(argument);
                     }
 
+                    self.new_level_stack.pop_level().expect("Missing `new` level stack");
+
                     // End of function call.
                     let level = self.new_level_stack.cur_level().expect("Missing `new` level stack");
                     let is_constructor_call = level > 0;
@@ -341,21 +418,69 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
                         self.new_level_stack.dec_level().expect("Missing `new` level stack");
                     }
 
-                    expr = Expr::function_call(expr, arguments, is_constructor_call);
+                    expr = Expr::Call(FunctionCall {
+                        constructor_call: is_constructor_call,
+                        target: Box::new(expr),
+                        arguments,
+                        loc,
+                        optional,
+                    });
                 }
                 TokenType::Dot => {
-                    let property = Expr::identifier(self.next()?.full.clone());
-                    expr = Expr::property_access(false, expr, property);
+                    let loc = self.current()?.loc;
+                    let property = match self.expect_and_skip(&[TokenType::Hash], false) {
+                        true => Expr::PrivateName(self.next_identifier()?),
+                        false => Expr::identifier(self.next()?.full.clone(), None),
+                    };
+                    expr = Expr::PropertyAccess(PropertyAccessExpr {
+                        computed: false,
+                        target: Box::new(expr),
+                        property: Box::new(property),
+                        loc: Some(loc),
+                        optional,
+                    });
                 }
                 TokenType::LeftSquareBrace => {
+                    let loc = self.previous()?.loc;
                     let property = self.parse_expression()?;
                     self.expect_and_skip(&[TokenType::RightSquareBrace], false);
-                    expr = Expr::property_access(true, expr, property);
+                    expr = Expr::PropertyAccess(PropertyAccessExpr {
+                        computed: true,
+                        target: Box::new(expr),
+                        property: Box::new(property),
+                        loc: Some(loc),
+                        optional,
+                    });
+                }
+                TokenType::TemplateLiteral => {
+                    // Tagged template, i.e. tag`a${b}c`, which calls the tag with the strings and the substitutions
+                    let loc = self.tokens.get(self.idx - 2).map(|tok| tok.loc);
+                    let first = self.previous()?.full.clone();
+                    let (raw, expressions) = self.parse_template_literal(first)?;
+                    let cooked = raw.iter().map(cook_template_segment).collect();
+
+                    let strings = Expr::TemplateStrings(TemplateStrings { cooked, raw });
+                    let arguments = std::iter::once(strings)
+                        .chain(expressions)
+                        .map(CallArgumentKind::Normal)
+                        .collect();
+
+                    expr = Expr::Call(FunctionCall {
+                        constructor_call: false,
+                        target: Box::new(expr),
+                        arguments,
+                        loc,
+                        optional: false,
+                    });
                 }
                 _ => unreachable!(),
             }
         }
 
+        if is_optional_chain {
+            expr = Expr::OptionalChain(Box::new(expr));
+        }
+
         Some(expr)
     }
 
@@ -368,22 +493,33 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
             // TODO: ; shouldnt be a valid expression
             TokenType::Semicolon => Expr::undefined_literal(),
             TokenType::TemplateLiteral => {
-                let mut left = Expr::string_literal(current.full);
-                while !self.is_eof() {
-                    if self.expect_and_skip(&[TokenType::Dollar], false) {
-                        self.expect_and_skip(&[TokenType::LeftBrace], true);
-                        let right = self.parse_expression()?;
-                        self.expect_and_skip(&[TokenType::RightBrace], true);
-                        left = Expr::binary(left, right, TokenType::Plus);
-                    } else if self.expect_and_skip(&[TokenType::TemplateLiteral], false) {
-                        let right = Expr::string_literal(self.previous()?.full.clone());
-                        left = Expr::binary(left, right, TokenType::Plus);
-                    } else {
-                        break;
-                    }
+                let (segments, expressions) = self.parse_template_literal(current.full.clone())?;
+                let Some(segments) = segments.iter().map(cook_template_segment).collect::<Option<Vec<_>>>() else {
+                    self.create_error(ErrorKind::InvalidEscapeSequence(current));
+                    return None;
+                };
+                let mut segments = segments.into_iter();
+
+                let mut left = Expr::string_literal(segments.next()?);
+                for (expr, segment) in expressions.into_iter().zip(segments) {
+                    left = Expr::binary(left, expr, TokenType::Plus, None);
+                    left = Expr::binary(left, Expr::string_literal(segment), TokenType::Plus, None);
                 }
                 left
             }
+            TokenType::Hash => {
+                let name = self.next_identifier()?;
+
+                // A private name on its own is only valid as a brand check, i.e. `#foo in obj`
+                let next = self.current()?;
+                if next.ty != TokenType::In {
+                    let next = next.clone();
+                    self.create_error(ErrorKind::UnexpectedToken(next, TokenType::In));
+                    return None;
+                }
+
+                Expr::PrivateName(name)
+            }
             TokenType::FalseLit => Expr::bool_literal(false),
             TokenType::TrueLit => Expr::bool_literal(true),
             TokenType::NullLit => Expr::null_literal(),
@@ -394,7 +530,11 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
                 let mut items = Vec::new();
                 while !self.expect_and_skip(&[TokenType::RightSquareBrace], false) {
                     self.expect_and_skip(&[TokenType::Comma], false);
-                    items.push(self.parse_expression()?);
+                    let item = match self.parse_spread_operator() {
+                        true => ArrayMemberKind::Spread(self.parse_expression()?),
+                        false => ArrayMemberKind::Item(self.parse_expression()?),
+                    };
+                    items.push(item);
                 }
                 Expr::array_literal(items)
             }
@@ -402,11 +542,27 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
                 let mut items = Vec::new();
                 while !self.expect_and_skip(&[TokenType::RightBrace], false) {
                     self.expect_and_skip(&[TokenType::Comma], false);
+
+                    if self.parse_spread_operator() {
+                        items.push((ObjectMemberKind::Spread, self.parse_expression()?));
+                        continue;
+                    }
+
                     let token = self.next()?.clone();
+
+                    // `get` and `set` are only accessors if followed by a property name,
+                    // otherwise they are a normal property or method named "get"/"set"
+                    let is_accessor = matches!(token.ty, TokenType::Get | TokenType::Set)
+                        && self.current().is_some_and(|t| {
+                            !matches!(
+                                t.ty,
+                                TokenType::LeftParen | TokenType::Colon | TokenType::Comma | TokenType::RightBrace
+                            )
+                        });
+
                     let key = match token.ty {
-                        // TODO: this breaks object literals with a normal property named "get"
-                        TokenType::Get => ObjectMemberKind::Getter(self.next()?.full.clone()),
-                        TokenType::Set => ObjectMemberKind::Setter(self.next()?.full.clone()),
+                        TokenType::Get if is_accessor => ObjectMemberKind::Getter(self.next()?.full.clone()),
+                        TokenType::Set if is_accessor => ObjectMemberKind::Setter(self.next()?.full.clone()),
                         TokenType::LeftSquareBrace => {
                             let t = self.parse_expression()?;
                             let o = ObjectMemberKind::Dynamic(t);
@@ -442,7 +598,16 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
                             } else {
                                 match key {
                                     ObjectMemberKind::Static(name) => {
-                                        items.push((key, Expr::identifier(Cow::Borrowed(name))))
+                                        let mut value = Expr::identifier(Cow::Borrowed(name), None);
+
+                                        // A shorthand property with an initializer is only valid in
+                                        // destructuring patterns, i.e. `({ a = 1 }) => a`
+                                        if self.expect_and_skip(&[TokenType::Assignment], false) {
+                                            let default = self.parse_expression()?;
+                                            value = Expr::assignment(value, default, TokenType::Assignment);
+                                        }
+
+                                        items.push((key, value))
                                     }
                                     ObjectMemberKind::Dynamic(..) => {
                                         self.create_error(ErrorKind::UnexpectedToken(token, TokenType::Colon));
@@ -490,12 +655,14 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
                                 FunctionDeclaration::new(None, func_id, params, stmts, FunctionKind::Function, false);
                             items.push((key, Expr::function(fun)));
                         }
+                        ObjectMemberKind::Spread => unreachable!(),
                     }
                 }
                 Expr::object_literal(items)
             }
             // TODO: this unwrap is not safe
             TokenType::NumberDec => Expr::number_literal(current.full.parse::<f64>().unwrap()),
+            TokenType::NumberBigInt => self.parse_bigint_literal(&current.full).map(Expr::bigint_literal)?,
             TokenType::NumberHex => self
                 .parse_prefixed_number_literal(&current.full, 16)
                 .map(Expr::number_literal)?,
@@ -512,21 +679,44 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
                         return None;
                     }
 
-                    return self.parse_arrow_function_end(Vec::new()).map(Expr::function);
+                    return self.parse_arrow_function_end(Vec::new(), None).map(Expr::function);
                 }
 
                 self.new_level_stack.add_level();
-                let mut exprs = vec![self.parse_expression()?];
+                let mut exprs = Vec::new();
+                let mut rest = None;
+
+                loop {
+                    // A rest parameter is always the last element of an arrow function parameter list
+                    if self.parse_spread_operator() {
+                        rest = Some(self.parse_binding_target()?);
+                        if !self.expect_and_skip(&[TokenType::RightParen], true) {
+                            return None;
+                        }
+                        break;
+                    }
 
-                while !self.expect_and_skip(&[TokenType::RightParen], false) {
-                    self.expect_and_skip(&[TokenType::Comma], false);
                     exprs.push(self.parse_expression()?);
+
+                    if self.expect_and_skip(&[TokenType::RightParen], false) {
+                        break;
+                    }
+                    self.expect_and_skip(&[TokenType::Comma], false);
                 }
                 self.new_level_stack.pop_level();
 
+                if rest.is_some() {
+                    // Only arrow functions can have a rest parameter
+                    if !self.expect_and_skip(&[TokenType::FatArrow], true) {
+                        return None;
+                    }
+
+                    return self.parse_arrow_function_end(exprs, rest).map(Expr::function);
+                }
+
                 // This is an arrow function if the next token is an arrow (`=>`)
                 if self.expect_and_skip(&[TokenType::FatArrow], false) {
-                    return self.parse_arrow_function_end(exprs).map(Expr::function);
+                    return self.parse_arrow_function_end(exprs, None).map(Expr::function);
                 }
 
                 // If it's not an arrow function, then it is a group
@@ -542,25 +732,31 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
             }
             TokenType::Function => Expr::function(self.parse_function(false)?),
             TokenType::RegexLiteral => {
-                // Trim / prefix and suffix
+                // Split the literal into the pattern between the slashes and the flags after it
                 let full = must_borrow_lexeme!(self, &current)?;
-                let full = &full[1..full.len() - 1];
-                let nodes = match dash_regex::Parser::new(full.as_bytes()).parse_all() {
-                    Ok(nodes) => nodes,
+                let end = full.rfind('/').unwrap_or(full.len());
+                let (source, flags) = (&full[1..end], &full[end + 1..]);
+
+                let regex = flags
+                    .parse::<dash_regex::Flags>()
+                    .and_then(|flags| Ok((dash_regex::Parser::new(source, flags).parse_all()?, flags)));
+
+                let (regex, flags) = match regex {
+                    Ok(regex) => regex,
                     Err(err) => {
                         let tok = self.current().unwrap().clone();
                         self.create_error(ErrorKind::RegexSyntaxError(tok, err));
                         return None;
                     }
                 };
-                Expr::regex_literal(nodes, full)
+                Expr::regex_literal(regex, flags, source)
             }
             other if other.is_identifier() => {
-                let expr = Expr::identifier(current.full);
+                let expr = Expr::identifier(current.full, Some(current.loc));
 
                 // If this identifier is followed by an arrow, this is an arrow function
                 if self.expect_and_skip(&[TokenType::FatArrow], false) {
-                    return self.parse_arrow_function_end(vec![expr]).map(Expr::function);
+                    return self.parse_arrow_function_end(vec![expr], None).map(Expr::function);
                 }
 
                 expr
@@ -618,17 +814,31 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
         ))
     }
 
-    fn parse_arrow_function_end(&mut self, prec: Vec<Expr<'a>>) -> Option<FunctionDeclaration<'a>> {
-        let mut list = Vec::with_capacity(prec.len());
+    fn parse_arrow_function_end(
+        &mut self,
+        prec: Vec<Expr<'a>>,
+        rest: Option<PatternTarget<'a>>,
+    ) -> Option<FunctionDeclaration<'a>> {
+        let mut list = Vec::with_capacity(prec.len() + usize::from(rest.is_some()));
 
         // If it is arrow function, we need to convert everything to their arrow func equivalents
         for expr in prec {
             // TODO: this currently breaks with types in arrow functions
             // e.g. (a: number) => {}
             // we need to properly convert types here too
+            let PatternElement { target, default } = expr_into_pattern_element(expr, false)?;
+
+            let parameter = match target {
+                PatternTarget::Identifier(ident) => Parameter::Identifier(ident),
+                PatternTarget::Pattern(pattern) => Parameter::Pattern(pattern),
+                PatternTarget::Expr(..) => unreachable!("Parameters are never assignment patterns"),
+            };
 
-            // TODO2: handle parameter default values
-            list.push((Parameter::Identifier(expr.as_identifier()?), None, None));
+            list.push((parameter, default, None));
+        }
+
+        if let Some(rest) = rest {
+            list.push((Parameter::rest(rest), None, None));
         }
 
         let is_statement = self.expect_and_skip(&[TokenType::LeftBrace], false);
@@ -652,4 +862,119 @@ impl<'a> ExpressionParser<'a> for Parser<'a> {
             false,
         ))
     }
+
+    fn parse_spread_operator(&mut self) -> bool {
+        if !self.expect_and_skip(&[TokenType::Dot], false) {
+            return false;
+        }
+
+        for _ in 0..2 {
+            self.expect_and_skip(&[TokenType::Dot], true);
+        }
+
+        true
+    }
+
+    fn parse_template_literal(&mut self, first: Cow<'a, str>) -> Option<(Vec<Cow<'a, str>>, Vec<Expr<'a>>)> {
+        let mut segments = vec![first];
+        let mut expressions = Vec::new();
+
+        // Segments and substitutions alternate, and the template literal always ends with a segment
+        while self.expect_and_skip(&[TokenType::Dollar], false) {
+            self.expect_and_skip(&[TokenType::LeftBrace], true);
+            expressions.push(self.parse_expression()?);
+            self.expect_and_skip(&[TokenType::RightBrace], true);
+
+            if !self.expect_and_skip(&[TokenType::TemplateLiteral], true) {
+                return None;
+            }
+            segments.push(self.previous()?.full.clone());
+        }
+
+        Some((segments, expressions))
+    }
+}
+
+/// Resolves the escape sequences of the raw text of a template literal segment,
+/// returning `None` if it contains an invalid escape sequence
+fn cook_template_segment<'a>(raw: &Cow<'a, str>) -> Option<Cow<'a, str>> {
+    match raw {
+        Cow::Borrowed(raw) => util::unescape(raw),
+        Cow::Owned(raw) => util::unescape(raw).map(|s| Cow::Owned(s.into_owned())),
+    }
+}
+
+/// Converts an expression that was parsed as part of an arrow function parameter list or as the left side
+/// of an assignment into the pattern element it stands for, i.e. `a = 1` or `{ b, c: [d] }`
+///
+/// Targets of destructuring assignments (`assignment`) can also be property accesses, i.e. `[a.b] = c`
+fn expr_into_pattern_element(expr: Expr<'_>, assignment: bool) -> Option<PatternElement<'_>> {
+    match expr {
+        Expr::Assignment(AssignmentExpr {
+            left: AssignmentTarget::Expr(left),
+            right,
+            operator: TokenType::Assignment,
+        }) => Some(PatternElement {
+            target: expr_into_pattern_target(*left, assignment)?,
+            default: Some(*right),
+        }),
+        expr => Some(PatternElement {
+            target: expr_into_pattern_target(expr, assignment)?,
+            default: None,
+        }),
+    }
+}
+
+/// Converts an identifier, object literal or array literal into the equivalent pattern target
+fn expr_into_pattern_target(expr: Expr<'_>, assignment: bool) -> Option<PatternTarget<'_>> {
+    match expr {
+        // Assignments need to resolve identifiers like any other assignment, so they remain expressions
+        expr @ (Expr::Literal(LiteralExpr::Identifier(..)) | Expr::PropertyAccess(..)) if assignment => {
+            Some(PatternTarget::Expr(expr))
+        }
+        Expr::Literal(LiteralExpr::Identifier(Cow::Borrowed(ident), _)) => Some(PatternTarget::Identifier(ident)),
+        Expr::Object(ObjectLiteral(members)) => {
+            let mut fields = Vec::with_capacity(members.len());
+            let mut rest = None;
+            let mut members = members.into_iter().peekable();
+
+            while let Some((kind, value)) = members.next() {
+                let key = match kind {
+                    ObjectMemberKind::Static(name) => PatternKey::Static(name),
+                    ObjectMemberKind::Dynamic(expr) => PatternKey::Computed(expr),
+                    // The rest element must be the last element
+                    ObjectMemberKind::Spread if members.peek().is_none() => {
+                        rest = Some(Box::new(expr_into_pattern_target(value, assignment)?));
+                        continue;
+                    }
+                    ObjectMemberKind::Spread | ObjectMemberKind::Getter(..) | ObjectMemberKind::Setter(..) => {
+                        return None
+                    }
+                };
+
+                fields.push((key, expr_into_pattern_element(value, assignment)?));
+            }
+
+            Some(PatternTarget::Pattern(Pattern::Object { fields, rest }))
+        }
+        Expr::Array(ArrayLiteral(members)) => {
+            let mut elements = Vec::with_capacity(members.len());
+            let mut rest = None;
+            let mut members = members.into_iter().peekable();
+
+            while let Some(member) = members.next() {
+                match member {
+                    ArrayMemberKind::Item(expr) => elements.push(Some(expr_into_pattern_element(expr, assignment)?)),
+                    // The rest element must be the last element
+                    ArrayMemberKind::Spread(expr) if members.peek().is_none() => {
+                        rest = Some(Box::new(expr_into_pattern_target(expr, assignment)?))
+                    }
+                    ArrayMemberKind::Spread(..) => return None,
+                }
+            }
+
+            Some(PatternTarget::Pattern(Pattern::Array { elements, rest }))
+        }
+        _ => None,
+    }
 }
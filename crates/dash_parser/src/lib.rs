@@ -9,6 +9,8 @@ use dash_middle::parser::statement::FuncId;
 use dash_middle::parser::statement::Statement;
 use dash_middle::util::Counter;
 use dash_middle::util::LevelStack;
+use num_bigint::BigInt;
+use num_traits::Num;
 use stmt::StatementParser;
 
 mod expr;
@@ -120,6 +122,25 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses a BigInt literal (`42n`, `0xffn`), which may be prefixed with a radix
+    pub fn parse_bigint_literal(&mut self, full: &str) -> Option<BigInt> {
+        let src = full.strip_suffix('n').unwrap_or(full);
+        let (src, radix) = match src.get(..2) {
+            Some("0x" | "0X") => (&src[2..], 16),
+            Some("0b" | "0B") => (&src[2..], 2),
+            Some("0o" | "0O") => (&src[2..], 8),
+            _ => (src, 10),
+        };
+
+        match BigInt::from_str_radix(src, radix) {
+            Ok(n) => Some(n),
+            Err(e) => {
+                self.create_error(ErrorKind::ParseBigIntError(self.previous().cloned()?, e));
+                None
+            }
+        }
+    }
+
     fn is_eof(&self) -> bool {
         self.idx >= self.tokens.len()
     }
@@ -19,6 +19,10 @@ use dash_middle::parser::statement::IfStatement;
 use dash_middle::parser::statement::ImportKind;
 use dash_middle::parser::statement::Loop;
 use dash_middle::parser::statement::Parameter;
+use dash_middle::parser::statement::Pattern;
+use dash_middle::parser::statement::PatternElement;
+use dash_middle::parser::statement::PatternKey;
+use dash_middle::parser::statement::PatternTarget;
 use dash_middle::parser::statement::ReturnStatement;
 use dash_middle::parser::statement::SpecifierKind;
 use dash_middle::parser::statement::Statement;
@@ -60,15 +64,28 @@ pub trait StatementParser<'a> {
     fn parse_variable_definition(&mut self) -> Option<Expr<'a>>;
     fn parse_if(&mut self, parse_else: bool) -> Option<IfStatement<'a>>;
     fn parse_switch(&mut self) -> Option<SwitchStatement<'a>>;
+    /// Parses a labelled statement, assuming that the label has already been consumed
+    fn parse_labelled(&mut self) -> Option<Statement<'a>>;
+    /// Parses the optional label of a `break` or `continue` statement
+    fn parse_jump_label(&mut self) -> Option<&'a str>;
     /// Parses a list of parameters (identifier, followed by optional type segment) delimited by comma,
     /// assuming that the ( has already been consumed
     fn parse_parameter_list(&mut self) -> ParameterList<'a>;
+    /// Parses a binding target, i.e. an identifier or a destructuring pattern
+    fn parse_binding_target(&mut self) -> Option<PatternTarget<'a>>;
+    /// Parses an object destructuring pattern, assuming that the { has already been consumed
+    fn parse_object_pattern(&mut self) -> Option<Pattern<'a>>;
+    /// Parses an array destructuring pattern, assuming that the [ has already been consumed
+    fn parse_array_pattern(&mut self) -> Option<Pattern<'a>>;
+    /// Parses the optional default value of a pattern element, i.e. `= 5`
+    fn parse_pattern_element(&mut self, target: PatternTarget<'a>) -> Option<PatternElement<'a>>;
 }
 
 impl<'a> StatementParser<'a> for Parser<'a> {
     fn parse_statement(&mut self) -> Option<Statement<'a>> {
         self.error_sync = false;
-        let stmt = match self.next()?.ty {
+        let ty = self.next()?.ty;
+        let stmt = match ty {
             TokenType::Let | TokenType::Const | TokenType::Var => self.parse_variable().map(Statement::Variable),
             TokenType::If => self.parse_if(true).map(Statement::If),
             TokenType::Function => self.parse_function(false).map(Statement::Function),
@@ -83,16 +100,22 @@ impl<'a> StatementParser<'a> for Parser<'a> {
             TokenType::While => self.parse_while_loop().map(Statement::Loop),
             TokenType::Do => self.parse_do_while_loop().map(Statement::Loop),
             TokenType::Try => self.parse_try().map(Statement::Try),
-            TokenType::Throw => self.parse_throw().map(Statement::Throw),
+            TokenType::Throw => {
+                let loc = self.previous()?.loc;
+                self.parse_throw().map(|expr| Statement::Throw(expr, loc))
+            }
             TokenType::Return => self.parse_return().map(Statement::Return),
             TokenType::For => self.parse_for_loop().map(Statement::Loop),
             TokenType::Import => self.parse_import().map(Statement::Import),
             TokenType::Export => self.parse_export().map(Statement::Export),
             TokenType::Class => self.parse_class().map(Statement::Class),
             TokenType::Switch => self.parse_switch().map(Statement::Switch),
-            TokenType::Continue => Some(Statement::Continue),
-            TokenType::Break => Some(Statement::Break),
+            TokenType::Continue => Some(Statement::Continue(self.parse_jump_label())),
+            TokenType::Break => Some(Statement::Break(self.parse_jump_label())),
             TokenType::Debugger => Some(Statement::Debugger),
+            other if other.is_identifier() && self.current().is_some_and(|tok| tok.ty == TokenType::Colon) => {
+                self.parse_labelled()
+            }
             _ => {
                 // We've skipped the current character because of the statement cases that skip the current token
                 // So we go back, as the skipped token belongs to this expression
@@ -127,6 +150,37 @@ impl<'a> StatementParser<'a> for Parser<'a> {
         // Start parsing class members
         while !self.expect_and_skip(&[TokenType::RightBrace], false) {
             let is_static = self.expect_and_skip(&[TokenType::Static], false);
+
+            if is_static && self.expect_and_skip(&[TokenType::LeftBrace], false) {
+                let BlockStatement(statements) = self.parse_block()?;
+
+                let func_id = self.function_counter.advance();
+                let func =
+                    FunctionDeclaration::new(None, func_id, Vec::new(), statements, FunctionKind::Function, false);
+
+                members.push(ClassMember {
+                    private: false,
+                    static_: true,
+                    kind: ClassMemberKind::StaticBlock(func),
+                });
+                continue;
+            }
+
+            // `get` and `set` are only accessors if followed by a member name,
+            // otherwise they are a normal method or property named "get"/"set"
+            let accessor = self.next()?.clone();
+            let is_accessor = matches!(accessor.ty, TokenType::Get | TokenType::Set)
+                && self.current().is_some_and(|t| {
+                    !matches!(
+                        t.ty,
+                        TokenType::LeftParen | TokenType::Assignment | TokenType::Semicolon | TokenType::RightBrace
+                    )
+                });
+
+            if !is_accessor {
+                self.advance_back();
+            }
+
             let is_private = self.expect_and_skip(&[TokenType::Hash], false);
 
             let name = self.next_identifier()?;
@@ -135,6 +189,22 @@ impl<'a> StatementParser<'a> for Parser<'a> {
 
             if is_method {
                 let arguments = self.parse_parameter_list()?;
+
+                // Make sure parameter count is correct
+                let expect = match accessor.ty {
+                    TokenType::Get if is_accessor => Some(0),
+                    TokenType::Set if is_accessor => Some(1),
+                    _ => None,
+                };
+                if let Some(expect) = expect.filter(|&expect| expect != arguments.len()) {
+                    self.create_error(ErrorKind::InvalidAccessorParams {
+                        token: accessor,
+                        expect,
+                        got: arguments.len(),
+                    });
+                    return None;
+                }
+
                 let body = self.parse_statement()?;
 
                 let func_id = self.function_counter.advance();
@@ -150,9 +220,21 @@ impl<'a> StatementParser<'a> for Parser<'a> {
                 members.push(ClassMember {
                     private: is_private,
                     static_: is_static,
-                    kind: ClassMemberKind::Method(func),
+                    kind: match accessor.ty {
+                        TokenType::Get if is_accessor => ClassMemberKind::Getter(func),
+                        TokenType::Set if is_accessor => ClassMemberKind::Setter(func),
+                        _ => ClassMemberKind::Method(func),
+                    },
                 });
             } else {
+                if is_accessor {
+                    self.create_error(ErrorKind::UnexpectedToken(
+                        self.current()?.clone(),
+                        TokenType::LeftParen,
+                    ));
+                    return None;
+                }
+
                 let kind = self.next()?.ty;
 
                 let value = match kind {
@@ -166,6 +248,22 @@ impl<'a> StatementParser<'a> for Parser<'a> {
                     }
                 };
 
+                // Static initializers are evaluated with the class as `this`, so they are wrapped in a function
+                let value = match value {
+                    Some(value) if is_static => {
+                        let func_id = self.function_counter.advance();
+                        Some(Expr::function(FunctionDeclaration::new(
+                            None,
+                            func_id,
+                            Vec::new(),
+                            vec![Statement::Return(ReturnStatement(value))],
+                            FunctionKind::Function,
+                            false,
+                        )))
+                    }
+                    value => value,
+                };
+
                 self.expect_and_skip(&[TokenType::Semicolon], false);
 
                 members.push(ClassMember {
@@ -253,21 +351,31 @@ impl<'a> StatementParser<'a> for Parser<'a> {
     fn parse_try(&mut self) -> Option<TryCatch<'a>> {
         let try_ = self.parse_statement()?;
 
-        self.expect_and_skip(&[TokenType::Catch], true);
+        let catch = if self.expect_and_skip(&[TokenType::Catch], false) {
+            let binding = if self.expect_and_skip(&[TokenType::LeftParen], false) {
+                let binding = self.parse_binding_target()?;
+                self.expect_and_skip(&[TokenType::RightParen], true);
+                Some(binding)
+            } else {
+                None
+            };
 
-        let capture_ident = if self.expect_and_skip(&[TokenType::LeftParen], false) {
-            let ident = self.next_identifier()?;
-            self.expect_and_skip(&[TokenType::RightParen], true);
-            Some(ident)
+            let body = self.parse_statement()?;
+            Some(Catch::new(body, binding))
         } else {
             None
         };
 
-        let catch = self.parse_statement()?;
-
-        // TODO: finally
+        // A try statement without a catch block must have a finally block
+        let finally = if self.expect_and_skip(&[TokenType::Finally], catch.is_none()) {
+            Some(self.parse_statement()?)
+        } else if catch.is_none() {
+            return None;
+        } else {
+            None
+        };
 
-        Some(TryCatch::new(try_, Catch::new(catch, capture_ident), None))
+        Some(TryCatch::new(try_, catch, finally))
     }
 
     fn parse_return(&mut self) -> Option<ReturnStatement<'a>> {
@@ -276,6 +384,7 @@ impl<'a> StatementParser<'a> for Parser<'a> {
     }
 
     fn parse_for_loop(&mut self) -> Option<Loop<'a>> {
+        let is_async = self.expect_and_skip(&[TokenType::Await], false);
         self.expect_and_skip(&[TokenType::LeftParen], true);
 
         let init = if self.expect_and_skip(&[TokenType::Semicolon], false) {
@@ -285,7 +394,12 @@ impl<'a> StatementParser<'a> for Parser<'a> {
 
             if is_binding {
                 let binding = self.parse_variable_binding()?;
-                let is_of_or_in = self.expect_and_skip(&[TokenType::Of, TokenType::In], false);
+                let is_of_or_in = if is_async {
+                    // Only for..of loops can be `for await`
+                    self.expect_and_skip(&[TokenType::Of], true)
+                } else {
+                    self.expect_and_skip(&[TokenType::Of, TokenType::In], false)
+                };
 
                 if is_of_or_in {
                     let ty = self.previous()?.ty;
@@ -297,7 +411,12 @@ impl<'a> StatementParser<'a> for Parser<'a> {
 
                     return Some(match ty {
                         TokenType::In => Loop::ForIn(ForInLoop { binding, expr, body }),
-                        TokenType::Of => Loop::ForOf(ForOfLoop { binding, expr, body }),
+                        TokenType::Of => Loop::ForOf(ForOfLoop {
+                            binding,
+                            expr,
+                            body,
+                            r#async: is_async,
+                        }),
                         _ => unreachable!(),
                     });
                 } else {
@@ -434,6 +553,30 @@ impl<'a> StatementParser<'a> for Parser<'a> {
         Some(IfStatement::new(condition, then, branches, el))
     }
 
+    fn parse_labelled(&mut self) -> Option<Statement<'a>> {
+        let label = self.previous()?.clone();
+        let label = must_borrow_lexeme!(self, &label)?;
+
+        // Skip the colon
+        self.advance();
+
+        let stmt = self.parse_statement()?;
+        Some(Statement::Labelled(label, Box::new(stmt)))
+    }
+
+    fn parse_jump_label(&mut self) -> Option<&'a str> {
+        let keyword_line = self.previous()?.loc.line;
+        let label = self.current()?.clone();
+
+        // A label on the next line is a new statement because of automatic semicolon insertion
+        if label.ty.is_identifier() && label.loc.line == keyword_line {
+            self.advance();
+            must_borrow_lexeme!(self, &label)
+        } else {
+            None
+        }
+    }
+
     fn parse_parameter_list(&mut self) -> ParameterList<'a> {
         let mut parameters = Vec::new();
 
@@ -447,11 +590,15 @@ impl<'a> StatementParser<'a> for Parser<'a> {
                         self.expect_and_skip(&[TokenType::Dot], true);
                     }
 
-                    let ident = self.next_identifier()?;
-
-                    Parameter::Spread(ident)
+                    Parameter::rest(self.parse_binding_target()?)
                 }
                 TokenType::Comma => continue,
+                TokenType::LeftBrace => Parameter::Pattern(self.parse_object_pattern()?),
+                TokenType::LeftSquareBrace => Parameter::Pattern(self.parse_array_pattern()?),
+                TokenType::EmptySquareBrace => Parameter::Pattern(Pattern::Array {
+                    elements: Vec::new(),
+                    rest: None,
+                }),
                 other if other.is_identifier() => Parameter::Identifier(must_borrow_lexeme!(self, &tok)?),
                 _ => {
                     self.create_error(ErrorKind::UnexpectedToken(tok.clone(), TokenType::Comma));
@@ -473,7 +620,7 @@ impl<'a> StatementParser<'a> for Parser<'a> {
                 None
             };
 
-            let is_spread = matches!(parameter, Parameter::Spread(..));
+            let is_spread = matches!(parameter, Parameter::Spread(..) | Parameter::SpreadPattern(..));
 
             parameters.push((parameter, default, ty));
 
@@ -490,116 +637,120 @@ impl<'a> StatementParser<'a> for Parser<'a> {
         Some(parameters)
     }
 
-    fn parse_variable_binding_with_kind(&mut self, kind: VariableDeclarationKind) -> Option<VariableBinding<'a>> {
-        let name = if self.expect_and_skip(&[TokenType::LeftBrace], false) {
-            // Object destructuring
-            let mut fields = Vec::new();
-            let mut rest = None;
+    fn parse_binding_target(&mut self) -> Option<PatternTarget<'a>> {
+        let tok = self.next().cloned()?;
+
+        match tok.ty {
+            TokenType::LeftBrace => self.parse_object_pattern().map(PatternTarget::Pattern),
+            TokenType::LeftSquareBrace => self.parse_array_pattern().map(PatternTarget::Pattern),
+            TokenType::EmptySquareBrace => Some(PatternTarget::Pattern(Pattern::Array {
+                elements: Vec::new(),
+                rest: None,
+            })),
+            other if other.is_identifier() => must_borrow_lexeme!(self, &tok).map(PatternTarget::Identifier),
+            _ => {
+                self.create_error(ErrorKind::UnexpectedToken(tok, TokenType::Identifier));
+                None
+            }
+        }
+    }
 
-            while !self.expect_and_skip(&[TokenType::RightBrace], false) {
-                self.expect_and_skip(&[TokenType::Comma], false); // TODO: should be an error
-
-                let cur = self.current()?.clone();
-                match cur.ty {
-                    TokenType::Dot => {
-                        // Skip the dot
-                        self.advance();
-                        // Begin of rest operator, must be followed by two more dots
-                        for _ in 0..2 {
-                            self.expect_and_skip(&[TokenType::Dot], true);
-                        }
-
-                        let name = self.current()?.clone();
-                        if name.ty.is_identifier() {
-                            if rest.is_some() {
-                                // Only allow one rest operator
-                                self.create_error(ErrorKind::MultipleRestInDestructuring(name));
-                                return None;
-                            }
-
-                            rest = Some(must_borrow_lexeme!(self, &name)?);
-                            self.advance();
-                        } else {
-                            self.create_error(ErrorKind::UnexpectedToken(name, TokenType::Identifier));
-                            return None;
-                        }
-                    }
-                    other if other.is_identifier() => {
-                        let name = must_borrow_lexeme!(self, &cur)?;
-                        self.advance();
-
-                        let alias = if self.expect_and_skip(&[TokenType::Colon], false) {
-                            let alias = self.current()?.clone();
-                            if alias.ty.is_identifier() {
-                                self.advance();
-                                Some(must_borrow_lexeme!(self, &alias)?)
-                            } else {
-                                self.create_error(ErrorKind::UnexpectedToken(alias, TokenType::Identifier));
-                                return None;
-                            }
-                        } else {
-                            None
-                        };
-                        fields.push((name, alias));
-                    }
-                    _ => {
-                        self.create_error(ErrorKind::UnexpectedToken(cur, TokenType::Identifier));
-                        return None;
-                    }
-                }
+    fn parse_object_pattern(&mut self) -> Option<Pattern<'a>> {
+        let mut fields = Vec::new();
+        let mut rest = None;
+
+        while !self.expect_and_skip(&[TokenType::RightBrace], false) {
+            if !fields.is_empty() && !self.expect_and_skip(&[TokenType::Comma], true) {
+                return None;
             }
 
-            VariableDeclarationName::ObjectDestructuring { fields, rest }
-        } else if self.expect_and_skip(&[TokenType::LeftSquareBrace], false) {
-            // Array destructuring
-            let mut fields = Vec::new();
-            let mut rest = None;
-
-            while !self.expect_and_skip(&[TokenType::RightSquareBrace], false) {
-                self.expect_and_skip(&[TokenType::Comma], false); // TODO: should be an error
-
-                let cur = self.current()?.clone();
-                match cur.ty {
-                    TokenType::Dot => {
-                        // Skip the dot
-                        self.advance();
-                        // Begin of rest operator, must be followed by two more dots
-                        for _ in 0..2 {
-                            self.expect_and_skip(&[TokenType::Dot], true);
-                        }
-
-                        let name = self.current()?.clone();
-                        if name.ty.is_identifier() {
-                            if rest.is_some() {
-                                // Only allow one rest operator
-                                self.create_error(ErrorKind::MultipleRestInDestructuring(name));
-                                return None;
-                            }
-
-                            rest = Some(must_borrow_lexeme!(self, &name)?);
-                            self.advance();
-                        } else {
-                            self.create_error(ErrorKind::UnexpectedToken(name, TokenType::Identifier));
-                            return None;
-                        }
-                    }
-                    other if other.is_identifier() => {
-                        let name = must_borrow_lexeme!(self, &cur)?;
-                        self.advance();
-                        fields.push(name);
-                    }
+            // Allow a trailing comma
+            if self.expect_and_skip(&[TokenType::RightBrace], false) {
+                break;
+            }
+
+            if self.parse_spread_operator() {
+                // The rest element must be the last element
+                rest = Some(Box::new(PatternTarget::Identifier(self.next_identifier()?)));
+                self.expect_and_skip(&[TokenType::RightBrace], true);
+                break;
+            }
+
+            let tok = self.next().cloned()?;
+            let key = match tok.ty {
+                TokenType::LeftSquareBrace => {
+                    let key = self.parse_expression()?;
+                    self.expect_and_skip(&[TokenType::RightSquareBrace], true);
+                    PatternKey::Computed(key)
+                }
+                _ => PatternKey::Static(must_borrow_lexeme!(self, &tok)?),
+            };
+
+            let target = if self.expect_and_skip(&[TokenType::Colon], false) {
+                self.parse_binding_target()?
+            } else {
+                match key {
+                    // Shorthand properties bind to a variable of the same name
+                    PatternKey::Static(name) if tok.ty.is_identifier() => PatternTarget::Identifier(name),
                     _ => {
-                        self.create_error(ErrorKind::UnexpectedToken(cur, TokenType::Identifier));
+                        self.create_error(ErrorKind::UnexpectedToken(tok, TokenType::Colon));
                         return None;
                     }
                 }
+            };
+
+            fields.push((key, self.parse_pattern_element(target)?));
+        }
+
+        Some(Pattern::Object { fields, rest })
+    }
+
+    fn parse_array_pattern(&mut self) -> Option<Pattern<'a>> {
+        let mut elements = Vec::new();
+        let mut rest = None;
+
+        while !self.expect_and_skip(&[TokenType::RightSquareBrace], false) {
+            // A comma without an element before it is a hole: `[, a]`
+            if self.expect_and_skip(&[TokenType::Comma], false) {
+                elements.push(None);
+                continue;
+            }
+
+            if self.parse_spread_operator() {
+                // The rest element must be the last element
+                rest = Some(Box::new(self.parse_binding_target()?));
+                self.expect_and_skip(&[TokenType::RightSquareBrace], true);
+                break;
+            }
+
+            let target = self.parse_binding_target()?;
+            elements.push(Some(self.parse_pattern_element(target)?));
+
+            // Elements are separated by commas
+            if !self.expect_and_skip(&[TokenType::Comma], false) {
+                self.expect_and_skip(&[TokenType::RightSquareBrace], true);
+                break;
             }
+        }
 
-            VariableDeclarationName::ArrayDestructuring { fields, rest }
+        Some(Pattern::Array { elements, rest })
+    }
+
+    fn parse_pattern_element(&mut self, target: PatternTarget<'a>) -> Option<PatternElement<'a>> {
+        let default = if self.expect_and_skip(&[TokenType::Assignment], false) {
+            Some(self.parse_expression()?)
         } else {
-            // Identifier
-            let name = self.next_identifier()?;
-            VariableDeclarationName::Identifier(name)
+            None
+        };
+
+        Some(PatternElement { target, default })
+    }
+
+    fn parse_variable_binding_with_kind(&mut self, kind: VariableDeclarationKind) -> Option<VariableBinding<'a>> {
+        let name = match self.parse_binding_target()? {
+            PatternTarget::Identifier(name) => VariableDeclarationName::Identifier(name),
+            PatternTarget::Pattern(pattern) => VariableDeclarationName::Pattern(pattern),
+            PatternTarget::Expr(..) => unreachable!("Binding targets are never expressions"),
         };
 
         let ty = if self.expect_and_skip(&[TokenType::Colon], false) {
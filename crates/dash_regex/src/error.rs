@@ -5,6 +5,39 @@ pub enum Error {
     #[error("unexpected end of file")]
     UnexpectedEof,
 
-    #[error("unexpected character: {}", *.0 as char)]
-    UnexpectedChar(u8),
+    #[error("unexpected character: {0}")]
+    UnexpectedChar(char),
+
+    #[error("nothing to repeat")]
+    NothingToRepeat,
+
+    #[error("unmatched parenthesis")]
+    UnmatchedParen,
+
+    #[error("numbers out of order in quantifier")]
+    QuantifierOutOfOrder,
+
+    #[error("range out of order in character class")]
+    RangeOutOfOrder,
+
+    #[error("invalid escape sequence")]
+    InvalidEscape,
+
+    #[error("invalid group name")]
+    InvalidGroupName,
+
+    #[error("duplicate capture group name: {0}")]
+    DuplicateGroupName(String),
+
+    #[error("invalid named reference: {0}")]
+    InvalidNamedReference(String),
+
+    #[error("invalid unicode property name: {0}")]
+    InvalidUnicodeProperty(String),
+
+    #[error("invalid regular expression flag: {0}")]
+    InvalidFlag(char),
+
+    #[error("duplicate regular expression flag: {0}")]
+    DuplicateFlag(char),
 }
@@ -0,0 +1,71 @@
+use std::fmt;
+use std::str::FromStr;
+
+use bitflags::bitflags;
+#[cfg(feature = "format")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+bitflags! {
+    #[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
+    #[derive(Default)]
+    pub struct Flags: u8 {
+        /// `g`: find all matches instead of stopping after the first one
+        const GLOBAL = 1 << 0;
+        /// `i`: compare characters case-insensitively
+        const IGNORE_CASE = 1 << 1;
+        /// `m`: `^` and `$` also match at line terminators
+        const MULTILINE = 1 << 2;
+        /// `s`: `.` also matches line terminators
+        const DOT_ALL = 1 << 3;
+        /// `u`: enables unicode escapes (`\u{...}`, `\p{...}`) and stricter parsing
+        const UNICODE = 1 << 4;
+        /// `y`: only match at the current position, without searching ahead
+        const STICKY = 1 << 5;
+    }
+}
+
+/// Flags in the canonical order they appear in `RegExp.prototype.flags`
+const FLAG_CHARS: [(char, Flags); 6] = [
+    ('g', Flags::GLOBAL),
+    ('i', Flags::IGNORE_CASE),
+    ('m', Flags::MULTILINE),
+    ('s', Flags::DOT_ALL),
+    ('u', Flags::UNICODE),
+    ('y', Flags::STICKY),
+];
+
+impl FromStr for Flags {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flags = Flags::empty();
+
+        for c in s.chars() {
+            let flag = match FLAG_CHARS.iter().find(|(fc, _)| *fc == c) {
+                Some((_, flag)) => *flag,
+                None => return Err(Error::InvalidFlag(c)),
+            };
+
+            if flags.contains(flag) {
+                return Err(Error::DuplicateFlag(c));
+            }
+
+            flags |= flag;
+        }
+
+        Ok(flags)
+    }
+}
+
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (c, flag) in FLAG_CHARS {
+            if self.contains(flag) {
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,29 +1,89 @@
 pub use error::Error;
+pub use flags::Flags;
 pub use matcher::Matcher;
 pub use node::Node;
+pub use node::ParsedRegex;
 pub use parser::Parser;
 
 pub mod error;
+pub mod flags;
 pub mod matcher;
 pub mod node;
 pub mod parser;
-mod stream;
-mod visitor;
-
-pub type Regex = Vec<Node>;
 
 #[cfg(test)]
 #[test]
 pub fn test() {
-    use parser::Parser;
+    use std::ops::Range;
 
-    use crate::matcher::Matcher;
+    fn find(regex: &str, flags: &str, input: &str) -> Option<Vec<Option<Range<usize>>>> {
+        let flags = flags.parse().unwrap();
+        let regex = Parser::new(regex, flags).parse_all().unwrap();
+        let mut matcher = Matcher::new(&regex, flags, input);
+        matcher.find_at(0).map(|m| m.groups)
+    }
 
     fn matches(regex: &str, input: &str) -> bool {
-        let nodes = Parser::new(regex.as_bytes()).parse_all().unwrap();
-        let mut matcher = Matcher::new(dbg!(&nodes), input.as_bytes());
-        matcher.matches()
+        find(regex, "", input).is_some()
     }
 
-    dbg!(matches("^(ab|cd|ef)+$", "abababcdcdabef"));
+    assert!(matches("^(ab|cd|ef)+$", "abababcdcdabef"));
+    assert!(!matches("^(ab|cd|ef)+$", "abababcdcdabeg"));
+    assert!(matches("^a{2,3}$", "aaa"));
+    assert!(!matches("^a{2,3}$", "aaaa"));
+    assert!(matches("^[a-c]+\\d$", "abcab1"));
+    assert!(matches("^[^a-c]$", "d"));
+
+    // Captures and backtracking
+    assert_eq!(
+        find("(a+)(a)", "", "aaa"),
+        Some(vec![Some(0..3), Some(0..2), Some(2..3)])
+    );
+    assert_eq!(
+        find("(a+?)(a*)", "", "aaa"),
+        Some(vec![Some(0..3), Some(0..1), Some(1..3)])
+    );
+    let groups = find("(z)((a+)?(b+)?(c))*", "", "zaacbbbcac").unwrap();
+    assert_eq!((groups[3].clone(), groups[4].clone()), (Some(8..9), None));
+    assert_eq!(find("(a)|b", "", "b"), Some(vec![Some(0..1), None]));
+
+    // Backtracking over long inputs does not overflow the stack
+    assert!(matches("(a|b)*c", &format!("{}c", "ab".repeat(20000))));
+    assert!(!matches("^(?:a+)+$", &format!("{}b", "a".repeat(12))));
+    assert_eq!(find("(a|b)*?c", "", "abc"), Some(vec![Some(0..3), Some(1..2)]));
+
+    // Backreferences and named groups
+    assert!(matches("^(\\w+) \\1$", "hello hello"));
+    assert!(!matches("^(\\w+) \\1$", "hello world"));
+    assert!(matches("^(?<word>\\w+) \\k<word>$", "abc abc"));
+    let regex = Parser::new("(?<year>\\d{4})-(?<month>\\d{2})", Flags::empty())
+        .parse_all()
+        .unwrap();
+    assert_eq!(regex.group_index("month"), Some(2));
+
+    // Lookaround
+    assert_eq!(find("\\w+(?=!)", "", "hey you!").unwrap()[0], Some(4..7));
+    assert_eq!(find("\\d+(?!px)", "", "12px 34em").unwrap()[0], Some(0..1));
+    assert_eq!(find("(?<=\\$)\\d+", "", "cost: $42").unwrap()[0], Some(7..9));
+    assert_eq!(find("(?<!\\$)\\b\\d+", "", "$42 17").unwrap()[0], Some(4..6));
+
+    // Flags
+    assert!(find("HELLO", "i", "say hello").is_some());
+    assert!(find("^b$", "", "a\nb\nc").is_none());
+    assert!(find("^b$", "m", "a\nb\nc").is_some());
+    assert!(find("a.b", "", "a\nb").is_none());
+    assert!(find("a.b", "s", "a\nb").is_some());
+    assert!(find("b", "y", "ab").is_none());
+    assert_eq!(find("\\u{1F600}", "u", "x😀").unwrap()[0], Some(1..5));
+    assert!(find("^\\p{Lu}\\p{Ll}+$", "u", "Émile").is_some());
+    assert!("gg".parse::<Flags>().is_err());
+    assert!("x".parse::<Flags>().is_err());
+    assert_eq!("yumigs".parse::<Flags>().unwrap().to_string(), "gimsuy");
+
+    // Syntax errors
+    assert!(Parser::new("(a", Flags::empty()).parse_all().is_err());
+    assert!(Parser::new("a)", Flags::empty()).parse_all().is_err());
+    assert!(Parser::new("*a", Flags::empty()).parse_all().is_err());
+    assert!(Parser::new("[z-a]", Flags::empty()).parse_all().is_err());
+    assert!(Parser::new("(?<a>x)(?<a>y)", Flags::empty()).parse_all().is_err());
 }
@@ -1,31 +1,614 @@
+use std::ops::Range;
+
+use crate::flags::Flags;
+use crate::node::Anchor;
+use crate::node::ClassItem;
+use crate::node::MetaSequence;
 use crate::node::Node;
-use crate::stream::BorrowedStream;
-use crate::visitor::Visit;
+use crate::node::ParsedRegex;
+
+/// A successful match. Positions are byte offsets into the matched text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// The range of every capture group, where group 0 is the whole match
+    pub groups: Vec<Option<Range<usize>>>,
+}
+
+impl Match {
+    pub fn start(&self) -> usize {
+        self.range().start
+    }
+
+    pub fn end(&self) -> usize {
+        self.range().end
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.groups[0].clone().expect("group 0 is always present")
+    }
+
+    pub fn group(&self, index: usize) -> Option<Range<usize>> {
+        self.groups.get(index).cloned().flatten()
+    }
+}
+
+/// An instruction of the program that a regex is compiled to
+#[derive(Debug, Clone, Copy)]
+enum Inst<'a> {
+    /// Matches a node that always consumes exactly one character
+    Char(&'a Node),
+    Anchor(&'a Anchor),
+    Backreference(usize),
+    /// Records the start of a capture group in a register
+    GroupStart {
+        register: usize,
+    },
+    /// Sets a capture group to the range from its start register to the current position
+    GroupEnd {
+        index: usize,
+        register: usize,
+    },
+    /// Continues at `first` and tries `second` when backtracking
+    Split {
+        first: usize,
+        second: usize,
+    },
+    Jump(usize),
+    /// Starts a quantified node by resetting its iteration counter
+    RepeatStart {
+        counter: usize,
+    },
+    /// Decides whether to run another iteration of a quantified node, which starts at the next instruction,
+    /// or to continue at `exit`
+    Repeat {
+        counter: usize,
+        min: usize,
+        max: Option<usize>,
+        greedy: bool,
+        exit: usize,
+    },
+    /// Starts an iteration by recording its start position and resetting the captures inside of the quantified node
+    IterationStart {
+        mark: usize,
+        groups: (usize, usize),
+    },
+    /// Ends an iteration and jumps back to the `Repeat` instruction at `repeat`
+    IterationEnd {
+        counter: usize,
+        mark: usize,
+        min: usize,
+        repeat: usize,
+    },
+    /// Matches the program starting at the next instruction as a lookaround, then continues at `next`
+    Lookaround {
+        ahead: bool,
+        negated: bool,
+        /// The maximum number of characters a lookbehind can match, or `None` if it is unbounded
+        width: Option<usize>,
+        next: usize,
+    },
+    Match,
+}
 
+/// An entry of the backtrack stack, which is either an alternative to try or a change to undo
+#[derive(Debug)]
+enum Backtrack {
+    Branch {
+        pc: usize,
+        pos: usize,
+    },
+    Register {
+        index: usize,
+        value: usize,
+    },
+    Capture {
+        index: usize,
+        value: Option<(usize, usize)>,
+    },
+    Captures(Vec<Option<(usize, usize)>>),
+}
+
+/// A backtracking regex matcher.
+///
+/// The regex is compiled to a small program, and alternatives that are left to try are kept on an explicit stack
+/// instead of the native stack, so that long inputs cannot overflow it.
 pub struct Matcher<'a> {
-    nodes: BorrowedStream<'a, Node>,
-    text: BorrowedStream<'a, u8>,
+    program: Vec<Inst<'a>>,
+    flags: Flags,
+    text: &'a str,
+    captures: Vec<Option<(usize, usize)>>,
+    /// Group start positions, iteration counters and iteration start positions
+    registers: Vec<usize>,
+    stack: Vec<Backtrack>,
 }
 
 impl<'a> Matcher<'a> {
-    pub fn new(nodes: &'a [Node], text: &'a [u8]) -> Self {
+    pub fn new(regex: &'a ParsedRegex, flags: Flags, text: &'a str) -> Self {
+        let mut compiler = Compiler::default();
+        compiler.compile_seq(&regex.nodes);
+        compiler.program.push(Inst::Match);
+
         Self {
-            nodes: BorrowedStream::new(nodes),
-            text: BorrowedStream::new(text),
+            program: compiler.program,
+            flags,
+            text,
+            captures: vec![None; regex.group_count + 1],
+            registers: vec![0; compiler.registers],
+            stack: Vec::new(),
         }
     }
 
+    /// Checks whether the regex matches anywhere in the text
     pub fn matches(&mut self) -> bool {
-        while !self.nodes.is_eof() {
-            if !self.matches_single() {
-                return false;
+        self.find_at(0).is_some()
+    }
+
+    /// Searches for a match starting at the byte offset `start`.
+    ///
+    /// If the regex has the sticky flag, only a match at exactly `start` is accepted.
+    pub fn find_at(&mut self, start: usize) -> Option<Match> {
+        let mut pos = start;
+
+        while pos <= self.text.len() {
+            if !self.text.is_char_boundary(pos) {
+                pos += 1;
+                continue;
+            }
+
+            self.captures.iter_mut().for_each(|c| *c = None);
+
+            if let Some(end) = self.run(0, pos, None) {
+                let mut groups = Vec::with_capacity(self.captures.len());
+                groups.push(Some(pos..end));
+                groups.extend(self.captures.iter().skip(1).map(|c| c.map(|(s, e)| s..e)));
+                return Some(Match { groups });
+            }
+
+            if self.flags.contains(Flags::STICKY) {
+                break;
             }
+
+            pos += self.char_at(pos).map_or(1, char::len_utf8);
         }
-        true
+
+        None
     }
 
-    pub fn matches_single(&mut self) -> bool {
-        let node = self.nodes.next().unwrap();
-        node.matches(&mut self.text)
+    /// Runs the program from `pc` at `pos`, returning the end position of the match.
+    ///
+    /// If `end` is given, the match must end at exactly that position. Alternatives are only tried until
+    /// the first match, so lookarounds that are run this way cannot be backtracked into.
+    fn run(&mut self, mut pc: usize, mut pos: usize, end: Option<usize>) -> Option<usize> {
+        let base = self.stack.len();
+
+        loop {
+            let matched = match self.program[pc] {
+                Inst::Char(node) => match self.match_single(node, pos) {
+                    Some(next) => {
+                        pos = next;
+                        pc += 1;
+                        true
+                    }
+                    None => false,
+                },
+                Inst::Anchor(anchor) => {
+                    pc += 1;
+                    self.matches_anchor(anchor, pos)
+                }
+                Inst::Backreference(index) => match self.captures.get(index).copied().flatten() {
+                    Some((start, end)) => match self.match_backreference(start, end, pos) {
+                        Some(next) => {
+                            pos = next;
+                            pc += 1;
+                            true
+                        }
+                        None => false,
+                    },
+                    // References to groups that did not participate in the match always succeed
+                    None => {
+                        pc += 1;
+                        true
+                    }
+                },
+                Inst::GroupStart { register } => {
+                    self.set_register(register, pos);
+                    pc += 1;
+                    true
+                }
+                Inst::GroupEnd { index, register } => {
+                    let value = self.captures[index].replace((self.registers[register], pos));
+                    self.stack.push(Backtrack::Capture { index, value });
+                    pc += 1;
+                    true
+                }
+                Inst::Split { first, second } => {
+                    self.stack.push(Backtrack::Branch { pc: second, pos });
+                    pc = first;
+                    true
+                }
+                Inst::Jump(target) => {
+                    pc = target;
+                    true
+                }
+                Inst::RepeatStart { counter } => {
+                    self.set_register(counter, 0);
+                    pc += 1;
+                    true
+                }
+                Inst::Repeat {
+                    counter,
+                    min,
+                    max,
+                    greedy,
+                    exit,
+                } => {
+                    let count = self.registers[counter];
+                    if count < min {
+                        pc += 1;
+                    } else if max == Some(count) {
+                        pc = exit;
+                    } else if greedy {
+                        // Greedy repetitions try another iteration before the rest of the pattern, lazy ones try it last
+                        self.stack.push(Backtrack::Branch { pc: exit, pos });
+                        pc += 1;
+                    } else {
+                        self.stack.push(Backtrack::Branch { pc: pc + 1, pos });
+                        pc = exit;
+                    }
+                    true
+                }
+                Inst::IterationStart { mark, groups } => {
+                    self.set_register(mark, pos);
+                    // Captures inside of a quantified node are reset on every iteration
+                    for index in groups.0..groups.1 {
+                        if let Some(value) = self.captures[index].take() {
+                            self.stack.push(Backtrack::Capture {
+                                index,
+                                value: Some(value),
+                            });
+                        }
+                    }
+                    pc += 1;
+                    true
+                }
+                Inst::IterationEnd {
+                    counter,
+                    mark,
+                    min,
+                    repeat,
+                } => {
+                    let count = self.registers[counter];
+                    // An iteration that matches the empty string would repeat forever
+                    if count >= min && self.registers[mark] == pos {
+                        false
+                    } else {
+                        self.set_register(counter, count + 1);
+                        pc = repeat;
+                        true
+                    }
+                }
+                Inst::Lookaround {
+                    ahead,
+                    negated,
+                    width,
+                    next,
+                } => {
+                    let saved = self.captures.clone();
+                    let matched = match ahead {
+                        true => self.run(pc + 1, pos, None).is_some(),
+                        false => self.match_lookbehind(pc + 1, width, pos),
+                    };
+
+                    if matched != negated {
+                        // Captures inside of negative lookarounds are never visible
+                        if negated {
+                            self.captures = saved;
+                        } else {
+                            self.stack.push(Backtrack::Captures(saved));
+                        }
+                        pc = next;
+                        true
+                    } else {
+                        self.captures = saved;
+                        false
+                    }
+                }
+                Inst::Match => {
+                    if end.is_none_or(|end| end == pos) {
+                        self.stack.truncate(base);
+                        return Some(pos);
+                    }
+                    false
+                }
+            };
+
+            if !matched {
+                (pc, pos) = self.backtrack(base)?;
+            }
+        }
+    }
+
+    /// Undoes changes until the most recent alternative above `base`, returning where to continue
+    fn backtrack(&mut self, base: usize) -> Option<(usize, usize)> {
+        while self.stack.len() > base {
+            match self.stack.pop()? {
+                Backtrack::Branch { pc, pos } => return Some((pc, pos)),
+                Backtrack::Register { index, value } => self.registers[index] = value,
+                Backtrack::Capture { index, value } => self.captures[index] = value,
+                Backtrack::Captures(captures) => self.captures = captures,
+            }
+        }
+
+        None
+    }
+
+    fn set_register(&mut self, index: usize, value: usize) {
+        let value = std::mem::replace(&mut self.registers[index], value);
+        self.stack.push(Backtrack::Register { index, value });
     }
+
+    fn char_at(&self, pos: usize) -> Option<char> {
+        self.text.get(pos..)?.chars().next()
+    }
+
+    fn char_before(&self, pos: usize) -> Option<char> {
+        self.text.get(..pos)?.chars().next_back()
+    }
+
+    fn ignore_case(&self) -> bool {
+        self.flags.contains(Flags::IGNORE_CASE)
+    }
+
+    /// Matches a node that always consumes exactly one character, returning the position after it
+    fn match_single(&self, node: &Node, pos: usize) -> Option<usize> {
+        let c = self.char_at(pos)?;
+
+        let matches = match node {
+            Node::AnyCharacter => self.flags.contains(Flags::DOT_ALL) || !is_line_terminator(c),
+            Node::MetaSequence(seq) => matches_meta_sequence(seq, c),
+            Node::LiteralCharacter(lit) => *lit == c || (self.ignore_case() && chars_eq_ignore_case(*lit, c)),
+            Node::CharacterClass { items, negated } => {
+                let matches = items.iter().any(|item| self.matches_class_item(item, c));
+                matches != *negated
+            }
+            _ => unreachable!(),
+        };
+
+        matches.then(|| pos + c.len_utf8())
+    }
+
+    fn matches_class_item(&self, item: &ClassItem, c: char) -> bool {
+        let matches = |c: char| match item {
+            ClassItem::Character(lit) => *lit == c,
+            ClassItem::Range(start, end) => (*start..=*end).contains(&c),
+            ClassItem::MetaSequence(seq) => matches_meta_sequence(seq, c),
+        };
+
+        matches(c) || (self.ignore_case() && case_variants(c).any(matches))
+    }
+
+    fn matches_anchor(&self, anchor: &Anchor, pos: usize) -> bool {
+        let multiline = self.flags.contains(Flags::MULTILINE);
+
+        match anchor {
+            Anchor::StartOfString => pos == 0 || (multiline && self.char_before(pos).is_some_and(is_line_terminator)),
+            Anchor::EndOfString => {
+                pos == self.text.len() || (multiline && self.char_at(pos).is_some_and(is_line_terminator))
+            }
+            Anchor::WordBoundary | Anchor::NotWordBoundary => {
+                let before = self.char_before(pos).is_some_and(is_word_character);
+                let after = self.char_at(pos).is_some_and(is_word_character);
+                (before != after) == matches!(anchor, Anchor::WordBoundary)
+            }
+        }
+    }
+
+    /// Matches the text of a previous capture group at `pos`, returning the position after it
+    fn match_backreference(&self, start: usize, end: usize, pos: usize) -> Option<usize> {
+        let mut rest = self.text.get(pos..)?.chars();
+        let mut len = 0;
+
+        for expected in self.text[start..end].chars() {
+            let c = rest.next()?;
+            if c != expected && !(self.ignore_case() && chars_eq_ignore_case(c, expected)) {
+                return None;
+            }
+            len += c.len_utf8();
+        }
+
+        Some(pos + len)
+    }
+
+    /// Checks whether the program at `pc` matches a substring that ends at `pos`
+    fn match_lookbehind(&mut self, pc: usize, width: Option<usize>, pos: usize) -> bool {
+        // Only look back as far as the lookbehind can possibly match
+        let max_chars = width.unwrap_or(usize::MAX);
+        let mut starts = vec![pos];
+        let mut start = pos;
+        while starts.len() <= max_chars {
+            match self.char_before(start) {
+                Some(c) => {
+                    start -= c.len_utf8();
+                    starts.push(start);
+                }
+                None => break,
+            }
+        }
+
+        // Lookbehinds match from right to left, so greedy quantifiers prefer the longest match
+        starts
+            .into_iter()
+            .rev()
+            .any(|start| self.run(pc, start, Some(pos)).is_some())
+    }
+}
+
+/// Compiles the nodes of a regex to a program for the matcher
+#[derive(Default)]
+struct Compiler<'a> {
+    program: Vec<Inst<'a>>,
+    /// The number of registers the program uses
+    registers: usize,
+}
+
+impl<'a> Compiler<'a> {
+    fn push(&mut self, inst: Inst<'a>) -> usize {
+        self.program.push(inst);
+        self.program.len() - 1
+    }
+
+    fn register(&mut self) -> usize {
+        self.registers += 1;
+        self.registers - 1
+    }
+
+    fn compile_seq(&mut self, nodes: &'a [Node]) {
+        for node in nodes {
+            self.compile_node(node);
+        }
+    }
+
+    fn compile_node(&mut self, node: &'a Node) {
+        match node {
+            Node::AnyCharacter | Node::MetaSequence(..) | Node::LiteralCharacter(..) | Node::CharacterClass { .. } => {
+                self.push(Inst::Char(node));
+            }
+            Node::Anchor(anchor) => {
+                self.push(Inst::Anchor(anchor));
+            }
+            Node::Backreference(index) => {
+                self.push(Inst::Backreference(*index));
+            }
+            Node::Group { nodes, index } => match *index {
+                Some(index) => {
+                    let register = self.register();
+                    self.push(Inst::GroupStart { register });
+                    self.compile_seq(nodes);
+                    self.push(Inst::GroupEnd { index, register });
+                }
+                None => self.compile_seq(nodes),
+            },
+            Node::Or(alternatives) => {
+                let mut jumps = Vec::new();
+                for (i, nodes) in alternatives.iter().enumerate() {
+                    if i == alternatives.len() - 1 {
+                        self.compile_seq(nodes);
+                        break;
+                    }
+
+                    let split = self.push(Inst::Split { first: 0, second: 0 });
+                    self.compile_seq(nodes);
+                    jumps.push(self.push(Inst::Jump(0)));
+                    self.program[split] = Inst::Split {
+                        first: split + 1,
+                        second: self.program.len(),
+                    };
+                }
+
+                let end = self.program.len();
+                for jump in jumps {
+                    self.program[jump] = Inst::Jump(end);
+                }
+            }
+            Node::Lookaround { nodes, ahead, negated } => {
+                let lookaround = self.push(Inst::Match);
+                self.compile_seq(nodes);
+                self.push(Inst::Match);
+                self.program[lookaround] = Inst::Lookaround {
+                    ahead: *ahead,
+                    negated: *negated,
+                    width: if *ahead { None } else { max_width(nodes) },
+                    next: self.program.len(),
+                };
+            }
+            Node::Repetition {
+                node,
+                min,
+                max,
+                greedy,
+                groups,
+            } => {
+                let counter = self.register();
+                let mark = self.register();
+
+                self.push(Inst::RepeatStart { counter });
+                let repeat = self.push(Inst::Match);
+                self.push(Inst::IterationStart {
+                    mark,
+                    groups: (groups.start, groups.end),
+                });
+                self.compile_node(node);
+                self.push(Inst::IterationEnd {
+                    counter,
+                    mark,
+                    min: *min,
+                    repeat,
+                });
+                self.program[repeat] = Inst::Repeat {
+                    counter,
+                    min: *min,
+                    max: *max,
+                    greedy: *greedy,
+                    exit: self.program.len(),
+                };
+            }
+        }
+    }
+}
+
+/// Returns the maximum number of characters the nodes can match, or `None` if it is unbounded
+fn max_width(nodes: &[Node]) -> Option<usize> {
+    nodes.iter().try_fold(0usize, |acc, node| {
+        let width = match node {
+            Node::AnyCharacter | Node::MetaSequence(..) | Node::LiteralCharacter(..) | Node::CharacterClass { .. } => 1,
+            Node::Anchor(..) | Node::Lookaround { .. } => 0,
+            Node::Group { nodes, .. } => max_width(nodes)?,
+            Node::Or(alternatives) => alternatives
+                .iter()
+                .map(|nodes| max_width(nodes))
+                .try_fold(0, |max, width| width.map(|width| max.max(width)))?,
+            Node::Repetition { node, max, .. } => max_width(std::slice::from_ref(&**node))?.checked_mul((*max)?)?,
+            Node::Backreference(..) => return None,
+        };
+        acc.checked_add(width)
+    })
+}
+
+fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+fn is_word_character(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_whitespace(c: char) -> bool {
+    c.is_whitespace() || c == '\u{feff}'
+}
+
+fn matches_meta_sequence(seq: &MetaSequence, c: char) -> bool {
+    match seq {
+        MetaSequence::Digit => c.is_ascii_digit(),
+        MetaSequence::NotDigit => !c.is_ascii_digit(),
+        MetaSequence::Word => is_word_character(c),
+        MetaSequence::NotWord => !is_word_character(c),
+        MetaSequence::Whitespace => is_whitespace(c),
+        MetaSequence::NotWhitespace => !is_whitespace(c),
+        MetaSequence::Property(property) => property.matches(c),
+        MetaSequence::NotProperty(property) => !property.matches(c),
+    }
+}
+
+/// Returns the single-character lowercase and uppercase mappings of a character
+fn case_variants(c: char) -> impl Iterator<Item = char> {
+    fn single(mut iter: impl Iterator<Item = char>) -> Option<char> {
+        let c = iter.next()?;
+        iter.next().is_none().then_some(c)
+    }
+
+    single(c.to_lowercase()).into_iter().chain(single(c.to_uppercase()))
+}
+
+fn chars_eq_ignore_case(a: char, b: char) -> bool {
+    a == b || case_variants(a).any(|a| a == b || case_variants(b).any(|b| a == b))
 }
@@ -1,6 +1,29 @@
+use std::ops::Range;
+
 #[cfg(feature = "format")]
 use serde::{Deserialize, Serialize};
 
+/// A parsed regular expression, along with information about its capture groups
+#[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedRegex {
+    pub nodes: Vec<Node>,
+    /// Number of capture groups, not including the implicit group 0 for the whole match
+    pub group_count: usize,
+    /// Names of named capture groups and their group index
+    pub group_names: Vec<(Box<str>, usize)>,
+}
+
+impl ParsedRegex {
+    /// Returns the index of the capture group with the given name
+    pub fn group_index(&self, name: &str) -> Option<usize> {
+        self.group_names
+            .iter()
+            .find(|(n, _)| n.as_ref() == name)
+            .map(|(_, index)| *index)
+    }
+}
+
 #[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Node {
@@ -10,41 +33,113 @@ pub enum Node {
         node: Box<Node>,
         min: usize,
         max: Option<usize>,
+        greedy: bool,
+        /// Capture groups contained in the repeated node, which are reset on every iteration
+        groups: Range<usize>,
+    },
+    LiteralCharacter(char),
+    CharacterClass {
+        items: Vec<ClassItem>,
+        negated: bool,
     },
-    LiteralCharacter(u8),
-    CharacterClass(Vec<Node>),
     Anchor(Anchor),
-    Or(Vec<Node>, Vec<Node>),
-    Optional(Box<Node>),
-    Group(Vec<Node>),
+    Or(Vec<Vec<Node>>),
+    Group {
+        nodes: Vec<Node>,
+        /// The capture group index, or `None` for non-capturing groups
+        index: Option<usize>,
+    },
+    Backreference(usize),
+    Lookaround {
+        nodes: Vec<Node>,
+        ahead: bool,
+        negated: bool,
+    },
 }
 
-impl Node {
-    pub fn unbounded_max_repetition(node: Node, min: usize) -> Self {
-        Self::Repetition {
-            node: Box::new(node),
-            min,
-            max: None,
-        }
-    }
-    pub fn repetition(node: Node, min: usize, max: usize) -> Self {
-        Self::Repetition {
-            node: Box::new(node),
-            min,
-            max: Some(max),
-        }
-    }
-    pub fn optional(node: Node) -> Self {
-        Self::Optional(Box::new(node))
-    }
+#[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassItem {
+    Character(char),
+    Range(char, char),
+    MetaSequence(MetaSequence),
 }
 
 #[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum MetaSequence {
     Digit,
+    NotDigit,
     Word,
+    NotWord,
     Whitespace,
+    NotWhitespace,
+    Property(UnicodeProperty),
+    NotProperty(UnicodeProperty),
+}
+
+/// Unicode properties that can be matched with `\p{...}` in unicode mode
+#[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnicodeProperty {
+    Any,
+    Ascii,
+    Alphabetic,
+    Letter,
+    UppercaseLetter,
+    LowercaseLetter,
+    Number,
+    DecimalNumber,
+    Punctuation,
+    WhiteSpace,
+}
+
+impl UnicodeProperty {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Any" => Some(Self::Any),
+            "ASCII" => Some(Self::Ascii),
+            "Alphabetic" | "Alpha" => Some(Self::Alphabetic),
+            "L" | "Letter" | "General_Category=Letter" | "gc=L" => Some(Self::Letter),
+            "Lu" | "Uppercase_Letter" | "Uppercase" | "Upper" => Some(Self::UppercaseLetter),
+            "Ll" | "Lowercase_Letter" | "Lowercase" | "Lower" => Some(Self::LowercaseLetter),
+            "N" | "Number" => Some(Self::Number),
+            "Nd" | "Decimal_Number" | "digit" => Some(Self::DecimalNumber),
+            "P" | "Punctuation" | "punct" => Some(Self::Punctuation),
+            "White_Space" | "space" => Some(Self::WhiteSpace),
+            _ => None,
+        }
+    }
+
+    pub fn matches(self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Ascii => c.is_ascii(),
+            Self::Alphabetic | Self::Letter => c.is_alphabetic(),
+            Self::UppercaseLetter => c.is_uppercase(),
+            Self::LowercaseLetter => c.is_lowercase(),
+            Self::Number => c.is_numeric(),
+            Self::DecimalNumber => is_decimal_number(c),
+            Self::Punctuation => c.is_ascii_punctuation() || (!c.is_ascii() && is_unicode_punctuation(c)),
+            Self::WhiteSpace => c.is_whitespace(),
+        }
+    }
+}
+
+/// Approximation of the general category `Nd`, covering ASCII, Arabic-Indic, Devanagari and fullwidth digits
+fn is_decimal_number(c: char) -> bool {
+    matches!(c, '0'..='9' | '\u{660}'..='\u{669}' | '\u{966}'..='\u{96f}' | '\u{ff10}'..='\u{ff19}')
+}
+
+/// Approximation of the general category `P` outside of ASCII, covering the common punctuation blocks
+fn is_unicode_punctuation(c: char) -> bool {
+    matches!(c,
+        '\u{a1}' | '\u{a7}' | '\u{ab}' | '\u{b6}' | '\u{b7}' | '\u{bb}' | '\u{bf}'
+        | '\u{2010}'..='\u{2027}'
+        | '\u{2030}'..='\u{205e}'
+        | '\u{3001}'..='\u{3003}'
+        | '\u{3008}'..='\u{3011}'
+    )
 }
 
 #[cfg_attr(feature = "format", derive(Serialize, Deserialize))]
@@ -52,4 +147,6 @@ pub enum MetaSequence {
 pub enum Anchor {
     StartOfString,
     EndOfString,
+    WordBoundary,
+    NotWordBoundary,
 }
@@ -1,25 +1,46 @@
-use std::mem;
+use std::ops::Range;
 
 use crate::error::Error;
+use crate::flags::Flags;
 use crate::node::Anchor;
+use crate::node::ClassItem;
 use crate::node::MetaSequence;
 use crate::node::Node;
+use crate::node::ParsedRegex;
+use crate::node::UnicodeProperty;
 
-pub struct Parser<'a> {
+pub struct Parser {
     index: usize,
-    input: &'a [u8],
+    input: Vec<char>,
+    flags: Flags,
+    /// Number of capture groups opened so far
+    group_count: usize,
+    group_names: Vec<(Box<str>, usize)>,
+    /// Total number of capture groups in the pattern, known ahead of time so that
+    /// backreferences to groups that appear later can be told apart from octal escapes
+    total_groups: usize,
+    /// All group names in the pattern, so that named backreferences can refer to later groups
+    all_group_names: Vec<(String, usize)>,
 }
 
-impl<'a> Parser<'a> {
-    pub fn new(input: &'a [u8]) -> Self {
-        Self { index: 0, input }
+impl Parser {
+    pub fn new(input: &str, flags: Flags) -> Self {
+        Self {
+            index: 0,
+            input: input.chars().collect(),
+            flags,
+            group_count: 0,
+            group_names: Vec::new(),
+            total_groups: 0,
+            all_group_names: Vec::new(),
+        }
     }
 
-    /// Advances the index and returns the previous byte
-    pub fn next_byte(&mut self) -> Option<u8> {
-        let byte = self.input.get(self.index);
+    /// Advances the index and returns the previous character
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.input.get(self.index);
         self.index += 1;
-        byte.copied()
+        c.copied()
     }
 
     pub fn advance(&mut self) {
@@ -30,153 +51,500 @@ impl<'a> Parser<'a> {
         self.index -= 1;
     }
 
-    pub fn current(&self) -> Option<u8> {
+    pub fn current(&self) -> Option<char> {
         self.input.get(self.index).copied()
     }
 
+    pub fn peek(&self, offset: usize) -> Option<char> {
+        self.input.get(self.index + offset).copied()
+    }
+
     pub fn is_eof(&self) -> bool {
         self.index >= self.input.len()
     }
 
-    pub fn parse_all(mut self) -> Result<Vec<Node>, Error> {
-        let mut nodes = Vec::new();
-        while !self.is_eof() {
-            if let Some(b'|') = self.current() {
-                self.advance();
+    /// Skips the current character if it is equal to `c`
+    fn eat(&mut self, c: char) -> bool {
+        let matches = self.current() == Some(c);
+        if matches {
+            self.advance();
+        }
+        matches
+    }
 
-                let left = mem::take(&mut nodes);
-                let mut right = Vec::new();
+    fn is_unicode(&self) -> bool {
+        self.flags.contains(Flags::UNICODE)
+    }
 
-                while !self.is_eof() {
-                    if let Some(b'|') = self.current() {
-                        // handle in outer loop
-                        break;
+    pub fn parse_all(mut self) -> Result<ParsedRegex, Error> {
+        self.prescan_groups();
+
+        let nodes = self.parse_disjunction()?;
+        if !self.is_eof() {
+            // The only way a disjunction can stop early is an unmatched `)`
+            return Err(Error::UnmatchedParen);
+        }
+
+        Ok(ParsedRegex {
+            nodes,
+            group_count: self.group_count,
+            group_names: self.group_names,
+        })
+    }
+
+    /// Counts the capture groups and collects group names in the pattern without parsing it
+    fn prescan_groups(&mut self) {
+        let mut in_class = false;
+        let mut i = 0;
+
+        while let Some(&c) = self.input.get(i) {
+            match c {
+                '\\' => i += 1,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => match (self.input.get(i + 1), self.input.get(i + 2), self.input.get(i + 3)) {
+                    (Some('?'), Some('<'), Some(c)) if *c != '=' && *c != '!' => {
+                        self.total_groups += 1;
+                        let name = self.input[i + 3..].iter().take_while(|&&c| c != '>').collect();
+                        self.all_group_names.push((name, self.total_groups));
                     }
-                    right.push(self.parse_primary()?);
-                }
-                nodes.push(Node::Or(left, right));
-            } else {
-                nodes.push(self.parse_primary()?);
+                    (Some('?'), ..) => {}
+                    _ => self.total_groups += 1,
+                },
+                _ => {}
             }
+            i += 1;
         }
-        Ok(nodes)
     }
 
-    fn parse_primary(&mut self) -> Result<Node, Error> {
-        let mut node = match self.next_byte() {
-            Some(b'.') => Ok(Node::AnyCharacter),
-            Some(b'\\') => self.parse_escape(),
-            Some(b'[') => self.parse_character_class(),
-            Some(b'(') => self.parse_group(),
-            // Anchor = Return early as they cannot have quantifiers
-            Some(b'^') => return Ok(Node::Anchor(Anchor::StartOfString)),
-            Some(b'$') => return Ok(Node::Anchor(Anchor::EndOfString)),
-            Some(other) => Ok(Node::LiteralCharacter(other)),
-            None => Err(Error::UnexpectedEof),
-        }?;
+    fn parse_disjunction(&mut self) -> Result<Vec<Node>, Error> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
 
-        match self.next_byte() {
-            Some(b'+') => node = Node::unbounded_max_repetition(node, 1),
-            Some(b'*') => node = Node::unbounded_max_repetition(node, 0),
-            Some(b'?') => node = Node::optional(node),
-            Some(b'{') => node = self.parse_bounded_repetition(node)?,
-            _ => self.back(), // back undos the advance in next()
+        if alternatives.len() == 1 {
+            Ok(alternatives.remove(0))
+        } else {
+            Ok(vec![Node::Or(alternatives)])
         }
+    }
 
-        Ok(node)
+    fn parse_alternative(&mut self) -> Result<Vec<Node>, Error> {
+        let mut nodes = Vec::new();
+        while !matches!(self.current(), None | Some('|' | ')')) {
+            nodes.push(self.parse_term()?);
+        }
+        Ok(nodes)
     }
 
-    fn read_int(&mut self) -> Result<usize, Error> {
-        let mut number = 0;
-        while let Some(byte) = self.current() {
-            match byte {
-                b'0'..=b'9' => {
-                    number = number * 10 + (byte - b'0') as usize;
-                    self.advance();
+    fn parse_term(&mut self) -> Result<Node, Error> {
+        let groups_before = self.group_count;
+
+        let node = match self.next_char() {
+            // Assertions return early as they cannot have quantifiers
+            Some('^') => return Ok(Node::Anchor(Anchor::StartOfString)),
+            Some('$') => return Ok(Node::Anchor(Anchor::EndOfString)),
+            Some('\\') if self.eat('b') => return Ok(Node::Anchor(Anchor::WordBoundary)),
+            Some('\\') if self.eat('B') => return Ok(Node::Anchor(Anchor::NotWordBoundary)),
+            Some('(') if self.current() == Some('?') && matches!(self.peek(1), Some('=' | '!')) => {
+                self.advance();
+                let negated = self.next_char() == Some('!');
+                return self.parse_lookaround(true, negated);
+            }
+            Some('(')
+                if self.current() == Some('?')
+                    && self.peek(1) == Some('<')
+                    && matches!(self.peek(2), Some('=' | '!')) =>
+            {
+                self.index += 2;
+                let negated = self.next_char() == Some('!');
+                return self.parse_lookaround(false, negated);
+            }
+            Some('.') => Node::AnyCharacter,
+            Some('\\') => self.parse_atom_escape()?,
+            Some('[') => self.parse_character_class()?,
+            Some('(') => self.parse_group()?,
+            Some('*' | '+' | '?') => return Err(Error::NothingToRepeat),
+            Some('{') => {
+                self.back();
+                if self.is_unicode() || self.try_parse_braced_quantifier()?.is_some() {
+                    return Err(Error::NothingToRepeat);
                 }
-                _ => return Ok(number),
+                self.advance();
+                Node::LiteralCharacter('{')
             }
-        }
-        Err(Error::UnexpectedEof)
+            Some(c @ (']' | '}')) if self.is_unicode() => return Err(Error::UnexpectedChar(c)),
+            Some(other) => Node::LiteralCharacter(other),
+            None => return Err(Error::UnexpectedEof),
+        };
+
+        let groups = groups_before + 1..self.group_count + 1;
+        self.parse_quantifier(node, groups)
     }
 
-    fn parse_bounded_repetition(&mut self, node: Node) -> Result<Node, Error> {
-        let min = self.read_int()?;
-        match self.current() {
-            Some(b',') => {
+    fn parse_quantifier(&mut self, node: Node, groups: Range<usize>) -> Result<Node, Error> {
+        let (min, max) = match self.current() {
+            Some('*') => {
+                self.advance();
+                (0, None)
+            }
+            Some('+') => {
+                self.advance();
+                (1, None)
+            }
+            Some('?') => {
                 self.advance();
-                match self.current() {
-                    Some(b'}') => {
-                        self.advance();
-                        Ok(Node::unbounded_max_repetition(node, min))
+                (0, Some(1))
+            }
+            Some('{') => match self.try_parse_braced_quantifier()? {
+                Some(bounds) => bounds,
+                None if self.is_unicode() => return Err(Error::UnexpectedChar('{')),
+                // In non-unicode mode, a `{` that doesn't start a quantifier is a literal character
+                None => return Ok(node),
+            },
+            _ => return Ok(node),
+        };
+
+        let greedy = !self.eat('?');
+
+        Ok(Node::Repetition {
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+            groups,
+        })
+    }
+
+    /// Tries to parse a `{n}`, `{n,}` or `{n,m}` quantifier, restoring the position if the input is not one
+    fn try_parse_braced_quantifier(&mut self) -> Result<Option<(usize, Option<usize>)>, Error> {
+        let start = self.index;
+        self.advance(); // {
+
+        let bounds = match self.read_int() {
+            Some(min) if self.eat('}') => Some((min, Some(min))),
+            Some(min) if self.eat(',') => match self.read_int() {
+                Some(max) if self.eat('}') => Some((min, Some(max))),
+                None if self.eat('}') => Some((min, None)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match bounds {
+            Some((min, Some(max))) if min > max => Err(Error::QuantifierOutOfOrder),
+            Some(bounds) => Ok(Some(bounds)),
+            None => {
+                self.index = start;
+                Ok(None)
+            }
+        }
+    }
+
+    fn read_int(&mut self) -> Option<usize> {
+        let mut number: Option<usize> = None;
+        while let Some(digit) = self.current().and_then(|c| c.to_digit(10)) {
+            number = Some(number.unwrap_or(0).saturating_mul(10).saturating_add(digit as usize));
+            self.advance();
+        }
+        number
+    }
+
+    fn parse_group(&mut self) -> Result<Node, Error> {
+        let index = if self.eat('?') {
+            match self.next_char() {
+                Some(':') => None,
+                Some('<') => {
+                    let name = self.parse_group_name()?;
+                    if self.group_names.iter().any(|(n, _)| *n == name) {
+                        return Err(Error::DuplicateGroupName(name.into()));
                     }
-                    _ => {
-                        let max = self.read_int()?;
-                        self.advance(); // }
-                        Ok(Node::repetition(node, min, max))
+
+                    self.group_count += 1;
+                    self.group_names.push((name, self.group_count));
+                    Some(self.group_count)
+                }
+                Some(other) => return Err(Error::UnexpectedChar(other)),
+                None => return Err(Error::UnexpectedEof),
+            }
+        } else {
+            self.group_count += 1;
+            Some(self.group_count)
+        };
+
+        let nodes = self.parse_group_body()?;
+        Ok(Node::Group { nodes, index })
+    }
+
+    fn parse_lookaround(&mut self, ahead: bool, negated: bool) -> Result<Node, Error> {
+        let nodes = self.parse_group_body()?;
+        Ok(Node::Lookaround { nodes, ahead, negated })
+    }
+
+    fn parse_group_body(&mut self) -> Result<Vec<Node>, Error> {
+        let nodes = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(Error::UnmatchedParen);
+        }
+        Ok(nodes)
+    }
+
+    /// Parses a group name, assuming the `<` has already been consumed
+    fn parse_group_name(&mut self) -> Result<Box<str>, Error> {
+        let mut name = String::new();
+        loop {
+            match self.next_char() {
+                Some('>') if !name.is_empty() => return Ok(name.into()),
+                Some(c) if c.is_alphanumeric() || c == '_' || c == '$' => {
+                    if name.is_empty() && c.is_numeric() {
+                        return Err(Error::InvalidGroupName);
                     }
+                    name.push(c);
                 }
+                Some(_) => return Err(Error::InvalidGroupName),
+                None => return Err(Error::UnexpectedEof),
             }
-            Some(b'}') => {
-                self.advance();
-                Ok(Node::repetition(node, min, min))
+        }
+    }
+
+    fn parse_atom_escape(&mut self) -> Result<Node, Error> {
+        match self.next_char() {
+            Some(c @ ('d' | 'D' | 'w' | 'W' | 's' | 'S')) => Ok(Node::MetaSequence(Self::meta_sequence(c))),
+            Some(c @ ('p' | 'P')) if self.is_unicode() => Ok(Node::MetaSequence(self.parse_unicode_property(c)?)),
+            Some('k') if self.is_unicode() || !self.all_group_names.is_empty() => {
+                if !self.eat('<') {
+                    return Err(Error::InvalidEscape);
+                }
+                let name = self.parse_group_name()?;
+                match self.all_group_names.iter().find(|(n, _)| **n == *name) {
+                    Some((_, index)) => Ok(Node::Backreference(*index)),
+                    None => Err(Error::InvalidNamedReference(name.into())),
+                }
             }
-            Some(other) => Err(Error::UnexpectedChar(other)),
+            Some('1'..='9') => {
+                self.back();
+                let start = self.index;
+                let n = self.read_int().unwrap_or(0);
+                if n <= self.total_groups {
+                    Ok(Node::Backreference(n))
+                } else if self.is_unicode() {
+                    Err(Error::InvalidEscape)
+                } else {
+                    // Annex B: not a backreference, so this is a legacy octal escape or an identity escape
+                    self.index = start;
+                    Ok(Node::LiteralCharacter(self.parse_legacy_octal_escape()))
+                }
+            }
+            Some(c) => self.parse_character_escape(c).map(Node::LiteralCharacter),
             None => Err(Error::UnexpectedEof),
         }
     }
 
-    fn parse_character_class(&mut self) -> Result<Node, Error> {
-        let mut nodes = Vec::new();
+    fn meta_sequence(c: char) -> MetaSequence {
+        match c {
+            'd' => MetaSequence::Digit,
+            'D' => MetaSequence::NotDigit,
+            'w' => MetaSequence::Word,
+            'W' => MetaSequence::NotWord,
+            's' => MetaSequence::Whitespace,
+            'S' => MetaSequence::NotWhitespace,
+            _ => unreachable!(),
+        }
+    }
 
-        while !self.is_eof() {
-            if let Some(b']') = self.current() {
-                self.advance();
+    fn parse_unicode_property(&mut self, c: char) -> Result<MetaSequence, Error> {
+        if !self.eat('{') {
+            return Err(Error::InvalidEscape);
+        }
+
+        let mut name = String::new();
+        loop {
+            match self.next_char() {
+                Some('}') => break,
+                Some(c) => name.push(c),
+                None => return Err(Error::UnexpectedEof),
+            }
+        }
+
+        let property = UnicodeProperty::from_name(&name).ok_or(Error::InvalidUnicodeProperty(name))?;
+        Ok(match c {
+            'p' => MetaSequence::Property(property),
+            _ => MetaSequence::NotProperty(property),
+        })
+    }
+
+    /// Parses the character of an escape sequence, assuming the `\` and `c` have already been consumed
+    fn parse_character_escape(&mut self, c: char) -> Result<char, Error> {
+        match c {
+            't' => Ok('\t'),
+            'n' => Ok('\n'),
+            'v' => Ok('\x0b'),
+            'f' => Ok('\x0c'),
+            'r' => Ok('\r'),
+            '0' if !self.current().is_some_and(|c| c.is_ascii_digit()) => Ok('\0'),
+            '0'..='7' if !self.is_unicode() => {
+                self.back();
+                Ok(self.parse_legacy_octal_escape())
+            }
+            'c' => match self.current() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.advance();
+                    Ok(char::from(letter as u8 % 32))
+                }
+                _ if self.is_unicode() => Err(Error::InvalidEscape),
+                _ => {
+                    // Annex B: `\c` not followed by a letter is a literal backslash
+                    self.back();
+                    Ok('\\')
+                }
+            },
+            'x' => match self.read_hex_digits(2) {
+                Some(value) => Ok(char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)),
+                None if self.is_unicode() => Err(Error::InvalidEscape),
+                None => Ok('x'),
+            },
+            'u' => match self.parse_unicode_escape()? {
+                Some(c) => Ok(c),
+                None if self.is_unicode() => Err(Error::InvalidEscape),
+                None => Ok('u'),
+            },
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '/' | '-' => Ok(c),
+            _ if self.is_unicode() => Err(Error::InvalidEscape),
+            _ => Ok(c),
+        }
+    }
+
+    /// Parses up to three octal digits into a character, or a single non-octal digit as itself
+    fn parse_legacy_octal_escape(&mut self) -> char {
+        let mut value = 0;
+        let mut digits = 0;
+
+        while let Some(digit) = self.current().and_then(|c| c.to_digit(8)) {
+            if digits == 3 || value * 8 + digit > 0o377 {
                 break;
             }
+            value = value * 8 + digit;
+            digits += 1;
+            self.advance();
+        }
 
-            nodes.push(self.parse_primary()?);
+        if digits == 0 {
+            // \8 and \9
+            return self.next_char().unwrap_or('\\');
         }
 
-        Ok(Node::CharacterClass(nodes))
+        char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)
     }
 
-    fn parse_group(&mut self) -> Result<Node, Error> {
-        let mut nodes = Vec::new();
+    fn read_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.input.get(self.index..self.index + count)?;
+        let mut value = 0;
+        for digit in digits {
+            value = value * 16 + digit.to_digit(16)?;
+        }
+        self.index += count;
+        Some(value)
+    }
+
+    /// Parses the rest of a `\u` escape, returning `None` if it is malformed
+    fn parse_unicode_escape(&mut self) -> Result<Option<char>, Error> {
+        if self.is_unicode() && self.current() == Some('{') {
+            let start = self.index;
+            self.advance();
 
-        while !self.is_eof() {
+            let mut value: u32 = 0;
+            let mut digits = 0;
+            while let Some(digit) = self.current().and_then(|c| c.to_digit(16)) {
+                value = value.saturating_mul(16).saturating_add(digit);
+                digits += 1;
+                self.advance();
+            }
+
+            if digits == 0 || !self.eat('}') || value > 0x10ffff {
+                self.index = start;
+                return Ok(None);
+            }
+
+            return Ok(Some(char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)));
+        }
+
+        let Some(value) = self.read_hex_digits(4) else {
+            return Ok(None);
+        };
+
+        // A surrogate pair written as two escapes forms a single code point
+        if (0xd800..0xdc00).contains(&value) && self.current() == Some('\\') && self.peek(1) == Some('u') {
+            let start = self.index;
+            self.index += 2;
+            match self.read_hex_digits(4) {
+                Some(low) if (0xdc00..0xe000).contains(&low) => {
+                    let code_point = 0x10000 + ((value - 0xd800) << 10) + (low - 0xdc00);
+                    return Ok(char::from_u32(code_point));
+                }
+                _ => self.index = start,
+            }
+        }
+
+        // Lone surrogates cannot be represented in a Rust string
+        Ok(Some(char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)))
+    }
+
+    fn parse_character_class(&mut self) -> Result<Node, Error> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+
+        loop {
             match self.current() {
-                Some(b')') => {
+                Some(']') => {
                     self.advance();
                     break;
                 }
-                Some(b'|') => {
-                    self.advance();
-                    let left = mem::take(&mut nodes);
-                    let mut right = Vec::new();
+                Some(_) => {}
+                None => return Err(Error::UnexpectedEof),
+            }
+
+            let start = self.parse_class_atom()?;
+            if self.current() == Some('-') && !matches!(self.peek(1), Some(']') | None) {
+                self.advance();
+                let end = self.parse_class_atom()?;
 
-                    while !self.is_eof() {
-                        if let Some(b')' | b'|') = self.current() {
-                            // handle in outer loop
-                            break;
+                match (start, end) {
+                    (ClassItem::Character(start), ClassItem::Character(end)) => {
+                        if start > end {
+                            return Err(Error::RangeOutOfOrder);
                         }
-                        right.push(self.parse_primary()?);
+                        items.push(ClassItem::Range(start, end));
+                    }
+                    _ if self.is_unicode() => return Err(Error::InvalidEscape),
+                    (start, end) => {
+                        // Annex B: a range with a class escape on either side is treated literally
+                        items.push(start);
+                        items.push(ClassItem::Character('-'));
+                        items.push(end);
                     }
-                    nodes.push(Node::Or(left, right));
                 }
-                _ => nodes.push(self.parse_primary()?),
+            } else {
+                items.push(start);
             }
         }
 
-        Ok(Node::Group(nodes))
+        Ok(Node::CharacterClass { items, negated })
     }
 
-    fn parse_escape(&mut self) -> Result<Node, Error> {
-        match self.next_byte() {
-            Some(b'd') => Ok(Node::MetaSequence(MetaSequence::Digit)),
-            Some(b'w') => Ok(Node::MetaSequence(MetaSequence::Word)),
-            Some(b's') => Ok(Node::MetaSequence(MetaSequence::Whitespace)),
-            Some(other) => Ok(Node::LiteralCharacter(other)),
+    fn parse_class_atom(&mut self) -> Result<ClassItem, Error> {
+        match self.next_char() {
+            Some('\\') => match self.next_char() {
+                Some(c @ ('d' | 'D' | 'w' | 'W' | 's' | 'S')) => Ok(ClassItem::MetaSequence(Self::meta_sequence(c))),
+                Some(c @ ('p' | 'P')) if self.is_unicode() => {
+                    Ok(ClassItem::MetaSequence(self.parse_unicode_property(c)?))
+                }
+                Some('b') => Ok(ClassItem::Character('\x08')),
+                Some(c @ ('8' | '9')) if !self.is_unicode() => Ok(ClassItem::Character(c)),
+                Some(c) => self.parse_character_escape(c).map(ClassItem::Character),
+                None => Err(Error::UnexpectedEof),
+            },
+            Some(c) => Ok(ClassItem::Character(c)),
             None => Err(Error::UnexpectedEof),
         }
     }
@@ -7,6 +7,8 @@ use dash_vm::eval::EvalError;
 use dash_vm::local::LocalScope;
 use dash_vm::params::VmParams;
 use dash_vm::throw;
+use dash_vm::value::error::Error;
+use dash_vm::value::ops::abstractions::conversions::ValueConversion;
 use dash_vm::value::Value;
 use dash_vm::Vm;
 use tokio::sync::mpsc;
@@ -40,6 +42,8 @@ impl Runtime {
 
         params = params
             .set_time_millis_callback(time_callback)
+            .set_timezone_offset_callback(timezone_offset_callback)
+            .set_promise_rejection_unhandled_callback(unhandled_rejection_callback)
             .set_state(Box::new(state));
 
         if let Some(threshold) = initial_gc_threshold {
@@ -58,6 +62,12 @@ impl Runtime {
         State::from_vm(&self.vm).set_root_module(module_manager);
     }
 
+    /// Sets whether an unhandled promise rejection exits the process with exit code 1,
+    /// like Node's `--unhandled-rejections=strict`. The reason is printed either way
+    pub fn set_exit_on_unhandled_rejection(&mut self, exit: bool) {
+        State::from_vm(&self.vm).set_exit_on_unhandled_rejection(exit);
+    }
+
     pub fn eval<'i>(&mut self, code: &'i str, opt: OptLevel) -> Result<Value, EvalError<'i>> {
         self.vm.eval(code, opt)
     }
@@ -105,6 +115,43 @@ fn time_callback(_: &mut Vm) -> Result<u64, Value> {
         .as_millis() as u64)
 }
 
+#[cfg(unix)]
+fn timezone_offset_callback(_: &mut Vm, utc_millis: i64) -> Result<i64, Value> {
+    let time = utc_millis.div_euclid(1000) as libc::time_t;
+    // SAFETY: `tm` is a plain C struct for which all zeroes is a valid bit pattern,
+    // and `localtime_r` only writes to the provided buffer
+    let offset = unsafe {
+        let mut tm = std::mem::zeroed::<libc::tm>();
+        if libc::localtime_r(&time, &mut tm).is_null() {
+            0
+        } else {
+            tm.tm_gmtoff as i64
+        }
+    };
+    Ok(offset * 1000)
+}
+
+#[cfg(not(unix))]
+fn timezone_offset_callback(_: &mut Vm, _: i64) -> Result<i64, Value> {
+    Ok(0)
+}
+
+/// Prints the reason of a promise rejection that has no handler,
+/// and exits the process if the runtime was configured to do so
+fn unhandled_rejection_callback(scope: &mut LocalScope, reason: Value) {
+    match Error::from_value(&reason) {
+        Some(error) => eprintln!("Uncaught (in promise) {}", error.stack),
+        None => match reason.to_string(scope) {
+            Ok(reason) => eprintln!("Uncaught (in promise) {reason}"),
+            Err(_) => eprintln!("Uncaught (in promise) <unprintable value>"),
+        },
+    }
+
+    if State::from_vm(scope).exit_on_unhandled_rejection() {
+        std::process::exit(1);
+    }
+}
+
 fn import_callback(vm: &mut Vm, import_ty: StaticImportKind, path: &str) -> Result<Value, Value> {
     let mut sc = LocalScope::new(vm);
 
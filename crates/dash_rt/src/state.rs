@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -17,6 +18,8 @@ pub struct State {
     root_module: Rc<RefCell<Option<Box<dyn ModuleLoader>>>>,
     tasks: TaskIds,
     promises: RefCell<HashMap<u64, Persistent<dyn Object>>>,
+    /// Whether an unhandled promise rejection exits the process
+    exit_on_unhandled_rejection: Cell<bool>,
 }
 
 impl State {
@@ -27,6 +30,7 @@ impl State {
             root_module: Rc::new(RefCell::new(None)),
             tasks: TaskIds::new(),
             promises: RefCell::new(HashMap::new()),
+            exit_on_unhandled_rejection: Cell::new(false),
         }
     }
 
@@ -38,6 +42,14 @@ impl State {
         &self.root_module
     }
 
+    pub(crate) fn set_exit_on_unhandled_rejection(&self, exit: bool) {
+        self.exit_on_unhandled_rejection.set(exit);
+    }
+
+    pub fn exit_on_unhandled_rejection(&self) -> bool {
+        self.exit_on_unhandled_rejection.get()
+    }
+
     pub fn active_tasks(&self) -> &TaskIds {
         &self.tasks
     }
@@ -164,6 +164,7 @@ mod handlers {
     use dash_middle::compiler::instruction::IntrinsicOperation;
     use dash_middle::compiler::FunctionCallMetadata;
     use dash_middle::compiler::ObjectMemberKind;
+    use dash_middle::compiler::PropertyDefinitionKind;
     use dash_middle::compiler::StaticImportKind;
     use if_chain::if_chain;
     use std::borrow::Cow;
@@ -179,20 +180,27 @@ mod handlers {
     use crate::local::LocalScope;
     use crate::throw;
     use crate::util::unlikely;
+    use crate::value::array;
     use crate::value::array::Array;
     use crate::value::array::ArrayIterator;
     use crate::value::function::adjust_stack_from_flat_call;
+    use crate::value::function::async_generator::AsyncFromSyncIterator;
     use crate::value::function::user::UserFunction;
     use crate::value::function::Function;
     use crate::value::function::FunctionKind;
     use crate::value::object::NamedObject;
     use crate::value::object::Object;
     use crate::value::object::ObjectMap;
+    use crate::value::object::PrivateElements;
+    use crate::value::object::PropertyDataDescriptor;
     use crate::value::object::PropertyKey;
     use crate::value::object::PropertyValue;
     use crate::value::object::PropertyValueKind;
     use crate::value::ops::abstractions::conversions::ValueConversion;
     use crate::value::ops::equality::ValueEquality;
+    use crate::value::primitive::Symbol;
+    use crate::value::Typeof;
+    use crate::value::ValueContext;
 
     use super::*;
 
@@ -273,8 +281,32 @@ mod handlers {
         Ok(None)
     }
 
-    pub fn objin(cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
-        throw!(cx, Error, "in keyword is unimplemented");
+    pub fn objin(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let (property, target) = cx.pop_stack2();
+
+        let mut sc = cx.scope();
+        sc.add_value(property.clone());
+        sc.add_value(target.clone());
+
+        let target = match target {
+            Value::Object(o) => o,
+            Value::External(o) => o.inner.clone(),
+            _ => throw!(
+                sc,
+                TypeError,
+                "Cannot use 'in' operator to search for a key in a non-object"
+            ),
+        };
+
+        let found = match PropertyKey::from_value(&mut sc, property)? {
+            // Private names are never inherited, and proxies do not forward them to their target or traps,
+            // so only the private elements of the object itself are checked
+            PropertyKey::Symbol(sym) if sym.is_private() => target.private_elements().is_some_and(|p| p.has(&sym)),
+            key => target.has_property(&mut sc, key)?,
+        };
+
+        sc.stack.push(Value::Boolean(found));
+        Ok(None)
     }
 
     pub fn instanceof(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
@@ -324,8 +356,8 @@ mod handlers {
     pub fn neg(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let value = cx.pop_stack();
         let mut scope = cx.scope();
-        let result = value.to_number(&mut scope)?;
-        scope.stack.push(Value::number(-result));
+        let result = value.neg(&mut scope)?;
+        scope.stack.push(result);
         Ok(None)
     }
 
@@ -352,6 +384,16 @@ mod handlers {
     pub fn ret(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let tc_depth = cx.fetchw_and_inc_ip();
         let value = cx.pop_stack();
+
+        let frame = cx.active_frame();
+        if !frame.is_this_initialized && !matches!(value, Value::Object(_) | Value::External(_)) {
+            throw!(
+                cx,
+                ReferenceError,
+                "Must call super constructor in derived class before returning from derived constructor"
+            );
+        }
+
         let this = cx.pop_frame();
 
         // Drain all try catch blocks that are in this frame.
@@ -445,9 +487,9 @@ mod handlers {
                     .global
                     .clone()
                     .get_property(&mut scope, PropertyKey::String(Cow::Borrowed(&name)))?;
-                let value = Value::number(value.to_number(&mut scope)?);
+                let value = value.to_numeric(&mut scope)?;
 
-                let right = Value::number(1.0);
+                let right = value.numeric_one();
                 let res = $op(&value, &right, &mut scope)?;
                 scope.global.clone().set_property(
                     &mut scope,
@@ -465,9 +507,9 @@ mod handlers {
                     .global
                     .clone()
                     .get_property(&mut scope, PropertyKey::String(Cow::Borrowed(&name)))?;
-                let value = Value::number(value.to_number(&mut scope)?);
+                let value = value.to_numeric(&mut scope)?;
 
-                let right = Value::number(1.0);
+                let right = value.numeric_one();
                 let res = $op(&value, &right, &mut scope)?;
                 scope.global.clone().set_property(
                     &mut scope,
@@ -589,10 +631,26 @@ mod handlers {
 
     pub fn call(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let meta = FunctionCallMetadata::from(cx.fetch_and_inc_ip());
-        let argc = usize::from(meta.value());
+        let mut argc = usize::from(cx.fetchw_and_inc_ip());
         let is_constructor = meta.is_constructor_call();
         let has_this = meta.is_object_call();
 
+        if meta.is_spread_call() {
+            // Spread arguments are collected into an array by the caller, which needs to be unpacked onto the stack
+            let args = cx.pop_stack();
+            let args = args
+                .downcast_ref::<Array>()
+                .expect("Spread call arguments must be an array")
+                .inner()
+                .borrow();
+
+            argc = args.len();
+            cx.stack.extend(
+                args.iter()
+                    .map(|arg| arg.kind().as_static().cloned().unwrap_or_else(Value::undefined)),
+            );
+        }
+
         let stack_len = cx.stack.len();
         let (callee, this) = if has_this {
             cx.stack[stack_len - argc - 2..].rotate_left(2);
@@ -861,8 +919,8 @@ mod handlers {
             ($op:expr) => {{
                 let value = cx.get_local(id);
                 let mut scope = cx.scope();
-                let value = Value::number(value.to_number(&mut scope)?);
-                let one = Value::number(1.0);
+                let value = value.to_numeric(&mut scope)?;
+                let one = value.numeric_one();
                 let res = $op(&value, &one, &mut scope)?;
                 scope.set_local(id, res.clone());
                 scope.stack.push(res);
@@ -873,8 +931,8 @@ mod handlers {
             ($op:expr) => {{
                 let value = cx.get_local(id);
                 let mut scope = cx.scope();
-                let value = Value::number(value.to_number(&mut scope)?);
-                let one = Value::number(1.0);
+                let value = value.to_numeric(&mut scope)?;
+                let one = value.numeric_one();
                 let res = $op(&value, &one, &mut scope)?;
                 scope.set_local(id, res);
                 scope.stack.push(value);
@@ -918,7 +976,15 @@ mod handlers {
 
     pub fn arraylit(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let len = cx.fetch_and_inc_ip() as usize;
+        arraylit_with_len(cx, len)
+    }
+
+    pub fn arraylitw(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let len = cx.fetchw_and_inc_ip() as usize;
+        arraylit_with_len(cx, len)
+    }
 
+    fn arraylit_with_len(mut cx: DispatchContext<'_>, len: usize) -> Result<Option<HandleResult>, Value> {
         let elements = cx
             .pop_stack_many(len)
             .map(PropertyValue::static_default)
@@ -929,10 +995,147 @@ mod handlers {
         Ok(None)
     }
 
+    pub fn array_push(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let value = cx.pop_stack();
+        let array = cx.peek_stack();
+        let array = array.downcast_ref::<Array>().expect("Value was not an array");
+        array.inner().borrow_mut().push(PropertyValue::static_default(value));
+        Ok(None)
+    }
+
+    pub fn array_spread(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let iterator = cx.pop_stack();
+        let array = cx.peek_stack();
+
+        let mut scope = cx.scope();
+        scope.add_value(iterator.clone());
+
+        let next = iterator.get_property(&mut scope, "next".into())?;
+        loop {
+            let item = next.apply(&mut scope, iterator.clone(), Vec::new())?;
+            if item.get_property(&mut scope, "done".into())?.is_truthy() {
+                break;
+            }
+
+            let value = item.get_property(&mut scope, "value".into())?;
+            let array = array.downcast_ref::<Array>().expect("Value was not an array");
+            array.inner().borrow_mut().push(PropertyValue::static_default(value));
+        }
+
+        Ok(None)
+    }
+
+    pub fn objrest(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let count = cx.fetchw_and_inc_ip();
+        let excluded = cx.pop_stack_many(count.into()).collect::<Vec<_>>();
+        let object = cx.pop_stack();
+        let mut scope = cx.scope();
+
+        let excluded = excluded
+            .into_iter()
+            .map(|key| PropertyKey::from_value(&mut scope, key))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Destructuring null or undefined throws
+        let object = object.to_object(&mut scope)?;
+        let rest = NamedObject::new(&mut scope);
+
+        for key in object.own_keys(&mut scope)? {
+            let key = PropertyKey::from_value(&mut scope, key)?;
+            if excluded.contains(&key) {
+                continue;
+            }
+
+            // Only enumerable properties are copied
+            let enumerable = object
+                .get_property_descriptor(&mut scope, key.clone())?
+                .is_some_and(|descriptor| descriptor.into_parts().1.contains(PropertyDataDescriptor::ENUMERABLE));
+
+            if !enumerable {
+                continue;
+            }
+
+            let value = object.get_property(&mut scope, key.clone())?;
+            rest.set_property(&mut scope, key, PropertyValue::static_default(value))?;
+        }
+
+        let rest = scope.register(rest);
+        scope.stack.push(Value::Object(rest));
+        Ok(None)
+    }
+
+    pub fn private_name(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let id = cx.fetchw_and_inc_ip();
+        let name = cx.identifier_constant(id.into());
+        cx.stack.push(Value::Symbol(Symbol::private(name)));
+        Ok(None)
+    }
+
+    pub fn iterdestruct(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        /// Advances the iterator, returning `None` if it is done
+        fn step(scope: &mut LocalScope, iterator: &Value, next: &Value) -> Result<Option<Value>, Value> {
+            let item = next.apply(scope, iterator.clone(), Vec::new())?;
+            if item.get_property(scope, "done".into())?.is_truthy() {
+                return Ok(None);
+            }
+
+            let value = item.get_property(scope, "value".into())?;
+            scope.add_value(value.clone());
+            Ok(Some(value))
+        }
+
+        let count = cx.fetchw_and_inc_ip();
+        let has_rest = cx.fetch_and_inc_ip() == 1;
+        let iterator = cx.pop_stack();
+
+        let mut scope = cx.scope();
+        scope.add_value(iterator.clone());
+
+        let next = iterator.get_property(&mut scope, "next".into())?;
+        let mut done = false;
+        let mut values = Vec::with_capacity(count.into());
+
+        for _ in 0..count {
+            let value = match done {
+                true => None,
+                false => step(&mut scope, &iterator, &next)?,
+            };
+
+            done = value.is_none();
+            values.push(value.unwrap_or_undefined());
+        }
+
+        if has_rest {
+            let mut rest = Vec::new();
+            while !done {
+                match step(&mut scope, &iterator, &next)? {
+                    Some(value) => rest.push(PropertyValue::static_default(value)),
+                    None => done = true,
+                }
+            }
+
+            let rest = Array::from_vec(&mut scope, rest);
+            let rest = scope.register(rest);
+            scope.stack.push(Value::Object(rest));
+        } else if !done {
+            // The pattern did not exhaust the iterator, so it needs to be closed
+            let return_fn = iterator.get_property(&mut scope, "return".into())?;
+            if !return_fn.is_nullish() {
+                return_fn.apply(&mut scope, iterator.clone(), Vec::new())?;
+            }
+        }
+
+        // The first value is pushed last so that it ends up on top of the stack
+        scope.stack.extend(values.into_iter().rev());
+        Ok(None)
+    }
+
     pub fn objlit(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let len = cx.fetch_and_inc_ip() as usize;
 
-        let mut obj = ObjectMap::default();
+        // Members are encoded in reverse order, so collect them first and define them in source order,
+        // which matters for duplicate keys and spread members
+        let mut members = Vec::with_capacity(len);
         for _ in 0..len {
             let kind = ObjectMemberKind::from_repr(cx.fetch_and_inc_ip()).unwrap();
 
@@ -941,12 +1144,12 @@ mod handlers {
                 ObjectMemberKind::Dynamic => {
                     // TODO: don't create LocalScope every time
                     match cx.pop_stack() {
-                        Value::Symbol(sym) => PropertyKey::Symbol(sym),
+                        Value::Symbol(sym) => Some(PropertyKey::Symbol(sym)),
                         value => {
                             let string = value.to_string(&mut cx.scope())?;
                             // TODO: can PropertyKey::String be a Rc<str>?
                             let string = Cow::Owned(String::from(&*string));
-                            PropertyKey::String(string)
+                            Some(PropertyKey::String(string))
                         }
                     }
                 }
@@ -955,13 +1158,29 @@ mod handlers {
 
                     // TODO: optimization opportunity: do not reallocate string from Rc<str>
                     let key = String::from(cx.identifier_constant(id.into()).as_ref());
-                    PropertyKey::String(Cow::Owned(key))
+                    Some(PropertyKey::String(Cow::Owned(key)))
                 }
+                ObjectMemberKind::Spread => None,
             };
             let value = cx.pop_stack();
+            members.push((kind, key, value));
+        }
+
+        // Spread members can invoke getters, so the values need to be rooted
+        let mut scope = cx.scope();
+        for (_, _, value) in &members {
+            scope.add_value(value.clone());
+        }
+
+        let mut obj = ObjectMap::default();
+        for (kind, key, value) in members.into_iter().rev() {
+            let Some(key) = key else {
+                copy_data_properties(&mut scope, value, &mut obj)?;
+                continue;
+            };
 
             match kind {
-                ObjectMemberKind::Dynamic | ObjectMemberKind::Static => {
+                ObjectMemberKind::Dynamic | ObjectMemberKind::Static | ObjectMemberKind::Spread => {
                     drop(obj.insert(key, PropertyValue::static_default(value)))
                 }
                 ObjectMemberKind::Getter => {
@@ -997,7 +1216,6 @@ mod handlers {
             };
         }
 
-        let mut scope = cx.scope();
         let obj = NamedObject::with_values(&mut scope, obj);
 
         let handle = scope.gc.register(obj);
@@ -1006,6 +1224,237 @@ mod handlers {
         Ok(None)
     }
 
+    /// Copies the own enumerable properties of a spread value into an object literal (CopyDataProperties)
+    fn copy_data_properties(
+        scope: &mut LocalScope,
+        value: Value,
+        obj: &mut ObjectMap<PropertyKey<'static>, PropertyValue>,
+    ) -> Result<(), Value> {
+        let source = match value {
+            Value::Undefined(_) | Value::Null(_) => return Ok(()),
+            value => value.to_object(scope)?,
+        };
+
+        for key in source.own_keys(scope)? {
+            let key = PropertyKey::from_value(scope, key)?;
+
+            let enumerable = source
+                .get_own_property_descriptor(scope, key.clone())?
+                .is_some_and(|descriptor| descriptor.descriptor().contains(PropertyDataDescriptor::ENUMERABLE));
+
+            if enumerable {
+                let value = source.get_property(scope, key.clone())?;
+                scope.add_value(value.clone());
+                obj.insert(key, PropertyValue::static_default(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the private elements of a value, which only objects have
+    fn private_elements(target: &Value) -> Option<&PrivateElements> {
+        match target {
+            Value::Object(o) => o.private_elements(),
+            Value::External(o) => o.private_elements(),
+            _ => None,
+        }
+    }
+
+    /// Reads a property of a property access expression
+    ///
+    /// Private names are not inherited, and reading one that the object does not have throws a TypeError
+    fn get_property_checked(sc: &mut LocalScope, target: &Value, key: PropertyKey) -> Result<Value, Value> {
+        match &key {
+            PropertyKey::Symbol(sym) if sym.is_private() => {
+                let name = sym.description().cloned().unwrap_or_default();
+                match private_elements(target).and_then(|p| p.get(sym)) {
+                    Some(descriptor) => descriptor.get_or_apply(sc, target.clone()),
+                    None => throw!(
+                        sc,
+                        TypeError,
+                        "Cannot read private member {} from an object whose class did not declare it",
+                        name
+                    ),
+                }
+            }
+            _ => target.get_property(sc, key),
+        }
+    }
+
+    /// Assigns to a property of a property access expression, invoking a setter if the property has one
+    ///
+    /// Private names must already be present on the object, and private methods cannot be assigned to
+    fn set_property_checked(
+        sc: &mut LocalScope,
+        target: &Value,
+        key: PropertyKey<'static>,
+        value: Value,
+    ) -> Result<(), Value> {
+        let descriptor = match (&key, target) {
+            (PropertyKey::Symbol(sym), _) if sym.is_private() => {
+                let name = sym.description().cloned().unwrap_or_default();
+                let Some(elements) = private_elements(target) else {
+                    throw!(
+                        sc,
+                        TypeError,
+                        "Cannot write private member {} to an object whose class did not declare it",
+                        name
+                    )
+                };
+
+                return match elements.get(sym).map(PropertyValue::into_parts) {
+                    Some((PropertyValueKind::Trap { set: Some(set), .. }, _)) => {
+                        set.apply(sc, target.clone(), vec![value])?;
+                        Ok(())
+                    }
+                    Some((PropertyValueKind::Trap { set: None, .. }, _)) => {
+                        throw!(sc, TypeError, "'{}' was defined without a setter", name)
+                    }
+                    Some((PropertyValueKind::Static(..), flags))
+                        if !flags.contains(PropertyDataDescriptor::WRITABLE) =>
+                    {
+                        throw!(sc, TypeError, "Private method {} is not writable", name)
+                    }
+                    Some((PropertyValueKind::Static(..), flags)) => {
+                        elements.insert(sym.clone(), PropertyValue::new(PropertyValueKind::Static(value), flags));
+                        Ok(())
+                    }
+                    None => throw!(
+                        sc,
+                        TypeError,
+                        "Cannot write private member {} to an object whose class did not declare it",
+                        name
+                    ),
+                };
+            }
+            (_, Value::Object(o)) => o.get_property_descriptor(sc, key.clone())?,
+            (_, Value::External(o)) => o.get_property_descriptor(sc, key.clone())?,
+            _ => None,
+        };
+
+        match descriptor.map(PropertyValue::into_parts) {
+            Some((PropertyValueKind::Trap { set: Some(set), .. }, _)) => {
+                set.apply(sc, target.clone(), vec![value])?;
+                Ok(())
+            }
+            // Assigning to an accessor without a setter or to a non-writable property has no effect
+            Some((PropertyValueKind::Trap { set: None, .. }, _)) => Ok(()),
+            Some((PropertyValueKind::Static(..), flags)) if !flags.contains(PropertyDataDescriptor::WRITABLE) => Ok(()),
+            _ => target.set_property(sc, key, PropertyValue::static_default(value)),
+        }
+    }
+
+    pub fn define_property(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let kind = PropertyDefinitionKind::from_repr(cx.fetch_and_inc_ip()).unwrap();
+        let (target, key, value) = cx.pop_stack3();
+
+        let mut sc = cx.scope();
+        let key = PropertyKey::from_value(&mut sc, key)?;
+
+        let target = match target {
+            Value::Object(o) => o,
+            Value::External(o) => o.inner.clone(),
+            _ => throw!(sc, TypeError, "Cannot define a class member on a non-object"),
+        };
+
+        // Private members are stored in the private elements of the object rather than as properties
+        let private = match &key {
+            PropertyKey::Symbol(sym) if sym.is_private() => match target.private_elements() {
+                Some(elements) => Some((sym.clone(), elements)),
+                None => throw!(
+                    sc,
+                    TypeError,
+                    "Cannot define private member {} on this object",
+                    sym.description().cloned().unwrap_or_default()
+                ),
+            },
+            _ => None,
+        };
+
+        let property = match kind {
+            PropertyDefinitionKind::Field | PropertyDefinitionKind::Method => {
+                if let Some((sym, elements)) = &private {
+                    if elements.has(sym) {
+                        throw!(
+                            sc,
+                            TypeError,
+                            "Cannot initialize {} twice on the same object",
+                            sym.description().cloned().unwrap_or_default()
+                        );
+                    }
+                }
+
+                let descriptor = match (kind, &private) {
+                    (PropertyDefinitionKind::Field, _) => PropertyDataDescriptor::default(),
+                    (_, Some(_)) => PropertyDataDescriptor::empty(),
+                    (_, None) => PropertyDataDescriptor::CONFIGURABLE | PropertyDataDescriptor::WRITABLE,
+                };
+
+                PropertyValue::new(PropertyValueKind::Static(value), descriptor)
+            }
+            PropertyDefinitionKind::Getter | PropertyDefinitionKind::Setter => {
+                let accessor = match value {
+                    Value::Object(o) => o,
+                    _ => panic!("Accessor is not an object"),
+                };
+
+                // A getter and a setter with the same name are merged into one property
+                let existing = match &private {
+                    Some((sym, elements)) => elements.get(sym),
+                    None => target.get_own_property_descriptor(&mut sc, key.clone())?,
+                };
+                let (mut get, mut set) = match existing.map(PropertyValue::into_kind) {
+                    Some(PropertyValueKind::Trap { get, set }) => (get, set),
+                    _ => (None, None),
+                };
+
+                match kind {
+                    PropertyDefinitionKind::Getter => get = Some(accessor),
+                    _ => set = Some(accessor),
+                }
+
+                PropertyValue::new(
+                    PropertyValueKind::Trap { get, set },
+                    PropertyDataDescriptor::CONFIGURABLE,
+                )
+            }
+        };
+
+        match private {
+            Some((sym, elements)) => elements.insert(sym, property),
+            None => target.set_property(&mut sc, key, property)?,
+        }
+        Ok(None)
+    }
+
+    pub fn template_strings(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let len = cx.fetchw_and_inc_ip() as usize;
+
+        let raw = cx.pop_stack_many(len).map(PropertyValue::static_default).collect();
+        let cooked = cx.pop_stack_many(len).map(PropertyValue::static_default).collect();
+
+        // The strings array and its `raw` array are frozen
+        let mut sc = cx.scope();
+        let raw = Array::from_vec(&mut sc, raw);
+        raw.freeze(&mut sc)?;
+        let raw = sc.register(raw);
+        let strings = Array::from_vec(&mut sc, cooked);
+        strings.set_property(
+            &mut sc,
+            "raw".into(),
+            PropertyValue::new(
+                PropertyValueKind::Static(Value::Object(raw)),
+                PropertyDataDescriptor::empty(),
+            ),
+        )?;
+        strings.freeze(&mut sc)?;
+        let strings = sc.register(strings);
+
+        sc.stack.push(Value::Object(strings));
+        Ok(None)
+    }
+
     pub fn staticpropertyaccess(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let id = cx.fetch_and_inc_ip();
         let ident = cx.identifier_constant(id.into());
@@ -1036,11 +1485,7 @@ mod handlers {
                 let p = target.get_property(&mut scope, PropertyKey::String(Cow::Borrowed(&key)))?;
                 let res = $op(&p, &value, &mut scope)?;
 
-                target.set_property(
-                    &mut scope,
-                    ToString::to_string(&key).into(),
-                    PropertyValue::static_default(res.clone()),
-                )?;
+                set_property_checked(&mut scope, &target, ToString::to_string(&key).into(), res.clone())?;
                 scope.stack.push(res);
             }};
         }
@@ -1050,14 +1495,10 @@ mod handlers {
                 let target = cx.pop_stack();
                 let mut scope = cx.scope();
                 let prop = target.get_property(&mut scope, PropertyKey::String(Cow::Borrowed(&key)))?;
-                let prop = Value::number(prop.to_number(&mut scope)?);
-                let one = Value::number(1.0);
+                let prop = prop.to_numeric(&mut scope)?;
+                let one = prop.numeric_one();
                 let res = $op(&prop, &one, &mut scope)?;
-                target.set_property(
-                    &mut scope,
-                    ToString::to_string(&key).into(),
-                    PropertyValue::static_default(res),
-                )?;
+                set_property_checked(&mut scope, &target, ToString::to_string(&key).into(), res)?;
                 scope.stack.push(prop);
             }};
         }
@@ -1067,14 +1508,10 @@ mod handlers {
                 let target = cx.pop_stack();
                 let mut scope = cx.scope();
                 let prop = target.get_property(&mut scope, PropertyKey::String(Cow::Borrowed(&key)))?;
-                let prop = Value::number(prop.to_number(&mut scope)?);
-                let one = Value::number(1.0);
+                let prop = prop.to_numeric(&mut scope)?;
+                let one = prop.numeric_one();
                 let res = $op(&prop, &one, &mut scope)?;
-                target.set_property(
-                    &mut scope,
-                    ToString::to_string(&key).into(),
-                    PropertyValue::static_default(res.clone()),
-                )?;
+                set_property_checked(&mut scope, &target, ToString::to_string(&key).into(), res.clone())?;
                 scope.stack.push(res);
             }};
         }
@@ -1083,11 +1520,7 @@ mod handlers {
             AssignKind::Assignment => {
                 let (target, value) = cx.pop_stack2();
                 let mut scope = cx.scope();
-                target.set_property(
-                    &mut scope,
-                    ToString::to_string(&key).into(),
-                    PropertyValue::static_default(value.clone()),
-                )?;
+                set_property_checked(&mut scope, &target, ToString::to_string(&key).into(), value.clone())?;
                 scope.stack.push(value);
             }
             AssignKind::AddAssignment => op!(Value::add),
@@ -1116,15 +1549,15 @@ mod handlers {
 
         macro_rules! op {
             ($op:expr) => {{
-                let (target, value, key) = cx.pop_stack3();
+                let (target, key, value) = cx.pop_stack3();
 
                 let mut scope = cx.scope();
                 let key = PropertyKey::from_value(&mut scope, key)?;
-                let prop = target.get_property(&mut scope, key.clone())?;
+                let prop = get_property_checked(&mut scope, &target, key.clone())?;
 
                 let result = $op(&prop, &value, &mut scope)?;
 
-                target.set_property(&mut scope, key, PropertyValue::static_default(result.clone()))?;
+                set_property_checked(&mut scope, &target, key, result.clone())?;
                 scope.stack.push(result);
             }};
         }
@@ -1134,11 +1567,11 @@ mod handlers {
                 let (target, key) = cx.pop_stack2();
                 let mut scope = cx.scope();
                 let key = PropertyKey::from_value(&mut scope, key)?;
-                let prop = target.get_property(&mut scope, key.clone())?;
-                let prop = Value::number(prop.to_number(&mut scope)?);
-                let one = Value::number(1.0);
+                let prop = get_property_checked(&mut scope, &target, key.clone())?;
+                let prop = prop.to_numeric(&mut scope)?;
+                let one = prop.numeric_one();
                 let res = $op(&prop, &one, &mut scope)?;
-                target.set_property(&mut scope, key, PropertyValue::static_default(res))?;
+                set_property_checked(&mut scope, &target, key, res)?;
                 scope.stack.push(prop);
             }};
         }
@@ -1148,23 +1581,23 @@ mod handlers {
                 let (target, key) = cx.pop_stack2();
                 let mut scope = cx.scope();
                 let key = PropertyKey::from_value(&mut scope, key)?;
-                let prop = target.get_property(&mut scope, key.clone())?;
-                let prop = Value::number(prop.to_number(&mut scope)?);
-                let one = Value::number(1.0);
+                let prop = get_property_checked(&mut scope, &target, key.clone())?;
+                let prop = prop.to_numeric(&mut scope)?;
+                let one = prop.numeric_one();
                 let res = $op(&prop, &one, &mut scope)?;
-                target.set_property(&mut scope, key, PropertyValue::static_default(res.clone()))?;
+                set_property_checked(&mut scope, &target, key, res.clone())?;
                 scope.stack.push(res);
             }};
         }
 
         match kind {
             AssignKind::Assignment => {
-                let (target, value, key) = cx.pop_stack3();
+                let (target, key, value) = cx.pop_stack3();
 
                 let mut scope = cx.scope();
                 let key = PropertyKey::from_value(&mut scope, key)?;
 
-                target.set_property(&mut scope, key, PropertyValue::static_default(value.clone()))?;
+                set_property_checked(&mut scope, &target, key, value.clone())?;
                 scope.stack.push(value);
             }
             AssignKind::AddAssignment => op!(Value::add),
@@ -1206,11 +1639,30 @@ mod handlers {
 
         let key = PropertyKey::from_value(&mut scope, key)?;
 
-        let value = target.get_property(&mut scope, key)?;
+        let value = get_property_checked(&mut scope, &target, key)?;
         scope.stack.push(value);
         Ok(None)
     }
 
+    pub fn super_property_access(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let (this, home, key) = cx.pop_stack3();
+
+        let mut sc = cx.scope();
+        sc.add_value(this.clone());
+        sc.add_value(home.clone());
+
+        let home = match home {
+            Value::Object(o) => o,
+            Value::External(o) => o.inner.clone(),
+            _ => throw!(sc, TypeError, "super is not an object"),
+        };
+
+        let key = PropertyKey::from_value(&mut sc, key)?;
+        let value = home.get_property_with_this(&mut sc, this, key)?;
+        sc.stack.push(value);
+        Ok(None)
+    }
+
     pub fn ldlocalext(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let id = cx.fetch_and_inc_ip();
         let value = Value::External(cx.get_external(id.into()).clone());
@@ -1246,8 +1698,9 @@ mod handlers {
         macro_rules! prefix {
             ($op:expr) => {{
                 let value = Value::External(cx.get_external(id.into()).clone()).unbox_external();
-                let right = Value::number(1.0);
                 let mut scope = cx.scope();
+                let value = value.to_numeric(&mut scope)?;
+                let right = value.numeric_one();
                 let res = $op(&value, &right, &mut scope)?;
                 let external = scope.get_external(id.into()).unwrap().clone();
                 assign_to_external(&mut scope, &external, res.clone());
@@ -1258,8 +1711,9 @@ mod handlers {
         macro_rules! postfix {
             ($op:expr) => {{
                 let value = Value::External(cx.get_external(id.into()).clone()).unbox_external();
-                let right = Value::number(1.0);
                 let mut scope = cx.scope();
+                let value = value.to_numeric(&mut scope)?;
+                let right = value.numeric_one();
                 let res = $op(&value, &right, &mut scope)?;
                 let external = scope.get_external(id.into()).unwrap().clone();
                 assign_to_external(&mut scope, &external, res);
@@ -1307,8 +1761,9 @@ mod handlers {
         let catch_offset = cx.fetchw_and_inc_ip() as usize;
         let catch_ip = ip + catch_offset + 2;
         let frame_ip = cx.frames.len();
+        let sp = cx.stack.len();
 
-        cx.try_blocks.push(TryBlock { catch_ip, frame_ip });
+        cx.try_blocks.push(TryBlock { catch_ip, frame_ip, sp });
 
         Ok(None)
     }
@@ -1432,6 +1887,14 @@ mod handlers {
     }
 
     pub fn this(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        if !cx.active_frame().is_this_initialized {
+            throw!(
+                cx,
+                ReferenceError,
+                "Must call super constructor in derived class before accessing 'this'"
+            );
+        }
+
         let this = cx
             .frames
             .iter()
@@ -1450,8 +1913,117 @@ mod handlers {
         Ok(None)
     }
 
-    pub fn super_(cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
-        throw!(cx, SyntaxError, "`super` keyword unexpected in this context");
+    pub fn super_(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let (superclass, args) = cx.pop_stack2();
+
+        // `super()` may also be called from arrow functions inside of the derived constructor,
+        // in which case the constructor frame is further up the stack
+        let Some(ctor_index) = cx.frames.iter().rposition(|frame| {
+            matches!(
+                frame.state,
+                FrameState::Function {
+                    is_constructor_call: true,
+                    ..
+                }
+            ) && frame.function.is_derived_ctor
+        }) else {
+            throw!(cx, TypeError, "Class constructor cannot be invoked without 'new'");
+        };
+
+        let frame = &cx.frames[ctor_index];
+        if frame.is_this_initialized {
+            throw!(cx, ReferenceError, "Super constructor may only be called once");
+        }
+
+        // This is the instance allocated for the derived class, with the prototype of the derived class
+        let this = frame.this.clone().expect("Constructor frame has no `this` value");
+
+        let mut scope = cx.scope();
+        scope.add_value(superclass.clone());
+        scope.add_value(args.clone());
+        scope.add_value(this.clone());
+
+        let len = args.length_of_array_like(&mut scope)?;
+        let args = (0..len)
+            .map(|index| array::spec_array_get_property(&mut scope, &args, index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let this = if_chain! {
+            if let Value::Object(callee) = &superclass;
+            if let Some(function) = superclass.downcast_ref::<Function>();
+            if let FunctionKind::User(_) = function.kind();
+            then {
+                // User functions initialize the instance that was already allocated
+                function.construct_with_this(&mut scope, callee.clone(), this, args)?
+            } else {
+                // Builtin constructors allocate their own object, which then needs to inherit from the derived class
+                if !matches!(superclass.type_of(), Typeof::Function) {
+                    throw!(&mut scope, TypeError, "Super constructor is not a constructor");
+                }
+
+                let prototype = match &this {
+                    Value::Object(this) => this.get_prototype(&mut scope)?,
+                    _ => Value::null(),
+                };
+
+                let value = superclass.construct(&mut scope, this, args)?;
+                if let Value::Object(object) = &value {
+                    object.set_prototype(&mut scope, prototype)?;
+                }
+                value
+            }
+        };
+
+        let frame = &mut scope.frames[ctor_index];
+        frame.this = Some(this.clone());
+        frame.is_this_initialized = true;
+
+        // Arrow functions share the `this` binding of the constructor
+        let frame = scope.frames.last_mut().expect("No frame");
+        frame.this = Some(this.clone());
+
+        scope.stack.push(this);
+        Ok(None)
+    }
+
+    pub fn extends(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let superclass = cx.pop_stack();
+        let class = cx.peek_stack();
+
+        let mut scope = cx.scope();
+
+        let (constructor_parent, prototype_parent) = match &superclass {
+            Value::Null(_) => (Value::Object(scope.statics.function_proto.clone()), Value::null()),
+            _ if matches!(superclass.type_of(), Typeof::Function) => {
+                let prototype = superclass.get_property(&mut scope, "prototype".into())?;
+                if !matches!(prototype, Value::Object(_) | Value::Null(_)) {
+                    throw!(
+                        &mut scope,
+                        TypeError,
+                        "Class extends value does not have valid prototype property"
+                    );
+                }
+                (superclass.clone(), prototype)
+            }
+            _ => throw!(
+                &mut scope,
+                TypeError,
+                "Class extends value is not a constructor or null"
+            ),
+        };
+
+        let Value::Object(class) = class else {
+            unreachable!("class constructor must be an object")
+        };
+
+        class.set_prototype(&mut scope, constructor_parent)?;
+
+        let prototype = class.get_property(&mut scope, "prototype".into())?;
+        if let Value::Object(prototype) = prototype {
+            prototype.set_prototype(&mut scope, prototype_parent)?;
+        }
+
+        Ok(None)
     }
 
     pub fn undef(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
@@ -1479,13 +2051,35 @@ mod handlers {
         Ok(None)
     }
 
+    pub fn call_async_iterator(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
+        let value = cx.pop_stack();
+        let mut scope = cx.scope();
+        let symbol_async_iterator = scope.statics.symbol_async_iterator.clone();
+        let method = value.get_property(&mut scope, PropertyKey::Symbol(symbol_async_iterator))?;
+
+        let iterator = match method {
+            Value::Undefined(..) | Value::Null(..) => {
+                // Not async iterable, fall back to its sync iterator
+                let symbol_iterator = scope.statics.symbol_iterator.clone();
+                let iterable = value.get_property(&mut scope, PropertyKey::Symbol(symbol_iterator))?;
+                let iterator = iterable.apply(&mut scope, value, Vec::new())?;
+                let iterator = AsyncFromSyncIterator::new(&scope, iterator);
+                Value::Object(scope.register(iterator))
+            }
+            method => method.apply(&mut scope, value, Vec::new())?,
+        };
+
+        scope.stack.push(iterator);
+        Ok(None)
+    }
+
     pub fn call_for_in_iterator(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let value = cx.pop_stack();
         let mut scope = cx.scope();
 
         let keys = match value {
-            Value::Object(obj) => obj.own_keys()?,
-            Value::External(obj) => obj.own_keys()?,
+            Value::Object(obj) => obj.own_keys(&mut scope)?,
+            Value::External(obj) => obj.own_keys(&mut scope)?,
             _ => Vec::new(),
         }
         .into_iter()
@@ -1566,44 +2160,6 @@ mod handlers {
         Ok(None)
     }
 
-    pub fn objdestruct(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
-        let count = cx.fetchw_and_inc_ip();
-        let obj = cx.pop_stack();
-        let mut scope = cx.scope();
-
-        for _ in 0..count {
-            let mut cx = DispatchContext::new(&mut scope);
-            let loc_id = cx.fetchw_and_inc_ip();
-            let ident_id = cx.fetchw_and_inc_ip();
-
-            let id = cx.number_constant(loc_id.into()) as usize;
-            let ident = cx.identifier_constant(ident_id.into());
-
-            let prop = obj.get_property(&mut scope, PropertyKey::from(ident.as_ref()))?;
-            scope.set_local(id, prop);
-        }
-
-        Ok(None)
-    }
-
-    pub fn arraydestruct(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
-        let count = cx.fetchw_and_inc_ip();
-        let array = cx.pop_stack();
-        let mut scope = cx.scope();
-
-        for i in 0..count {
-            let mut cx = DispatchContext::new(&mut scope);
-            let loc_id = cx.fetchw_and_inc_ip();
-
-            let id = cx.number_constant(loc_id.into()) as usize;
-
-            let prop = array.get_property(&mut scope, PropertyKey::from(i.to_string().as_ref()))?;
-            scope.set_local(id, prop);
-        }
-
-        Ok(None)
-    }
-
     pub fn intrinsic_op(mut cx: DispatchContext<'_>) -> Result<Option<HandleResult>, Value> {
         let op = IntrinsicOperation::from_repr(cx.fetch_and_inc_ip()).unwrap();
 
@@ -1824,6 +2380,15 @@ pub fn handle(vm: &mut Vm, instruction: Instruction) -> Result<Option<HandleResu
         Instruction::StoreLocal => handlers::storelocal(cx),
         Instruction::LdLocal => handlers::ldlocal(cx),
         Instruction::ArrayLit => handlers::arraylit(cx),
+        Instruction::ArrayLitW => handlers::arraylitw(cx),
+        Instruction::ArrayPush => handlers::array_push(cx),
+        Instruction::ArraySpread => handlers::array_spread(cx),
+        Instruction::ObjRest => handlers::objrest(cx),
+        Instruction::IterDestruct => handlers::iterdestruct(cx),
+        Instruction::PrivateName => handlers::private_name(cx),
+        Instruction::DefineProperty => handlers::define_property(cx),
+        Instruction::TemplateStrings => handlers::template_strings(cx),
+        Instruction::SuperPropAccess => handlers::super_property_access(cx),
         Instruction::ObjLit => handlers::objlit(cx),
         Instruction::StaticPropAccess => handlers::staticpropertyaccess(cx),
         Instruction::StaticPropAssign => handlers::staticpropertyassign(cx),
@@ -1850,6 +2415,7 @@ pub fn handle(vm: &mut Vm, instruction: Instruction) -> Result<Option<HandleResu
         Instruction::This => handlers::this(cx),
         Instruction::Global => handlers::global_this(cx),
         Instruction::Super => handlers::super_(cx),
+        Instruction::Extends => handlers::extends(cx),
         Instruction::Debugger => handlers::debugger(cx),
         Instruction::Neg => handlers::neg(cx),
         Instruction::Pos => handlers::pos(cx),
@@ -1859,11 +2425,10 @@ pub fn handle(vm: &mut Vm, instruction: Instruction) -> Result<Option<HandleResu
         Instruction::Infinity => handlers::infinity(cx),
         Instruction::CallSymbolIterator => handlers::call_symbol_iterator(cx),
         Instruction::CallForInIterator => handlers::call_for_in_iterator(cx),
+        Instruction::CallAsyncIterator => handlers::call_async_iterator(cx),
         Instruction::DeletePropertyDynamic => handlers::delete_property_dynamic(cx),
         Instruction::DeletePropertyStatic => handlers::delete_property_static(cx),
         Instruction::Switch => handlers::switch(cx),
-        Instruction::ObjDestruct => handlers::objdestruct(cx),
-        Instruction::ArrayDestruct => handlers::arraydestruct(cx),
         Instruction::Nop => Ok(None),
         Instruction::IntrinsicOp => handlers::intrinsic_op(cx),
         _ => unimplemented!("{:?}", instruction),
@@ -4,6 +4,7 @@ use std::collections::HashSet;
 use std::rc::Rc;
 
 use dash_middle::compiler::constant::Function;
+use dash_middle::compiler::line_table::SourcePosition;
 use dash_middle::compiler::CompileResult;
 use dash_middle::parser::statement::FunctionKind;
 use dash_proc_macro::Trace;
@@ -19,6 +20,8 @@ use super::value::Value;
 pub struct TryBlock {
     pub catch_ip: usize,
     pub frame_ip: usize,
+    /// The stack height when entering the try block, which is restored when jumping to the catch block
+    pub sp: usize,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -94,6 +97,9 @@ pub struct Frame {
     /// Contains local variable values from the outer scope
     pub externals: Rc<[Handle<ExternalValue>]>,
     pub this: Option<Value>,
+    /// Whether the `this` binding is initialized, which is only not the case
+    /// in constructors of derived classes before `super()` is called
+    pub is_this_initialized: bool,
     pub sp: usize,
     pub state: FrameState,
 
@@ -102,6 +108,12 @@ pub struct Frame {
 }
 
 impl Frame {
+    /// Returns the source position of the instruction that this frame is currently executing
+    pub fn source_position(&self) -> Option<SourcePosition> {
+        // The instruction pointer has already been incremented past the opcode being executed
+        self.function.line_table.lookup(self.ip.saturating_sub(1))
+    }
+
     pub fn from_function(
         this: Option<Value>,
         uf: &UserFunction,
@@ -111,6 +123,7 @@ impl Frame {
         let inner = uf.inner();
         Self {
             this,
+            is_this_initialized: !(is_constructor_call && inner.is_derived_ctor),
             function: inner.clone(),
             externals: uf.externals().clone(),
             ip: 0,
@@ -128,6 +141,7 @@ impl Frame {
         let inner = uf.inner();
         Self {
             this,
+            is_this_initialized: true,
             function: inner.clone(),
             externals: uf.externals().clone(),
             ip: 0,
@@ -157,11 +171,14 @@ impl Frame {
             ty: FunctionKind::Function,
             r#async: false,
             rest_local: None,
+            is_derived_ctor: false,
+            line_table: cr.line_table,
             poison_ips: RefCell::new(HashSet::new()),
         };
 
         Self {
             this: None,
+            is_this_initialized: true,
             function: Rc::new(fun),
             externals: Vec::new().into(),
             ip: 0,
@@ -108,6 +108,17 @@ impl<T: ?Sized> Handle<T> {
     pub fn as_ptr(&self) -> *mut GcNode<T> {
         self.0.as_ptr()
     }
+
+    /// Whether this handle has been marked in the current mark phase
+    pub fn is_marked(&self) -> bool {
+        unsafe { (*self.as_ptr()).flags.is_marked() }
+    }
+
+    /// Whether this handle survives the next sweep, either because it has been marked
+    /// or because it is referenced by a [`super::persistent::Persistent`]
+    pub fn is_live(&self) -> bool {
+        unsafe { self.is_marked() || (*self.as_ptr()).refcount.get() > 0 }
+    }
 }
 
 impl Handle<dyn Object> {
@@ -10,10 +10,12 @@ use crate::value::object::Object;
 
 use self::handle::GcNode;
 use self::handle::Handle;
+use self::weak::Finalizer;
 
 pub mod handle;
 pub mod persistent;
 pub mod trace;
+pub mod weak;
 
 pub struct Gc {
     /// The very first node of this [`Gc`]
@@ -21,6 +23,10 @@ pub struct Gc {
     /// The last-inserted node of this [`Gc`]
     tail: Option<NonNull<GcNode<dyn Object>>>,
     node_count: usize,
+    /// All registered objects that hold weak references, see [`weak::WeakContainer`]
+    weak_containers: Vec<Handle<dyn Object>>,
+    /// Finalization callbacks of objects that were deallocated in the last sweep
+    pending_finalizers: Vec<Finalizer>,
 }
 
 impl Default for Gc {
@@ -35,6 +41,8 @@ impl Gc {
             head: None,
             tail: None,
             node_count: 0,
+            weak_containers: Vec::new(),
+            pending_finalizers: Vec::new(),
         }
     }
 
@@ -64,13 +72,50 @@ impl Gc {
     }
 
     pub fn register<O: Object + 'static>(&mut self, value: O) -> Handle<dyn Object> {
-        value.into_handle(self)
+        let handle = value.into_handle(self);
+        if handle.as_weak_container().is_some() {
+            self.weak_containers.push(handle.clone());
+        }
+        handle
+    }
+
+    /// Returns the finalization callbacks that were collected in the last sweep
+    pub fn take_pending_finalizers(&mut self) -> Vec<Finalizer> {
+        std::mem::take(&mut self.pending_finalizers)
+    }
+
+    /// Finishes the mark phase for values of weak containers and removes their entries that are about to be deallocated
+    fn sweep_weak_containers(&mut self) {
+        // Values of ephemerons are only reachable if their key is, and marking them can make other keys reachable,
+        // so keep tracing until nothing new gets marked
+        loop {
+            let mut marked_any = false;
+            for container in self.weak_containers.iter().filter(|c| c.is_live()) {
+                if let Some(container) = container.as_weak_container() {
+                    marked_any |= container.trace_ephemerons();
+                }
+            }
+
+            if !marked_any {
+                break;
+            }
+        }
+
+        self.weak_containers.retain(|c| c.is_live());
+
+        for container in &self.weak_containers {
+            if let Some(container) = container.as_weak_container() {
+                container.sweep_dead(&mut self.pending_finalizers);
+            }
+        }
     }
 
     /// # Safety
     /// Calling this function while there are unmarked, live [`Handle`]s is Undefined Behavior.
     /// Any unmarked node is deallocated during a sweep cycle.
     pub unsafe fn sweep(&mut self) {
+        self.sweep_weak_containers();
+
         // The last valid pointer that was found
         let mut previous = None;
         let mut cur = self.head;
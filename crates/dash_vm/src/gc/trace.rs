@@ -1,16 +1,22 @@
 use std::cell::Cell;
 use std::cell::RefCell;
 use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::rc::Rc;
 
 use dash_middle::compiler::constant::Function;
+use dash_middle::compiler::line_table::SourcePosition;
 
+use crate::value::function::generator::ResumeKind;
+use crate::value::primitive::BigInt;
 use crate::value::primitive::Null;
 use crate::value::primitive::Number;
 use crate::value::primitive::Symbol;
 use crate::value::primitive::Undefined;
+use crate::value::promise::RejectionTracking;
 use crate::value::regex::RegExpInner;
 use crate::value::typedarray::TypedArrayKind;
+use crate::PromiseAction;
 
 /// # Safety
 /// Implementors of this trait must provide a valid trace implementation
@@ -41,6 +47,14 @@ unsafe impl<T: Trace> Trace for Vec<T> {
     }
 }
 
+unsafe impl<T: Trace> Trace for VecDeque<T> {
+    fn trace(&self) {
+        for item in self {
+            item.trace();
+        }
+    }
+}
+
 unsafe impl<T: Trace> Trace for HashSet<T> {
     fn trace(&self) {
         for t in self.iter() {
@@ -104,6 +118,11 @@ unsafe_empty_trace!(
     Null,
     Symbol,
     Number,
+    BigInt,
     RegExpInner,
-    TypedArrayKind
+    TypedArrayKind,
+    SourcePosition,
+    ResumeKind,
+    PromiseAction,
+    RejectionTracking
 );
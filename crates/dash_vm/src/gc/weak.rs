@@ -0,0 +1,47 @@
+use crate::value::object::Object;
+use crate::value::Value;
+
+use super::handle::Handle;
+use super::trace::Trace;
+
+/// A finalization callback of a `FinalizationRegistry` together with the held value it is called with
+pub type Finalizer = (Handle<dyn Object>, Value);
+
+/// An object that holds references to other objects without keeping them alive,
+/// such as `WeakMap`, `WeakSet`, `WeakRef` and `FinalizationRegistry`.
+///
+/// The [`Trace`] implementation of a weak container must not trace any weakly held handles.
+/// Instead, the garbage collector calls into this trait at the end of the mark phase.
+pub trait WeakContainer {
+    /// Traces values that are only reachable through a live key (ephemerons).
+    /// Returns whether any handle that was previously unmarked got marked.
+    fn trace_ephemerons(&self) -> bool {
+        false
+    }
+
+    /// Removes all entries whose weakly held handles did not survive the mark phase
+    /// and collects the finalization callbacks that need to be called for them
+    fn sweep_dead(&self, finalizers: &mut Vec<Finalizer>);
+}
+
+/// Returns the object handle of a value that can be held weakly, i.e. a key of a `WeakMap`
+/// or the target of a `WeakRef` (CanBeHeldWeakly)
+pub fn weak_target(value: &Value) -> Option<Handle<dyn Object>> {
+    match value {
+        Value::Object(o) => Some(o.clone()),
+        Value::External(o) => Some(o.inner.clone()),
+        _ => None,
+    }
+}
+
+/// Traces a value that is reachable through a live ephemeron key, returning whether it was not marked before
+pub fn trace_ephemeron_value(value: &Value) -> bool {
+    let was_marked = match value {
+        Value::Object(o) => o.is_marked(),
+        Value::External(o) => o.is_marked(),
+        _ => return false,
+    };
+
+    value.trace();
+    !was_marked
+}
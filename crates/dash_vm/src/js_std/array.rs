@@ -6,6 +6,7 @@ use crate::value::array;
 use crate::value::array::Array;
 use crate::value::array::ArrayIterator;
 use crate::value::function::native::CallContext;
+use crate::value::object::Object;
 use crate::value::object::PropertyValue;
 use crate::value::ops::abstractions::conversions::ValueConversion;
 use crate::value::ops::equality::ValueEquality;
@@ -351,9 +352,20 @@ pub fn pop(cx: CallContext) -> Result<Value, Value> {
 }
 
 pub fn push(cx: CallContext) -> Result<Value, Value> {
-    let this = Value::Object(cx.this.to_object(cx.scope)?);
+    let object = cx.this.to_object(cx.scope)?;
+    let extensible = object.is_extensible(cx.scope)?;
+    let this = Value::Object(object);
     let len = this.length_of_array_like(cx.scope)?;
 
+    if !extensible {
+        throw!(
+            cx.scope,
+            TypeError,
+            "Cannot add property {}, object is not extensible",
+            len
+        );
+    }
+
     let mut last = Value::undefined();
 
     if cx.args.is_empty() {
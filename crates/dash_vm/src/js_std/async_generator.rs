@@ -0,0 +1,57 @@
+use crate::throw;
+use crate::value::function::async_generator::AsyncFromSyncIterator;
+use crate::value::function::async_generator::AsyncFromSyncIteratorContinuation;
+use crate::value::function::async_generator::AsyncGeneratorIterator;
+use crate::value::function::generator::ResumeKind;
+use crate::value::function::native::CallContext;
+use crate::value::object::PropertyKey;
+use crate::value::promise::wrap_promise;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+fn enqueue(cx: CallContext, kind: ResumeKind) -> Result<Value, Value> {
+    let value = cx.args.first().unwrap_or_undefined();
+    let generator = match cx.this.downcast_ref::<AsyncGeneratorIterator>() {
+        Some(generator) => generator,
+        None => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    generator.enqueue(cx.scope, &cx.this, kind, value)
+}
+
+pub fn next(cx: CallContext) -> Result<Value, Value> {
+    enqueue(cx, ResumeKind::Next)
+}
+
+pub fn return_(cx: CallContext) -> Result<Value, Value> {
+    enqueue(cx, ResumeKind::Return)
+}
+
+pub fn throw(cx: CallContext) -> Result<Value, Value> {
+    enqueue(cx, ResumeKind::Throw)
+}
+
+pub fn async_from_sync_iterator_next(cx: CallContext) -> Result<Value, Value> {
+    let iterator = match cx.this.downcast_ref::<AsyncFromSyncIterator>() {
+        Some(iterator) => iterator.sync_iterator().clone(),
+        None => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let next = iterator.get_property(cx.scope, PropertyKey::String("next".into()))?;
+    let result = next.apply(cx.scope, iterator, cx.args)?;
+    let done = result
+        .get_property(cx.scope, PropertyKey::String("done".into()))?
+        .is_truthy();
+    let value = result.get_property(cx.scope, PropertyKey::String("value".into()))?;
+
+    // The value of the sync iterator is awaited before the iterator result is created
+    let continuation = AsyncFromSyncIteratorContinuation::new(cx.scope, done);
+    let continuation = cx.scope.register(continuation);
+    let value = wrap_promise(cx.scope, value);
+
+    cx.scope
+        .statics
+        .promise_then
+        .clone()
+        .apply(cx.scope, value, vec![Value::Object(continuation)])
+}
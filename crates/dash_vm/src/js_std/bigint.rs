@@ -0,0 +1,136 @@
+use num_bigint::BigInt as NumBigInt;
+use num_traits::FromPrimitive;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::Zero;
+
+use crate::local::LocalScope;
+use crate::throw;
+use crate::util::format_f64;
+use crate::value::boxed::BigInt as BoxedBigInt;
+use crate::value::function::native::CallContext;
+use crate::value::ops::abstractions::conversions::PreferredType;
+use crate::value::ops::abstractions::conversions::ValueConversion;
+use crate::value::ops::arithmetic::MAX_BIGINT_BITS;
+use crate::value::primitive::BigInt;
+use crate::value::primitive::Number;
+use crate::value::primitive::MAX_SAFE_INTEGERF;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+pub fn constructor(cx: CallContext) -> Result<Value, Value> {
+    if cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "BigInt is not a constructor");
+    }
+
+    let value = cx
+        .args
+        .first()
+        .unwrap_or_undefined()
+        .to_primitive(cx.scope, Some(PreferredType::Number))?;
+
+    // Unlike ToBigInt, BigInt() also accepts numbers as long as they are integers (NumberToBigInt)
+    if let Value::Number(Number(n)) = value {
+        return match NumBigInt::from_f64(n) {
+            Some(b) if n.fract() == 0.0 => Ok(Value::BigInt(b.into())),
+            _ => throw!(
+                cx.scope,
+                RangeError,
+                "The number {} cannot be converted to a BigInt because it is not an integer",
+                format_f64(n)
+            ),
+        };
+    }
+
+    value.to_bigint(cx.scope).map(Value::BigInt)
+}
+
+/// Converts the `bits` argument of `BigInt.asIntN` and `BigInt.asUintN` to an index (ToIndex)
+fn to_bits(sc: &mut LocalScope, value: &Value) -> Result<u64, Value> {
+    let bits = value.to_integer_or_infinity(sc)?;
+
+    if !(0.0..=MAX_SAFE_INTEGERF).contains(&bits) {
+        throw!(sc, RangeError, "Invalid value: not (convertible to) a safe integer");
+    }
+
+    Ok(bits as u64)
+}
+
+/// Returns `value` modulo 2^bits
+fn truncate_to_bits(value: &NumBigInt, bits: u64) -> NumBigInt {
+    // The bitwise and of BigInts operates on their two's complement representation,
+    // so this also works for negative values
+    let mask = (NumBigInt::one() << bits) - 1;
+    value & mask
+}
+
+pub fn as_int_n(cx: CallContext) -> Result<Value, Value> {
+    let bits = to_bits(cx.scope, &cx.args.first().unwrap_or_undefined())?;
+    let value = cx.args.get(1).unwrap_or_undefined().to_bigint(cx.scope)?;
+
+    if bits == 0 {
+        return Ok(Value::BigInt(NumBigInt::zero().into()));
+    }
+
+    // Values that fit in `bits` bits including the sign bit are unchanged.
+    // This also keeps the mask from growing larger than the value itself
+    if value.bits() < bits {
+        return Ok(Value::BigInt(value));
+    }
+
+    let modulo = truncate_to_bits(&value, bits);
+    let result = if modulo.bit(bits - 1) {
+        modulo - (NumBigInt::one() << bits)
+    } else {
+        modulo
+    };
+
+    Ok(Value::BigInt(result.into()))
+}
+
+pub fn as_uint_n(cx: CallContext) -> Result<Value, Value> {
+    let bits = to_bits(cx.scope, &cx.args.first().unwrap_or_undefined())?;
+    let value = cx.args.get(1).unwrap_or_undefined().to_bigint(cx.scope)?;
+
+    if !value.is_negative() && value.bits() <= bits {
+        return Ok(Value::BigInt(value));
+    }
+
+    // Negative values wrap around to 2^bits - |value|, which needs all of the bits
+    if bits > MAX_BIGINT_BITS {
+        throw!(cx.scope, RangeError, "Maximum BigInt size exceeded");
+    }
+
+    Ok(Value::BigInt(truncate_to_bits(&value, bits).into()))
+}
+
+/// Returns the BigInt of a primitive BigInt or a BigInt object (thisBigIntValue)
+fn this_bigint_value(sc: &mut LocalScope, value: &Value) -> Result<BigInt, Value> {
+    if let Value::BigInt(bigint) = value {
+        return Ok(bigint.clone());
+    }
+
+    match value.downcast_ref::<BoxedBigInt>() {
+        Some(boxed) => Ok(boxed.value().clone()),
+        None => throw!(sc, TypeError, "BigInt.prototype method called on incompatible receiver"),
+    }
+}
+
+pub fn to_string(cx: CallContext) -> Result<Value, Value> {
+    let bigint = this_bigint_value(cx.scope, &cx.this)?;
+
+    let radix = match cx.args.first() {
+        Some(Value::Undefined(_)) | None => 10.0,
+        Some(radix) => radix.to_integer_or_infinity(cx.scope)?,
+    };
+
+    if !(2.0..=36.0).contains(&radix) {
+        throw!(cx.scope, RangeError, "toString() radix must be between 2 and 36");
+    }
+
+    Ok(Value::String(bigint.to_str_radix(radix as u32).into()))
+}
+
+pub fn value_of(cx: CallContext) -> Result<Value, Value> {
+    this_bigint_value(cx.scope, &cx.this).map(Value::BigInt)
+}
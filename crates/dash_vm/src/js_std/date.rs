@@ -1,6 +1,13 @@
+use crate::local::LocalScope;
 use crate::throw;
+use crate::value::date;
+use crate::value::date::Date;
+use crate::value::date::ParsedDate;
 use crate::value::function::native::CallContext;
+use crate::value::ops::abstractions::conversions::PreferredType;
+use crate::value::ops::abstractions::conversions::ValueConversion;
 use crate::value::Value;
+use crate::value::ValueContext;
 
 pub fn time_millis(cx: &mut CallContext) -> Result<u64, Value> {
     let callback = match cx.scope.params().time_millis_callback() {
@@ -11,11 +18,379 @@ pub fn time_millis(cx: &mut CallContext) -> Result<u64, Value> {
     callback(cx.scope)
 }
 
-pub fn constructor(cx: CallContext) -> Result<Value, Value> {
-    throw!(cx.scope, Error, "Date constructor is currently unimplemented")
+fn this_time_value(cx: &mut CallContext) -> Result<f64, Value> {
+    match cx.this.downcast_ref::<Date>() {
+        Some(date) => Ok(date.time()),
+        None => throw!(&mut cx.scope, TypeError, "this is not a Date object"),
+    }
+}
+
+fn set_this_time_value(cx: &mut CallContext, time: f64) -> Result<f64, Value> {
+    match cx.this.downcast_ref::<Date>() {
+        Some(date) => Ok(date.set_time(time)),
+        None => throw!(&mut cx.scope, TypeError, "this is not a Date object"),
+    }
+}
+
+/// Converts a date string to a UTC time value, or NaN if the string is not a valid date
+fn parse_date(sc: &mut LocalScope, s: &str) -> Result<f64, Value> {
+    let time = match date::parse(s) {
+        Some(ParsedDate::Utc(time)) => time,
+        Some(ParsedDate::Local(time)) => date::utc(sc, time)?,
+        None => f64::NAN,
+    };
+
+    Ok(date::time_clip(time))
+}
+
+/// Converts date components (year, month[, date[, hours[, minutes[, seconds[, ms]]]]]) to a time value
+fn time_from_components(sc: &mut LocalScope, args: &[Value]) -> Result<f64, Value> {
+    let mut components = [f64::NAN, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    for (component, arg) in components.iter_mut().zip(args) {
+        *component = arg.to_number(sc)?;
+    }
+
+    let [year, month, day, hours, minutes, seconds, ms] = components;
+
+    // Years 0-99 map to 1900-1999
+    let year = match year {
+        y if y.is_nan() => y,
+        y if (0.0..=99.0).contains(&y.trunc()) => 1900.0 + y.trunc(),
+        y => y,
+    };
+
+    Ok(date::make_date(
+        date::make_day(year, month, day),
+        date::make_time(hours, minutes, seconds, ms),
+    ))
+}
+
+fn date_to_string(sc: &mut LocalScope, tv: f64) -> Result<String, Value> {
+    if tv.is_nan() {
+        return Ok(String::from("Invalid Date"));
+    }
+
+    let offset = date::local_tza(sc, tv)?;
+    let t = tv + offset;
+    Ok(format!("{} {}", date::date_string(t), date::time_string(t, offset)))
+}
+
+pub fn constructor(mut cx: CallContext) -> Result<Value, Value> {
+    if !cx.is_constructor_call {
+        // Date called as a function ignores its arguments and returns the current time as a string
+        let now = time_millis(&mut cx)? as f64;
+        let string = date_to_string(cx.scope, now)?;
+        return Ok(Value::String(string.into()));
+    }
+
+    let time = match cx.args.len() {
+        0 => time_millis(&mut cx)? as f64,
+        1 => {
+            let value = cx.args[0].clone();
+            match value.downcast_ref::<Date>() {
+                Some(date) => date.time(),
+                None => match value.to_primitive(cx.scope, None)? {
+                    Value::String(s) => parse_date(cx.scope, &s)?,
+                    other => other.to_number(cx.scope)?,
+                },
+            }
+        }
+        _ => {
+            let time = time_from_components(cx.scope, &cx.args)?;
+            date::utc(cx.scope, time)?
+        }
+    };
+
+    let date = Date::new(cx.scope, time);
+    Ok(Value::Object(cx.scope.register(date)))
 }
 
 pub fn now(mut cx: CallContext) -> Result<Value, Value> {
     let time = time_millis(&mut cx)?;
     Ok(Value::number(time as f64))
 }
+
+pub fn parse(cx: CallContext) -> Result<Value, Value> {
+    let string = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    parse_date(cx.scope, &string).map(Value::number)
+}
+
+pub fn utc(cx: CallContext) -> Result<Value, Value> {
+    let time = time_from_components(cx.scope, &cx.args)?;
+    Ok(Value::number(date::time_clip(time)))
+}
+
+macro_rules! define_getters {
+    ($($local:ident $utc:ident => $component:ident),*) => {
+        $(
+            pub fn $local(mut cx: CallContext) -> Result<Value, Value> {
+                let t = this_time_value(&mut cx)?;
+                if t.is_nan() {
+                    return Ok(Value::number(f64::NAN));
+                }
+
+                let t = date::local_time(cx.scope, t)?;
+                Ok(Value::number(date::$component(t)))
+            }
+
+            pub fn $utc(mut cx: CallContext) -> Result<Value, Value> {
+                let t = this_time_value(&mut cx)?;
+                if t.is_nan() {
+                    return Ok(Value::number(f64::NAN));
+                }
+
+                Ok(Value::number(date::$component(t)))
+            }
+        )*
+    };
+}
+
+define_getters!(
+    get_date get_utc_date => date_from_time,
+    get_day get_utc_day => week_day,
+    get_full_year get_utc_full_year => year_from_time,
+    get_hours get_utc_hours => hour_from_time,
+    get_milliseconds get_utc_milliseconds => ms_from_time,
+    get_minutes get_utc_minutes => min_from_time,
+    get_month get_utc_month => month_from_time,
+    get_seconds get_utc_seconds => sec_from_time
+);
+
+pub fn get_time(mut cx: CallContext) -> Result<Value, Value> {
+    this_time_value(&mut cx).map(Value::number)
+}
+
+pub fn value_of(mut cx: CallContext) -> Result<Value, Value> {
+    this_time_value(&mut cx).map(Value::number)
+}
+
+pub fn get_timezone_offset(mut cx: CallContext) -> Result<Value, Value> {
+    let t = this_time_value(&mut cx)?;
+    if t.is_nan() {
+        return Ok(Value::number(f64::NAN));
+    }
+
+    let local = date::local_time(cx.scope, t)?;
+    Ok(Value::number((t - local) / date::MS_PER_MINUTE))
+}
+
+/// Shared implementation of the setters that update individual components of a date.
+///
+/// Up to `argc` arguments are converted to numbers (a missing first argument is NaN),
+/// and `f` computes the new time value from the current (local or UTC) time value and the arguments.
+fn set_components(mut cx: CallContext, argc: usize, local: bool, f: fn(f64, &[f64]) -> f64) -> Result<Value, Value> {
+    let t = this_time_value(&mut cx)?;
+
+    let mut args = Vec::with_capacity(argc);
+    for index in 0..argc {
+        match cx.args.get(index) {
+            Some(arg) => args.push(arg.to_number(cx.scope)?),
+            None if index == 0 => args.push(f64::NAN),
+            None => break,
+        }
+    }
+
+    if t.is_nan() {
+        return Ok(Value::number(f64::NAN));
+    }
+
+    let time = match local {
+        true => {
+            let t = date::local_time(cx.scope, t)?;
+            date::utc(cx.scope, f(t, &args))?
+        }
+        false => f(t, &args),
+    };
+
+    set_this_time_value(&mut cx, time).map(Value::number)
+}
+
+fn with_milliseconds(t: f64, args: &[f64]) -> f64 {
+    let time = date::make_time(
+        date::hour_from_time(t),
+        date::min_from_time(t),
+        date::sec_from_time(t),
+        args[0],
+    );
+    date::make_date(date::day(t), time)
+}
+
+fn with_seconds(t: f64, args: &[f64]) -> f64 {
+    let ms = args.get(1).copied().unwrap_or_else(|| date::ms_from_time(t));
+    let time = date::make_time(date::hour_from_time(t), date::min_from_time(t), args[0], ms);
+    date::make_date(date::day(t), time)
+}
+
+fn with_minutes(t: f64, args: &[f64]) -> f64 {
+    let s = args.get(1).copied().unwrap_or_else(|| date::sec_from_time(t));
+    let ms = args.get(2).copied().unwrap_or_else(|| date::ms_from_time(t));
+    let time = date::make_time(date::hour_from_time(t), args[0], s, ms);
+    date::make_date(date::day(t), time)
+}
+
+fn with_hours(t: f64, args: &[f64]) -> f64 {
+    let m = args.get(1).copied().unwrap_or_else(|| date::min_from_time(t));
+    let s = args.get(2).copied().unwrap_or_else(|| date::sec_from_time(t));
+    let ms = args.get(3).copied().unwrap_or_else(|| date::ms_from_time(t));
+    let time = date::make_time(args[0], m, s, ms);
+    date::make_date(date::day(t), time)
+}
+
+fn with_date(t: f64, args: &[f64]) -> f64 {
+    let day = date::make_day(date::year_from_time(t), date::month_from_time(t), args[0]);
+    date::make_date(day, date::time_within_day(t))
+}
+
+fn with_month(t: f64, args: &[f64]) -> f64 {
+    let dt = args.get(1).copied().unwrap_or_else(|| date::date_from_time(t));
+    let day = date::make_day(date::year_from_time(t), args[0], dt);
+    date::make_date(day, date::time_within_day(t))
+}
+
+macro_rules! define_setters {
+    ($($local:ident $utc:ident => $argc:expr, $f:ident),*) => {
+        $(
+            pub fn $local(cx: CallContext) -> Result<Value, Value> {
+                set_components(cx, $argc, true, $f)
+            }
+
+            pub fn $utc(cx: CallContext) -> Result<Value, Value> {
+                set_components(cx, $argc, false, $f)
+            }
+        )*
+    };
+}
+
+define_setters!(
+    set_milliseconds set_utc_milliseconds => 1, with_milliseconds,
+    set_seconds set_utc_seconds => 2, with_seconds,
+    set_minutes set_utc_minutes => 3, with_minutes,
+    set_hours set_utc_hours => 4, with_hours,
+    set_date set_utc_date => 1, with_date,
+    set_month set_utc_month => 2, with_month
+);
+
+fn set_full_year_inner(mut cx: CallContext, local: bool) -> Result<Value, Value> {
+    let t = this_time_value(&mut cx)?;
+
+    // Unlike the other setters, an invalid date is treated as +0 here
+    let t = match (t.is_nan(), local) {
+        (true, _) => 0.0,
+        (false, true) => date::local_time(cx.scope, t)?,
+        (false, false) => t,
+    };
+
+    let year = cx.args.first().unwrap_or_undefined().to_number(cx.scope)?;
+    let month = match cx.args.get(1) {
+        Some(month) => month.to_number(cx.scope)?,
+        None => date::month_from_time(t),
+    };
+    let dt = match cx.args.get(2) {
+        Some(dt) => dt.to_number(cx.scope)?,
+        None => date::date_from_time(t),
+    };
+
+    let new_date = date::make_date(date::make_day(year, month, dt), date::time_within_day(t));
+    let time = match local {
+        true => date::utc(cx.scope, new_date)?,
+        false => new_date,
+    };
+
+    set_this_time_value(&mut cx, time).map(Value::number)
+}
+
+pub fn set_full_year(cx: CallContext) -> Result<Value, Value> {
+    set_full_year_inner(cx, true)
+}
+
+pub fn set_utc_full_year(cx: CallContext) -> Result<Value, Value> {
+    set_full_year_inner(cx, false)
+}
+
+pub fn set_time(mut cx: CallContext) -> Result<Value, Value> {
+    this_time_value(&mut cx)?;
+    let time = cx.args.first().unwrap_or_undefined().to_number(cx.scope)?;
+    set_this_time_value(&mut cx, time).map(Value::number)
+}
+
+pub fn to_string(mut cx: CallContext) -> Result<Value, Value> {
+    let tv = this_time_value(&mut cx)?;
+    let string = date_to_string(cx.scope, tv)?;
+    Ok(Value::String(string.into()))
+}
+
+pub fn to_date_string(mut cx: CallContext) -> Result<Value, Value> {
+    let tv = this_time_value(&mut cx)?;
+    if tv.is_nan() {
+        return Ok(Value::String("Invalid Date".into()));
+    }
+
+    let t = date::local_time(cx.scope, tv)?;
+    Ok(Value::String(date::date_string(t).into()))
+}
+
+pub fn to_time_string(mut cx: CallContext) -> Result<Value, Value> {
+    let tv = this_time_value(&mut cx)?;
+    if tv.is_nan() {
+        return Ok(Value::String("Invalid Date".into()));
+    }
+
+    let offset = date::local_tza(cx.scope, tv)?;
+    Ok(Value::String(date::time_string(tv + offset, offset).into()))
+}
+
+pub fn to_utc_string(mut cx: CallContext) -> Result<Value, Value> {
+    let tv = this_time_value(&mut cx)?;
+    if tv.is_nan() {
+        return Ok(Value::String("Invalid Date".into()));
+    }
+
+    Ok(Value::String(date::utc_string(tv).into()))
+}
+
+pub fn to_iso_string(mut cx: CallContext) -> Result<Value, Value> {
+    let tv = this_time_value(&mut cx)?;
+    if tv.is_nan() {
+        throw!(cx.scope, RangeError, "Invalid time value");
+    }
+
+    Ok(Value::String(date::to_iso_string(tv).into()))
+}
+
+pub fn to_json(cx: CallContext) -> Result<Value, Value> {
+    // 1. Let O be ? ToObject(this value).
+    let o = Value::Object(cx.this.to_object(cx.scope)?);
+
+    // 2. Let tv be ? ToPrimitive(O, number).
+    let tv = o.to_primitive(cx.scope, Some(PreferredType::Number))?;
+
+    // 3. If tv is a Number and tv is not finite, return null.
+    if let Value::Number(n) = tv {
+        if !n.0.is_finite() {
+            return Ok(Value::null());
+        }
+    }
+
+    // 4. Return ? Invoke(O, "toISOString").
+    let to_iso_string = o.get_property(cx.scope, "toISOString".into())?;
+    to_iso_string.apply(cx.scope, o, Vec::new())
+}
+
+pub fn to_primitive(cx: CallContext) -> Result<Value, Value> {
+    if !matches!(cx.this, Value::Object(_) | Value::External(_)) {
+        throw!(
+            cx.scope,
+            TypeError,
+            "Date.prototype[Symbol.toPrimitive] called on non-object"
+        );
+    }
+
+    let hint = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let preferred_type = match hint.as_ref() {
+        "string" | "default" => PreferredType::String,
+        "number" => PreferredType::Number,
+        _ => throw!(cx.scope, TypeError, "Invalid hint: {}", hint),
+    };
+
+    cx.this.ordinary_to_primitive(cx.scope, preferred_type)
+}
@@ -0,0 +1,83 @@
+use crate::gc::weak::weak_target;
+use crate::throw;
+use crate::value::finalization_registry::FinalizationRegistry;
+use crate::value::function::native::CallContext;
+use crate::value::Typeof;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+pub fn constructor(cx: CallContext) -> Result<Value, Value> {
+    if !cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "Constructor FinalizationRegistry requires 'new'");
+    }
+
+    let callback = cx.args.first().unwrap_or_undefined();
+    let callback = match weak_target(&callback) {
+        Some(callback) if matches!(callback.type_of(), Typeof::Function) => callback,
+        _ => throw!(cx.scope, TypeError, "FinalizationRegistry: cleanup must be callable"),
+    };
+
+    let registry = FinalizationRegistry::new(cx.scope, callback);
+    Ok(Value::Object(cx.scope.register(registry)))
+}
+
+pub fn register(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<FinalizationRegistry>() {
+        Some(registry) => registry,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let target_value = cx.args.first().unwrap_or_undefined();
+    let target = match weak_target(&target_value) {
+        Some(target) => target,
+        None => throw!(
+            cx.scope,
+            TypeError,
+            "FinalizationRegistry.prototype.register: invalid target"
+        ),
+    };
+
+    let held_value = cx.args.get(1).unwrap_or_undefined();
+    if let Some(held) = weak_target(&held_value) {
+        if held == target {
+            throw!(
+                cx.scope,
+                TypeError,
+                "FinalizationRegistry.prototype.register: target and holdings must not be same"
+            );
+        }
+    }
+
+    let unregister_token = match cx.args.get(2).unwrap_or_undefined() {
+        Value::Undefined(_) => None,
+        token => match weak_target(&token) {
+            Some(token) => Some(token),
+            None => throw!(
+                cx.scope,
+                TypeError,
+                "FinalizationRegistry.prototype.register: invalid unregister token"
+            ),
+        },
+    };
+
+    this.register(target, held_value, unregister_token);
+    Ok(Value::undefined())
+}
+
+pub fn unregister(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<FinalizationRegistry>() {
+        Some(registry) => registry,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let token = match weak_target(&cx.args.first().unwrap_or_undefined()) {
+        Some(token) => token,
+        None => throw!(
+            cx.scope,
+            TypeError,
+            "FinalizationRegistry.prototype.unregister: invalid unregister token"
+        ),
+    };
+
+    Ok(Value::Boolean(this.unregister(&token)))
+}
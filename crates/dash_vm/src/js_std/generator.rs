@@ -1,11 +1,13 @@
-use std::mem;
+use dash_middle::compiler::instruction::YIELD_RETURN_OFFSET;
 
 use crate::dispatch::HandleResult;
 use crate::frame::Frame;
+use crate::frame::TryBlock;
 use crate::local::LocalScope;
 use crate::throw;
 use crate::value::function::generator::as_generator;
 use crate::value::function::generator::GeneratorState;
+use crate::value::function::generator::ResumeKind;
 use crate::value::function::native::CallContext;
 use crate::value::function::Function;
 use crate::value::function::FunctionKind;
@@ -16,71 +18,140 @@ use crate::value::Value;
 use crate::value::ValueContext;
 
 pub fn next(cx: CallContext) -> Result<Value, Value> {
+    resume_with(cx, ResumeKind::Next)
+}
+
+pub fn return_(cx: CallContext) -> Result<Value, Value> {
+    resume_with(cx, ResumeKind::Return)
+}
+
+pub fn throw(cx: CallContext) -> Result<Value, Value> {
+    resume_with(cx, ResumeKind::Throw)
+}
+
+fn resume_with(cx: CallContext, kind: ResumeKind) -> Result<Value, Value> {
     let arg = cx.args.first().unwrap_or_undefined();
-    let frame = {
-        let generator = as_generator(cx.scope, &cx.this)?;
-
-        let (ip, old_stack) = match &mut *generator.state().borrow_mut() {
-            GeneratorState::Finished => return create_generator_value(cx.scope, true, None),
-            GeneratorState::Running { ip, stack } => (*ip, mem::take(stack)),
-        };
-
-        let function = match generator
-            .function()
-            .as_any()
-            .downcast_ref::<Function>()
-            .map(|fun| fun.kind())
-        {
-            Some(FunctionKind::Generator(gen)) => gen.function(),
-            Some(FunctionKind::Async(fun)) => fun.inner().function(),
-            _ => throw!(cx.scope, TypeError, "Incompatible generator function"),
-        };
-
-        let current_sp = cx.scope.stack_size();
-        cx.scope.try_extend_stack(old_stack)?;
-
-        let mut frame = Frame::from_function(None, function, false, false);
-        frame.set_ip(ip);
-        frame.set_sp(current_sp);
-
-        if !generator.did_run() {
-            // If it hasn't run before, do the stack space management initially (push undefined values for locals)
-            // We only want to do this if the generator hasn't run already, because the locals are already in `old_stack`
-            cx.scope.pad_stack_for_frame(&frame);
-        } else {
-            // Generator did run before. Push the yielded value onto the stack, which will be what the yield expression
-            // evaluates to.
-            cx.scope.stack.push(arg);
+    let result = resume(cx.scope, &cx.this, kind, arg)?;
+
+    match result {
+        HandleResult::Return(value) => create_generator_value(cx.scope, true, Some(value)),
+        // Async functions are desugared to generators, so `await` is treated equivalent to `yield` here
+        HandleResult::Yield(value) | HandleResult::Await(value) => create_generator_value(cx.scope, false, Some(value)),
+    }
+}
+
+/// Resumes a generator with the given completion and runs it until it suspends again, returns or throws.
+///
+/// A generator that has not started yet or has already finished is not resumed by `throw` and `return` completions,
+/// and instead immediately throws or returns the value.
+pub(crate) fn resume(
+    scope: &mut LocalScope,
+    this: &Value,
+    kind: ResumeKind,
+    arg: Value,
+) -> Result<HandleResult, Value> {
+    let generator = as_generator(scope, this)?;
+
+    // The generator is considered finished until it suspends again
+    let (ip, old_stack, old_try_blocks) = match generator.state().replace(GeneratorState::Finished) {
+        GeneratorState::Running { ip, stack, try_blocks } if ip != 0 || kind == ResumeKind::Next => {
+            (ip, stack, try_blocks)
+        }
+        _ => {
+            return match kind {
+                ResumeKind::Next => Ok(HandleResult::Return(Value::undefined())),
+                ResumeKind::Return => Ok(HandleResult::Return(arg)),
+                ResumeKind::Throw => Err(arg),
+            }
         }
+    };
+    let did_run = ip != 0;
 
-        frame
+    let function = match generator
+        .function()
+        .as_any()
+        .downcast_ref::<Function>()
+        .map(|fun| fun.kind())
+    {
+        Some(FunctionKind::Generator(gen)) => gen.function(),
+        Some(FunctionKind::AsyncGenerator(gen)) => gen.inner().function(),
+        Some(FunctionKind::Async(fun)) => fun.inner().function(),
+        _ => throw!(scope, TypeError, "Incompatible generator function"),
     };
 
-    // Generators work a bit different from normal functions, so we do the stack padding management ourselves here
-    let result = cx.scope.execute_frame_raw(frame)?;
-    let generator = as_generator(cx.scope, &cx.this)?;
+    let current_sp = scope.stack_size();
+    scope.try_extend_stack(old_stack)?;
 
-    match result {
-        HandleResult::Return(value) => {
-            generator.state().replace(GeneratorState::Finished);
+    let mut frame = Frame::from_function(None, function, false, false);
+    frame.set_ip(ip);
+    frame.set_sp(current_sp);
+
+    // Restore the try blocks of the generator frame, which is about to be pushed
+    let frame_ip = scope.frames.len() + 1;
+    let try_blocks = old_try_blocks.into_iter().map(|tb| TryBlock {
+        frame_ip,
+        sp: current_sp + tb.sp,
+        ..tb
+    });
+    scope.try_blocks.extend(try_blocks);
 
-            create_generator_value(cx.scope, true, Some(value))
+    if !did_run {
+        // If it hasn't run before, do the stack space management initially (push undefined values for locals)
+        // We only want to do this if the generator hasn't run already, because the locals are already in `old_stack`
+        scope.pad_stack_for_frame(&frame);
+    } else if kind != ResumeKind::Throw {
+        // Generator did run before. Push the sent value onto the stack, which will be what the yield expression
+        // evaluates to, or the value that is returned
+        scope.stack.push(arg.clone());
+
+        if kind == ResumeKind::Return {
+            frame.set_ip(ip + YIELD_RETURN_OFFSET);
         }
-        HandleResult::Yield(value) | HandleResult::Await(value) => {
-            // Async functions are desugared to generators, so `await` is treated equivalent to `yield`, for now...
-            let frame = cx.scope.pop_frame().expect("Generator frame is missing");
-            let stack = cx.scope.drain_stack(frame.sp..).collect::<Vec<_>>();
+    }
 
-            generator
-                .state()
-                .replace(GeneratorState::Running { ip: frame.ip, stack });
+    // Generators work a bit different from normal functions, so we do the stack padding management ourselves here
+    let result = match kind {
+        ResumeKind::Throw => scope.execute_frame_raw_throw(frame, arg),
+        _ => scope.execute_frame_raw(frame),
+    };
 
-            create_generator_value(cx.scope, false, Some(value))
+    let result = match result {
+        Ok(result) => result,
+        Err(err) => {
+            // The generator frame was unwound, but its stack space is left for us to clean up
+            scope.stack.truncate(current_sp);
+            return Err(err);
         }
+    };
+
+    if let HandleResult::Yield(..) | HandleResult::Await(..) = result {
+        let frame_ip = scope.frames.len();
+        let frame = scope.pop_frame().expect("Generator frame is missing");
+        let stack = scope.drain_stack(frame.sp..).collect::<Vec<_>>();
+
+        // Try blocks of the generator frame are saved and restored when it is resumed
+        let lower_tcp = scope.try_blocks.partition_point(|tb| tb.frame_ip < frame_ip);
+        let try_blocks = scope
+            .try_blocks
+            .drain(lower_tcp..)
+            .map(|tb| TryBlock {
+                sp: tb.sp - frame.sp,
+                ..tb
+            })
+            .collect();
+
+        let generator = as_generator(scope, this)?;
+        generator.state().replace(GeneratorState::Running {
+            ip: frame.ip,
+            stack,
+            try_blocks,
+        });
     }
+
+    Ok(result)
 }
 
-fn create_generator_value(scope: &mut LocalScope, done: bool, value: Option<Value>) -> Result<Value, Value> {
+pub(crate) fn create_generator_value(scope: &mut LocalScope, done: bool, value: Option<Value>) -> Result<Value, Value> {
     let obj = NamedObject::new(scope);
     obj.set_property(scope, "done".into(), PropertyValue::static_default(done.into()))?;
     obj.set_property(
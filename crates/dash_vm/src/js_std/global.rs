@@ -1,5 +1,6 @@
+use crate::throw;
 use crate::value::{
-    function::native::CallContext, ops::abstractions::conversions::ValueConversion, Value, ValueContext,
+    function::native::CallContext, ops::abstractions::conversions::ValueConversion, Typeof, Value, ValueContext,
 };
 
 #[rustfmt::skip]
@@ -61,3 +62,13 @@ pub fn parse_int(cx: CallContext) -> Result<Value, Value> {
 
     Ok(num)
 }
+
+pub fn queue_microtask(cx: CallContext) -> Result<Value, Value> {
+    let callback = match cx.args.first() {
+        Some(Value::Object(callback)) if matches!(callback.type_of(), Typeof::Function) => callback.clone(),
+        _ => throw!(cx.scope, TypeError, "queueMicrotask callback must be a function"),
+    };
+
+    cx.scope.add_async_task(callback);
+    Ok(Value::undefined())
+}
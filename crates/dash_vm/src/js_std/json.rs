@@ -0,0 +1,648 @@
+use std::fmt::Write;
+
+use crate::gc::handle::Handle;
+use crate::local::LocalScope;
+use crate::throw;
+use crate::value::array::Array;
+use crate::value::boxed::BigInt as BoxedBigInt;
+use crate::value::boxed::Boolean as BoxedBoolean;
+use crate::value::boxed::Number as BoxedNumber;
+use crate::value::boxed::String as BoxedString;
+use crate::value::function::native::CallContext;
+use crate::value::object::NamedObject;
+use crate::value::object::Object;
+use crate::value::object::ObjectMap;
+use crate::value::object::PropertyDataDescriptor;
+use crate::value::object::PropertyKey;
+use crate::value::object::PropertyValue;
+use crate::value::ops::abstractions::conversions::ValueConversion;
+use crate::value::Typeof;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+/// Maximum nesting depth of arrays and objects that `JSON.parse` accepts
+const MAX_DEPTH: usize = 512;
+
+pub fn parse(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let reviver = cx.args.get(1).cloned().filter(is_callable);
+
+    let value = Parser::new(cx.scope, &text).parse_document()?;
+
+    match reviver {
+        Some(reviver) => {
+            let root = NamedObject::new(cx.scope);
+            root.set_property(cx.scope, "".into(), PropertyValue::static_default(value))?;
+            let root = cx.scope.register(root);
+            internalize(cx.scope, &reviver, &Value::Object(root), "")
+        }
+        None => Ok(value),
+    }
+}
+
+pub fn stringify(cx: CallContext) -> Result<Value, Value> {
+    let value = cx.args.first().unwrap_or_undefined();
+    let replacer = cx.args.get(1).unwrap_or_undefined();
+    let space = cx.args.get(2).unwrap_or_undefined();
+
+    let mut state = StringifyState {
+        replacer_function: None,
+        property_list: None,
+        stack: Vec::new(),
+        indent: String::new(),
+        gap: gap_from_space(cx.scope, space)?,
+    };
+
+    if is_callable(&replacer) {
+        state.replacer_function = Some(replacer);
+    } else if replacer.downcast_ref::<Array>().is_some() {
+        state.property_list = Some(property_list_from_array(cx.scope, &replacer)?);
+    }
+
+    let wrapper = NamedObject::new(cx.scope);
+    wrapper.set_property(cx.scope, "".into(), PropertyValue::static_default(value))?;
+    let wrapper = Value::Object(cx.scope.register(wrapper));
+
+    match serialize_property(cx.scope, &mut state, "", &wrapper)? {
+        Some(string) => Ok(Value::String(string.into())),
+        None => Ok(Value::undefined()),
+    }
+}
+
+fn is_callable(value: &Value) -> bool {
+    matches!(value.type_of(), Typeof::Function)
+}
+
+struct Parser<'a, 's, 'v> {
+    sc: &'s mut LocalScope<'v>,
+    input: &'a [u8],
+    index: usize,
+    depth: usize,
+}
+
+impl<'a, 's, 'v> Parser<'a, 's, 'v> {
+    fn new(sc: &'s mut LocalScope<'v>, input: &'a str) -> Self {
+        Self {
+            sc,
+            input: input.as_bytes(),
+            index: 0,
+            depth: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.index).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.index += 1;
+        }
+    }
+
+    fn unexpected<T>(&mut self) -> Result<T, Value> {
+        match self.peek() {
+            Some(c) => throw!(
+                &mut *self.sc,
+                SyntaxError,
+                "Unexpected token {} in JSON at position {}",
+                c as char,
+                self.index
+            ),
+            None => throw!(
+                &mut *self.sc,
+                SyntaxError,
+                "Unexpected end of JSON input at position {}",
+                self.index
+            ),
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), Value> {
+        if self.peek() == Some(c) {
+            self.index += 1;
+            Ok(())
+        } else {
+            self.unexpected()
+        }
+    }
+
+    fn parse_document(mut self) -> Result<Value, Value> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+
+        if self.index < self.input.len() {
+            return self.unexpected();
+        }
+
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<Value, Value> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => self.parse_string().map(|s| Value::String(s.into())),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b't') => self.parse_keyword("true", Value::Boolean(true)),
+            Some(b'f') => self.parse_keyword("false", Value::Boolean(false)),
+            Some(b'n') => self.parse_keyword("null", Value::null()),
+            _ => self.unexpected(),
+        }
+    }
+
+    fn parse_keyword(&mut self, keyword: &str, value: Value) -> Result<Value, Value> {
+        for &c in keyword.as_bytes() {
+            self.expect(c)?;
+        }
+        Ok(value)
+    }
+
+    fn enter(&mut self) -> Result<(), Value> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            throw!(&mut *self.sc, RangeError, "Maximum JSON nesting depth exceeded");
+        }
+        Ok(())
+    }
+
+    fn parse_object(&mut self) -> Result<Value, Value> {
+        self.enter()?;
+        self.expect(b'{')?;
+
+        let mut values = ObjectMap::default();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.index += 1;
+        } else {
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some(b'"') {
+                    return self.unexpected();
+                }
+                let key = self.parse_string()?;
+
+                self.skip_whitespace();
+                self.expect(b':')?;
+
+                // Members are always own data properties, so a `__proto__` member does not change the prototype
+                let value = self.parse_value()?;
+                values.insert(key.into(), PropertyValue::static_default(value));
+
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.index += 1,
+                    Some(b'}') => {
+                        self.index += 1;
+                        break;
+                    }
+                    _ => return self.unexpected(),
+                }
+            }
+        }
+
+        self.depth -= 1;
+        let object = NamedObject::with_values(self.sc, values);
+        Ok(Value::Object(self.sc.register(object)))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, Value> {
+        self.enter()?;
+        self.expect(b'[')?;
+
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.index += 1;
+        } else {
+            loop {
+                let value = self.parse_value()?;
+                items.push(PropertyValue::static_default(value));
+
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.index += 1,
+                    Some(b']') => {
+                        self.index += 1;
+                        break;
+                    }
+                    _ => return self.unexpected(),
+                }
+            }
+        }
+
+        self.depth -= 1;
+        let array = Array::from_vec(self.sc, items);
+        Ok(Value::Object(self.sc.register(array)))
+    }
+
+    fn parse_number(&mut self) -> Result<Value, Value> {
+        let start = self.index;
+
+        if self.peek() == Some(b'-') {
+            self.index += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.index += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return self.unexpected(),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.index += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return self.unexpected();
+            }
+            self.skip_digits();
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            self.index += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.index += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return self.unexpected();
+            }
+            self.skip_digits();
+        }
+
+        // The grammar above only admits ASCII, so this is always valid UTF-8
+        let source = std::str::from_utf8(&self.input[start..self.index]).unwrap();
+        let number = source.parse::<f64>().unwrap_or(f64::NAN);
+        Ok(Value::number(number))
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.index += 1;
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, Value> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = match self.peek().and_then(|c| (c as char).to_digit(16)) {
+                Some(digit) => digit,
+                None => return self.unexpected(),
+            };
+            code = code * 16 + digit;
+            self.index += 1;
+        }
+        Ok(code)
+    }
+
+    fn parse_string(&mut self) -> Result<String, Value> {
+        self.expect(b'"')?;
+
+        let mut bytes = Vec::new();
+
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.index += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.index += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.index += 1;
+                            let char = self.parse_unicode_escape()?;
+                            let mut buf = [0; 4];
+                            bytes.extend_from_slice(char.encode_utf8(&mut buf).as_bytes());
+                            continue;
+                        }
+                        _ => return self.unexpected(),
+                    };
+                    self.index += 1;
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                }
+                Some(0x00..=0x1f) | None => return self.unexpected(),
+                Some(c) => {
+                    self.index += 1;
+                    bytes.push(c);
+                }
+            }
+        }
+
+        // The input came from a `&str` and escapes are pushed as complete UTF-8 sequences
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    /// Parses the hex digits of a `\u` escape, combining surrogate pairs.
+    /// Unpaired surrogates can't be represented in a Rust string and are replaced with U+FFFD.
+    fn parse_unicode_escape(&mut self) -> Result<char, Value> {
+        let high = self.parse_hex4()?;
+
+        if (0xD800..0xDC00).contains(&high) && self.input[self.index..].starts_with(b"\\u") {
+            let before = self.index;
+            self.index += 2;
+            let low = self.parse_hex4()?;
+
+            if (0xDC00..0xE000).contains(&low) {
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return Ok(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+
+            self.index = before;
+        }
+
+        Ok(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+}
+
+/// Implements the InternalizeJSONProperty abstract operation
+fn internalize(sc: &mut LocalScope, reviver: &Value, holder: &Value, name: &str) -> Result<Value, Value> {
+    let value = holder.get_property(sc, name.into())?;
+
+    if let Value::Object(object) = &value {
+        if value.downcast_ref::<Array>().is_some() {
+            let len = value.length_of_array_like(sc)?;
+            for index in 0..len {
+                let key = index.to_string();
+                internalize_element(sc, reviver, object, key)?;
+            }
+        } else {
+            for key in enumerable_string_keys(sc, object)? {
+                internalize_element(sc, reviver, object, key)?;
+            }
+        }
+    }
+
+    let name = Value::String(name.into());
+    reviver.apply(sc, holder.clone(), vec![name, value])
+}
+
+fn internalize_element(
+    sc: &mut LocalScope,
+    reviver: &Value,
+    object: &Handle<dyn Object>,
+    key: String,
+) -> Result<(), Value> {
+    let holder = Value::Object(object.clone());
+    let new_element = internalize(sc, reviver, &holder, &key)?;
+
+    if matches!(new_element, Value::Undefined(_)) {
+        object.delete_property(sc, key.into())?;
+    } else {
+        sc.add_value(new_element.clone());
+        object.set_property(sc, key.into(), PropertyValue::static_default(new_element))?;
+    }
+
+    Ok(())
+}
+
+/// Returns the enumerable, string-keyed own properties of an object
+fn enumerable_string_keys(sc: &mut LocalScope, object: &Handle<dyn Object>) -> Result<Vec<String>, Value> {
+    let mut keys = Vec::new();
+
+    for key in object.own_keys(sc)? {
+        if let Value::String(key) = key {
+            let descriptor = object.get_own_property_descriptor(sc, PropertyKey::from(&*key))?;
+            let enumerable = descriptor.is_some_and(|d| d.into_parts().1.contains(PropertyDataDescriptor::ENUMERABLE));
+
+            if enumerable {
+                keys.push(String::from(&*key));
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+struct StringifyState {
+    replacer_function: Option<Value>,
+    property_list: Option<Vec<String>>,
+    stack: Vec<Handle<dyn Object>>,
+    indent: String,
+    gap: String,
+}
+
+fn gap_from_space(sc: &mut LocalScope, space: Value) -> Result<String, Value> {
+    let space = match &space {
+        Value::Object(o) if o.as_any().is::<BoxedNumber>() => Value::number(space.to_number(sc)?),
+        Value::Object(o) if o.as_any().is::<BoxedString>() => Value::String(space.to_string(sc)?),
+        _ => space,
+    };
+
+    match space {
+        Value::Number(_) => {
+            let count = space.to_integer_or_infinity(sc)?.clamp(0.0, 10.0) as usize;
+            Ok(" ".repeat(count))
+        }
+        Value::String(s) => Ok(s.chars().take(10).collect()),
+        _ => Ok(String::new()),
+    }
+}
+
+fn property_list_from_array(sc: &mut LocalScope, replacer: &Value) -> Result<Vec<String>, Value> {
+    let len = replacer.length_of_array_like(sc)?;
+    let mut list: Vec<String> = Vec::new();
+
+    for index in 0..len {
+        let key = index.to_string();
+        let element = replacer.get_property(sc, key.as_str().into())?;
+
+        let item = match &element {
+            Value::String(s) => Some(String::from(&**s)),
+            Value::Number(_) => Some(String::from(&*element.to_string(sc)?)),
+            Value::Object(o) if o.as_any().is::<BoxedString>() || o.as_any().is::<BoxedNumber>() => {
+                Some(String::from(&*element.to_string(sc)?))
+            }
+            _ => None,
+        };
+
+        if let Some(item) = item {
+            if !list.contains(&item) {
+                list.push(item);
+            }
+        }
+    }
+
+    Ok(list)
+}
+
+/// Implements the SerializeJSONProperty abstract operation.
+/// Returns `None` if the value has no JSON representation (e.g. functions and undefined)
+fn serialize_property(
+    sc: &mut LocalScope,
+    state: &mut StringifyState,
+    key: &str,
+    holder: &Value,
+) -> Result<Option<String>, Value> {
+    let mut value = holder.get_property(sc, key.into())?;
+
+    if let Value::Object(_) | Value::BigInt(_) = value {
+        let to_json = value.get_property(sc, "toJSON".into())?;
+        if is_callable(&to_json) {
+            value = to_json.apply(sc, value, vec![Value::String(key.into())])?;
+            sc.add_value(value.clone());
+        }
+    }
+
+    if let Some(replacer) = &state.replacer_function {
+        value = replacer.apply(sc, holder.clone(), vec![Value::String(key.into()), value])?;
+        sc.add_value(value.clone());
+    }
+
+    if let Value::Object(object) = &value {
+        let any = object.as_any();
+        if any.is::<BoxedNumber>() {
+            value = Value::number(value.to_number(sc)?);
+        } else if any.is::<BoxedString>() {
+            value = Value::String(value.to_string(sc)?);
+        } else if let Some(boolean) = any.downcast_ref::<BoxedBoolean>() {
+            value = Value::Boolean(*boolean.value());
+        } else if let Some(bigint) = any.downcast_ref::<BoxedBigInt>() {
+            value = Value::BigInt(bigint.value().clone());
+        }
+    }
+
+    match &value {
+        Value::Null(_) => Ok(Some("null".into())),
+        Value::Boolean(b) => Ok(Some(if *b { "true" } else { "false" }.into())),
+        Value::String(s) => Ok(Some(quote(s))),
+        Value::Number(n) if n.0.is_finite() => Ok(Some(String::from(&*value.to_string(sc)?))),
+        Value::Number(_) => Ok(Some("null".into())),
+        Value::BigInt(_) => throw!(sc, TypeError, "Do not know how to serialize a BigInt"),
+        Value::Object(object) if !is_callable(&value) => {
+            if value.downcast_ref::<Array>().is_some() {
+                serialize_array(sc, state, object).map(Some)
+            } else {
+                serialize_object(sc, state, object).map(Some)
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Implements the QuoteJSONString abstract operation
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+fn enter_cycle_check(
+    sc: &mut LocalScope,
+    state: &mut StringifyState,
+    object: &Handle<dyn Object>,
+) -> Result<(), Value> {
+    if state.stack.contains(object) {
+        throw!(sc, TypeError, "Converting circular structure to JSON");
+    }
+
+    state.stack.push(object.clone());
+    Ok(())
+}
+
+/// Joins the serialized members of an object or array, taking indentation into account
+fn join_members(state: &StringifyState, stepback: &str, open: char, close: char, members: Vec<String>) -> String {
+    if members.is_empty() {
+        return format!("{open}{close}");
+    }
+
+    if state.gap.is_empty() {
+        format!("{open}{}{close}", members.join(","))
+    } else {
+        let separator = format!(",\n{}", state.indent);
+        format!(
+            "{open}\n{}{}\n{stepback}{close}",
+            state.indent,
+            members.join(&separator)
+        )
+    }
+}
+
+/// Implements the SerializeJSONObject abstract operation
+fn serialize_object(
+    sc: &mut LocalScope,
+    state: &mut StringifyState,
+    object: &Handle<dyn Object>,
+) -> Result<String, Value> {
+    enter_cycle_check(sc, state, object)?;
+
+    let stepback = state.indent.clone();
+    state.indent.push_str(&state.gap);
+
+    let keys = match &state.property_list {
+        Some(list) => list.clone(),
+        None => enumerable_string_keys(sc, object)?,
+    };
+
+    let holder = Value::Object(object.clone());
+    let mut members = Vec::new();
+
+    for key in keys {
+        if let Some(value) = serialize_property(sc, state, &key, &holder)? {
+            let separator = if state.gap.is_empty() { ":" } else { ": " };
+            members.push(format!("{}{separator}{value}", quote(&key)));
+        }
+    }
+
+    let result = join_members(state, &stepback, '{', '}', members);
+
+    state.stack.pop();
+    state.indent = stepback;
+    Ok(result)
+}
+
+/// Implements the SerializeJSONArray abstract operation
+fn serialize_array(
+    sc: &mut LocalScope,
+    state: &mut StringifyState,
+    object: &Handle<dyn Object>,
+) -> Result<String, Value> {
+    enter_cycle_check(sc, state, object)?;
+
+    let stepback = state.indent.clone();
+    state.indent.push_str(&state.gap);
+
+    let holder = Value::Object(object.clone());
+    let len = holder.length_of_array_like(sc)?;
+    let mut members = Vec::with_capacity(len);
+
+    for index in 0..len {
+        let value = serialize_property(sc, state, &index.to_string(), &holder)?;
+        members.push(value.unwrap_or_else(|| "null".into()));
+    }
+
+    let result = join_members(state, &stepback, '[', ']', members);
+
+    state.stack.pop();
+    state.indent = stepback;
+    Ok(result)
+}
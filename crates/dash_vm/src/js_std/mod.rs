@@ -4,22 +4,31 @@ use crate::value::Value;
 pub mod array;
 pub mod array_iterator;
 pub mod arraybuffer;
+pub mod async_generator;
+pub mod bigint;
 pub mod boolean;
 pub mod date;
 pub mod error;
+pub mod finalization_registry;
 pub mod function;
 pub mod generator;
 pub mod global;
+pub mod json;
 pub mod map;
 pub mod math;
 pub mod number;
 pub mod object;
 pub mod promise;
+pub mod proxy;
+pub mod reflect;
 pub mod regex;
 pub mod set;
 pub mod string;
 pub mod symbol;
 pub mod typedarray;
+pub mod weakmap;
+pub mod weakref;
+pub mod weakset;
 
 pub fn identity_this(cx: CallContext) -> Result<Value, Value> {
     Ok(cx.this)
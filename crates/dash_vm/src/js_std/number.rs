@@ -1,3 +1,5 @@
+use num_traits::ToPrimitive;
+
 use crate::throw;
 use crate::util::format_f64;
 use crate::value::function::native::CallContext;
@@ -8,7 +10,11 @@ use crate::value::Value;
 use crate::value::ValueContext;
 
 pub fn constructor(cx: CallContext) -> Result<Value, Value> {
-    let value = cx.args.get(0).unwrap_or_undefined().to_number(cx.scope)?;
+    let value = match cx.args.first().unwrap_or_undefined().to_numeric(cx.scope)? {
+        // Unlike implicit conversions, Number() accepts BigInts
+        Value::BigInt(b) => b.to_f64().unwrap_or(f64::NAN),
+        value => value.to_number(cx.scope)?,
+    };
     Ok(Value::number(value))
 }
 
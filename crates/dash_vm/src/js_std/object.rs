@@ -5,6 +5,7 @@ use crate::value::array::Array;
 use crate::value::boxed::Boolean as BoxedBoolean;
 use crate::value::boxed::Number as BoxedNumber;
 use crate::value::boxed::String as BoxedString;
+use crate::value::date::Date;
 use crate::value::error::Error;
 use crate::value::function::native::CallContext;
 use crate::value::function::Function;
@@ -33,7 +34,7 @@ pub fn create(cx: CallContext) -> Result<Value, Value> {
 
 pub fn keys(cx: CallContext) -> Result<Value, Value> {
     let obj = cx.args.first().unwrap_or_undefined().to_object(cx.scope)?;
-    let keys = obj.own_keys()?;
+    let keys = obj.own_keys(cx.scope)?;
     let array = Array::from_vec(cx.scope, keys.into_iter().map(PropertyValue::static_default).collect());
     Ok(cx.scope.gc_mut().register(array).into())
 }
@@ -46,6 +47,8 @@ pub fn to_string(cx: CallContext) -> Result<Value, Value> {
             "[object Function]"
         } else if o.is::<Error>() {
             "[object Error]"
+        } else if o.is::<Date>() {
+            "[object Date]"
         } else if o.is::<BoxedBoolean>() {
             "[object Boolean]"
         } else if o.is::<BoxedNumber>() {
@@ -101,7 +104,7 @@ pub fn get_own_property_descriptors(cx: CallContext) -> Result<Value, Value> {
     };
 
     let mut descriptors = Vec::new();
-    let keys = o.own_keys()?;
+    let keys = o.own_keys(cx.scope)?;
 
     for key in keys {
         let key = PropertyKey::from_value(cx.scope, key)?;
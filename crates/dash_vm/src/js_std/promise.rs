@@ -1,20 +1,32 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use dash_proc_macro::Trace;
 
 use crate::delegate;
 use crate::gc::handle::Handle;
+use crate::gc::trace::Trace;
+use crate::local::LocalScope;
 use crate::throw;
+use crate::value::array::Array;
+use crate::value::error::AggregateError;
 use crate::value::function::bound::BoundFunction;
 use crate::value::function::native::CallContext;
 use crate::value::object::NamedObject;
 use crate::value::object::Object;
 use crate::value::object::PropertyKey;
+use crate::value::object::PropertyValue;
+use crate::value::promise::create_resolving_functions;
+use crate::value::promise::resolve_promise;
+use crate::value::promise::wrap_promise;
 use crate::value::promise::Promise;
-use crate::value::promise::PromiseRejecter;
-use crate::value::promise::PromiseResolver;
 use crate::value::promise::PromiseState;
+use crate::value::promise::RejectionTracking;
 use crate::value::Typeof;
 use crate::value::Value;
 use crate::value::ValueContext;
+use crate::PromiseAction;
 use crate::Vm;
 
 pub fn constructor(cx: CallContext) -> Result<Value, Value> {
@@ -28,44 +40,66 @@ pub fn constructor(cx: CallContext) -> Result<Value, Value> {
         cx.scope.register(p)
     };
 
-    let (resolve, reject) = {
-        let r1 = PromiseResolver::new(cx.scope, promise.clone());
-        let r2 = PromiseRejecter::new(cx.scope, promise.clone());
-        (cx.scope.register(r1), cx.scope.register(r2))
-    };
+    let (resolve, reject) = create_resolving_functions(cx.scope, promise.clone());
 
-    initiator.apply(
+    // Exceptions thrown by the executor reject the promise
+    if let Err(err) = initiator.apply(
         cx.scope,
         Value::undefined(),
-        vec![Value::Object(resolve), Value::Object(reject)],
-    )?;
+        vec![Value::Object(resolve), Value::Object(reject.clone())],
+    ) {
+        reject.apply(cx.scope, Value::undefined(), vec![err])?;
+    }
 
-    Ok(Value::Object(cx.scope.register(promise)))
+    Ok(Value::Object(promise))
 }
 
 pub fn resolve(cx: CallContext) -> Result<Value, Value> {
     let value = cx.args.first().unwrap_or_undefined();
-    // TODO: do not wrap thenable values in another promise
-    let promise = Promise::resolved(cx.scope, value);
-    Ok(Value::Object(cx.scope.register(promise)))
+    Ok(wrap_promise(cx.scope, value))
 }
 
 pub fn reject(cx: CallContext) -> Result<Value, Value> {
     let value = cx.args.first().unwrap_or_undefined();
-    let promise = Promise::resolved(cx.scope, value);
+    let promise = Promise::rejected(cx.scope, value);
     Ok(Value::Object(cx.scope.register(promise)))
 }
 
+pub fn with_resolvers(cx: CallContext) -> Result<Value, Value> {
+    let promise = {
+        let p = Promise::new(cx.scope);
+        cx.scope.register(p)
+    };
+    let (resolve, reject) = create_resolving_functions(cx.scope, promise.clone());
+
+    let obj = NamedObject::new(cx.scope);
+    for (key, value) in [("promise", promise), ("resolve", resolve), ("reject", reject)] {
+        obj.set_property(
+            cx.scope,
+            key.into(),
+            PropertyValue::static_default(Value::Object(value)),
+        )?;
+    }
+
+    Ok(Value::Object(cx.scope.register(obj)))
+}
+
+/// Returns the handler passed to `then`, which is ignored if it is not a function
+fn then_handler(value: Option<&Value>) -> Option<Handle<dyn Object>> {
+    match value {
+        Some(Value::Object(obj)) if matches!(obj.type_of(), Typeof::Function) => Some(obj.clone()),
+        _ => None,
+    }
+}
+
 pub fn then(cx: CallContext) -> Result<Value, Value> {
     let promise = match cx.this.downcast_ref::<Promise>() {
         Some(promise) => promise,
         None => throw!(cx.scope, TypeError, "Receiver must be a promise"),
     };
 
-    let handler = match cx.args.first() {
-        Some(Value::Object(obj)) if matches!(obj.type_of(), Typeof::Function) => obj.clone(),
-        _ => throw!(cx.scope, TypeError, "Promise handler must be a function"),
-    };
+    let on_fulfilled = then_handler(cx.args.first());
+    let on_rejected = then_handler(cx.args.get(1));
 
     let mut state = promise.state().borrow_mut();
 
@@ -73,36 +107,426 @@ pub fn then(cx: CallContext) -> Result<Value, Value> {
         let p = Promise::new(cx.scope);
         cx.scope.register(p)
     };
-    let resolver = {
-        let p = PromiseResolver::new(cx.scope, then_promise.clone());
-        cx.scope.register(p)
+    let (resolve_task, reject_task) = {
+        let t1 = ThenTask::new(cx.scope, then_promise.clone(), on_fulfilled, PromiseAction::Resolve);
+        let t2 = ThenTask::new(cx.scope, then_promise.clone(), on_rejected, PromiseAction::Reject);
+        (cx.scope.register(t1), cx.scope.register(t2))
     };
-    let then_task = {
-        let t = ThenTask::new(cx.scope, then_promise.clone(), handler, resolver);
-        cx.scope.register(t)
+
+    let settled = match &mut *state {
+        PromiseState::Pending { resolve, reject } => {
+            resolve.push(resolve_task);
+            reject.push(reject_task);
+            None
+        }
+        PromiseState::Resolved(value) => Some((resolve_task, value.clone())),
+        PromiseState::Rejected(value) => Some((reject_task, value.clone())),
     };
 
-    match &mut *state {
-        PromiseState::Pending { resolve, .. } => resolve.push(then_task),
-        PromiseState::Resolved(value) => {
-            let bf = BoundFunction::new(cx.scope, then_task, None, Some(vec![value.clone()]));
-            let bf = cx.scope.register(bf);
-            cx.scope.add_async_task(bf);
+    if let Some((task, value)) = settled {
+        let bf = BoundFunction::new(cx.scope, task, None, Some(vec![value]));
+        let bf = cx.scope.register(bf);
+        cx.scope.add_async_task(bf);
+    }
+    drop(state);
+
+    // Promises that were reported as unhandled rejections are reported again once they get a handler
+    if promise.rejection_tracking().replace(RejectionTracking::Handled) == RejectionTracking::Reported {
+        if let Some(callback) = cx.scope.params().promise_rejection_handled_callback() {
+            let reason = match &*promise.state().borrow() {
+                PromiseState::Rejected(reason) => reason.clone(),
+                _ => unreachable!("reported promises are always rejected"),
+            };
+            callback(cx.scope, reason);
         }
-        PromiseState::Rejected(..) => {}
     }
 
     Ok(Value::Object(then_promise))
 }
 
-// TODO: Promise.prototype.catch
+pub fn catch(cx: CallContext) -> Result<Value, Value> {
+    let on_rejected = cx.args.first().unwrap_or_undefined();
+    invoke_then(cx.scope, cx.this, Value::undefined(), on_rejected)
+}
+
+pub fn finally(cx: CallContext) -> Result<Value, Value> {
+    let (then_finally, catch_finally) = match cx.args.first() {
+        Some(Value::Object(on_finally)) if matches!(on_finally.type_of(), Typeof::Function) => {
+            let t1 = FinallyTask::new(cx.scope, on_finally.clone(), PromiseAction::Resolve);
+            let t2 = FinallyTask::new(cx.scope, on_finally.clone(), PromiseAction::Reject);
+            (
+                Value::Object(cx.scope.register(t1)),
+                Value::Object(cx.scope.register(t2)),
+            )
+        }
+        other => {
+            let other = other.unwrap_or_undefined();
+            (other.clone(), other)
+        }
+    };
+
+    invoke_then(cx.scope, cx.this, then_finally, catch_finally)
+}
+
+pub fn all(cx: CallContext) -> Result<Value, Value> {
+    combinator(cx, CombinatorKind::All)
+}
+
+pub fn all_settled(cx: CallContext) -> Result<Value, Value> {
+    combinator(cx, CombinatorKind::AllSettled)
+}
+
+pub fn any(cx: CallContext) -> Result<Value, Value> {
+    combinator(cx, CombinatorKind::Any)
+}
+
+pub fn race(cx: CallContext) -> Result<Value, Value> {
+    combinator(cx, CombinatorKind::Race)
+}
+
+/// Calls the `then` method of a value, which is not necessarily a promise (Invoke(promise, "then"))
+fn invoke_then(
+    scope: &mut LocalScope,
+    promise: Value,
+    on_fulfilled: Value,
+    on_rejected: Value,
+) -> Result<Value, Value> {
+    let then = promise.get_property(scope, PropertyKey::String("then".into()))?;
+    then.apply(scope, promise, vec![on_fulfilled, on_rejected])
+}
+
+/// Calls `f` with every value of an iterable.
+/// The iterator is closed if `f` returns an error
+fn for_each_iterator_value(
+    scope: &mut LocalScope,
+    iterable: Value,
+    mut f: impl FnMut(&mut LocalScope, Value) -> Result<(), Value>,
+) -> Result<(), Value> {
+    let symbol_iterator = scope.statics.symbol_iterator.clone();
+    let iterator = iterable.get_property(scope, PropertyKey::Symbol(symbol_iterator))?;
+    if !matches!(iterator.type_of(), Typeof::Function) {
+        throw!(scope, TypeError, "Value is not iterable");
+    }
+    let iterator = iterator.apply(scope, iterable, Vec::new())?;
+    let next = iterator.get_property(scope, PropertyKey::String("next".into()))?;
+
+    loop {
+        let item = next.apply(scope, iterator.clone(), Vec::new())?;
+        let done = item.get_property(scope, PropertyKey::String("done".into()))?;
+        if done.is_truthy() {
+            return Ok(());
+        }
+        let value = item.get_property(scope, PropertyKey::String("value".into()))?;
+
+        if let Err(err) = f(scope, value) {
+            // Errors thrown while closing the iterator are ignored in favor of the original error
+            if let Ok(Some(return_)) = iterator
+                .get_property(scope, PropertyKey::String("return".into()))
+                .map(Value::into_option)
+            {
+                let _ = return_.apply(scope, iterator, Vec::new());
+            }
+            return Err(err);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CombinatorKind {
+    All,
+    AllSettled,
+    Any,
+    Race,
+}
+
+/// Implements `Promise.all`, `Promise.allSettled`, `Promise.any` and `Promise.race`,
+/// which subscribe to every promise of an iterable and settle a new promise depending on their results
+fn combinator(cx: CallContext, kind: CombinatorKind) -> Result<Value, Value> {
+    let promise = {
+        let p = Promise::new(cx.scope);
+        cx.scope.register(p)
+    };
+    let (resolve, reject) = create_resolving_functions(cx.scope, promise.clone());
+    let state = Rc::new(CombinatorState {
+        promise: promise.clone(),
+        kind,
+        values: RefCell::new(Vec::new()),
+        remaining: Cell::new(1),
+    });
+    let iterable = cx.args.first().unwrap_or_undefined();
+
+    let result = for_each_iterator_value(cx.scope, iterable, |scope, value| {
+        let next_promise = wrap_promise(scope, value);
+
+        let mut element = |action| {
+            let index = state.values.borrow().len();
+            let element = CombinatorElement::new(scope, state.clone(), index, action);
+            Value::Object(scope.register(element))
+        };
+        let (on_fulfilled, on_rejected) = match kind {
+            CombinatorKind::All => (element(PromiseAction::Resolve), Value::Object(reject.clone())),
+            CombinatorKind::AllSettled => (element(PromiseAction::Resolve), element(PromiseAction::Reject)),
+            CombinatorKind::Any => (Value::Object(resolve.clone()), element(PromiseAction::Reject)),
+            CombinatorKind::Race => (Value::Object(resolve.clone()), Value::Object(reject.clone())),
+        };
+
+        if kind != CombinatorKind::Race {
+            state.values.borrow_mut().push(Value::undefined());
+            state.remaining.set(state.remaining.get() + 1);
+        }
+
+        invoke_then(scope, next_promise, on_fulfilled, on_rejected)?;
+        Ok(())
+    });
+
+    match result {
+        // Race only settles once one of the promises settles
+        Ok(()) if kind == CombinatorKind::Race => {}
+        Ok(()) => state.element_settled(cx.scope)?,
+        Err(err) => {
+            reject.apply(cx.scope, Value::undefined(), vec![err])?;
+        }
+    }
+
+    Ok(Value::Object(promise))
+}
+
+#[derive(Debug)]
+struct CombinatorState {
+    /// The promise returned by the combinator
+    promise: Handle<dyn Object>,
+    kind: CombinatorKind,
+    /// The results of the promises, in iteration order.
+    /// These are the fulfillment values for `Promise.all`, the rejection reasons for `Promise.any`
+    /// and the settlement objects for `Promise.allSettled`.
+    values: RefCell<Vec<Value>>,
+    /// The number of promises that have not settled yet, plus one while the iterable is still being iterated over
+    remaining: Cell<usize>,
+}
+
+unsafe impl Trace for CombinatorState {
+    fn trace(&self) {
+        self.promise.trace();
+        self.values.trace();
+    }
+}
+
+impl CombinatorState {
+    /// Settles the promise of the combinator once all values have been collected
+    fn element_settled(&self, scope: &mut LocalScope) -> Result<(), Value> {
+        self.remaining.set(self.remaining.get() - 1);
+        if self.remaining.get() > 0 {
+            return Ok(());
+        }
+
+        let values = self
+            .values
+            .take()
+            .into_iter()
+            .map(PropertyValue::static_default)
+            .collect();
+        let values = Array::from_vec(scope, values);
+        let values = Value::Object(scope.register(values));
+        let promise = self.promise.as_any().downcast_ref::<Promise>().unwrap();
+
+        match self.kind {
+            CombinatorKind::Any => {
+                let err = AggregateError::new(scope, "All promises were rejected");
+                err.set_property(scope, "errors".into(), PropertyValue::static_default(values))?;
+                let err = Value::Object(scope.register(err));
+                scope.drive_promise(PromiseAction::Reject, promise, vec![err]);
+            }
+            _ => scope.drive_promise(PromiseAction::Resolve, promise, vec![values]),
+        }
+
+        Ok(())
+    }
+}
+
+/// A function that stores the result of a promise passed to a combinator
+/// (Promise.all Resolve Element Functions and the equivalents of `allSettled` and `any`)
+#[derive(Debug, Trace)]
+struct CombinatorElement {
+    state: Rc<CombinatorState>,
+    index: usize,
+    /// Whether this function is called with the fulfillment value (`Resolve`) or the rejection reason (`Reject`)
+    action: PromiseAction,
+    already_called: Cell<bool>,
+    obj: NamedObject,
+}
+
+impl CombinatorElement {
+    pub fn new(vm: &mut Vm, state: Rc<CombinatorState>, index: usize, action: PromiseAction) -> Self {
+        Self {
+            state,
+            index,
+            action,
+            already_called: Cell::new(false),
+            obj: NamedObject::new(vm),
+        }
+    }
+}
+
+impl Object for CombinatorElement {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        if self.already_called.replace(true) {
+            return Ok(Value::undefined());
+        }
+
+        let mut value = args.first().unwrap_or_undefined();
+        if self.state.kind == CombinatorKind::AllSettled {
+            let (status, key) = match self.action {
+                PromiseAction::Resolve => ("fulfilled", "value"),
+                PromiseAction::Reject => ("rejected", "reason"),
+            };
+            let obj = NamedObject::new(scope);
+            obj.set_property(
+                scope,
+                "status".into(),
+                PropertyValue::static_default(Value::String(status.into())),
+            )?;
+            obj.set_property(scope, key.into(), PropertyValue::static_default(value))?;
+            value = Value::Object(scope.register(obj));
+        }
+
+        self.state.values.borrow_mut()[self.index] = value;
+        self.state.element_settled(scope)?;
+
+        Ok(Value::undefined())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
+}
+
+/// The handler that `finally` registers for one of the two outcomes of a promise.
+/// It calls the callback and then passes on the settled value, after waiting for the result of the callback
+#[derive(Debug, Trace)]
+struct FinallyTask {
+    on_finally: Handle<dyn Object>,
+    action: PromiseAction,
+    obj: NamedObject,
+}
 
+impl FinallyTask {
+    pub fn new(vm: &mut Vm, on_finally: Handle<dyn Object>, action: PromiseAction) -> Self {
+        Self {
+            on_finally,
+            action,
+            obj: NamedObject::new(vm),
+        }
+    }
+}
+
+impl Object for FinallyTask {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        let value = args.first().unwrap_or_undefined();
+        let result = self.on_finally.apply(scope, Value::undefined(), Vec::new())?;
+        let result = wrap_promise(scope, result);
+
+        let settled_value = SettledValueThunk {
+            value,
+            action: self.action,
+            obj: NamedObject::new(scope),
+        };
+        let settled_value = Value::Object(scope.register(settled_value));
+
+        invoke_then(scope, result, settled_value, Value::undefined())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
+}
+
+/// Returns or throws a value when called
+#[derive(Debug, Trace)]
+struct SettledValueThunk {
+    value: Value,
+    action: PromiseAction,
+    obj: NamedObject,
+}
+
+impl Object for SettledValueThunk {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        _scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        _args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        match self.action {
+            PromiseAction::Resolve => Ok(self.value.clone()),
+            PromiseAction::Reject => Err(self.value.clone()),
+        }
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
+}
+
+/// A reaction of a promise that was registered with `then`.
+/// It calls the handler with the settled value, or passes the value on if there is none,
+/// and settles the promise returned by `then` with the result.
 #[derive(Debug, Trace)]
 struct ThenTask {
     // TODO: make a type like CastHandle<Promise> that implements Deref by downcasting
     then_promise: Handle<dyn Object>,
-    handler: Handle<dyn Object>,
-    resolver: Handle<dyn Object>,
+    handler: Option<Handle<dyn Object>>,
+    action: PromiseAction,
     obj: NamedObject,
 }
 
@@ -110,13 +534,13 @@ impl ThenTask {
     pub fn new(
         vm: &mut Vm,
         then_promise: Handle<dyn Object>,
-        handler: Handle<dyn Object>,
-        resolver: Handle<dyn Object>,
+        handler: Option<Handle<dyn Object>>,
+        action: PromiseAction,
     ) -> Self {
         Self {
             then_promise,
             handler,
-            resolver,
+            action,
             obj: NamedObject::new(vm),
         }
     }
@@ -138,27 +562,26 @@ impl Object for ThenTask {
 
     fn apply(
         &self,
-        scope: &mut crate::local::LocalScope,
+        scope: &mut LocalScope,
         _callee: Handle<dyn Object>,
         _this: Value,
         args: Vec<Value>,
     ) -> Result<Value, Value> {
-        let resolved = args.first().unwrap_or_undefined();
-        let ret = self.handler.apply(scope, Value::undefined(), vec![resolved])?;
+        let settled = args.first().unwrap_or_undefined();
 
-        let ret_then = ret.get_property(scope, PropertyKey::String("then".into()))?;
+        let ret = match (&self.handler, &self.action) {
+            (Some(handler), _) => handler.apply(scope, Value::undefined(), vec![settled]),
+            (None, PromiseAction::Resolve) => Ok(settled),
+            (None, PromiseAction::Reject) => Err(settled),
+        };
 
-        match ret_then {
-            Value::Undefined(..) => {
-                // Not a promise. Call resolver(value)
-                let bf = BoundFunction::new(scope, self.resolver.clone(), None, Some(vec![ret]));
-                let bf = scope.register(bf);
-                scope.add_async_task(bf);
-            }
-            _ => {
-                // Is a promise. Call value.then(resolver)
-                ret_then.apply(scope, ret, vec![Value::Object(self.resolver.clone())])?;
-            }
+        match ret {
+            Ok(ret) => resolve_promise(scope, &self.then_promise, ret),
+            Err(err) => scope.drive_promise(
+                PromiseAction::Reject,
+                self.then_promise.as_any().downcast_ref::<Promise>().unwrap(),
+                vec![err],
+            ),
         }
 
         Ok(Value::undefined())
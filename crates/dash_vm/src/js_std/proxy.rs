@@ -0,0 +1,53 @@
+use crate::gc::handle::Handle;
+use crate::local::LocalScope;
+use crate::throw;
+use crate::value::function::native::CallContext;
+use crate::value::object::NamedObject;
+use crate::value::object::Object;
+use crate::value::object::PropertyValue;
+use crate::value::proxy::Proxy;
+use crate::value::proxy::ProxyRevoker;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+pub fn constructor(cx: CallContext) -> Result<Value, Value> {
+    if !cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "Constructor Proxy requires 'new'");
+    }
+
+    let proxy = create_proxy(cx.scope, &cx.args)?;
+    Ok(Value::Object(proxy))
+}
+
+pub fn revocable(cx: CallContext) -> Result<Value, Value> {
+    let proxy = create_proxy(cx.scope, &cx.args)?;
+    let revoke = ProxyRevoker::new(cx.scope, proxy.clone());
+    let revoke = cx.scope.register(revoke);
+
+    let obj = NamedObject::new(cx.scope);
+    for (key, value) in [("proxy", proxy), ("revoke", revoke)] {
+        obj.set_property(
+            cx.scope,
+            key.into(),
+            PropertyValue::static_default(Value::Object(value)),
+        )?;
+    }
+
+    Ok(Value::Object(cx.scope.register(obj)))
+}
+
+fn create_proxy(sc: &mut LocalScope, args: &[Value]) -> Result<Handle<dyn Object>, Value> {
+    let target = match args.first().unwrap_or_undefined() {
+        Value::Object(o) => o,
+        Value::External(o) => o.inner.clone(),
+        _ => throw!(sc, TypeError, "Cannot create proxy with a non-object as target"),
+    };
+
+    let handler = match args.get(1).unwrap_or_undefined() {
+        Value::Object(o) => o,
+        Value::External(o) => o.inner.clone(),
+        _ => throw!(sc, TypeError, "Cannot create proxy with a non-object as handler"),
+    };
+
+    Ok(Proxy::register(sc, target, handler))
+}
@@ -0,0 +1,172 @@
+use crate::gc::handle::Handle;
+use crate::local::LocalScope;
+use crate::throw;
+use crate::value::array::Array;
+use crate::value::function::native::CallContext;
+use crate::value::object::Object;
+use crate::value::object::PropertyDataDescriptor;
+use crate::value::object::PropertyKey;
+use crate::value::object::PropertyValue;
+use crate::value::ops::abstractions::conversions::ValueConversion;
+use crate::value::proxy::Proxy;
+use crate::value::Typeof;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+/// Returns the target object of a Reflect function, throwing if it is not an object
+fn target_object(sc: &mut LocalScope, value: Value, name: &str) -> Result<Handle<dyn Object>, Value> {
+    match value {
+        Value::Object(o) => Ok(o),
+        Value::External(o) => Ok(o.inner.clone()),
+        _ => throw!(sc, TypeError, "Reflect.{} called on non-object", name),
+    }
+}
+
+/// Converts an array-like object to a list of arguments
+fn arguments_list(sc: &mut LocalScope, value: Value) -> Result<Vec<Value>, Value> {
+    if !matches!(value, Value::Object(_) | Value::External(_)) {
+        throw!(sc, TypeError, "CreateListFromArrayLike called on non-object");
+    }
+
+    let len = value.length_of_array_like(sc)?;
+    let mut args = Vec::with_capacity(len);
+    for i in 0..len {
+        let arg = value.get_property(sc, i.to_string().into())?;
+        args.push(arg);
+    }
+
+    Ok(args)
+}
+
+fn property_key(sc: &mut LocalScope, value: Option<&Value>) -> Result<PropertyKey<'static>, Value> {
+    PropertyKey::from_value(sc, value.unwrap_or_undefined())
+}
+
+pub fn apply(cx: CallContext) -> Result<Value, Value> {
+    let target = cx.args.first().unwrap_or_undefined();
+    if !matches!(target.type_of(), Typeof::Function) {
+        throw!(cx.scope, TypeError, "Reflect.apply target is not a function");
+    }
+
+    let this = cx.args.get(1).unwrap_or_undefined();
+    let args = arguments_list(cx.scope, cx.args.get(2).unwrap_or_undefined())?;
+    target.apply(cx.scope, this, args)
+}
+
+pub fn construct(cx: CallContext) -> Result<Value, Value> {
+    let target = cx.args.first().unwrap_or_undefined();
+    if !matches!(target.type_of(), Typeof::Function) {
+        throw!(cx.scope, TypeError, "Reflect.construct target is not a constructor");
+    }
+
+    let args = arguments_list(cx.scope, cx.args.get(1).unwrap_or_undefined())?;
+    target.construct(cx.scope, Value::undefined(), args)
+}
+
+pub fn define_property(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "defineProperty")?;
+    let key = property_key(cx.scope, cx.args.get(1))?;
+    let value = PropertyValue::from_descriptor_value(cx.scope, cx.args.get(2).unwrap_or_undefined())?;
+
+    if let Some(proxy) = target.as_any().downcast_ref::<Proxy>() {
+        return proxy.define_property(cx.scope, key, value).map(Value::Boolean);
+    }
+
+    // Properties that are neither writable nor configurable cannot be redefined,
+    // and new properties cannot be added to non-extensible objects
+    let success = match target.get_own_property_descriptor(cx.scope, key.clone())? {
+        Some(existing) => existing
+            .descriptor()
+            .intersects(PropertyDataDescriptor::WRITABLE | PropertyDataDescriptor::CONFIGURABLE),
+        None => target.is_extensible(cx.scope)?,
+    };
+
+    if success {
+        target.set_property(cx.scope, key, value)?;
+    }
+    Ok(Value::Boolean(success))
+}
+
+pub fn delete_property(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "deleteProperty")?;
+    let key = property_key(cx.scope, cx.args.get(1))?;
+
+    if let Some(proxy) = target.as_any().downcast_ref::<Proxy>() {
+        return proxy.delete(cx.scope, key).map(Value::Boolean);
+    }
+
+    target.delete_property(cx.scope, key)?;
+    Ok(Value::Boolean(true))
+}
+
+pub fn get(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "get")?;
+    let key = property_key(cx.scope, cx.args.get(1))?;
+    let receiver = cx.args.get(2).cloned().unwrap_or_else(|| Value::Object(target.clone()));
+    target.get_property_with_this(cx.scope, receiver, key)
+}
+
+pub fn get_own_property_descriptor(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(
+        cx.scope,
+        cx.args.first().unwrap_or_undefined(),
+        "getOwnPropertyDescriptor",
+    )?;
+    let key = property_key(cx.scope, cx.args.get(1))?;
+
+    Ok(target
+        .get_own_property_descriptor(cx.scope, key)?
+        .map(|d| d.to_descriptor_value(cx.scope))
+        .transpose()?
+        .unwrap_or_undefined())
+}
+
+pub fn get_prototype_of(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "getPrototypeOf")?;
+    target.get_prototype(cx.scope)
+}
+
+pub fn has(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "has")?;
+    let key = property_key(cx.scope, cx.args.get(1))?;
+    target.has_property(cx.scope, key).map(Value::Boolean)
+}
+
+pub fn is_extensible(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "isExtensible")?;
+    target.is_extensible(cx.scope).map(Value::Boolean)
+}
+
+pub fn own_keys(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "ownKeys")?;
+    let keys = target.own_keys(cx.scope)?;
+    let array = Array::from_vec(cx.scope, keys.into_iter().map(PropertyValue::static_default).collect());
+    Ok(Value::Object(cx.scope.register(array)))
+}
+
+pub fn prevent_extensions(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "preventExtensions")?;
+    target.prevent_extensions(cx.scope).map(Value::Boolean)
+}
+
+pub fn set(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "set")?;
+    let key = property_key(cx.scope, cx.args.get(1))?;
+    let value = cx.args.get(2).unwrap_or_undefined();
+
+    let receiver = cx.args.get(3).cloned().unwrap_or_else(|| Value::Object(target.clone()));
+    target
+        .set_property_with_this(cx.scope, receiver, key, value)
+        .map(Value::Boolean)
+}
+
+pub fn set_prototype_of(cx: CallContext) -> Result<Value, Value> {
+    let target = target_object(cx.scope, cx.args.first().unwrap_or_undefined(), "setPrototypeOf")?;
+    let prototype = cx.args.get(1).unwrap_or_undefined();
+    if !matches!(prototype, Value::Object(_) | Value::External(_) | Value::Null(_)) {
+        throw!(cx.scope, TypeError, "Object prototype may only be an Object or null");
+    }
+
+    target.set_prototype(cx.scope, prototype)?;
+    Ok(Value::Boolean(true))
+}
@@ -1,38 +1,483 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::gc::handle::Handle;
+use crate::local::LocalScope;
 use crate::throw;
+use crate::value::array::Array;
+use crate::value::array::ArrayIterator;
 use crate::value::function::native::CallContext;
+use crate::value::object::NamedObject;
+use crate::value::object::Object;
+use crate::value::object::PropertyValue;
 use crate::value::ops::abstractions::conversions::ValueConversion;
 use crate::value::regex::RegExp;
+use crate::value::Typeof;
 use crate::value::Value;
 use crate::value::ValueContext;
-use dash_regex::matcher::Matcher as RegexMatcher;
-use dash_regex::parser::Parser as RegexParser;
+use dash_regex::matcher::Match;
+use dash_regex::Flags;
+use dash_regex::Matcher as RegexMatcher;
+use dash_regex::ParsedRegex;
+use dash_regex::Parser as RegexParser;
+
+use super::string::get_substitution;
+
+/// Creates a new RegExp object from a pattern and flags (RegExpCreate)
+pub fn create(sc: &mut LocalScope, pattern: Value, flags: Flags) -> Result<Handle<dyn Object>, Value> {
+    let pattern = match pattern {
+        Value::Undefined(_) => Rc::from(""),
+        pattern => pattern.to_string(sc)?,
+    };
+
+    let regex = match RegexParser::new(&pattern, flags).parse_all() {
+        Ok(regex) => regex,
+        Err(err) => throw!(sc, SyntaxError, "Regex parser error: {}", err),
+    };
+
+    let regex = RegExp::new(regex, flags, pattern, sc);
+    Ok(sc.register(regex))
+}
 
 pub fn constructor(cx: CallContext) -> Result<Value, Value> {
-    let pattern = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let flags = match cx.args.get(1) {
+        Some(Value::Undefined(_)) | None => Flags::empty(),
+        Some(flags) => match flags.to_string(cx.scope)?.parse::<Flags>() {
+            Ok(flags) => flags,
+            Err(err) => throw!(cx.scope, SyntaxError, "Regex parser error: {}", err),
+        },
+    };
+
+    let regex = create(cx.scope, cx.args.first().unwrap_or_undefined(), flags)?;
+    Ok(Value::Object(regex))
+}
 
-    let nodes = match RegexParser::new(pattern.as_bytes()).parse_all() {
-        Ok(nodes) => nodes,
-        Err(err) => throw!(cx.scope, SyntaxError, "Regex parser error: {}", err),
+/// Returns the parsed regex and flags of a RegExp receiver
+fn this_regex<'a>(sc: &mut LocalScope, this: &'a Value) -> Result<(&'a ParsedRegex, Flags), Value> {
+    let regex = match this.downcast_ref::<RegExp>() {
+        Some(regex) => regex,
+        None => throw!(sc, TypeError, "Receiver must be a RegExp"),
     };
 
-    let regex = RegExp::new(nodes, pattern, cx.scope);
+    match regex.inner() {
+        Some((regex, flags, _)) => Ok((regex, flags)),
+        None => throw!(sc, TypeError, "Receiver must be an initialized RegExp object"),
+    }
+}
+
+fn get_last_index(sc: &mut LocalScope, this: &Value) -> Result<usize, Value> {
+    this.get_property(sc, "lastIndex".into())?.to_length_u(sc)
+}
+
+fn set_last_index(sc: &mut LocalScope, this: &Value, index: usize) -> Result<(), Value> {
+    this.set_property(
+        sc,
+        "lastIndex".into(),
+        PropertyValue::static_default(Value::number(index as f64)),
+    )
+}
+
+/// Runs the regex against the text (RegExpBuiltinExec).
+///
+/// Global and sticky regexes start matching at `lastIndex` and update it afterwards.
+fn exec_match(sc: &mut LocalScope, this: &Value, text: &str) -> Result<Option<Match>, Value> {
+    let (regex, flags) = this_regex(sc, this)?;
+
+    if !flags.intersects(Flags::GLOBAL | Flags::STICKY) {
+        return Ok(RegexMatcher::new(regex, flags, text).find_at(0));
+    }
+
+    let last_index = get_last_index(sc, this)?;
+    let result = if last_index <= text.len() {
+        RegexMatcher::new(regex, flags, text).find_at(last_index)
+    } else {
+        None
+    };
+
+    set_last_index(sc, this, result.as_ref().map_or(0, Match::end))?;
+    Ok(result)
+}
+
+/// Returns the index after an empty match at `index`, so that global matching does not loop forever
+fn advance_index(text: &str, index: usize) -> usize {
+    index + text[index..].chars().next().map_or(1, char::len_utf8)
+}
+
+fn substring(text: &str, range: Option<Range<usize>>) -> Value {
+    range.map(|r| Value::String(text[r].into())).unwrap_or_undefined()
+}
+
+/// Creates the groups object of a match result, or undefined if the regex has no named groups
+fn create_groups_object(sc: &mut LocalScope, regex: &ParsedRegex, m: &Match, text: &str) -> Result<Value, Value> {
+    if regex.group_names.is_empty() {
+        return Ok(Value::undefined());
+    }
+
+    let groups = NamedObject::new(sc);
+    for (name, index) in &regex.group_names {
+        let value = substring(text, m.group(*index));
+        groups.set_property(
+            sc,
+            String::from(name.as_ref()).into(),
+            PropertyValue::static_default(value),
+        )?;
+    }
 
-    Ok(Value::Object(cx.scope.register(regex)))
+    Ok(Value::Object(sc.register(groups)))
+}
+
+/// Creates the array returned by `exec`, containing the captures and the `index`, `input` and `groups` properties
+fn create_match_result(sc: &mut LocalScope, this: &Value, m: &Match, text: &Rc<str>) -> Result<Value, Value> {
+    let (regex, _) = this_regex(sc, this)?;
+
+    let captures = (0..m.groups.len())
+        .map(|i| PropertyValue::static_default(substring(text, m.group(i))))
+        .collect();
+
+    let groups = create_groups_object(sc, regex, m, text)?;
+
+    let array = Array::from_vec(sc, captures);
+    let array = sc.register(array);
+    array.set_property(
+        sc,
+        "index".into(),
+        PropertyValue::static_default(Value::number(m.start() as f64)),
+    )?;
+    array.set_property(
+        sc,
+        "input".into(),
+        PropertyValue::static_default(Value::String(text.clone())),
+    )?;
+    array.set_property(sc, "groups".into(), PropertyValue::static_default(groups))?;
+
+    Ok(Value::Object(array))
 }
 
 pub fn test(cx: CallContext) -> Result<Value, Value> {
     let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let result = exec_match(cx.scope, &cx.this, &text)?;
+    Ok(Value::Boolean(result.is_some()))
+}
+
+pub fn exec(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
 
-    let regex = match cx.this.downcast_ref::<RegExp>() {
-        Some(regex) => regex,
-        None => throw!(cx.scope, TypeError, "Receiver must be a RegExp"),
+    match exec_match(cx.scope, &cx.this, &text)? {
+        Some(m) => create_match_result(cx.scope, &cx.this, &m, &text),
+        None => Ok(Value::null()),
+    }
+}
+
+pub fn source(cx: CallContext) -> Result<Value, Value> {
+    let Some(regex) = cx.this.downcast_ref::<RegExp>() else {
+        throw!(
+            cx.scope,
+            TypeError,
+            "RegExp.prototype.source getter called on incompatible receiver"
+        );
+    };
+
+    // RegExp.prototype itself is the only RegExp object without a pattern
+    let source = match regex.inner() {
+        Some((_, _, source)) => escape_pattern(source),
+        None => String::from("(?:)"),
     };
 
-    let (nodes, _) = match regex.inner() {
-        Some(nodes) => nodes,
-        None => throw!(cx.scope, TypeError, "Receiver must be an initialized RegExp object"),
+    Ok(Value::String(source.into()))
+}
+
+pub fn flags(cx: CallContext) -> Result<Value, Value> {
+    let Some(regex) = cx.this.downcast_ref::<RegExp>() else {
+        throw!(
+            cx.scope,
+            TypeError,
+            "RegExp.prototype.flags getter called on incompatible receiver"
+        );
     };
 
-    let mut matcher = RegexMatcher::new(nodes, text.as_bytes());
-    Ok(Value::Boolean(matcher.matches()))
+    let flags = regex.inner().map(|(_, flags, _)| flags).unwrap_or_default();
+    Ok(Value::String(flags.to_string().into()))
+}
+
+pub fn to_string(cx: CallContext) -> Result<Value, Value> {
+    if !matches!(cx.this, Value::Object(_) | Value::External(_)) {
+        throw!(cx.scope, TypeError, "RegExp.prototype.toString called on a non-object");
+    }
+
+    let source = cx.this.get_property(cx.scope, "source".into())?.to_string(cx.scope)?;
+    let flags = cx.this.get_property(cx.scope, "flags".into())?.to_string(cx.scope)?;
+    Ok(Value::String(format!("/{source}/{flags}").into()))
+}
+
+/// Escapes a pattern so that it can be used as the body of a regex literal (EscapeRegExpPattern)
+fn escape_pattern(pattern: &str) -> String {
+    if pattern.is_empty() {
+        return String::from("(?:)");
+    }
+
+    let mut escaped = String::with_capacity(pattern.len());
+    let mut in_class = false;
+    let mut in_escape = false;
+
+    for c in pattern.chars() {
+        let sequence = match c {
+            '\n' => Some('n'),
+            '\r' => Some('r'),
+            '\u{2028}' | '\u{2029}' => None,
+            '/' if !in_escape && !in_class => Some('/'),
+            _ => {
+                match c {
+                    '[' if !in_escape => in_class = true,
+                    ']' if !in_escape => in_class = false,
+                    _ => {}
+                }
+                in_escape = !in_escape && c == '\\';
+                escaped.push(c);
+                continue;
+            }
+        };
+
+        if !in_escape {
+            escaped.push('\\');
+        }
+        match sequence {
+            Some(c) => escaped.push(c),
+            None => escaped.push_str(&format!("u{:04x}", c as u32)),
+        }
+        in_escape = false;
+    }
+
+    escaped
+}
+
+/// Collects all matches of a global regex, starting at index 0 and resetting `lastIndex` afterwards
+fn exec_all(sc: &mut LocalScope, this: &Value, text: &str) -> Result<Vec<Match>, Value> {
+    set_last_index(sc, this, 0)?;
+
+    let mut matches = Vec::new();
+    while let Some(m) = exec_match(sc, this, text)? {
+        if m.start() == m.end() {
+            let index = get_last_index(sc, this)?;
+            set_last_index(sc, this, advance_index(text, index))?;
+        }
+        matches.push(m);
+    }
+
+    Ok(matches)
+}
+
+pub fn symbol_match(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let (_, flags) = this_regex(cx.scope, &cx.this)?;
+
+    if !flags.contains(Flags::GLOBAL) {
+        return match exec_match(cx.scope, &cx.this, &text)? {
+            Some(m) => create_match_result(cx.scope, &cx.this, &m, &text),
+            None => Ok(Value::null()),
+        };
+    }
+
+    let matches = exec_all(cx.scope, &cx.this, &text)?;
+    if matches.is_empty() {
+        return Ok(Value::null());
+    }
+
+    let matches = matches
+        .into_iter()
+        .map(|m| PropertyValue::static_default(substring(&text, Some(m.range()))))
+        .collect();
+
+    let array = Array::from_vec(cx.scope, matches);
+    Ok(Value::Object(cx.scope.register(array)))
+}
+
+pub fn symbol_match_all(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let (regex, flags) = this_regex(cx.scope, &cx.this)?;
+
+    // The matches are collected eagerly, without touching the `lastIndex` of the receiver
+    let mut index = get_last_index(cx.scope, &cx.this)?;
+    let mut matcher = RegexMatcher::new(regex, flags, &text);
+    let mut matches = Vec::new();
+
+    while index <= text.len() {
+        let Some(m) = matcher.find_at(index) else {
+            break;
+        };
+
+        index = if m.start() == m.end() {
+            advance_index(&text, m.end())
+        } else {
+            m.end()
+        };
+        matches.push(m);
+
+        if !flags.contains(Flags::GLOBAL) {
+            break;
+        }
+    }
+
+    let mut results = Vec::with_capacity(matches.len());
+    for m in &matches {
+        let result = create_match_result(cx.scope, &cx.this, m, &text)?;
+        results.push(PropertyValue::static_default(result));
+    }
+
+    let array = Array::from_vec(cx.scope, results);
+    let array = cx.scope.register(array);
+    let iter = ArrayIterator::new(cx.scope, Value::Object(array))?;
+    Ok(Value::Object(cx.scope.register(iter)))
+}
+
+pub fn symbol_search(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+
+    let previous_last_index = cx.this.get_property(cx.scope, "lastIndex".into())?;
+    set_last_index(cx.scope, &cx.this, 0)?;
+
+    let result = exec_match(cx.scope, &cx.this, &text)?;
+
+    cx.this.set_property(
+        cx.scope,
+        "lastIndex".into(),
+        PropertyValue::static_default(previous_last_index),
+    )?;
+
+    Ok(Value::number(result.map_or(-1.0, |m| m.start() as f64)))
+}
+
+pub fn symbol_replace(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let replace_value = cx.args.get(1).unwrap_or_undefined();
+    let (_, flags) = this_regex(cx.scope, &cx.this)?;
+
+    // A string replacement value is expanded with `$` patterns, a function is called for every match
+    let replacer = if matches!(replace_value.type_of(), Typeof::Function) {
+        None
+    } else {
+        Some(replace_value.to_string(cx.scope)?)
+    };
+
+    let matches = if flags.contains(Flags::GLOBAL) {
+        exec_all(cx.scope, &cx.this, &text)?
+    } else {
+        exec_match(cx.scope, &cx.this, &text)?.into_iter().collect()
+    };
+
+    let mut result = String::with_capacity(text.len());
+    let mut next_position = 0;
+
+    for m in matches {
+        let matched = &text[m.range()];
+        let captures = (1..m.groups.len())
+            .map(|i| m.group(i).map(|r| &text[r]))
+            .collect::<Vec<_>>();
+
+        let replacement = match &replacer {
+            Some(replacer) => {
+                let (regex, _) = this_regex(cx.scope, &cx.this)?;
+                get_substitution(matched, &text, m.start(), &captures, &regex.group_names, replacer)
+            }
+            None => {
+                let mut args = Vec::with_capacity(captures.len() + 4);
+                args.push(Value::String(matched.into()));
+                args.extend(
+                    captures
+                        .iter()
+                        .map(|c| c.map(|c| Value::String(c.into())).unwrap_or_undefined()),
+                );
+                args.push(Value::number(m.start() as f64));
+                args.push(Value::String(text.clone()));
+
+                let (regex, _) = this_regex(cx.scope, &cx.this)?;
+                let groups = create_groups_object(cx.scope, regex, &m, &text)?;
+                if !matches!(groups, Value::Undefined(_)) {
+                    args.push(groups);
+                }
+
+                let replacement = replace_value.apply(cx.scope, Value::undefined(), args)?;
+                String::from(replacement.to_string(cx.scope)?.as_ref())
+            }
+        };
+
+        // Matches are found in increasing order, but guard against overlapping results anyway
+        if m.start() >= next_position {
+            result.push_str(&text[next_position..m.start()]);
+            result.push_str(&replacement);
+            next_position = m.end();
+        }
+    }
+
+    result.push_str(&text[next_position..]);
+    Ok(Value::String(result.into()))
+}
+
+pub fn symbol_split(cx: CallContext) -> Result<Value, Value> {
+    let text = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let limit = match cx.args.get(1) {
+        Some(Value::Undefined(_)) | None => u32::MAX,
+        Some(limit) => limit.to_number(cx.scope)? as u32,
+    };
+
+    let (regex, flags) = this_regex(cx.scope, &cx.this)?;
+
+    // Splitting always searches from the end of the previous match, regardless of the sticky flag
+    let mut matcher = RegexMatcher::new(regex, flags - Flags::STICKY, &text);
+    let mut parts = Vec::new();
+
+    if limit == 0 {
+        let array = Array::from_vec(cx.scope, parts);
+        return Ok(Value::Object(cx.scope.register(array)));
+    }
+
+    if text.is_empty() {
+        if matcher.find_at(0).is_none() {
+            parts.push(PropertyValue::static_default(Value::String(text)));
+        }
+
+        let array = Array::from_vec(cx.scope, parts);
+        return Ok(Value::Object(cx.scope.register(array)));
+    }
+
+    let mut last_end = 0;
+    let mut index = 0;
+
+    'outer: while index < text.len() {
+        let Some(m) = matcher.find_at(index) else {
+            break;
+        };
+
+        if m.start() >= text.len() {
+            break;
+        }
+
+        if m.end() == last_end {
+            index = advance_index(&text, m.start());
+            continue;
+        }
+
+        parts.push(PropertyValue::static_default(Value::String(
+            text[last_end..m.start()].into(),
+        )));
+        if parts.len() as u32 == limit {
+            break;
+        }
+
+        for i in 1..m.groups.len() {
+            parts.push(PropertyValue::static_default(substring(&text, m.group(i))));
+            if parts.len() as u32 == limit {
+                break 'outer;
+            }
+        }
+
+        last_end = m.end();
+        index = last_end;
+    }
+
+    if (parts.len() as u32) < limit {
+        parts.push(PropertyValue::static_default(Value::String(text[last_end..].into())));
+    }
+
+    let array = Array::from_vec(cx.scope, parts);
+    Ok(Value::Object(cx.scope.register(array)))
 }
@@ -1,3 +1,4 @@
+use crate::js_std;
 use crate::local::LocalScope;
 use crate::throw;
 use crate::value::array::Array;
@@ -6,14 +7,23 @@ use crate::value::boxed::String as BoxedString;
 use crate::value::function::native::CallContext;
 use crate::value::object::PropertyValue;
 use crate::value::ops::abstractions::conversions::ValueConversion;
+use crate::value::primitive::Symbol;
+use crate::value::regex::RegExp;
+use crate::value::Typeof;
 use crate::value::Value;
 use crate::value::ValueContext;
+use dash_regex::Flags;
 use std::borrow::Cow;
 use std::fmt::Write;
 use std::rc::Rc;
 
 pub fn constructor(cx: CallContext) -> Result<Value, Value> {
-    let value = cx.args.get(0).unwrap_or_undefined().to_string(cx.scope)?;
+    let value = match cx.args.first() {
+        // Symbols cannot be implicitly converted to strings, but `String(symbol)` is allowed
+        Some(Value::Symbol(symbol)) if !cx.is_constructor_call => format!("{symbol}").into(),
+        Some(value) => value.to_string(cx.scope)?,
+        None => "".into(),
+    };
     if cx.is_constructor_call {
         let boxed = BoxedString::new(cx.scope, value);
         Ok(Value::Object(cx.scope.register(boxed)))
@@ -234,44 +244,265 @@ pub fn repeat(cx: CallContext) -> Result<Value, Value> {
     Ok(Value::String(result.into()))
 }
 
-pub fn replace(cx: CallContext) -> Result<Value, Value> {
-    // TODO: once we have regexp, we can properly implement this
-    let string = cx.this.to_string(cx.scope)?;
+/// Looks up the well-known symbol method of a pattern argument, which RegExp objects use to
+/// take over string methods like `replace` and `split`
+fn get_pattern_method(sc: &mut LocalScope, pattern: &Value, symbol: Symbol) -> Result<Option<Value>, Value> {
+    if matches!(pattern, Value::Undefined(_) | Value::Null(_)) {
+        return Ok(None);
+    }
 
-    let search_string = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    match pattern.get_property(sc, symbol.into())? {
+        Value::Undefined(_) | Value::Null(_) => Ok(None),
+        method => Ok(Some(method)),
+    }
+}
 
-    let replace_value = cx.args.get(1).unwrap_or_undefined().to_string(cx.scope)?;
+/// Throws if the pattern is a RegExp without the global flag, which `matchAll` and `replaceAll` require
+fn require_global_regex(sc: &mut LocalScope, pattern: &Value, name: &str) -> Result<(), Value> {
+    let non_global = pattern
+        .downcast_ref::<RegExp>()
+        .and_then(RegExp::inner)
+        .is_some_and(|(_, flags, _)| !flags.contains(Flags::GLOBAL));
 
-    let string = string.replacen(search_string.as_ref(), replace_value.as_ref(), 1);
+    if non_global {
+        throw!(sc, TypeError, "{} must be called with a global RegExp", name);
+    }
 
-    Ok(Value::String(string.into()))
+    Ok(())
+}
+
+/// Expands the `$` patterns of a replacement string (GetSubstitution).
+///
+/// `captures` holds the capture groups starting at group 1, `group_names` maps named groups to their index.
+pub fn get_substitution(
+    matched: &str,
+    string: &str,
+    position: usize,
+    captures: &[Option<&str>],
+    group_names: &[(Box<str>, usize)],
+    replacement: &str,
+) -> String {
+    let bytes = replacement.as_bytes();
+    let mut result = String::with_capacity(replacement.len());
+    let mut index = 0;
+
+    let capture = |n: usize| captures.get(n - 1).copied().flatten().unwrap_or("");
+    let is_group = |n: usize| (1..=captures.len()).contains(&n);
+    let digit = |i: usize| bytes.get(i).filter(|b| b.is_ascii_digit()).map(|b| (b - b'0') as usize);
+
+    while let Some(offset) = replacement[index..].find('$') {
+        let dollar = index + offset;
+        result.push_str(&replacement[index..dollar]);
+        index = dollar + 1;
+
+        match bytes.get(index) {
+            Some(b'$') => {
+                result.push('$');
+                index += 1;
+            }
+            Some(b'&') => {
+                result.push_str(matched);
+                index += 1;
+            }
+            Some(b'`') => {
+                result.push_str(&string[..position]);
+                index += 1;
+            }
+            Some(b'\'') => {
+                result.push_str(&string[(position + matched.len()).min(string.len())..]);
+                index += 1;
+            }
+            Some(b'0'..=b'9') => {
+                // Two digit group references take precedence if such a group exists
+                let first = digit(index).unwrap_or(0);
+                match digit(index + 1).map(|second| first * 10 + second) {
+                    Some(n) if is_group(n) => {
+                        result.push_str(capture(n));
+                        index += 2;
+                    }
+                    _ if is_group(first) => {
+                        result.push_str(capture(first));
+                        index += 1;
+                    }
+                    _ => result.push('$'),
+                }
+            }
+            Some(b'<') if !group_names.is_empty() => match replacement[index..].find('>') {
+                Some(close) => {
+                    let name = &replacement[index + 1..index + close];
+                    if let Some((_, group)) = group_names.iter().find(|(n, _)| n.as_ref() == name) {
+                        result.push_str(capture(*group));
+                    }
+                    index += close + 1;
+                }
+                None => result.push('$'),
+            },
+            _ => result.push('$'),
+        }
+    }
+
+    result.push_str(&replacement[index..]);
+    result
 }
 
-pub fn replace_all(cx: CallContext) -> Result<Value, Value> {
+/// Replaces the first or all occurrences of a search string
+fn replace_string(cx: CallContext, all: bool) -> Result<Value, Value> {
     let string = cx.this.to_string(cx.scope)?;
-
     let search_string = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let replace_value = cx.args.get(1).unwrap_or_undefined();
+
+    let replacer = if matches!(replace_value.type_of(), Typeof::Function) {
+        None
+    } else {
+        Some(replace_value.to_string(cx.scope)?)
+    };
+
+    let mut positions = string
+        .match_indices(search_string.as_ref())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if search_string.is_empty() {
+        // An empty search string matches at every character boundary, including the end
+        positions = string.char_indices().map(|(i, _)| i).chain([string.len()]).collect();
+    }
+    if !all {
+        positions.truncate(1);
+    }
+
+    let mut result = String::with_capacity(string.len());
+    let mut end_of_last_match = 0;
+
+    for position in positions {
+        let replacement = match &replacer {
+            Some(replacer) => get_substitution(&search_string, &string, position, &[], &[], replacer),
+            None => {
+                let args = vec![
+                    Value::String(search_string.clone()),
+                    Value::number(position as f64),
+                    Value::String(string.clone()),
+                ];
+                let replacement = replace_value.apply(cx.scope, Value::undefined(), args)?;
+                String::from(replacement.to_string(cx.scope)?.as_ref())
+            }
+        };
+
+        result.push_str(&string[end_of_last_match..position]);
+        result.push_str(&replacement);
+        end_of_last_match = position + search_string.len();
+    }
+
+    result.push_str(&string[end_of_last_match..]);
+    Ok(Value::String(result.into()))
+}
+
+pub fn replace(cx: CallContext) -> Result<Value, Value> {
+    let pattern = cx.args.first().unwrap_or_undefined();
+    let symbol = cx.scope.statics.symbol_replace.clone();
+
+    if let Some(replacer) = get_pattern_method(cx.scope, &pattern, symbol)? {
+        let replace_value = cx.args.get(1).unwrap_or_undefined();
+        return replacer.apply(cx.scope, pattern, vec![cx.this, replace_value]);
+    }
 
-    let replace_value = cx.args.get(1).unwrap_or_undefined().to_string(cx.scope)?;
+    replace_string(cx, false)
+}
 
-    let string = string.replace(search_string.as_ref(), replace_value.as_ref());
+pub fn replace_all(cx: CallContext) -> Result<Value, Value> {
+    let pattern = cx.args.first().unwrap_or_undefined();
+    require_global_regex(cx.scope, &pattern, "replaceAll")?;
 
-    Ok(Value::String(string.into()))
+    let symbol = cx.scope.statics.symbol_replace.clone();
+    if let Some(replacer) = get_pattern_method(cx.scope, &pattern, symbol)? {
+        let replace_value = cx.args.get(1).unwrap_or_undefined();
+        return replacer.apply(cx.scope, pattern, vec![cx.this, replace_value]);
+    }
+
+    replace_string(cx, true)
 }
 
 pub fn split(cx: CallContext) -> Result<Value, Value> {
+    let separator = cx.args.first().unwrap_or_undefined();
+    let limit = cx.args.get(1).unwrap_or_undefined();
+
+    let symbol = cx.scope.statics.symbol_split.clone();
+    if let Some(splitter) = get_pattern_method(cx.scope, &separator, symbol)? {
+        return splitter.apply(cx.scope, separator, vec![cx.this, limit]);
+    }
+
     let string = cx.this.to_string(cx.scope)?;
-    let separator = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+    let limit = match limit {
+        Value::Undefined(_) => u32::MAX,
+        limit => limit.to_number(cx.scope)? as u32,
+    } as usize;
+
+    let result = match separator {
+        Value::Undefined(_) => vec![string],
+        separator => {
+            let separator = separator.to_string(cx.scope)?;
+            if separator.is_empty() {
+                string.chars().map(|c| Rc::from(c.to_string())).collect()
+            } else {
+                string.split(separator.as_ref()).map(Rc::from).collect()
+            }
+        }
+    };
 
-    let result = string
-        .split(separator.as_ref())
-        .map(|s| PropertyValue::static_default(Value::String(s.into())))
+    let result = result
+        .into_iter()
+        .take(limit)
+        .map(|s| PropertyValue::static_default(Value::String(s)))
         .collect();
 
     let array = Array::from_vec(cx.scope, result);
     Ok(cx.scope.gc_mut().register(array).into())
 }
 
+pub fn r#match(cx: CallContext) -> Result<Value, Value> {
+    let pattern = cx.args.first().unwrap_or_undefined();
+
+    let symbol = cx.scope.statics.symbol_match.clone();
+    if let Some(matcher) = get_pattern_method(cx.scope, &pattern, symbol)? {
+        return matcher.apply(cx.scope, pattern, vec![cx.this]);
+    }
+
+    let string = cx.this.to_string(cx.scope)?;
+    let regex = Value::Object(js_std::regex::create(cx.scope, pattern, Flags::empty())?);
+    let symbol = cx.scope.statics.symbol_match.clone();
+    let method = regex.get_property(cx.scope, symbol.into())?;
+    method.apply(cx.scope, regex, vec![Value::String(string)])
+}
+
+pub fn match_all(cx: CallContext) -> Result<Value, Value> {
+    let pattern = cx.args.first().unwrap_or_undefined();
+    require_global_regex(cx.scope, &pattern, "matchAll")?;
+
+    let symbol = cx.scope.statics.symbol_match_all.clone();
+    if let Some(matcher) = get_pattern_method(cx.scope, &pattern, symbol)? {
+        return matcher.apply(cx.scope, pattern, vec![cx.this]);
+    }
+
+    let string = cx.this.to_string(cx.scope)?;
+    let regex = Value::Object(js_std::regex::create(cx.scope, pattern, Flags::GLOBAL)?);
+    let symbol = cx.scope.statics.symbol_match_all.clone();
+    let method = regex.get_property(cx.scope, symbol.into())?;
+    method.apply(cx.scope, regex, vec![Value::String(string)])
+}
+
+pub fn search(cx: CallContext) -> Result<Value, Value> {
+    let pattern = cx.args.first().unwrap_or_undefined();
+
+    let symbol = cx.scope.statics.symbol_search.clone();
+    if let Some(searcher) = get_pattern_method(cx.scope, &pattern, symbol)? {
+        return searcher.apply(cx.scope, pattern, vec![cx.this]);
+    }
+
+    let string = cx.this.to_string(cx.scope)?;
+    let regex = Value::Object(js_std::regex::create(cx.scope, pattern, Flags::empty())?);
+    let symbol = cx.scope.statics.symbol_search.clone();
+    let method = regex.get_property(cx.scope, symbol.into())?;
+    method.apply(cx.scope, regex, vec![Value::String(string)])
+}
+
 pub fn to_uppercase(cx: CallContext) -> Result<Value, Value> {
     let string = cx.this.to_string(cx.scope)?;
     let result = string.to_uppercase();
@@ -308,6 +539,27 @@ pub fn from_char_code(cx: CallContext) -> Result<Value, Value> {
     Ok(Value::String(s.to_string().into()))
 }
 
+pub fn raw(cx: CallContext) -> Result<Value, Value> {
+    let strings = Value::Object(cx.args.first().unwrap_or_undefined().to_object(cx.scope)?);
+    let raw = Value::Object(strings.get_property(cx.scope, "raw".into())?.to_object(cx.scope)?);
+    let len = raw.length_of_array_like(cx.scope)?;
+
+    let mut result = String::new();
+    for index in 0..len {
+        let segment = raw.get_property(cx.scope, index.to_string().as_str().into())?;
+        result.push_str(&segment.to_string(cx.scope)?);
+
+        // Substitutions are only inserted between segments
+        if index + 1 < len {
+            if let Some(substitution) = cx.args.get(index + 1) {
+                result.push_str(&substitution.to_string(cx.scope)?);
+            }
+        }
+    }
+
+    Ok(Value::String(result.into()))
+}
+
 pub fn substr(cx: CallContext) -> Result<Value, Value> {
     let string = cx.this.to_string(cx.scope)?;
     let (start, end) = {
@@ -1,3 +1,6 @@
+use crate::local::LocalScope;
+use crate::throw;
+use crate::value::boxed::Symbol as BoxedSymbol;
 use crate::value::function::native::CallContext;
 use crate::value::ops::abstractions::conversions::ValueConversion;
 use crate::value::primitive::Symbol;
@@ -5,7 +8,70 @@ use crate::value::Value;
 use crate::value::ValueContext;
 
 pub fn constructor(cx: CallContext) -> Result<Value, Value> {
-    let description = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
-    let symbol = Symbol::new(description);
+    if cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "Symbol is not a constructor");
+    }
+
+    let symbol = match cx.args.first() {
+        Some(Value::Undefined(_)) | None => Symbol::without_description(),
+        Some(description) => Symbol::new(description.to_string(cx.scope)?),
+    };
+
     Ok(symbol.into())
 }
+
+/// Returns the symbol of a primitive symbol or a Symbol object (thisSymbolValue)
+fn this_symbol_value(sc: &mut LocalScope, value: &Value) -> Result<Symbol, Value> {
+    if let Value::Symbol(symbol) = value {
+        return Ok(symbol.clone());
+    }
+
+    match value.downcast_ref::<BoxedSymbol>() {
+        Some(boxed) => Ok(boxed.value().clone()),
+        None => throw!(sc, TypeError, "Symbol.prototype method called on incompatible receiver"),
+    }
+}
+
+pub fn to_string(cx: CallContext) -> Result<Value, Value> {
+    let symbol = this_symbol_value(cx.scope, &cx.this)?;
+    Ok(Value::String(format!("{symbol}").into()))
+}
+
+pub fn description(cx: CallContext) -> Result<Value, Value> {
+    let symbol = this_symbol_value(cx.scope, &cx.this)?;
+    Ok(symbol.description().cloned().map(Value::String).unwrap_or_undefined())
+}
+
+pub fn value_of(cx: CallContext) -> Result<Value, Value> {
+    let symbol = this_symbol_value(cx.scope, &cx.this)?;
+    Ok(Value::Symbol(symbol))
+}
+
+pub fn for_(cx: CallContext) -> Result<Value, Value> {
+    let key = cx.args.first().unwrap_or_undefined().to_string(cx.scope)?;
+
+    let symbol = cx
+        .scope
+        .symbol_registry
+        .entry(key.clone())
+        .or_insert_with(|| Symbol::new(key))
+        .clone();
+
+    Ok(Value::Symbol(symbol))
+}
+
+pub fn key_for(cx: CallContext) -> Result<Value, Value> {
+    let symbol = match cx.args.first() {
+        Some(Value::Symbol(symbol)) => symbol,
+        _ => throw!(cx.scope, TypeError, "Symbol.keyFor argument is not a symbol"),
+    };
+
+    let key = cx
+        .scope
+        .symbol_registry
+        .iter()
+        .find(|(_, registered)| *registered == symbol)
+        .map(|(key, _)| Value::String(key.clone()));
+
+    Ok(key.unwrap_or_undefined())
+}
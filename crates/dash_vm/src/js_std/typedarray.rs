@@ -46,14 +46,17 @@ pub fn fill(cx: CallContext) -> Result<Value, Value> {
         None => throw!(cx.scope, TypeError, "Invalid receiver"),
     };
     let value = match cx.args.first() {
-        Some(value) => value.to_number(cx.scope)?,
+        Some(value) => value,
         None => throw!(cx.scope, TypeError, "Missing fill value"), // TODO: shouldn't throw
     };
     let buf = this.buffer().as_any().downcast_ref::<ArrayBuffer>().unwrap().storage();
 
     macro_rules! fill_typed_array {
-        ($ty:ty) => {{
-            let value = <$ty>::to_ne_bytes(value as $ty);
+        ($ty:ty) => {
+            fill_typed_array!($ty, value.to_number(cx.scope)? as $ty)
+        };
+        ($ty:ty, $value:expr) => {{
+            let value = <$ty>::to_ne_bytes($value);
             for chunk in buf.chunks_exact(value.len()) {
                 // For Uint8Array, it only compiles to a memset if we use an indexed for loop
                 // It seems like zipped iterators are not smart enough
@@ -74,6 +77,8 @@ pub fn fill(cx: CallContext) -> Result<Value, Value> {
         TypedArrayKind::Int32Array => fill_typed_array!(i32),
         TypedArrayKind::Float32Array => fill_typed_array!(f32),
         TypedArrayKind::Float64Array => fill_typed_array!(f64),
+        TypedArrayKind::BigInt64Array => fill_typed_array!(i64, value.to_bigint(cx.scope)?.to_u64_wrapping() as i64),
+        TypedArrayKind::BigUint64Array => fill_typed_array!(u64, value.to_bigint(cx.scope)?.to_u64_wrapping()),
     }
     Ok(Value::undefined())
 }
@@ -87,3 +92,5 @@ typedarray!(module: i32array, kind: TypedArrayKind::Int32Array);
 typedarray!(module: u32array, kind: TypedArrayKind::Uint32Array);
 typedarray!(module: f32array, kind: TypedArrayKind::Float32Array);
 typedarray!(module: f64array, kind: TypedArrayKind::Float64Array);
+typedarray!(module: bigi64array, kind: TypedArrayKind::BigInt64Array);
+typedarray!(module: bigu64array, kind: TypedArrayKind::BigUint64Array);
@@ -0,0 +1,82 @@
+use crate::gc::weak::weak_target;
+use crate::throw;
+use crate::value::function::native::CallContext;
+use crate::value::object::PropertyKey;
+use crate::value::ops::abstractions::conversions::ValueConversion;
+use crate::value::weakmap::WeakMap;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+pub fn constructor(cx: CallContext) -> Result<Value, Value> {
+    if !cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "Constructor WeakMap requires 'new'");
+    }
+
+    let map = WeakMap::new(cx.scope);
+    if let Some(iter) = cx
+        .args
+        .first()
+        .filter(|v| !matches!(v, Value::Undefined(_) | Value::Null(_)))
+    {
+        let len = iter.length_of_array_like(cx.scope)?;
+
+        for i in 0..len {
+            let i = i.to_string();
+            let item = iter.get_property(cx.scope, PropertyKey::String(i.into()))?;
+            let k = item.get_property(cx.scope, PropertyKey::String("0".into()))?;
+            let v = item.get_property(cx.scope, PropertyKey::String("1".into()))?;
+            match weak_target(&k) {
+                Some(k) => map.set(k, v),
+                None => throw!(cx.scope, TypeError, "Invalid value used as weak map key"),
+            }
+        }
+    }
+
+    Ok(Value::Object(cx.scope.register(map)))
+}
+
+pub fn set(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakMap>() {
+        Some(map) => map,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let k = match weak_target(&cx.args.first().unwrap_or_undefined()) {
+        Some(k) => k,
+        None => throw!(cx.scope, TypeError, "Invalid value used as weak map key"),
+    };
+    let v = cx.args.get(1).unwrap_or_undefined();
+    this.set(k, v);
+
+    Ok(cx.this)
+}
+
+pub fn get(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakMap>() {
+        Some(map) => map,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let value = weak_target(&cx.args.first().unwrap_or_undefined()).and_then(|k| this.get(&k));
+    Ok(value.unwrap_or_undefined())
+}
+
+pub fn has(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakMap>() {
+        Some(map) => map,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let has = weak_target(&cx.args.first().unwrap_or_undefined()).is_some_and(|k| this.has(&k));
+    Ok(Value::Boolean(has))
+}
+
+pub fn delete(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakMap>() {
+        Some(map) => map,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let did_delete = weak_target(&cx.args.first().unwrap_or_undefined()).is_some_and(|k| this.delete(&k));
+    Ok(Value::Boolean(did_delete))
+}
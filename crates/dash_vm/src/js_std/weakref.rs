@@ -0,0 +1,35 @@
+use crate::gc::weak::weak_target;
+use crate::throw;
+use crate::value::function::native::CallContext;
+use crate::value::weakref::WeakRef;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+pub fn constructor(cx: CallContext) -> Result<Value, Value> {
+    if !cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "Constructor WeakRef requires 'new'");
+    }
+
+    let target = match weak_target(&cx.args.first().unwrap_or_undefined()) {
+        Some(target) => target,
+        None => throw!(cx.scope, TypeError, "WeakRef: target must be an object"),
+    };
+
+    cx.scope.add_to_kept_objects(target.clone());
+    let weakref = WeakRef::new(cx.scope, target);
+    Ok(Value::Object(cx.scope.register(weakref)))
+}
+
+pub fn deref(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakRef>() {
+        Some(weakref) => weakref,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let target = this.target();
+    if let Some(target) = &target {
+        cx.scope.add_to_kept_objects(target.clone());
+    }
+
+    Ok(target.map(Value::Object).unwrap_or_undefined())
+}
@@ -0,0 +1,68 @@
+use crate::gc::weak::weak_target;
+use crate::throw;
+use crate::value::function::native::CallContext;
+use crate::value::object::PropertyKey;
+use crate::value::ops::abstractions::conversions::ValueConversion;
+use crate::value::weakset::WeakSet;
+use crate::value::Value;
+use crate::value::ValueContext;
+
+pub fn constructor(cx: CallContext) -> Result<Value, Value> {
+    if !cx.is_constructor_call {
+        throw!(cx.scope, TypeError, "Constructor WeakSet requires 'new'");
+    }
+
+    let set = WeakSet::new(cx.scope);
+    if let Some(iter) = cx
+        .args
+        .first()
+        .filter(|v| !matches!(v, Value::Undefined(_) | Value::Null(_)))
+    {
+        let len = iter.length_of_array_like(cx.scope)?;
+
+        for i in 0..len {
+            let i = i.to_string();
+            let item = iter.get_property(cx.scope, PropertyKey::String(i.into()))?;
+            match weak_target(&item) {
+                Some(item) => set.add(item),
+                None => throw!(cx.scope, TypeError, "Invalid value used in weak set"),
+            }
+        }
+    }
+
+    Ok(Value::Object(cx.scope.register(set)))
+}
+
+pub fn add(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakSet>() {
+        Some(set) => set,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    match weak_target(&cx.args.first().unwrap_or_undefined()) {
+        Some(item) => this.add(item),
+        None => throw!(cx.scope, TypeError, "Invalid value used in weak set"),
+    }
+
+    Ok(cx.this)
+}
+
+pub fn has(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakSet>() {
+        Some(set) => set,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let has = weak_target(&cx.args.first().unwrap_or_undefined()).is_some_and(|item| this.has(&item));
+    Ok(Value::Boolean(has))
+}
+
+pub fn delete(cx: CallContext) -> Result<Value, Value> {
+    let this = match cx.this.downcast_ref::<WeakSet>() {
+        Some(set) => set,
+        _ => throw!(cx.scope, TypeError, "Incompatible receiver"),
+    };
+
+    let did_delete = weak_target(&cx.args.first().unwrap_or_undefined()).is_some_and(|item| this.delete(&item));
+    Ok(Value::Boolean(did_delete))
+}
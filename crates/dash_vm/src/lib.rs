@@ -1,10 +1,14 @@
-
-use std::{fmt, ops::RangeBounds, vec::Drain, mem};
-
-use crate::{
-    value::function::Function, util::cold_path, gc::trace::Trace,
+use std::{
+    cell::Cell,
+    collections::{HashSet, VecDeque},
+    fmt, mem,
+    ops::RangeBounds,
+    rc::Rc,
+    vec::Drain,
 };
 
+use crate::{gc::trace::Trace, util::cold_path, value::function::Function};
+
 use self::{
     dispatch::HandleResult,
     external::Externals,
@@ -14,6 +18,7 @@ use self::{
     statics::Statics,
     value::{
         object::{Object, PropertyValue},
+        primitive::Symbol,
         Value,
     },
 };
@@ -22,7 +27,12 @@ use dash_log::{debug, error, span, Level};
 use dash_middle::compiler::instruction::Instruction;
 use gc::{handle::Handle, Gc};
 use util::unlikely;
-use value::{promise::{Promise, PromiseState}, ValueContext, function::bound::BoundFunction, PureBuiltin, object::NamedObject, ExternalValue};
+use value::{
+    function::bound::BoundFunction,
+    object::NamedObject,
+    promise::{PendingRejection, Promise, PromiseState, RejectionTracking},
+    ExternalValue, PureBuiltin, ValueContext,
+};
 
 #[cfg(feature = "jit")]
 mod jit;
@@ -35,13 +45,13 @@ pub mod frame;
 pub mod gc;
 pub mod js_std;
 pub mod local;
+mod macros;
 pub mod params;
 pub mod statics;
-pub mod util;
-pub mod value;
-mod macros;
 #[cfg(test)]
 mod test;
+pub mod util;
+pub mod value;
 
 pub const MAX_FRAME_STACK_SIZE: usize = 1024;
 pub const MAX_STACK_SIZE: usize = 8192;
@@ -49,7 +59,12 @@ const DEFAULT_GC_OBJECT_COUNT_THRESHOLD: usize = 8192;
 
 pub struct Vm {
     frames: Vec<Frame>,
-    async_tasks: Vec<Handle<dyn Object>>,
+    async_tasks: VecDeque<Handle<dyn Object>>,
+    /// Promises that were rejected without a handler since the async task queue was last drained
+    pending_rejections: Vec<PendingRejection>,
+    /// Targets of WeakRefs that were created or dereferenced since the last microtask checkpoint,
+    /// which are kept alive until then so that a `deref()` cannot observe them disappearing mid-job
+    kept_objects: HashSet<Handle<dyn Object>>,
     stack: Vec<Value>,
     gc: Gc,
     global: Handle<dyn Object>,
@@ -64,25 +79,28 @@ pub struct Vm {
     /// or adding a property to a builtin, will cause this to be set to `false`, which in turn
     /// will disable many optimizations such as specialized intrinsics.
     builtins_pure: bool,
+    /// The global symbol registry used by `Symbol.for` and `Symbol.keyFor`
+    symbol_registry: ahash::HashMap<Rc<str>, Symbol>,
     #[cfg(feature = "jit")]
-    jit: jit::Frontend
+    jit: jit::Frontend,
 }
 
-
 impl Vm {
     pub fn new(params: VmParams) -> Self {
         debug!("create vm");
         let mut gc = Gc::default();
         let statics = Statics::new(&mut gc);
         // TODO: global __proto__ and constructor
-        let global = gc.register(PureBuiltin::new(NamedObject::null())); 
+        let global = gc.register(PureBuiltin::new(NamedObject::null()));
         let gc_object_threshold = params
             .initial_gc_object_threshold()
             .unwrap_or(DEFAULT_GC_OBJECT_COUNT_THRESHOLD);
 
         let mut vm = Self {
             frames: Vec::new(),
-            async_tasks: Vec::new(),
+            async_tasks: VecDeque::new(),
+            pending_rejections: Vec::new(),
+            kept_objects: HashSet::new(),
             stack: Vec::with_capacity(512),
             gc,
             global,
@@ -92,6 +110,7 @@ impl Vm {
             params,
             gc_object_threshold,
             builtins_pure: true,
+            symbol_registry: ahash::HashMap::default(),
 
             #[cfg(feature = "jit")]
             jit: jit::Frontend::new(),
@@ -122,6 +141,7 @@ impl Vm {
         /// #[fn_prototype] - Only valid on function values
         ///                   This will set the [[Prototype]] field of the function
         /// #[properties] - "Reference" properties (i.e. object of some kind)
+        /// #[getters] - Accessor properties with a getter function (e.g. RegExp.prototype.source)
         /// #[symbols] - Symbol properties (e.g. @@iterator)
         /// #[fields] - Primitive fields (e.g. PI: 3.1415)
         macro_rules! register_builtin_type {
@@ -134,6 +154,7 @@ impl Vm {
                         #[fn_name] $fnname:ident;
                     )?
                     $( #[properties] $( $prop:ident: $prop_path:expr; )+ )?
+                    $( #[getters] $( $getter:ident: $getter_path:expr; )+ )?
                     $( #[symbols] $( $symbol:expr => $symbol_path:expr; )+ )?
                     $( #[fields] $( $field:ident: $value:expr; )+ )?
                 }
@@ -161,6 +182,19 @@ impl Vm {
                     })+
                 )?
 
+                // Getters
+                $(
+                    $({
+                        let name = stringify!($getter);
+                        let path = $getter_path.clone();
+                        register_builtin_type!(path, {
+                            #[prototype] scope.statics.function_proto;
+                            #[constructor] scope.statics.function_ctor;
+                        });
+                        base.set_property(&mut scope, name.into(), PropertyValue::getter_default(path)).unwrap();
+                    })+
+                )?
+
                 // Symbols
                 $(
                     $({
@@ -189,7 +223,7 @@ impl Vm {
                 )?
 
                 base
-            }}            
+            }}
         }
 
         let function_ctor = register_builtin_type!(scope.statics.function_ctor, {
@@ -239,6 +273,35 @@ impl Vm {
             log: scope.statics.console_log;
         });
 
+        let json = register_builtin_type!(scope.statics.json, {
+            #[prototype] scope.statics.object_prototype;
+            #[constructor] object_ctor;
+
+            #[properties]
+            parse: scope.statics.json_parse;
+            stringify: scope.statics.json_stringify;
+        });
+
+        let reflect = register_builtin_type!(scope.statics.reflect, {
+            #[prototype] scope.statics.object_prototype;
+            #[constructor] object_ctor;
+
+            #[properties]
+            apply: scope.statics.reflect_apply;
+            construct: scope.statics.reflect_construct;
+            defineProperty: scope.statics.reflect_define_property;
+            deleteProperty: scope.statics.reflect_delete_property;
+            get: scope.statics.reflect_get;
+            getOwnPropertyDescriptor: scope.statics.reflect_get_own_property_descriptor;
+            getPrototypeOf: scope.statics.reflect_get_prototype_of;
+            has: scope.statics.reflect_has;
+            isExtensible: scope.statics.reflect_is_extensible;
+            ownKeys: scope.statics.reflect_own_keys;
+            preventExtensions: scope.statics.reflect_prevent_extensions;
+            set: scope.statics.reflect_set;
+            setPrototypeOf: scope.statics.reflect_set_prototype_of;
+        });
+
         let math = register_builtin_type!(scope.statics.math, {
             #[prototype] scope.statics.object_prototype;
             #[constructor] object_ctor;
@@ -294,12 +357,32 @@ impl Vm {
         register_builtin_type!(scope.statics.number_prototype, {
             #[prototype] object_proto;
             #[constructor] number_ctor;
-            
+
             #[properties]
             toString: scope.statics.number_tostring;
             toFixed: scope.statics.number_to_fixed;
         });
 
+        let bigint_ctor = register_builtin_type!(scope.statics.bigint_ctor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.bigint_prototype;
+            #[fn_name] BigInt;
+
+            #[properties]
+            asIntN: scope.statics.bigint_as_int_n;
+            asUintN: scope.statics.bigint_as_uint_n;
+        });
+
+        register_builtin_type!(scope.statics.bigint_prototype, {
+            #[prototype] object_proto;
+            #[constructor] bigint_ctor;
+
+            #[properties]
+            toString: scope.statics.bigint_to_string;
+            valueOf: scope.statics.bigint_value_of;
+        });
+
         let boolean_ctor = register_builtin_type!(scope.statics.boolean_ctor, {
             #[prototype] function_proto;
             #[constructor] function_ctor;
@@ -323,8 +406,9 @@ impl Vm {
             #[fn_name] String;
             #[properties]
             fromCharCode: scope.statics.string_from_char_code;
+            raw: scope.statics.string_raw;
         });
-        
+
         register_builtin_type!(scope.statics.string_prototype, {
             #[prototype] object_proto;
             #[constructor] string_ctor;
@@ -345,6 +429,9 @@ impl Vm {
             replace: scope.statics.string_replace;
             replaceAll: scope.statics.string_replace_all;
             split: scope.statics.string_split;
+            match: scope.statics.string_match;
+            matchAll: scope.statics.string_match_all;
+            search: scope.statics.string_search;
             toLowerCase: scope.statics.string_to_lowercase;
             toUpperCase: scope.statics.string_to_uppercase;
             big: scope.statics.string_big;
@@ -363,7 +450,7 @@ impl Vm {
             trimEnd: scope.statics.string_trim_end;
             substr: scope.statics.string_substr;
             substring: scope.statics.string_substring;
-            
+
             #[symbols]
             scope.statics.symbol_iterator => scope.statics.string_iterator;
         });
@@ -377,7 +464,7 @@ impl Vm {
             #[properties]
             from: scope.statics.array_from;
         });
-        
+
         register_builtin_type!(scope.statics.array_prototype, {
             #[prototype] object_proto;
             #[constructor] array_ctor;
@@ -431,11 +518,37 @@ impl Vm {
 
             #[properties]
             next: scope.statics.generator_iterator_next;
+            return: scope.statics.generator_iterator_return;
+            throw: scope.statics.generator_iterator_throw;
 
             #[symbols]
             scope.statics.symbol_iterator => scope.statics.identity_this;
         });
 
+        register_builtin_type!(scope.statics.async_generator_iterator_prototype, {
+            #[prototype] object_proto; // TODO: this is incorrect
+            #[constructor] function_ctor; // TODO: ^
+
+            #[properties]
+            next: scope.statics.async_generator_iterator_next;
+            return: scope.statics.async_generator_iterator_return;
+            throw: scope.statics.async_generator_iterator_throw;
+
+            #[symbols]
+            scope.statics.symbol_async_iterator => scope.statics.identity_this;
+        });
+
+        register_builtin_type!(scope.statics.async_from_sync_iterator_prototype, {
+            #[prototype] object_proto;
+            #[constructor] function_ctor;
+
+            #[properties]
+            next: scope.statics.async_from_sync_iterator_next;
+
+            #[symbols]
+            scope.statics.symbol_async_iterator => scope.statics.identity_this;
+        });
+
         let symbol_ctor = register_builtin_type!(scope.statics.symbol_ctor, {
             #[prototype] function_proto;
             #[constructor] function_ctor;
@@ -443,6 +556,8 @@ impl Vm {
             #[fn_name] Symbol;
 
             #[properties]
+            for: scope.statics.symbol_for;
+            keyFor: scope.statics.symbol_key_for;
             asyncIterator: scope.statics.symbol_async_iterator;
             hasInstance: scope.statics.symbol_has_instance;
             iterator: scope.statics.symbol_iterator;
@@ -457,6 +572,16 @@ impl Vm {
             unscopables: scope.statics.symbol_unscopables;
         });
 
+        register_builtin_type!(scope.statics.symbol_prototype, {
+            #[prototype] object_proto;
+            #[constructor] symbol_ctor;
+            #[properties]
+            toString: scope.statics.symbol_to_string;
+            valueOf: scope.statics.symbol_value_of;
+            #[getters]
+            description: scope.statics.symbol_description;
+        });
+
         let error_ctor = register_builtin_type!(scope.statics.error_ctor, {
             #[prototype] function_proto;
             #[constructor] function_ctor;
@@ -595,6 +720,34 @@ impl Vm {
             fill: scope.statics.typedarray_fill;
         });
 
+        let bigi64array_ctor = register_builtin_type!(scope.statics.bigint64array_ctor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.bigint64array_prototype;
+            #[fn_name] BigInt64Array;
+        });
+
+        register_builtin_type!(scope.statics.bigint64array_prototype, {
+            #[prototype] object_proto;
+            #[constructor] bigi64array_ctor;
+            #[properties]
+            fill: scope.statics.typedarray_fill;
+        });
+
+        let bigu64array_ctor = register_builtin_type!(scope.statics.biguint64array_ctor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.biguint64array_prototype;
+            #[fn_name] BigUint64Array;
+        });
+
+        register_builtin_type!(scope.statics.biguint64array_prototype, {
+            #[prototype] object_proto;
+            #[constructor] bigu64array_ctor;
+            #[properties]
+            fill: scope.statics.typedarray_fill;
+        });
+
         let promise_ctor = register_builtin_type!(scope.statics.promise_ctor, {
             #[prototype] function_proto;
             #[constructor] function_ctor;
@@ -603,6 +756,11 @@ impl Vm {
             #[properties]
             resolve: scope.statics.promise_resolve;
             reject: scope.statics.promise_reject;
+            all: scope.statics.promise_all;
+            allSettled: scope.statics.promise_all_settled;
+            any: scope.statics.promise_any;
+            race: scope.statics.promise_race;
+            withResolvers: scope.statics.promise_with_resolvers;
         });
 
         register_builtin_type!(scope.statics.promise_proto, {
@@ -610,6 +768,8 @@ impl Vm {
             #[constructor] promise_ctor;
             #[properties]
             then: scope.statics.promise_then;
+            catch: scope.statics.promise_catch;
+            finally: scope.statics.promise_finally;
         });
 
         let set_ctor = register_builtin_type!(scope.statics.set_constructor, {
@@ -649,6 +809,68 @@ impl Vm {
             size: scope.statics.map_size; // TODO: getter, not a function
         });
 
+        let weakmap_ctor = register_builtin_type!(scope.statics.weakmap_constructor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.weakmap_prototype;
+            #[fn_name] WeakMap;
+        });
+
+        register_builtin_type!(scope.statics.weakmap_prototype, {
+            #[prototype] object_proto;
+            #[constructor] weakmap_ctor;
+            #[properties]
+            set: scope.statics.weakmap_set;
+            get: scope.statics.weakmap_get;
+            has: scope.statics.weakmap_has;
+            delete: scope.statics.weakmap_delete;
+        });
+
+        let weakset_ctor = register_builtin_type!(scope.statics.weakset_constructor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.weakset_prototype;
+            #[fn_name] WeakSet;
+        });
+
+        register_builtin_type!(scope.statics.weakset_prototype, {
+            #[prototype] object_proto;
+            #[constructor] weakset_ctor;
+            #[properties]
+            add: scope.statics.weakset_add;
+            has: scope.statics.weakset_has;
+            delete: scope.statics.weakset_delete;
+        });
+
+        let weakref_ctor = register_builtin_type!(scope.statics.weakref_constructor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.weakref_prototype;
+            #[fn_name] WeakRef;
+        });
+
+        register_builtin_type!(scope.statics.weakref_prototype, {
+            #[prototype] object_proto;
+            #[constructor] weakref_ctor;
+            #[properties]
+            deref: scope.statics.weakref_deref;
+        });
+
+        let finalization_registry_ctor = register_builtin_type!(scope.statics.finalization_registry_constructor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+            #[fn_prototype] scope.statics.finalization_registry_prototype;
+            #[fn_name] FinalizationRegistry;
+        });
+
+        register_builtin_type!(scope.statics.finalization_registry_prototype, {
+            #[prototype] object_proto;
+            #[constructor] finalization_registry_ctor;
+            #[properties]
+            register: scope.statics.finalization_registry_register;
+            unregister: scope.statics.finalization_registry_unregister;
+        });
+
         let regexp_ctor = register_builtin_type!(scope.statics.regexp_ctor, {
             #[prototype] function_proto;
             #[constructor] function_ctor;
@@ -661,6 +883,17 @@ impl Vm {
             #[constructor] regexp_ctor;
             #[properties]
             test: scope.statics.regexp_test;
+            exec: scope.statics.regexp_exec;
+            toString: scope.statics.regexp_to_string;
+            #[getters]
+            source: scope.statics.regexp_source;
+            flags: scope.statics.regexp_flags;
+            #[symbols]
+            scope.statics.symbol_match => scope.statics.regexp_match;
+            scope.statics.symbol_match_all => scope.statics.regexp_match_all;
+            scope.statics.symbol_replace => scope.statics.regexp_replace;
+            scope.statics.symbol_search => scope.statics.regexp_search;
+            scope.statics.symbol_split => scope.statics.regexp_split;
         });
 
         let eval_error_ctor = register_builtin_type!(scope.statics.eval_error_ctor, {
@@ -754,11 +987,68 @@ impl Vm {
             #[fn_name] Date;
             #[properties]
             now: scope.statics.date_now;
+            parse: scope.statics.date_parse;
+            UTC: scope.statics.date_utc;
         });
 
         register_builtin_type!(scope.statics.date_prototype, {
             #[prototype] object_proto;
             #[constructor] date_ctor;
+            #[properties]
+            getDate: scope.statics.date_get_date;
+            getDay: scope.statics.date_get_day;
+            getFullYear: scope.statics.date_get_full_year;
+            getHours: scope.statics.date_get_hours;
+            getMilliseconds: scope.statics.date_get_milliseconds;
+            getMinutes: scope.statics.date_get_minutes;
+            getMonth: scope.statics.date_get_month;
+            getSeconds: scope.statics.date_get_seconds;
+            getTime: scope.statics.date_get_time;
+            getTimezoneOffset: scope.statics.date_get_timezone_offset;
+            getUTCDate: scope.statics.date_get_utc_date;
+            getUTCDay: scope.statics.date_get_utc_day;
+            getUTCFullYear: scope.statics.date_get_utc_full_year;
+            getUTCHours: scope.statics.date_get_utc_hours;
+            getUTCMilliseconds: scope.statics.date_get_utc_milliseconds;
+            getUTCMinutes: scope.statics.date_get_utc_minutes;
+            getUTCMonth: scope.statics.date_get_utc_month;
+            getUTCSeconds: scope.statics.date_get_utc_seconds;
+            setDate: scope.statics.date_set_date;
+            setFullYear: scope.statics.date_set_full_year;
+            setHours: scope.statics.date_set_hours;
+            setMilliseconds: scope.statics.date_set_milliseconds;
+            setMinutes: scope.statics.date_set_minutes;
+            setMonth: scope.statics.date_set_month;
+            setSeconds: scope.statics.date_set_seconds;
+            setTime: scope.statics.date_set_time;
+            setUTCDate: scope.statics.date_set_utc_date;
+            setUTCFullYear: scope.statics.date_set_utc_full_year;
+            setUTCHours: scope.statics.date_set_utc_hours;
+            setUTCMilliseconds: scope.statics.date_set_utc_milliseconds;
+            setUTCMinutes: scope.statics.date_set_utc_minutes;
+            setUTCMonth: scope.statics.date_set_utc_month;
+            setUTCSeconds: scope.statics.date_set_utc_seconds;
+            toDateString: scope.statics.date_to_date_string;
+            toISOString: scope.statics.date_to_iso_string;
+            toJSON: scope.statics.date_to_json;
+            toLocaleDateString: scope.statics.date_to_locale_date_string;
+            toLocaleString: scope.statics.date_to_locale_string;
+            toLocaleTimeString: scope.statics.date_to_locale_time_string;
+            toString: scope.statics.date_to_string;
+            toTimeString: scope.statics.date_to_time_string;
+            toUTCString: scope.statics.date_to_utc_string;
+            valueOf: scope.statics.date_value_of;
+
+            #[symbols]
+            scope.statics.symbol_to_primitive => scope.statics.date_to_primitive;
+        });
+
+        let proxy_ctor = register_builtin_type!(scope.statics.proxy_ctor, {
+            #[prototype] function_proto;
+            #[constructor] function_ctor;
+
+            #[properties]
+            revocable: scope.statics.proxy_revocable;
         });
 
         register_builtin_type!(global, {
@@ -770,6 +1060,7 @@ impl Vm {
             isFinite: scope.statics.is_finite;
             parseFloat: scope.statics.parse_float;
             parseInt: scope.statics.parse_int;
+            queueMicrotask: scope.statics.queue_microtask;
             RegExp: regexp_ctor;
             Symbol: symbol_ctor;
             Date: date_ctor;
@@ -782,6 +1073,8 @@ impl Vm {
             Int32Array: i32array_ctor;
             Float32Array: f32array_ctor;
             Float64Array: f64array_ctor;
+            BigInt64Array: bigi64array_ctor;
+            BigUint64Array: bigu64array_ctor;
             Array: array_ctor;
             Error: error_ctor;
             EvalError: eval_error_ctor;
@@ -795,9 +1088,17 @@ impl Vm {
             Object: object_ctor;
             Set: set_ctor;
             Map: map_ctor;
+            WeakMap: weakmap_ctor;
+            WeakSet: weakset_ctor;
+            WeakRef: weakref_ctor;
+            FinalizationRegistry: finalization_registry_ctor;
             console: console;
             Math: math;
+            JSON: json;
+            Reflect: reflect;
+            Proxy: proxy_ctor;
             Number: number_ctor;
+            BigInt: bigint_ctor;
             Boolean: boolean_ctor;
             Promise: promise_ctor;
         });
@@ -898,6 +1199,7 @@ impl Vm {
             // if we're in a try-catch block, we need to jump to it
             let try_fp = last.frame_ip;
             let catch_ip = last.catch_ip;
+            let sp = last.sp;
 
             // Do not unwind further than we are allowed to. If the last try block is "outside" of
             // the frame that this execution context was instantiated in, then we can't jump there.
@@ -910,8 +1212,9 @@ impl Vm {
             // If we've found a suitable try block, actually remove it.
             self.try_blocks.pop();
 
-            // Unwind frames
+            // Unwind frames, and remove any values that were pushed onto the stack during the try block
             drop(self.frames.drain(try_fp..));
+            self.stack.truncate(sp);
 
             let frame = self.frames.last_mut().expect("No frame");
             frame.ip = catch_ip;
@@ -936,44 +1239,76 @@ impl Vm {
             match v {
                 Value::Object(o) => println!("{:#?}", &**o),
                 Value::External(o) => println!("[[external]]: {:#?}", &*o.inner),
-                _ => println!("{v:?}")
+                _ => println!("{v:?}"),
             }
         }
     }
 
-    /// Adds a function to the async task queue.
+    /// Adds a function to the end of the async task queue (microtask queue).
     pub fn add_async_task(&mut self, fun: Handle<dyn Object>) {
-        self.async_tasks.push(fun);
+        self.async_tasks.push_back(fun);
     }
 
-    pub fn has_async_tasks(&self)  -> bool {
+    pub fn has_async_tasks(&self) -> bool {
         !self.async_tasks.is_empty()
     }
 
-    /// Processes all queued async tasks
+    /// Processes all queued async tasks in the order they were queued,
+    /// including tasks that are queued by the tasks themselves
     pub fn process_async_tasks(&mut self) {
         debug!("process async tasks");
         debug!(async_task_count = %self.async_tasks.len());
 
-        while !self.async_tasks.is_empty() {
-            let tasks = mem::take(&mut self.async_tasks);
-
+        while let Some(task) = self.async_tasks.pop_front() {
+            debug!("process task {:?}", task);
             let mut scope = LocalScope::new(self);
-
-            for task in tasks {
-                debug!("process task {:?}", task);
-                if let Err(ex) = task.apply(&mut scope, Value::undefined(), Vec::new()) {
-                    if let Some(callback) = scope.params.unhandled_task_exception_callback() {
-                        error!("uncaught async task exception");
-                        callback(&mut scope, ex);
-                    }
+            scope.add_ref(task.clone());
+            if let Err(ex) = task.apply(&mut scope, Value::undefined(), Vec::new()) {
+                if let Some(callback) = scope.params.unhandled_task_exception_callback() {
+                    error!("uncaught async task exception");
+                    callback(&mut scope, ex);
                 }
             }
         }
+
+        self.report_unhandled_rejections();
+        self.kept_objects.clear();
+    }
+
+    /// Keeps a WeakRef target alive until the next microtask checkpoint (AddToKeptObjects)
+    pub(crate) fn add_to_kept_objects(&mut self, object: Handle<dyn Object>) {
+        self.kept_objects.insert(object);
+    }
+
+    /// Remembers a promise rejection that has no handler yet
+    pub(crate) fn track_rejection(&mut self, tracking: Rc<Cell<RejectionTracking>>, reason: Value) {
+        if tracking.get() == RejectionTracking::Unhandled {
+            self.pending_rejections.push(PendingRejection { tracking, reason });
+        }
+    }
+
+    /// Reports promises that were rejected and still have no handler to the rejection unhandled callback
+    fn report_unhandled_rejections(&mut self) {
+        let rejections = mem::take(&mut self.pending_rejections);
+        let mut scope = LocalScope::new(self);
+
+        for PendingRejection { tracking, reason } in rejections {
+            if tracking.get() != RejectionTracking::Unhandled {
+                continue;
+            }
+
+            tracking.set(RejectionTracking::Reported);
+            scope.add_value(reason.clone());
+
+            if let Some(callback) = scope.params.promise_rejection_unhandled_callback() {
+                error!("unhandled promise rejection");
+                callback(&mut scope, reason);
+            }
+        }
     }
 
     /// Executes a frame in this VM and initializes local variables (excluding parameters)
-    /// 
+    ///
     /// Parameters must be pushed onto the stack in the correct order by the caller before this function is called.
     pub fn execute_frame(&mut self, frame: Frame) -> Result<HandleResult, Value> {
         debug!("execute frame {:?}", frame.function.name);
@@ -989,19 +1324,27 @@ impl Vm {
         let pad_to = self.stack.len() + frame.extra_stack_space;
         debug!(pad_to);
         // TODO: check that the stack space won't exceed our stack frame limit
-        self.stack
-            .resize(pad_to, Value::undefined());
+        self.stack.resize(pad_to, Value::undefined());
     }
 
     /// Executes a frame in this VM, without doing any sort of stack management
-    fn execute_frame_raw(&mut self, frame: Frame) -> Result<HandleResult, Value>
-    {
+    fn execute_frame_raw(&mut self, frame: Frame) -> Result<HandleResult, Value> {
         // TODO: if this fails, we MUST revert the stack management,
         // like reserving space for undefined values
         self.try_push_frame(frame)?;
         self.handle_instruction_loop()
     }
 
+    /// Executes a frame in this VM by throwing an error at its current instruction pointer,
+    /// without doing any sort of stack management.
+    ///
+    /// This is used for resuming generators with a throw completion.
+    pub(crate) fn execute_frame_raw_throw(&mut self, frame: Frame, err: Value) -> Result<HandleResult, Value> {
+        self.try_push_frame(frame)?;
+        self.handle_rt_error(err, self.frames.len())?;
+        self.handle_instruction_loop()
+    }
+
     fn handle_instruction_loop(&mut self) -> Result<HandleResult, Value> {
         let fp = self.frames.len();
 
@@ -1044,6 +1387,13 @@ impl Vm {
         sweep.in_scope(|| unsafe { self.gc.sweep() });
         debug!("object count after sweep: {}", self.gc.node_count());
 
+        // Cleanup callbacks of finalization registries run as async tasks after the current job
+        for (callback, held_value) in self.gc.take_pending_finalizers() {
+            let task = BoundFunction::new(self, callback, None, Some(vec![held_value]));
+            let task = self.register(task);
+            self.add_async_task(task);
+        }
+
         // Adjust GC threshold
         let new_object_count = self.gc.node_count();
         self.gc_object_threshold = new_object_count * 2;
@@ -1055,6 +1405,10 @@ impl Vm {
         self.frames.trace();
         debug!("trace async tasks");
         self.async_tasks.trace();
+        debug!("trace pending rejections");
+        self.pending_rejections.trace();
+        debug!("trace kept objects");
+        self.kept_objects.trace();
         debug!("trace stack");
         self.stack.trace();
         debug!("trace globals");
@@ -1089,17 +1443,24 @@ impl Vm {
         let arg = args.first().unwrap_or_undefined();
         let mut state = promise.state().borrow_mut();
 
-        if let PromiseState::Pending { resolve, reject } = &mut *state {
-            let handlers = match action {
-                PromiseAction::Resolve => mem::take(resolve),
-                PromiseAction::Reject => mem::take(reject)
-            };
+        // Promises can only be settled once
+        let PromiseState::Pending { resolve, reject } = &mut *state else {
+            return;
+        };
 
-            for handler in handlers {
-                let bf = BoundFunction::new(self, handler, None, Some(args.clone()));
-                let bf = self.register(bf);
-                self.add_async_task(bf);
-            }
+        let handlers = match action {
+            PromiseAction::Resolve => mem::take(resolve),
+            PromiseAction::Reject => mem::take(reject),
+        };
+
+        if let PromiseAction::Reject = action {
+            self.track_rejection(promise.rejection_tracking().clone(), arg.clone());
+        }
+
+        for handler in handlers {
+            let bf = BoundFunction::new(self, handler, None, Some(args.clone()));
+            let bf = self.register(bf);
+            self.add_async_task(bf);
         }
 
         *state = match action {
@@ -1132,17 +1493,21 @@ impl Vm {
         use dash_typed_cfg::passes::bb_generation::ConditionalBranchAction;
 
         if let Some(trace) = self.jit.recording_trace_mut() {
-            trace.record_conditional_jump(ip, match did_jump {
-                true => ConditionalBranchAction::Taken,
-                false => ConditionalBranchAction::NotTaken,
-            });
+            trace.record_conditional_jump(
+                ip,
+                match did_jump {
+                    true => ConditionalBranchAction::Taken,
+                    false => ConditionalBranchAction::NotTaken,
+                },
+            );
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PromiseAction {
     Resolve,
-    Reject
+    Reject,
 }
 
 impl fmt::Debug for Vm {
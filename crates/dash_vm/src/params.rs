@@ -9,19 +9,26 @@ use super::Vm;
 
 pub type MathRandomCallback = fn(vm: &mut Vm) -> Result<f64, Value>;
 pub type TimeMillisCallback = fn(vm: &mut Vm) -> Result<u64, Value>;
+/// Returns the offset of local time from UTC (in milliseconds) at the given UTC time (in milliseconds since the epoch)
+pub type TimezoneOffsetCallback = fn(vm: &mut Vm, utc_millis: i64) -> Result<i64, Value>;
 pub type StaticImportCallback = fn(vm: &mut Vm, ty: StaticImportKind, path: &str) -> Result<Value, Value>;
 pub type DynamicImportCallback = fn(vm: &mut Vm, val: Value) -> Result<Value, Value>;
 pub type DebuggerCallback = fn(vm: &mut Vm) -> Result<(), Value>;
 pub type UnhandledTaskException = fn(vm: &mut LocalScope, exception: Value);
+/// Called with the reason of a promise rejection
+pub type PromiseRejectionCallback = fn(vm: &mut LocalScope, reason: Value);
 
 #[derive(Default)]
 pub struct VmParams {
     math_random_callback: Option<MathRandomCallback>,
     time_millis_callback: Option<TimeMillisCallback>,
+    timezone_offset_callback: Option<TimezoneOffsetCallback>,
     static_import_callback: Option<StaticImportCallback>,
     dynamic_import_callback: Option<DynamicImportCallback>,
     debugger_callback: Option<DebuggerCallback>,
     unhandled_task_exception_callback: Option<UnhandledTaskException>,
+    promise_rejection_unhandled_callback: Option<PromiseRejectionCallback>,
+    promise_rejection_handled_callback: Option<PromiseRejectionCallback>,
     initial_gc_object_threshold: Option<usize>,
     state: Option<Box<dyn Any>>,
 }
@@ -84,6 +91,17 @@ impl VmParams {
         self.time_millis_callback
     }
 
+    /// Sets the callback used for converting between UTC and local time.
+    /// If no callback is set, local time is assumed to be UTC.
+    pub fn set_timezone_offset_callback(mut self, callback: TimezoneOffsetCallback) -> Self {
+        self.timezone_offset_callback = Some(callback);
+        self
+    }
+
+    pub fn timezone_offset_callback(&self) -> Option<TimezoneOffsetCallback> {
+        self.timezone_offset_callback
+    }
+
     pub fn set_debugger_callback(mut self, callback: DebuggerCallback) -> Self {
         self.debugger_callback = Some(callback);
         self
@@ -102,6 +120,27 @@ impl VmParams {
         self.unhandled_task_exception_callback
     }
 
+    /// Sets the callback for promises that were rejected and still have no handler once all async tasks have run
+    pub fn set_promise_rejection_unhandled_callback(mut self, callback: PromiseRejectionCallback) -> Self {
+        self.promise_rejection_unhandled_callback = Some(callback);
+        self
+    }
+
+    pub fn promise_rejection_unhandled_callback(&self) -> Option<PromiseRejectionCallback> {
+        self.promise_rejection_unhandled_callback
+    }
+
+    /// Sets the callback for promises that get a handler after they were reported
+    /// to the rejection unhandled callback
+    pub fn set_promise_rejection_handled_callback(mut self, callback: PromiseRejectionCallback) -> Self {
+        self.promise_rejection_handled_callback = Some(callback);
+        self
+    }
+
+    pub fn promise_rejection_handled_callback(&self) -> Option<PromiseRejectionCallback> {
+        self.promise_rejection_handled_callback
+    }
+
     pub fn set_initial_gc_object_threshold(mut self, threshold: usize) -> Self {
         self.initial_gc_object_threshold = Some(threshold);
         self
@@ -3,6 +3,7 @@ use dash_proc_macro::Trace;
 use crate::gc::handle::Handle;
 use crate::gc::Gc;
 use crate::js_std;
+use crate::value::date::Date;
 use crate::value::error::AggregateError;
 use crate::value::error::EvalError;
 use crate::value::error::RangeError;
@@ -10,16 +11,21 @@ use crate::value::error::ReferenceError;
 use crate::value::error::SyntaxError;
 use crate::value::error::TypeError;
 use crate::value::error::URIError;
+use crate::value::finalization_registry::FinalizationRegistry;
 use crate::value::function::Function;
 use crate::value::function::FunctionKind;
 use crate::value::map::Map;
 use crate::value::regex::RegExp;
 use crate::value::set::Set;
+use crate::value::weakmap::WeakMap;
+use crate::value::weakref::WeakRef;
+use crate::value::weakset::WeakSet;
 use crate::value::PureBuiltin;
 
 use super::value::array::Array;
 use super::value::array::ArrayIterator;
 use super::value::arraybuffer::ArrayBuffer;
+use super::value::boxed::BigInt as BoxedBigInt;
 use super::value::boxed::Boolean as BoxedBoolean;
 use super::value::boxed::Number as BoxedNumber;
 use super::value::boxed::String as BoxedString;
@@ -29,6 +35,7 @@ use super::value::function::generator::GeneratorIterator;
 use super::value::function::native::NativeFunction;
 use super::value::object::NamedObject;
 use super::value::object::Object;
+use super::value::primitive::BigInt;
 use super::value::primitive::Symbol;
 
 use std::rc::Rc;
@@ -54,8 +61,12 @@ pub struct Statics {
     pub is_finite: Handle<dyn Object>,
     pub parse_float: Handle<dyn Object>,
     pub parse_int: Handle<dyn Object>,
+    pub queue_microtask: Handle<dyn Object>,
     pub console: Handle<dyn Object>,
     pub console_log: Handle<dyn Object>,
+    pub json: Handle<dyn Object>,
+    pub json_parse: Handle<dyn Object>,
+    pub json_stringify: Handle<dyn Object>,
     pub math: Handle<dyn Object>,
     pub math_floor: Handle<dyn Object>,
     pub math_abs: Handle<dyn Object>,
@@ -102,6 +113,12 @@ pub struct Statics {
     pub number_is_nan: Handle<dyn Object>,
     pub number_is_safe_integer: Handle<dyn Object>,
     pub number_to_fixed: Handle<dyn Object>,
+    pub bigint_ctor: Handle<dyn Object>,
+    pub bigint_prototype: Handle<dyn Object>,
+    pub bigint_as_int_n: Handle<dyn Object>,
+    pub bigint_as_uint_n: Handle<dyn Object>,
+    pub bigint_to_string: Handle<dyn Object>,
+    pub bigint_value_of: Handle<dyn Object>,
     pub boolean_ctor: Handle<dyn Object>,
     pub boolean_tostring: Handle<dyn Object>,
     pub boolean_prototype: Handle<dyn Object>,
@@ -123,6 +140,9 @@ pub struct Statics {
     pub string_replace: Handle<dyn Object>,
     pub string_replace_all: Handle<dyn Object>,
     pub string_split: Handle<dyn Object>,
+    pub string_match: Handle<dyn Object>,
+    pub string_match_all: Handle<dyn Object>,
+    pub string_search: Handle<dyn Object>,
     pub string_to_uppercase: Handle<dyn Object>,
     pub string_to_lowercase: Handle<dyn Object>,
     pub string_big: Handle<dyn Object>,
@@ -140,6 +160,7 @@ pub struct Statics {
     pub string_trim_start: Handle<dyn Object>,
     pub string_trim_end: Handle<dyn Object>,
     pub string_from_char_code: Handle<dyn Object>,
+    pub string_raw: Handle<dyn Object>,
     pub string_substr: Handle<dyn Object>,
     pub string_substring: Handle<dyn Object>,
     pub string_iterator: Handle<dyn Object>,
@@ -150,6 +171,11 @@ pub struct Statics {
     pub array_values: Handle<dyn Object>,
     pub symbol_ctor: Handle<dyn Object>,
     pub symbol_prototype: Handle<dyn Object>,
+    pub symbol_for: Handle<dyn Object>,
+    pub symbol_key_for: Handle<dyn Object>,
+    pub symbol_to_string: Handle<dyn Object>,
+    pub symbol_value_of: Handle<dyn Object>,
+    pub symbol_description: Handle<dyn Object>,
     pub symbol_async_iterator: Symbol,
     pub symbol_has_instance: Symbol,
     pub symbol_is_concat_spreadable: Symbol,
@@ -192,6 +218,14 @@ pub struct Statics {
     pub array_from: Handle<dyn Object>,
     pub generator_iterator_prototype: Handle<dyn Object>,
     pub generator_iterator_next: Handle<dyn Object>,
+    pub generator_iterator_return: Handle<dyn Object>,
+    pub generator_iterator_throw: Handle<dyn Object>,
+    pub async_generator_iterator_prototype: Handle<dyn Object>,
+    pub async_generator_iterator_next: Handle<dyn Object>,
+    pub async_generator_iterator_return: Handle<dyn Object>,
+    pub async_generator_iterator_throw: Handle<dyn Object>,
+    pub async_from_sync_iterator_prototype: Handle<dyn Object>,
+    pub async_from_sync_iterator_next: Handle<dyn Object>,
     pub error_ctor: Handle<dyn Object>,
     pub error_prototype: Handle<dyn Object>,
     pub error_to_string: Handle<dyn Object>,
@@ -227,12 +261,23 @@ pub struct Statics {
     pub float32array_prototype: Handle<dyn Object>,
     pub float64array_ctor: Handle<dyn Object>,
     pub float64array_prototype: Handle<dyn Object>,
+    pub bigint64array_ctor: Handle<dyn Object>,
+    pub bigint64array_prototype: Handle<dyn Object>,
+    pub biguint64array_ctor: Handle<dyn Object>,
+    pub biguint64array_prototype: Handle<dyn Object>,
     pub typedarray_fill: Handle<dyn Object>,
     pub promise_ctor: Handle<dyn Object>,
     pub promise_proto: Handle<dyn Object>,
     pub promise_resolve: Handle<dyn Object>,
     pub promise_reject: Handle<dyn Object>,
     pub promise_then: Handle<dyn Object>,
+    pub promise_catch: Handle<dyn Object>,
+    pub promise_finally: Handle<dyn Object>,
+    pub promise_all: Handle<dyn Object>,
+    pub promise_all_settled: Handle<dyn Object>,
+    pub promise_any: Handle<dyn Object>,
+    pub promise_race: Handle<dyn Object>,
+    pub promise_with_resolvers: Handle<dyn Object>,
     pub set_constructor: Handle<dyn Object>,
     pub set_prototype: Handle<dyn Object>,
     pub set_add: Handle<dyn Object>,
@@ -248,12 +293,101 @@ pub struct Statics {
     pub map_delete: Handle<dyn Object>,
     pub map_clear: Handle<dyn Object>,
     pub map_size: Handle<dyn Object>,
+    pub weakmap_constructor: Handle<dyn Object>,
+    pub weakmap_prototype: Handle<dyn Object>,
+    pub weakmap_set: Handle<dyn Object>,
+    pub weakmap_get: Handle<dyn Object>,
+    pub weakmap_has: Handle<dyn Object>,
+    pub weakmap_delete: Handle<dyn Object>,
+    pub weakset_constructor: Handle<dyn Object>,
+    pub weakset_prototype: Handle<dyn Object>,
+    pub weakset_add: Handle<dyn Object>,
+    pub weakset_has: Handle<dyn Object>,
+    pub weakset_delete: Handle<dyn Object>,
+    pub weakref_constructor: Handle<dyn Object>,
+    pub weakref_prototype: Handle<dyn Object>,
+    pub weakref_deref: Handle<dyn Object>,
+    pub finalization_registry_constructor: Handle<dyn Object>,
+    pub finalization_registry_prototype: Handle<dyn Object>,
+    pub finalization_registry_register: Handle<dyn Object>,
+    pub finalization_registry_unregister: Handle<dyn Object>,
     pub regexp_ctor: Handle<dyn Object>,
     pub regexp_prototype: Handle<dyn Object>,
     pub regexp_test: Handle<dyn Object>,
+    pub regexp_exec: Handle<dyn Object>,
+    pub regexp_match: Handle<dyn Object>,
+    pub regexp_match_all: Handle<dyn Object>,
+    pub regexp_replace: Handle<dyn Object>,
+    pub regexp_search: Handle<dyn Object>,
+    pub regexp_split: Handle<dyn Object>,
+    pub regexp_source: Handle<dyn Object>,
+    pub regexp_flags: Handle<dyn Object>,
+    pub regexp_to_string: Handle<dyn Object>,
     pub date_ctor: Handle<dyn Object>,
     pub date_prototype: Handle<dyn Object>,
     pub date_now: Handle<dyn Object>,
+    pub date_get_date: Handle<dyn Object>,
+    pub date_get_day: Handle<dyn Object>,
+    pub date_get_full_year: Handle<dyn Object>,
+    pub date_get_hours: Handle<dyn Object>,
+    pub date_get_milliseconds: Handle<dyn Object>,
+    pub date_get_minutes: Handle<dyn Object>,
+    pub date_get_month: Handle<dyn Object>,
+    pub date_get_seconds: Handle<dyn Object>,
+    pub date_get_time: Handle<dyn Object>,
+    pub date_get_timezone_offset: Handle<dyn Object>,
+    pub date_get_utc_date: Handle<dyn Object>,
+    pub date_get_utc_day: Handle<dyn Object>,
+    pub date_get_utc_full_year: Handle<dyn Object>,
+    pub date_get_utc_hours: Handle<dyn Object>,
+    pub date_get_utc_milliseconds: Handle<dyn Object>,
+    pub date_get_utc_minutes: Handle<dyn Object>,
+    pub date_get_utc_month: Handle<dyn Object>,
+    pub date_get_utc_seconds: Handle<dyn Object>,
+    pub date_set_date: Handle<dyn Object>,
+    pub date_set_full_year: Handle<dyn Object>,
+    pub date_set_hours: Handle<dyn Object>,
+    pub date_set_milliseconds: Handle<dyn Object>,
+    pub date_set_minutes: Handle<dyn Object>,
+    pub date_set_month: Handle<dyn Object>,
+    pub date_set_seconds: Handle<dyn Object>,
+    pub date_set_time: Handle<dyn Object>,
+    pub date_set_utc_date: Handle<dyn Object>,
+    pub date_set_utc_full_year: Handle<dyn Object>,
+    pub date_set_utc_hours: Handle<dyn Object>,
+    pub date_set_utc_milliseconds: Handle<dyn Object>,
+    pub date_set_utc_minutes: Handle<dyn Object>,
+    pub date_set_utc_month: Handle<dyn Object>,
+    pub date_set_utc_seconds: Handle<dyn Object>,
+    pub date_to_date_string: Handle<dyn Object>,
+    pub date_to_iso_string: Handle<dyn Object>,
+    pub date_to_json: Handle<dyn Object>,
+    pub date_to_locale_date_string: Handle<dyn Object>,
+    pub date_to_locale_string: Handle<dyn Object>,
+    pub date_to_locale_time_string: Handle<dyn Object>,
+    pub date_to_string: Handle<dyn Object>,
+    pub date_to_time_string: Handle<dyn Object>,
+    pub date_to_utc_string: Handle<dyn Object>,
+    pub date_value_of: Handle<dyn Object>,
+    pub date_to_primitive: Handle<dyn Object>,
+    pub date_parse: Handle<dyn Object>,
+    pub date_utc: Handle<dyn Object>,
+    pub proxy_ctor: Handle<dyn Object>,
+    pub proxy_revocable: Handle<dyn Object>,
+    pub reflect: Handle<dyn Object>,
+    pub reflect_apply: Handle<dyn Object>,
+    pub reflect_construct: Handle<dyn Object>,
+    pub reflect_define_property: Handle<dyn Object>,
+    pub reflect_delete_property: Handle<dyn Object>,
+    pub reflect_get: Handle<dyn Object>,
+    pub reflect_get_own_property_descriptor: Handle<dyn Object>,
+    pub reflect_get_prototype_of: Handle<dyn Object>,
+    pub reflect_has: Handle<dyn Object>,
+    pub reflect_is_extensible: Handle<dyn Object>,
+    pub reflect_own_keys: Handle<dyn Object>,
+    pub reflect_prevent_extensions: Handle<dyn Object>,
+    pub reflect_set: Handle<dyn Object>,
+    pub reflect_set_prototype_of: Handle<dyn Object>,
 }
 
 fn builtin_object<O: Object + 'static>(gc: &mut Gc, obj: O) -> Handle<dyn Object> {
@@ -289,6 +423,9 @@ impl Statics {
             function_to_string: function(gc, "toString", js_std::function::to_string),
             console: empty_object(gc),
             console_log: function(gc, "log", js_std::global::log),
+            json: empty_object(gc),
+            json_parse: function(gc, "parse", js_std::json::parse),
+            json_stringify: function(gc, "stringify", js_std::json::stringify),
             math: empty_object(gc),
             math_floor: function(gc, "floor", js_std::math::floor),
             object_ctor: function(gc, "Object", js_std::object::constructor),
@@ -319,6 +456,7 @@ impl Statics {
             is_finite: function(gc, "isFinite", js_std::global::is_finite),
             parse_float: function(gc, "parseFloat", js_std::global::parse_float),
             parse_int: function(gc, "parseInt", js_std::global::parse_int),
+            queue_microtask: function(gc, "queueMicrotask", js_std::global::queue_microtask),
             math_abs: function(gc, "abs", js_std::math::abs),
             math_acos: function(gc, "acos", js_std::math::acos),
             math_acosh: function(gc, "acosh", js_std::math::acosh),
@@ -352,6 +490,12 @@ impl Statics {
             number_is_nan: function(gc, "isNaN", js_std::number::is_nan),
             number_is_safe_integer: function(gc, "isSafeInteger", js_std::number::is_safe_integer),
             number_to_fixed: function(gc, "toFixed", js_std::number::to_fixed),
+            bigint_ctor: function(gc, "BigInt", js_std::bigint::constructor),
+            bigint_prototype: builtin_object(gc, BoxedBigInt::with_obj(BigInt::new(0.into()), NamedObject::null())),
+            bigint_as_int_n: function(gc, "asIntN", js_std::bigint::as_int_n),
+            bigint_as_uint_n: function(gc, "asUintN", js_std::bigint::as_uint_n),
+            bigint_to_string: function(gc, "toString", js_std::bigint::to_string),
+            bigint_value_of: function(gc, "valueOf", js_std::bigint::value_of),
             boolean_valueof: function(gc, "valueOf", js_std::boolean::value_of),
             string_tostring: function(gc, "toString", js_std::string::to_string),
             string_char_at: function(gc, "charAt", js_std::string::char_at),
@@ -368,6 +512,9 @@ impl Statics {
             string_replace: function(gc, "replace", js_std::string::replace),
             string_replace_all: function(gc, "replaceAll", js_std::string::replace_all),
             string_split: function(gc, "split", js_std::string::split),
+            string_match: function(gc, "match", js_std::string::r#match),
+            string_match_all: function(gc, "matchAll", js_std::string::match_all),
+            string_search: function(gc, "search", js_std::string::search),
             string_to_uppercase: function(gc, "toUpperCase", js_std::string::to_uppercase),
             string_to_lowercase: function(gc, "toLowerCase", js_std::string::to_lowercase),
             string_big: function(gc, "big", js_std::string::big),
@@ -385,6 +532,7 @@ impl Statics {
             string_trim_start: function(gc, "trimStart", js_std::string::trim_start),
             string_trim_end: function(gc, "trimEnd", js_std::string::trim_end),
             string_from_char_code: function(gc, "fromCharCode", js_std::string::from_char_code),
+            string_raw: function(gc, "raw", js_std::string::raw),
             string_substr: function(gc, "substr", js_std::string::substr),
             string_substring: function(gc, "substring", js_std::string::substring),
             string_iterator: function(gc, "iterator", js_std::string::iterator),
@@ -396,6 +544,11 @@ impl Statics {
             array_reverse: function(gc, "reverse", js_std::array::reverse),
             symbol_ctor: function(gc, "Symbol", js_std::symbol::constructor),
             symbol_prototype: builtin_object(gc, BoxedSymbol::with_obj(Symbol::new(empty_str), NamedObject::null())),
+            symbol_for: function(gc, "for", js_std::symbol::for_),
+            symbol_key_for: function(gc, "keyFor", js_std::symbol::key_for),
+            symbol_to_string: function(gc, "toString", js_std::symbol::to_string),
+            symbol_value_of: function(gc, "valueOf", js_std::symbol::value_of),
+            symbol_description: function(gc, "get description", js_std::symbol::description),
             symbol_async_iterator: Symbol::new("Symbol.asyncIterator".into()),
             symbol_has_instance: Symbol::new("Symbol.hasInstance".into()),
             symbol_is_concat_spreadable: Symbol::new("Symbol.isConcatSpreadable".into()),
@@ -406,7 +559,7 @@ impl Statics {
             symbol_search: Symbol::new("Symbol.search".into()),
             symbol_species: Symbol::new("Symbol.species".into()),
             symbol_split: Symbol::new("Symbol.split".into()),
-            symbol_to_primitive: Symbol::new("SymboltoPrimitive".into()),
+            symbol_to_primitive: Symbol::new("Symbol.toPrimitive".into()),
             symbol_to_string_tag: Symbol::new("Symbol.toStringTag".into()),
             symbol_unscopables: Symbol::new("Symbol.unscopables".into()),
             array_iterator_prototype: builtin_object(gc, ArrayIterator::empty()),
@@ -440,6 +593,14 @@ impl Statics {
                 builtin_object(gc, GeneratorIterator::empty(obj))
             },
             generator_iterator_next: function(gc, "next", js_std::generator::next),
+            generator_iterator_return: function(gc, "return", js_std::generator::return_),
+            generator_iterator_throw: function(gc, "throw", js_std::generator::throw),
+            async_generator_iterator_prototype: empty_object(gc),
+            async_generator_iterator_next: function(gc, "next", js_std::async_generator::next),
+            async_generator_iterator_return: function(gc, "return", js_std::async_generator::return_),
+            async_generator_iterator_throw: function(gc, "throw", js_std::async_generator::throw),
+            async_from_sync_iterator_prototype: empty_object(gc),
+            async_from_sync_iterator_next: function(gc, "next", js_std::async_generator::async_from_sync_iterator_next),
             error_ctor: function(gc, "Error", js_std::error::error_constructor),
             error_prototype: builtin_object(gc, Error::empty()),
             error_to_string: function(gc, "toString", js_std::error::to_string),
@@ -475,12 +636,23 @@ impl Statics {
             float32array_prototype: empty_object(gc),
             float64array_ctor: function(gc, "Float64Array", js_std::typedarray::f64array::constructor),
             float64array_prototype: empty_object(gc),
+            bigint64array_ctor: function(gc, "BigInt64Array", js_std::typedarray::bigi64array::constructor),
+            bigint64array_prototype: empty_object(gc),
+            biguint64array_ctor: function(gc, "BigUint64Array", js_std::typedarray::bigu64array::constructor),
+            biguint64array_prototype: empty_object(gc),
             typedarray_fill: function(gc, "fill", js_std::typedarray::fill),
             promise_ctor: function(gc, "Promise", js_std::promise::constructor),
             promise_proto: empty_object(gc),
             promise_resolve: function(gc, "resolve", js_std::promise::resolve),
             promise_reject: function(gc, "reject", js_std::promise::reject),
             promise_then: function(gc, "then", js_std::promise::then),
+            promise_catch: function(gc, "catch", js_std::promise::catch),
+            promise_finally: function(gc, "finally", js_std::promise::finally),
+            promise_all: function(gc, "all", js_std::promise::all),
+            promise_all_settled: function(gc, "allSettled", js_std::promise::all_settled),
+            promise_any: function(gc, "any", js_std::promise::any),
+            promise_race: function(gc, "race", js_std::promise::race),
+            promise_with_resolvers: function(gc, "withResolvers", js_std::promise::with_resolvers),
             set_constructor: function(gc, "Set", js_std::set::constructor),
             set_add: function(gc, "add", js_std::set::add),
             set_has: function(gc, "has", js_std::set::has),
@@ -496,12 +668,109 @@ impl Statics {
             map_prototype: builtin_object(gc, Map::with_obj(NamedObject::null())),
             map_clear: function(gc, "clear", js_std::map::clear),
             map_size: function(gc, "size", js_std::map::size),
+            weakmap_constructor: function(gc, "WeakMap", js_std::weakmap::constructor),
+            weakmap_prototype: builtin_object(gc, WeakMap::with_obj(NamedObject::null())),
+            weakmap_set: function(gc, "set", js_std::weakmap::set),
+            weakmap_get: function(gc, "get", js_std::weakmap::get),
+            weakmap_has: function(gc, "has", js_std::weakmap::has),
+            weakmap_delete: function(gc, "delete", js_std::weakmap::delete),
+            weakset_constructor: function(gc, "WeakSet", js_std::weakset::constructor),
+            weakset_prototype: builtin_object(gc, WeakSet::with_obj(NamedObject::null())),
+            weakset_add: function(gc, "add", js_std::weakset::add),
+            weakset_has: function(gc, "has", js_std::weakset::has),
+            weakset_delete: function(gc, "delete", js_std::weakset::delete),
+            weakref_constructor: function(gc, "WeakRef", js_std::weakref::constructor),
+            weakref_prototype: builtin_object(gc, WeakRef::with_obj(NamedObject::null())),
+            weakref_deref: function(gc, "deref", js_std::weakref::deref),
+            finalization_registry_constructor: function(
+                gc,
+                "FinalizationRegistry",
+                js_std::finalization_registry::constructor,
+            ),
+            finalization_registry_prototype: builtin_object(gc, FinalizationRegistry::with_obj(NamedObject::null())),
+            finalization_registry_register: function(gc, "register", js_std::finalization_registry::register),
+            finalization_registry_unregister: function(gc, "unregister", js_std::finalization_registry::unregister),
             regexp_ctor: function(gc, "RegExp", js_std::regex::constructor),
             regexp_prototype: builtin_object(gc, RegExp::empty()),
             regexp_test: function(gc, "test", js_std::regex::test),
+            regexp_exec: function(gc, "exec", js_std::regex::exec),
+            regexp_match: function(gc, "[Symbol.match]", js_std::regex::symbol_match),
+            regexp_match_all: function(gc, "[Symbol.matchAll]", js_std::regex::symbol_match_all),
+            regexp_replace: function(gc, "[Symbol.replace]", js_std::regex::symbol_replace),
+            regexp_search: function(gc, "[Symbol.search]", js_std::regex::symbol_search),
+            regexp_split: function(gc, "[Symbol.split]", js_std::regex::symbol_split),
+            regexp_source: function(gc, "get source", js_std::regex::source),
+            regexp_flags: function(gc, "get flags", js_std::regex::flags),
+            regexp_to_string: function(gc, "toString", js_std::regex::to_string),
             date_ctor: function(gc, "Date", js_std::date::constructor),
-            date_prototype: builtin_object(gc, NamedObject::null()),
+            date_prototype: builtin_object(gc, Date::empty()),
             date_now: function(gc, "now", js_std::date::now),
+            date_get_date: function(gc, "getDate", js_std::date::get_date),
+            date_get_day: function(gc, "getDay", js_std::date::get_day),
+            date_get_full_year: function(gc, "getFullYear", js_std::date::get_full_year),
+            date_get_hours: function(gc, "getHours", js_std::date::get_hours),
+            date_get_milliseconds: function(gc, "getMilliseconds", js_std::date::get_milliseconds),
+            date_get_minutes: function(gc, "getMinutes", js_std::date::get_minutes),
+            date_get_month: function(gc, "getMonth", js_std::date::get_month),
+            date_get_seconds: function(gc, "getSeconds", js_std::date::get_seconds),
+            date_get_time: function(gc, "getTime", js_std::date::get_time),
+            date_get_timezone_offset: function(gc, "getTimezoneOffset", js_std::date::get_timezone_offset),
+            date_get_utc_date: function(gc, "getUTCDate", js_std::date::get_utc_date),
+            date_get_utc_day: function(gc, "getUTCDay", js_std::date::get_utc_day),
+            date_get_utc_full_year: function(gc, "getUTCFullYear", js_std::date::get_utc_full_year),
+            date_get_utc_hours: function(gc, "getUTCHours", js_std::date::get_utc_hours),
+            date_get_utc_milliseconds: function(gc, "getUTCMilliseconds", js_std::date::get_utc_milliseconds),
+            date_get_utc_minutes: function(gc, "getUTCMinutes", js_std::date::get_utc_minutes),
+            date_get_utc_month: function(gc, "getUTCMonth", js_std::date::get_utc_month),
+            date_get_utc_seconds: function(gc, "getUTCSeconds", js_std::date::get_utc_seconds),
+            date_set_date: function(gc, "setDate", js_std::date::set_date),
+            date_set_full_year: function(gc, "setFullYear", js_std::date::set_full_year),
+            date_set_hours: function(gc, "setHours", js_std::date::set_hours),
+            date_set_milliseconds: function(gc, "setMilliseconds", js_std::date::set_milliseconds),
+            date_set_minutes: function(gc, "setMinutes", js_std::date::set_minutes),
+            date_set_month: function(gc, "setMonth", js_std::date::set_month),
+            date_set_seconds: function(gc, "setSeconds", js_std::date::set_seconds),
+            date_set_time: function(gc, "setTime", js_std::date::set_time),
+            date_set_utc_date: function(gc, "setUTCDate", js_std::date::set_utc_date),
+            date_set_utc_full_year: function(gc, "setUTCFullYear", js_std::date::set_utc_full_year),
+            date_set_utc_hours: function(gc, "setUTCHours", js_std::date::set_utc_hours),
+            date_set_utc_milliseconds: function(gc, "setUTCMilliseconds", js_std::date::set_utc_milliseconds),
+            date_set_utc_minutes: function(gc, "setUTCMinutes", js_std::date::set_utc_minutes),
+            date_set_utc_month: function(gc, "setUTCMonth", js_std::date::set_utc_month),
+            date_set_utc_seconds: function(gc, "setUTCSeconds", js_std::date::set_utc_seconds),
+            date_to_date_string: function(gc, "toDateString", js_std::date::to_date_string),
+            date_to_iso_string: function(gc, "toISOString", js_std::date::to_iso_string),
+            date_to_json: function(gc, "toJSON", js_std::date::to_json),
+            date_to_locale_date_string: function(gc, "toLocaleDateString", js_std::date::to_date_string),
+            date_to_locale_string: function(gc, "toLocaleString", js_std::date::to_string),
+            date_to_locale_time_string: function(gc, "toLocaleTimeString", js_std::date::to_time_string),
+            date_to_string: function(gc, "toString", js_std::date::to_string),
+            date_to_time_string: function(gc, "toTimeString", js_std::date::to_time_string),
+            date_to_utc_string: function(gc, "toUTCString", js_std::date::to_utc_string),
+            date_value_of: function(gc, "valueOf", js_std::date::value_of),
+            date_to_primitive: function(gc, "[Symbol.toPrimitive]", js_std::date::to_primitive),
+            date_parse: function(gc, "parse", js_std::date::parse),
+            date_utc: function(gc, "UTC", js_std::date::utc),
+            proxy_ctor: function(gc, "Proxy", js_std::proxy::constructor),
+            proxy_revocable: function(gc, "revocable", js_std::proxy::revocable),
+            reflect: empty_object(gc),
+            reflect_apply: function(gc, "apply", js_std::reflect::apply),
+            reflect_construct: function(gc, "construct", js_std::reflect::construct),
+            reflect_define_property: function(gc, "defineProperty", js_std::reflect::define_property),
+            reflect_delete_property: function(gc, "deleteProperty", js_std::reflect::delete_property),
+            reflect_get: function(gc, "get", js_std::reflect::get),
+            reflect_get_own_property_descriptor: function(
+                gc,
+                "getOwnPropertyDescriptor",
+                js_std::reflect::get_own_property_descriptor,
+            ),
+            reflect_get_prototype_of: function(gc, "getPrototypeOf", js_std::reflect::get_prototype_of),
+            reflect_has: function(gc, "has", js_std::reflect::has),
+            reflect_is_extensible: function(gc, "isExtensible", js_std::reflect::is_extensible),
+            reflect_own_keys: function(gc, "ownKeys", js_std::reflect::own_keys),
+            reflect_prevent_extensions: function(gc, "preventExtensions", js_std::reflect::prevent_extensions),
+            reflect_set: function(gc, "set", js_std::reflect::set),
+            reflect_set_prototype_of: function(gc, "setPrototypeOf", js_std::reflect::set_prototype_of),
         }
     }
 
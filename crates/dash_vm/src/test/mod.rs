@@ -1,11 +1,45 @@
 use dash_optimizer::OptLevel;
 
+use crate::eval::EvalError;
+use crate::local::LocalScope;
+use crate::params::VmParams;
+use crate::value::object::Object;
 use crate::value::primitive::Number;
 use crate::value::Value;
 use crate::Vm;
 
 const INTERPRETER: &str = include_str!("interpreter.js");
 
+/// Helpers that are available to every script evaluated by [`eval_in`]
+const PRELUDE: &str = r#"
+    globalThis.throws = function (f, type = TypeError) {
+        try {
+            f();
+            return false;
+        } catch (e) {
+            return e instanceof type;
+        }
+    };
+"#;
+
+/// Evaluates a script in `vm` and returns the string it evaluates to
+fn eval_in(vm: &mut Vm, source: &str) -> String {
+    // The prelude is evaluated separately so that it does not shift the line numbers of the script
+    vm.eval(PRELUDE, OptLevel::Basic).unwrap();
+    let value = vm.eval(source, OptLevel::Basic).unwrap();
+
+    assert_eq!(vm.stack.len(), 0);
+    match value {
+        Value::String(s) => s.to_string(),
+        _ => unreachable!("{:?}", value),
+    }
+}
+
+/// Evaluates a script in a new VM and returns the string it evaluates to
+fn eval_to_string(source: &str) -> String {
+    eval_in(&mut Vm::new(Default::default()), source)
+}
+
 #[test]
 fn interpreter() {
     let mut vm = Vm::new(Default::default());
@@ -18,3 +52,990 @@ fn interpreter() {
         _ => unreachable!("{:?}", value),
     }
 }
+
+#[test]
+fn date() {
+    // A fixed offset of UTC+1 makes local time deterministic
+    let params = VmParams::new().set_timezone_offset_callback(|_, _| Ok(3_600_000));
+    let mut vm = Vm::new(params);
+    let value = eval_in(
+        &mut vm,
+        r#"
+            const epoch = new Date(-86400000);
+            const local = new Date(2020, 0, 15, 10, 30);
+            local.setMinutes(45);
+            [
+                epoch.toISOString(),
+                epoch.toUTCString(),
+                1 / epoch.getUTCHours(),
+                epoch.getUTCDay(),
+                local.toISOString(),
+                local.getHours(),
+                local.getMinutes(),
+                local.getTimezoneOffset(),
+                Date.UTC(2000, 1, 29),
+                Date.parse("2000-02-29T12:00:00Z"),
+                new Date(Date.UTC(2021, 11, 31, 23)).getDate(),
+                [
+                    "2020-02-30T00:00:00Z",
+                    "2021-02-29",
+                    "2020-04-31",
+                    "1900-02-29"
+                ].map(s => isNaN(new Date(s).getTime())).join(":"),
+                Date.parse("2020-02-29") === Date.UTC(2020, 1, 29) && Date.parse("2020-12-31") === Date.UTC(2020, 11, 31)
+            ].join("|")
+        "#,
+    );
+    assert_eq!(value, "1969-12-31T00:00:00.000Z|Wed, 31 Dec 1969 00:00:00 GMT|Infinity|3|2020-01-15T09:45:00.000Z|10|45|-60|951782400000|951825600000|1|true:true:true:true|true");
+}
+
+#[test]
+fn json() {
+    let value = eval_to_string(
+        r#"
+            const cyclic = {};
+            cyclic.self = cyclic;
+            let error;
+            try {
+                JSON.stringify(cyclic);
+            } catch (e) {
+                error = e instanceof TypeError;
+            }
+            // `__proto__` members are own properties and never change the prototype
+            const proto = JSON.parse('{"__proto__":{"x":1}}');
+            const own = JSON.parse('{"__proto__":1}');
+            [
+                JSON.stringify(JSON.parse('{"a":[1,{"b":2}]}', (key, value) => typeof value === "number" ? value * 10 : value)),
+                JSON.stringify({ a: 1, b: "2" }, (key, value) => key === "b" ? undefined : value),
+                JSON.stringify({ a: 1, b: 2, c: { a: 3, d: 4 } }, ["c", "a"]),
+                JSON.stringify([1, { a: [] }, "x"], null, 2),
+                JSON.stringify({ d: { toJSON(key) { return "json:" + key; } } }),
+                JSON.stringify([undefined, function () {}, NaN, "\n"]),
+                error,
+                proto.x === undefined && Reflect.getPrototypeOf(proto) === Object.prototype,
+                Object.keys(own) + ":" + own.__proto__,
+                JSON.parse('{"constructor":2}').constructor
+            ].join("|")
+        "#,
+    );
+    assert_eq!(value, "{\"a\":[10,{\"b\":20}]}|{\"a\":1}|{\"c\":{\"a\":3},\"a\":1}|[\n  1,\n  {\n    \"a\": []\n  },\n  \"x\"\n]|{\"d\":\"json:d\"}|[null,null,null,\"\\n\"]|true|true|__proto__:1|2");
+}
+
+#[test]
+fn classes() {
+    let value = eval_to_string(
+        r#"
+            class A {
+                constructor(x) {
+                    this.x = x;
+                }
+                get() {
+                    return "A" + this.x;
+                }
+                static s() {
+                    return "sA";
+                }
+            }
+            class B extends A {
+                y = 5;
+                constructor(x) {
+                    const init = () => super(x * 2);
+                    init();
+                    this.z = this.x + this.y;
+                }
+                get() {
+                    return "B" + super.get();
+                }
+                static s() {
+                    return "sB" + super.s();
+                }
+            }
+            class C extends A {
+                constructor() {
+                    this.x = 1;
+                }
+            }
+            class D extends A {
+                constructor() {
+                    super(1);
+                    super(2);
+                }
+            }
+            class E extends Array {}
+            // Getters reached through `super` see the current `this`
+            class F {
+                get v() { return this.k; }
+                static get s() { return this.n; }
+            }
+            class G extends F {
+                k = 1;
+                static n = 2;
+                get v() { return super.v + super["v"]; }
+                static get s() { return super.s; }
+            }
+
+            const b = new B(1);
+            const errors = [C, D].map(Class => {
+                try {
+                    new Class();
+                } catch (e) {
+                    return e instanceof ReferenceError;
+                }
+            });
+            const array = new E();
+            array.push(1, 2);
+            [b.x, b.y, b.z, b.get(), B.s(), b instanceof A, errors, array.length, array instanceof E, new G().v, G.s].join(",")
+        "#,
+    );
+    assert_eq!(value, "2,5,7,BA2,sBsA,true,true,true,2,true,2,2");
+
+    // Compile errors inside of nested functions and blocks are reported instead of leaving the compiler in a bad state
+    let mut vm = Vm::new(Default::default());
+    for source in [
+        "function f() { super(); }",
+        "class X extends Object { m() { { const f = () => super(); } } }",
+        "class X { constructor() { { super(); } } }",
+        "{ function f() { { break foo; } } }",
+        "class X { m() { { function g() { return this.#x; } } } }",
+    ] {
+        assert!(
+            matches!(vm.eval(source, OptLevel::Basic), Err(EvalError::Compiler(_))),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn proxy_reflect() {
+    let value = eval_to_string(
+        r#"
+            const target = { a: 1 };
+            Reflect.defineProperty(target, "fixed", { value: 1, writable: false, configurable: false });
+
+            const logging = new Proxy(target, {
+                get(t, key, receiver) { return key === "fixed" ? t.fixed : key + "!"; },
+                has(t, key) { return key === "fixed" || key === "virtual"; }
+            });
+            const lying = new Proxy(target, {
+                get() { return 2; },
+                has() { return false; },
+                ownKeys() { return ["a"]; },
+                getOwnPropertyDescriptor() { return undefined; },
+                deleteProperty() { return true; }
+            });
+
+            const sealed = { x: 1 };
+            const extensibility = [Reflect.isExtensible(sealed), Reflect.preventExtensions(sealed), Reflect.isExtensible(sealed)];
+            sealed.y = 2;
+            const lyingExtensible = new Proxy(sealed, { isExtensible() { return true; } });
+            const extraKeys = new Proxy(sealed, { ownKeys() { return ["x", "y"]; } });
+
+            const { proxy, revoke } = Proxy.revocable({ value: 3 }, {});
+            const beforeRevoke = proxy.value;
+            revoke();
+
+            [
+                logging.foo,
+                logging.fixed,
+                "virtual" in logging,
+                "a" in logging,
+                Reflect.has(target, "fixed"),
+                extensibility.join(":"),
+                sealed.y === undefined,
+                throws(() => lying.fixed),
+                throws(() => "fixed" in lying),
+                throws(() => Reflect.ownKeys(lying)),
+                throws(() => Reflect.getOwnPropertyDescriptor(lying, "fixed")),
+                throws(() => Reflect.deleteProperty(lying, "fixed")),
+                throws(() => Reflect.isExtensible(lyingExtensible)),
+                throws(() => Reflect.ownKeys(extraKeys)),
+                beforeRevoke,
+                throws(() => proxy.value),
+                throws(() => new Proxy({}, { get: 1 }).x)
+            ].join(",")
+        "#,
+    );
+    assert_eq!(
+        value,
+        "foo!,1,true,false,true,true:true:false,true,true,true,true,true,true,true,true,3,true,true"
+    );
+}
+
+#[test]
+fn proxy_reflect_receiver() {
+    let value = eval_to_string(
+        r#"
+            const getter = { get x() { return this.y; }, y: 1 };
+            const setter = { set x(v) { this.z = v; } };
+            const data = { a: 1 };
+            const receiver = { y: 2 };
+
+            let seen;
+            const trapping = new Proxy({}, {
+                get(t, key, r) { seen = r; return 1; },
+                set(t, key, value, r) { seen = r; return true; }
+            });
+            const forwarding = new Proxy({ get self() { return this; }, set v(x) { this.w = x; } }, {});
+
+            const receivers = [];
+            trapping.q;
+            receivers.push(seen === trapping);
+            Reflect.get(trapping, "q", receiver);
+            receivers.push(seen === receiver);
+            Reflect.set(trapping, "q", 1, receiver);
+            receivers.push(seen === receiver);
+
+            [
+                Reflect.get(getter, "x"),
+                Reflect.get(getter, "x", receiver),
+                Reflect.set(setter, "x", 3, receiver),
+                receiver.z,
+                "z" in setter,
+                Reflect.set(data, "a", 5, receiver),
+                data.a,
+                receiver.a,
+                Reflect.set(data, "a", 1, 5),
+                receivers.join(":"),
+                forwarding.self === forwarding,
+                Reflect.get(forwarding, "self", receiver) === receiver,
+                Reflect.set(forwarding, "v", 7, receiver),
+                receiver.w
+            ].join(",")
+        "#,
+    );
+    assert_eq!(value, "1,2,true,3,false,true,1,5,false,true:true:true,true,true,true,7");
+}
+
+#[test]
+fn regex() {
+    let value = eval_to_string(
+        r#"
+            const match = /(\d+)-(?<rest>\d+)/.exec("tel 12-345");
+            [
+                /(a|b)*c/.test("ab".repeat(5000) + "c"),
+                match.index,
+                match[1],
+                match.groups.rest,
+                String(/a/g),
+                new RegExp("a/b", "yi").toString(),
+                /x/gimsuy.flags,
+                RegExp.prototype.source,
+                String(new RegExp(""))
+            ].join(",")
+        "#,
+    );
+    assert_eq!(value, "true,4,12,345,/a/g,/a\\/b/iy,gimsuy,(?:),/(?:)/");
+}
+
+#[test]
+fn string_regex_methods() {
+    let value = eval_to_string(
+        r##"
+            const s = "The year 1999 and 2024";
+            [
+                s.match(/\d+/)[0],
+                s.match(/\d+/g).join("|"),
+                s.match(/xyz/) === null,
+                s.replace(/(\d+)/, "<$1>"),
+                s.replace(/\d+/g, m => m.length),
+                s.replace(/(?<y>\d{2})(\d{2})/g, "$2$<y>"),
+                s.replaceAll(/\d/g, "#"),
+                "a1b22c333".split(/\d+/).join(","),
+                "a,b;c".split(/([,;])/).join(" "),
+                "abc".split(/(?:)/).length,
+                s.search(/\d/),
+                s.search(/z/),
+                [..."a1b2".matchAll(/[a-z](\d)/g)].map(m => m[1] + "@" + m.index).join(";"),
+                throws(() => s.replaceAll(/\d/, "#")),
+                throws(() => s.matchAll(/\d/))
+            ].join("/")
+        "##,
+    );
+    assert_eq!(
+        value,
+        "1999/1999|2024/true/The year <1999> and 2024/The year 4 and 4/The year 9919 and 2420/\
+             The year #### and ####/a,b,c,/a , b ; c/3/9/-1/1@0;2@2/true/true"
+    );
+}
+
+#[test]
+fn symbols() {
+    let value = eval_to_string(
+        r#"
+            const a = Symbol("a");
+            const b = Symbol("a");
+            const o = { [a]: 1 };
+            [
+                a === a,
+                a === b,
+                String(a),
+                a.description,
+                Symbol().description === undefined,
+                typeof a,
+                o[a],
+                o[b] === undefined,
+                Symbol.for("app") === Symbol.for("app"),
+                Symbol.for("app") === Symbol("app"),
+                Symbol.for("app").description,
+                Symbol.keyFor(Symbol.for("app")),
+                Symbol.keyFor(a) === undefined,
+                Symbol.keyFor(Symbol.iterator) === undefined,
+                throws(() => Symbol.keyFor("app")),
+                throws(() => new Symbol()),
+                typeof Reflect.getOwnPropertyDescriptor(Symbol.prototype, "description").get,
+                Reflect.getOwnPropertyDescriptor(Symbol.prototype, "description").get.call(a),
+                throws(() => Reflect.getOwnPropertyDescriptor(Symbol.prototype, "description").get.call({})),
+                (() => { try { a(); } catch (e) { return e.message; } })()
+            ].join(",")
+        "#,
+    );
+    assert_eq!(
+        value,
+        "true,false,Symbol(a),a,true,symbol,1,true,true,false,app,app,true,true,true,true,function,a,true,Symbol(a) is not a function"
+    );
+}
+
+#[test]
+fn weak_references() {
+    let mut vm = Vm::new(Default::default());
+    let value = eval_in(
+        &mut vm,
+        r#"
+            const map = new WeakMap();
+            const key = {};
+            globalThis.ref = new WeakRef({});
+            (function () {
+                const inner = {};
+                map.set(key, inner);
+                map.set({}, {});
+                map.set(inner, { value: 42 });
+            })();
+
+            // allocate enough garbage to trigger a gc cycle
+            for (let i = 0; i < 20000; i++) {
+                const garbage = { i };
+            }
+
+            // The target of a WeakRef is kept alive until the end of the job that created it
+            String(map.get(map.get(key)).value + (ref.deref() === undefined ? 0 : 1))
+        "#,
+    );
+    assert_eq!(value, "43");
+
+    // Once the microtask checkpoint has passed, the target can be collected
+    vm.process_async_tasks();
+    vm.perform_gc();
+    let value = eval_in(&mut vm, "String(globalThis.ref.deref() === undefined)");
+    assert_eq!(value, "true");
+}
+
+#[test]
+fn stack_trace_positions() {
+    let value = eval_to_string(
+        r#"
+function inner() {
+    return new Error("boom");
+}
+function outer() {
+    return inner();
+}
+outer().stack
+"#,
+    );
+    assert_eq!(
+        value,
+        "Error: boom\n  at inner (script.js:3:16)\n  at outer (script.js:6:12)\n  at <anonymous> (script.js:8:1)"
+    );
+
+    let value = eval_to_string(
+        r#"
+const p = new Map();
+function stack(f) {
+    try {
+        f();
+    } catch (e) {
+        return e.stack.split("\n")[1];
+    }
+}
+[
+    stack(() => { foo(p, "a"); }),
+    stack(() => { return 1n +
+        1; }),
+    stack(() => { return "x" in 1; })
+].join("|")
+"#,
+    );
+    assert_eq!(
+        value,
+        "  at <anonymous> (script.js:11:19)|  at <anonymous> (script.js:12:29)|  at <anonymous> (script.js:14:30)"
+    );
+}
+
+#[test]
+fn spread() {
+    let value = eval_to_string(
+        r#"
+            function sum(a, b, c, d) {
+                return a + b + c + d;
+            }
+            function count(...args) {
+                return args.length;
+            }
+            class Point {
+                constructor(...args) {
+                    this.size = args.length;
+                }
+            }
+
+            const arr = [1, 2];
+            const base = { a: 1, b: 2 };
+            const accessor = {
+                get x() {
+                    return 5;
+                }
+            };
+            const copy = { ...base, b: 3, ...null, ...undefined, c: 4 };
+            const chars = { ..."hi" };
+            const { ...rest } = "ab";
+            const many = [];
+            for (let i = 0; i < 100; i++) many.push(i);
+            [
+                sum(...arr, ...[3, 4]),
+                sum(0, ...arr, 5),
+                count(...[]),
+                count(..."abc"),
+                count(...many, 1),
+                [...arr, 3, ...arr].join(""),
+                [..."hi"].join("-"),
+                copy.a + copy.b + copy.c,
+                base.b,
+                { b: 0, ...base }.b,
+                { ...[5, 6] }[1],
+                { ...accessor }.x,
+                Reflect.ownKeys(chars).length + chars[0] + chars[1],
+                rest[0] + rest[1] + rest.length,
+                Math.max(...[1, 5, 3]),
+                new Point(...arr, ...arr).size
+            ].join(",")
+        "#,
+    );
+    assert_eq!(value, "10,8,0,3,101,12312,h-i,8,2,2,6,5,2hi,abundefined,5,4");
+}
+
+#[test]
+fn optional_chaining() {
+    let value = eval_to_string(
+        r#"
+            const o = { a: { b: 1, f() { return this.b; } }, n: null };
+            let calls = 0;
+            o.n?.b.c(calls++);
+            o.n?.[calls++];
+            [o?.a.b, o.n?.b, o.a?.f(), o.a.f?.(), o.a.g?.(), o?.["a"]?.["b"], calls]
+                .map(v => v === undefined ? "-" : v)
+                .join(",")
+        "#,
+    );
+    assert_eq!(value, "1,-,1,1,-,1,0");
+}
+
+#[test]
+fn parameter_destructuring() {
+    let value = eval_to_string(
+        r#"
+            function f({ x, y = 2, ...r }, [p, , q = 5, ...t] = []) {
+                return [x, y, r.z + r.w + (r.x === undefined), p, q, t.length].join(",");
+            }
+            function g(a, ...[b, c]) {
+                return a + b + c;
+            }
+            const count = (...r) => r.length;
+            const sum = (a, ...[b, c = 10]) => a + b + c;
+            let caught;
+            try { throw { message: "boom" }; } catch ({ message }) { caught = message; }
+            [
+                f({ x: 1, z: 3, w: 4 }, [10, 11, undefined, 13, 14]),
+                (([a, { b }]) => a + b)([1, { b: 2 }]),
+                caught,
+                g(1, 2, 3, 4),
+                count(1, 2, 3),
+                count(),
+                sum(1, 2)
+            ].join(";")
+        "#,
+    );
+    assert_eq!(value, "1,2,8,10,5,2;3;boom;6;3;0;13");
+}
+
+#[test]
+fn destructuring() {
+    let value = eval_to_string(
+        r#"
+            function* gen() { yield 1; yield 2; yield 3; yield 4; }
+            const { a, b: { c = 3 } = {}, ...rest } = { a: 1, d: 4, e: 5 };
+            const [x, , ...tail] = gen();
+            let m = 1, n = 2;
+            [m, n] = [n, m];
+            const o = {};
+            ({ d: o.d, e: o["e"] } = rest);
+            [a, c, rest.d + rest.e, x, tail.join(""), m, n, o.d, o.e].join(",")
+        "#,
+    );
+    assert_eq!(value, "1,3,9,1,34,2,1,4,5");
+}
+
+#[test]
+fn member_assignment() {
+    let value = eval_to_string(
+        r#"
+            const order = [];
+            const o = { x: 5, n: 1 };
+            const step = (name, value) => (order.push(name), value);
+            step("t", o)[step("k", "x")] += step("v", 2);
+            const arr = [1, 2, 3];
+            let i = 0;
+            arr[i++] *= 10;
+            const post = arr[i]--;
+            const pre = ++o.n;
+            (o.n) **= 3;
+            [order.join(""), o.x, arr.join(","), i, post, pre, o.n].join(";")
+        "#,
+    );
+    assert_eq!(value, "tkv;7;10,1,3;1;2;2;8");
+}
+
+#[test]
+fn logical_assignment() {
+    let value = eval_to_string(
+        r#"
+            let calls = 0;
+            const f = (v) => (calls++, v);
+            let a = 0, b = 1, c = null;
+            a ||= f(2); b ||= f(3); c ??= f(4); b &&= f(5);
+            const o = { x: 0, y: null };
+            let evaluations = 0;
+            const t = () => (evaluations++, o);
+            t().x ||= 6; t()["y"] ??= 7; t().x &&= 8; t()["y"] ??= f(9);
+            [a, b, c, o.x, o.y, calls, evaluations].join(",")
+        "#,
+    );
+    assert_eq!(value, "2,5,4,8,7,3,4");
+}
+
+#[test]
+fn labels() {
+    let value = eval_to_string(
+        r#"
+            const out = [];
+            outer: for (let i = 0; i < 3; i++) {
+                for (let j = 0; j < 3; j++) {
+                    if (j === 1) continue outer;
+                    if (i === 2) break outer;
+                    out.push(i + "" + j);
+                }
+            }
+            block: {
+                out.push("a");
+                break block;
+                out.push("b");
+            }
+            let k = 0;
+            while (k < 4) {
+                k++;
+                switch (k) {
+                    case 2: continue;
+                    case 3: break;
+                }
+                out.push(k);
+            }
+            out.join(",")
+        "#,
+    );
+    assert_eq!(value, "00,10,a,1,3,4");
+
+    // Nesting a label inside of a statement with the same label is an early error
+    let mut vm = Vm::new(Default::default());
+    for source in ["foo: foo: for (;;) {}", "foo: { bar: { foo: ; } }"] {
+        assert!(
+            matches!(vm.eval(source, OptLevel::Basic), Err(EvalError::Compiler(_))),
+            "{source}"
+        );
+    }
+
+    // Labels are scoped to their function, and sibling statements can reuse a label
+    let value = eval_to_string("foo: { function f() { foo: ; } } foo: ; bar: { baz: ; } bar: ; 'ok'");
+    assert_eq!(value, "ok");
+}
+
+#[test]
+fn try_finally() {
+    let value = eval_to_string(
+        r#"
+            const log = [];
+            function returns() { try { return "try"; } finally { log.push("a"); } }
+            function overrides() { try { return "try"; } finally { return "finally"; } }
+            function rethrows() { try { throw 1; } catch (e) { throw e + 1; } finally { log.push("b"); } }
+            log.push(returns(), overrides());
+            try { rethrows(); } catch (e) { log.push(e); }
+            for (let i = 0; i < 3; i++) {
+                try {
+                    if (i === 0) continue;
+                    if (i === 2) break;
+                } finally {
+                    log.push("c" + i);
+                }
+            }
+            try {
+                try { throw 1; } catch {}
+                throw 2;
+            } catch (e) {
+                log.push(e);
+            }
+            log.join(",")
+        "#,
+    );
+    assert_eq!(value, "a,try,finally,b,2,c0,c1,c2,2");
+}
+
+#[test]
+fn in_operator() {
+    let value = eval_to_string(
+        r#"
+            const s = Symbol();
+            const o = { a: 1, [s]: 2 };
+            const log = ["a" in o, "b" in o, s in o, "toString" in o, 0 in [1], 1 in [1]];
+            try { "a" in 1; } catch (e) { log.push(e instanceof TypeError); }
+            class A {
+                #x;
+                static has(o) { return #x in o; }
+            }
+            const a = new A();
+            log.push(A.has(a), A.has({}), A.has(Object.create(a)));
+            // Brand checks never look through proxies, and never call any of their traps
+            const traps = [];
+            const handler = {};
+            for (const name of ["has", "get", "getOwnPropertyDescriptor", "getPrototypeOf", "ownKeys"]) {
+                handler[name] = function () { traps.push(name); };
+            }
+            log.push(A.has(new Proxy(a, handler)), traps.length);
+            log.join(",")
+        "#,
+    );
+    assert_eq!(value, "true,false,true,true,true,false,true,true,false,false,false,0");
+}
+
+#[test]
+fn class_members() {
+    let value = eval_to_string(
+        r#"
+            const log = [];
+            class A {
+                #x = 1;
+                static #count = 0;
+                static initial = this.name;
+                constructor() { A.#count++; }
+                #double() { return this.#x * 2; }
+                get x() { return this.#double(); }
+                set x(v) { this.#x = v; }
+                static count() { return A.#count; }
+                static read(o) { return o.#x; }
+                static write(o) { o.#x = 2; }
+                static call(o) { return o.#double(); }
+                static { log.push("static " + this.initial); }
+            }
+            const a = new A();
+            a.x = 5;
+            log.push(a.x, A.count(), Object.keys(a).length, Reflect.ownKeys(a).length);
+            log.push(throws(() => A.read({})));
+
+            // Private members are not forwarded to the target of a proxy, nor are they visible to its traps
+            const traps = [];
+            const handler = {};
+            for (const name of ["get", "set", "has", "getOwnPropertyDescriptor", "defineProperty", "ownKeys"]) {
+                handler[name] = function (t, k, v) { traps.push(name); return Reflect[name](t, k, v); };
+            }
+            const p = new Proxy(a, handler);
+            log.push(throws(() => A.read(p)), throws(() => A.write(p)), throws(() => A.call(p)), traps.length);
+
+            // A base constructor can return a proxy, which then gets private fields of its own
+            class Base { constructor(o) { return o; } }
+            class Stamp extends Base {
+                #y = 7;
+                static read(o) { return o.#y; }
+            }
+            const target = {};
+            const stamped = new Proxy(target, handler);
+            new Stamp(stamped);
+            log.push(Stamp.read(stamped), throws(() => Stamp.read(target)), traps.length);
+            log.join(",")
+        "#,
+    );
+    assert_eq!(value, "static A,10,1,0,0,true,true,true,true,0,7,true,0");
+}
+
+#[test]
+fn tagged_templates() {
+    let value = eval_to_string(
+        r#"
+            function tag(strings, ...values) { return strings; }
+            function site(x) { return tag`a${x}b\n`; }
+            const strings = site(1);
+            strings[0] = "z";
+            let pushed;
+            try { strings.push("x"); } catch (e) { pushed = e instanceof TypeError; }
+            strings.length = 0;
+            strings.raw[0] = "z";
+            const element = Reflect.getOwnPropertyDescriptor(strings, 0);
+            const o = { tag(strings, value) { return this === o && strings[0] + value; } };
+            const invalid = tag`\unicode${1}\x4${2}\1${3}\x41\u{42}\u0043`;
+            [
+                strings === site(2),
+                strings[0],
+                strings[1] === "b\n",
+                strings.raw[1],
+                pushed,
+                site().length,
+                strings.raw[0],
+                Reflect.set(strings, 0, "z"),
+                delete strings[0],
+                element.writable || element.configurable,
+                Reflect.isExtensible(strings) || Reflect.isExtensible(strings.raw),
+                o.tag`x${1}`,
+                String.raw`a\n${2}b`,
+                invalid[0] === undefined && invalid[1] === undefined && invalid[2] === undefined,
+                invalid[3],
+                invalid.raw[0]
+            ].join(",")
+        "#,
+    );
+    assert_eq!(
+        value,
+        "true,a,true,b\\n,true,2,a,false,false,false,false,x1,a\\n2b,true,ABC,\\unicode"
+    );
+
+    let mut vm = Vm::new(Default::default());
+    let source = "`\\unicode`";
+    assert!(matches!(vm.eval(source, OptLevel::Basic), Err(EvalError::Parser(_))));
+}
+
+#[test]
+fn bigint() {
+    let value = eval_to_string(
+        r#"
+            const id = 9007199254740993n;
+            let mixed;
+            try { id + 1; } catch (e) { mixed = e instanceof TypeError; }
+            const array = new BigInt64Array(new ArrayBuffer(8));
+            array[0] = -2n;
+            [
+                id + 2n,
+                typeof id,
+                2n ** 64n / 3n,
+                -7n % 2n,
+                ~5n & -8n,
+                1n << 65n,
+                1n < 2 && 2n == 2 && 2n !== 2,
+                mixed,
+                BigInt("0xff"),
+                BigInt.asIntN(8, 255n),
+                BigInt.asUintN(64, -1n),
+                (255n).toString(16),
+                array[0],
+                BigInt.asUintN(2 ** 40, 5n),
+                BigInt.asIntN(2 ** 40, -5n),
+                throws(() => BigInt.asUintN(2 ** 40, -5n), RangeError),
+                1n ** 10000000000n,
+                (-1n) ** 10000000001n,
+                0n ** 10000000000n,
+                throws(() => 2n ** 10000000000n, RangeError)
+            ].join(",")
+        "#,
+    );
+    assert_eq!(
+        value,
+        "9007199254740995,bigint,6148914691236517205,-1,-8,36893488147419103232,true,true,255,-1,18446744073709551615,ff,-2,5,-5,true,1,-1,0,true"
+    );
+}
+
+#[test]
+fn async_generators() {
+    let mut vm = Vm::new(Default::default());
+    vm.eval(
+        r#"
+            async function* numbers() {
+                try {
+                    yield 1;
+                    yield Promise.resolve(2);
+                    yield await Promise.resolve(3);
+                } finally {
+                    finallyCount++;
+                }
+            }
+
+            globalThis.finallyCount = 0;
+            (async function () {
+                const values = [];
+                for await (const value of numbers()) values.push(value);
+                for await (const value of [Promise.resolve(4), 5]) values.push(value);
+
+                const iterator = numbers();
+                const pending = [iterator.next(), iterator.return(6), iterator.next()];
+                for (const promise of pending) {
+                    const { value, done } = await promise;
+                    values.push(value + ":" + done);
+                }
+
+                const throwing = numbers();
+                await throwing.next();
+                try {
+                    await throwing.throw(new Error("thrown"));
+                } catch (e) {
+                    values.push(e.message);
+                }
+
+                globalThis.result = values.join(",") + "," + finallyCount;
+            })();
+        "#,
+        OptLevel::Basic,
+    )
+    .unwrap();
+    vm.process_async_tasks();
+
+    let value = eval_in(&mut vm, "result");
+    assert_eq!(value, "1,2,3,4,5,1:false,6:true,undefined:true,thrown,3");
+}
+
+#[test]
+fn generator_protocol() {
+    let value = eval_to_string(
+        r#"
+            const log = [];
+            function* numbers() {
+                try {
+                    yield 1;
+                    yield 2;
+                    yield 3;
+                } finally {
+                    log.push("closed");
+                }
+            }
+
+            const returned = numbers();
+            returned.next();
+            log.push(returned.return(4).value, returned.next().done);
+
+            const thrown = numbers();
+            thrown.next();
+            try {
+                thrown.throw("error");
+            } catch (e) {
+                log.push(e);
+            }
+
+            function* delegate() {
+                const received = yield* [5, 6];
+                log.push(received);
+                while (true) log.push(yield* numbers());
+            }
+            const delegating = delegate();
+            log.push(delegating.next().value, delegating.next("ignored").value, delegating.next().value);
+            log.push(delegating.return(7).value);
+
+            for (const value of numbers()) {
+                if (value === 2) break;
+            }
+
+            function closing(result) {
+                return {
+                    [Symbol.iterator]() {
+                        return { next() { return { value: 1, done: false }; }, return() { return result; } };
+                    }
+                };
+            }
+            log.push(
+                throws(() => { for (const value of closing(1)) break; }),
+                throws(() => { for (const value of closing(null)) break; }),
+                throws(() => { for (const value of closing(1)) throw new RangeError(); }, RangeError),
+                (() => { for (const value of closing({})) return 8; })()
+            );
+            log.join(",");
+        "#,
+    );
+    assert_eq!(
+        value,
+        "closed,4,true,closed,error,undefined,5,6,1,closed,7,closed,true,true,true,8"
+    );
+}
+
+#[test]
+fn promise_combinators() {
+    let mut vm = Vm::new(Default::default());
+    vm.eval(
+        r#"
+            const log = [];
+            Promise.resolve(1)
+                .then(() => {})
+                .then(value => log.push("then:" + value));
+            Promise.reject("error")
+                .catch(reason => reason)
+                .finally(() => log.push("finally"))
+                .then(value => log.push("catch:" + value));
+            queueMicrotask(() => log.push("microtask"));
+            Promise.resolve({ then: resolve => resolve("thenable") }).then(value => log.push(value));
+            Promise.all([1, Promise.resolve(2), new Promise(resolve => resolve(3))]).then(values => log.push("all:" + values));
+            Promise.allSettled([Promise.reject(4)]).then(([result]) => log.push(result.status + ":" + result.reason));
+            Promise.any([Promise.reject(5)]).catch(error => log.push("any:" + error.errors));
+            Promise.race([new Promise(() => {}), 6]).then(value => log.push("race:" + value));
+
+            const { promise, resolve } = Promise.withResolvers();
+            promise.then(value => {
+                globalThis.result = log.join(",");
+            });
+            Promise.all([1, 2, 3, 4].map(() => Promise.resolve())).then(() => resolve());
+        "#,
+        OptLevel::Basic,
+    )
+    .unwrap();
+    vm.process_async_tasks();
+
+    let value = eval_in(&mut vm, "result");
+    assert_eq!(
+        value,
+        "microtask,then:undefined,finally,thenable,all:1,2,3,rejected:4,any:5,race:6"
+    );
+}
+
+#[test]
+fn unhandled_rejections() {
+    fn report(scope: &mut LocalScope, kind: &str, reason: Value) {
+        let global = scope.global();
+        let report = global.get_property(scope, "report".into()).unwrap();
+        report
+            .apply(scope, Value::undefined(), vec![Value::String(kind.into()), reason])
+            .unwrap();
+    }
+
+    let params = VmParams::new()
+        .set_promise_rejection_unhandled_callback(|scope, reason| report(scope, "unhandled", reason))
+        .set_promise_rejection_handled_callback(|scope, reason| report(scope, "handled", reason));
+    let mut vm = Vm::new(params);
+    vm.eval(
+        r#"
+            globalThis.log = [];
+            globalThis.report = function (kind, reason) {
+                log.push(kind + ":" + reason);
+            };
+            Promise.reject(1).catch(() => {});
+            Promise.reject(2);
+            globalThis.late = Promise.reject(3);
+            (async function () { throw 4; })().then(() => {});
+        "#,
+        OptLevel::Basic,
+    )
+    .unwrap();
+    vm.process_async_tasks();
+    vm.eval("late.catch(() => {})", OptLevel::Basic).unwrap();
+    vm.process_async_tasks();
+
+    let value = eval_in(&mut vm, "log.join(',')");
+    assert_eq!(value, "unhandled:2,unhandled:3,unhandled:4,handled:3");
+}
@@ -12,6 +12,8 @@ use crate::Vm;
 
 use super::object::NamedObject;
 use super::object::Object;
+use super::object::PrivateElements;
+use super::object::PropertyDataDescriptor;
 use super::object::PropertyKey;
 use super::object::PropertyValue;
 use super::object::PropertyValueKind;
@@ -25,6 +27,15 @@ pub const MAX_LENGTH: usize = 4294967295;
 pub struct Array {
     items: RefCell<Vec<PropertyValue>>,
     obj: NamedObject,
+    /// Whether assigning to `length` can change the length of this array, which is not the case for frozen arrays
+    length_writable: Cell<bool>,
+}
+
+/// Checks whether an element can neither be assigned to nor be redefined or deleted
+fn is_frozen_element(element: &PropertyValue) -> bool {
+    !element
+        .descriptor()
+        .intersects(PropertyDataDescriptor::WRITABLE | PropertyDataDescriptor::CONFIGURABLE)
 }
 
 fn get_named_object(vm: &mut Vm) -> NamedObject {
@@ -36,6 +47,7 @@ impl Array {
         Array {
             items: RefCell::new(Vec::new()),
             obj: get_named_object(vm),
+            length_writable: Cell::new(true),
         }
     }
 
@@ -43,6 +55,7 @@ impl Array {
         Array {
             items: RefCell::new(values),
             obj: get_named_object(vm),
+            length_writable: Cell::new(true),
         }
     }
 
@@ -50,7 +63,22 @@ impl Array {
         Self {
             items: RefCell::new(Vec::new()),
             obj,
+            length_writable: Cell::new(true),
+        }
+    }
+
+    /// Freezes this array, so that its elements and its length can no longer be changed
+    /// and no other properties can be added to it
+    pub fn freeze(&self, sc: &mut LocalScope) -> Result<(), Value> {
+        for element in self.items.borrow_mut().iter_mut() {
+            let (kind, descriptor) = element.clone().into_parts();
+            let descriptor = descriptor - (PropertyDataDescriptor::WRITABLE | PropertyDataDescriptor::CONFIGURABLE);
+            *element = PropertyValue::new(kind, descriptor);
         }
+
+        self.length_writable.set(false);
+        self.obj.prevent_extensions(sc)?;
+        Ok(())
     }
 
     pub fn inner(&self) -> &RefCell<Vec<PropertyValue>> {
@@ -68,7 +96,15 @@ impl Object for Array {
 
         if let PropertyKey::String(key) = &key {
             if key == "length" {
-                return Ok(Some(PropertyValue::static_default(Value::number(items.len() as f64))));
+                let descriptor = match self.length_writable.get() {
+                    true => PropertyDataDescriptor::WRITABLE,
+                    false => PropertyDataDescriptor::empty(),
+                };
+
+                return Ok(Some(PropertyValue::new(
+                    PropertyValueKind::Static(Value::number(items.len() as f64)),
+                    descriptor,
+                )));
             }
 
             if let Ok(index) = key.parse::<usize>() {
@@ -96,14 +132,31 @@ impl Object for Array {
                     throw!(sc, RangeError, "Invalid array length");
                 }
 
-                items.resize(new_len, PropertyValue::static_default(Value::undefined()));
+                if !self.length_writable.get() {
+                    return Ok(());
+                }
+
+                // Shrinking the array stops at the last element that cannot be deleted
+                let min_len = items
+                    .iter()
+                    .rposition(|element| !element.descriptor().contains(PropertyDataDescriptor::CONFIGURABLE))
+                    .map_or(0, |index| index + 1);
+
+                items.resize(new_len.max(min_len), PropertyValue::static_default(Value::undefined()));
                 return Ok(());
             }
 
             if let Ok(index) = key.parse::<usize>() {
                 if index < MAX_LENGTH {
                     if index >= items.len() {
+                        // Adding elements to non-extensible arrays silently fails, as in sloppy mode
+                        if !self.obj.is_extensible(sc)? {
+                            return Ok(());
+                        }
+
                         items.resize(index + 1, PropertyValue::static_default(Value::undefined()));
+                    } else if is_frozen_element(&items[index]) {
+                        return Ok(());
                     }
 
                     items[index] = value;
@@ -125,6 +178,11 @@ impl Object for Array {
                 let mut items = self.items.borrow_mut();
 
                 if let Some(item) = items.get_mut(index) {
+                    // Non-configurable elements cannot be deleted
+                    if !item.descriptor().contains(PropertyDataDescriptor::CONFIGURABLE) {
+                        return Ok(Value::undefined());
+                    }
+
                     let old = std::mem::replace(item, PropertyValue::static_default(Value::null()));
                     return Ok(match old.into_kind() {
                         PropertyValueKind::Static(value) => value,
@@ -159,10 +217,22 @@ impl Object for Array {
         self.obj.get_prototype(sc)
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         let items = self.items.borrow();
         Ok(array_like_keys(items.len()).collect())
     }
+
+    fn is_extensible(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        self.obj.is_extensible(sc)
+    }
+
+    fn prevent_extensions(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        self.obj.prevent_extensions(sc)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        self.obj.private_elements()
+    }
 }
 
 #[derive(Debug, Trace)]
@@ -259,9 +329,16 @@ pub fn spec_array_set_property(
 ) -> Result<(), Value> {
     // specialize array path
     if let Some(arr) = target.downcast_ref::<Array>() {
+        let extensible = arr.obj.is_extensible(scope)?;
         let mut inner = arr.inner().borrow_mut();
 
-        if index < MAX_LENGTH {
+        // Elements of frozen or non-extensible arrays are handled by `set_property`, which leaves them untouched
+        let writable = match inner.get(index) {
+            Some(element) => !is_frozen_element(element),
+            None => extensible,
+        };
+
+        if index < MAX_LENGTH && writable {
             if index >= inner.len() {
                 inner.resize(index + 1, PropertyValue::static_default(Value::undefined()));
             }
@@ -62,7 +62,8 @@ impl Object for ArrayBuffer {
         delete_property,
         set_prototype,
         get_prototype,
-        own_keys // TODO: byteLength
+        own_keys, // TODO: byteLength
+        private_elements
     );
 
     fn apply(
@@ -13,6 +13,7 @@ use std::rc::Rc;
 
 use super::object::NamedObject;
 use super::object::Object;
+use super::primitive::BigInt as PrimitiveBigInt;
 use super::primitive::PrimitiveCapabilities;
 use super::primitive::Symbol as PrimitiveSymbol;
 use super::Value;
@@ -47,8 +48,8 @@ macro_rules! boxed_primitive {
                     delete_property,
                     set_prototype,
                     get_prototype,
-                    own_keys,
-                    apply
+                    apply,
+                    private_elements
                 );
 
 
@@ -64,6 +65,12 @@ macro_rules! boxed_primitive {
                     return self.obj.get_own_property_descriptor(sc, key);
                 }
 
+                fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+                    let mut keys = self.inner.own_keys(sc)?;
+                    keys.extend(self.obj.own_keys(sc)?);
+                    Ok(keys)
+                }
+
                 fn as_any(&self) -> &dyn Any {
                     self
                 }
@@ -141,7 +148,8 @@ boxed_primitive! {
     Number: f64, // TODO: should this store a primitive::Number?
     Boolean: bool,
     String: Rc<str>,
-    Symbol: PrimitiveSymbol
+    Symbol: PrimitiveSymbol,
+    BigInt: PrimitiveBigInt
 }
 
 impl PrimitiveCapabilities for Number {
@@ -179,3 +187,13 @@ impl PrimitiveCapabilities for Symbol {
         Value::Symbol(self.inner.clone())
     }
 }
+
+impl PrimitiveCapabilities for BigInt {
+    fn as_bigint(&self) -> Option<PrimitiveBigInt> {
+        Some(self.inner.clone())
+    }
+
+    fn unbox(&self) -> Value {
+        Value::BigInt(self.inner.clone())
+    }
+}
@@ -0,0 +1,630 @@
+use std::cell::Cell;
+use std::fmt::Write;
+
+use dash_proc_macro::Trace;
+
+use crate::delegate;
+use crate::local::LocalScope;
+use crate::Vm;
+
+use super::object::NamedObject;
+use super::object::Object;
+use super::Value;
+
+pub const MS_PER_SECOND: f64 = 1000.0;
+pub const MS_PER_MINUTE: f64 = 60000.0;
+pub const MS_PER_HOUR: f64 = 3600000.0;
+pub const MS_PER_DAY: f64 = 86400000.0;
+
+/// The largest absolute time value a Date can hold (100,000,000 days relative to the epoch)
+pub const MAX_TIME: f64 = 8.64e15;
+
+const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Debug, Trace)]
+pub struct Date {
+    time: Cell<f64>,
+    obj: NamedObject,
+}
+
+impl Date {
+    pub fn new(vm: &mut Vm, time: f64) -> Self {
+        let prototype = vm.statics.date_prototype.clone();
+        let ctor = vm.statics.date_ctor.clone();
+
+        Self {
+            time: Cell::new(time_clip(time)),
+            obj: NamedObject::with_prototype_and_constructor(prototype, ctor),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            time: Cell::new(f64::NAN),
+            obj: NamedObject::null(),
+        }
+    }
+
+    /// Returns the time value of this date, in milliseconds since the epoch (UTC)
+    pub fn time(&self) -> f64 {
+        self.time.get()
+    }
+
+    /// Sets the time value of this date, clipping it to the valid range, and returns the new time value
+    pub fn set_time(&self, time: f64) -> f64 {
+        let time = time_clip(time);
+        self.time.set(time);
+        time
+    }
+}
+
+impl Object for Date {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys,
+        private_elements
+    );
+}
+
+fn rem_euclid(a: f64, b: f64) -> f64 {
+    let r = a % b;
+    if r < 0.0 {
+        r + b
+    } else {
+        // Negative multiples of `b` leave a remainder of -0, which must not show up in date components
+        r + 0.0
+    }
+}
+
+pub fn day(t: f64) -> f64 {
+    (t / MS_PER_DAY).floor()
+}
+
+pub fn time_within_day(t: f64) -> f64 {
+    rem_euclid(t, MS_PER_DAY)
+}
+
+pub fn days_in_year(y: f64) -> f64 {
+    if y % 4.0 != 0.0 {
+        365.0
+    } else if y % 100.0 != 0.0 {
+        366.0
+    } else if y % 400.0 != 0.0 {
+        365.0
+    } else {
+        366.0
+    }
+}
+
+pub fn day_from_year(y: f64) -> f64 {
+    365.0 * (y - 1970.0) + ((y - 1969.0) / 4.0).floor() - ((y - 1901.0) / 100.0).floor()
+        + ((y - 1601.0) / 400.0).floor()
+}
+
+pub fn time_from_year(y: f64) -> f64 {
+    MS_PER_DAY * day_from_year(y)
+}
+
+pub fn year_from_time(t: f64) -> f64 {
+    // Estimate the year and correct the estimate, which can be off by one in either direction
+    let mut y = (t / (MS_PER_DAY * 365.2425)).floor() + 1970.0;
+    while time_from_year(y) > t {
+        y -= 1.0;
+    }
+    while time_from_year(y + 1.0) <= t {
+        y += 1.0;
+    }
+    y
+}
+
+pub fn in_leap_year(t: f64) -> bool {
+    days_in_year(year_from_time(t)) == 366.0
+}
+
+pub fn day_within_year(t: f64) -> f64 {
+    day(t) - day_from_year(year_from_time(t))
+}
+
+/// Returns the day number (within the year) that the given month starts on
+fn month_start(month: usize, leap: bool) -> f64 {
+    const STARTS: [f64; 13] = [
+        0.0, 31.0, 59.0, 90.0, 120.0, 151.0, 181.0, 212.0, 243.0, 273.0, 304.0, 334.0, 365.0,
+    ];
+
+    let leap = if leap && month >= 2 { 1.0 } else { 0.0 };
+    STARTS[month] + leap
+}
+
+/// Returns the number of days in the given month (0-11) of a year
+fn days_in_month(year: f64, month: usize) -> f64 {
+    let leap = days_in_year(year) == 366.0;
+    month_start(month + 1, leap) - month_start(month, leap)
+}
+
+pub fn month_from_time(t: f64) -> f64 {
+    let leap = in_leap_year(t);
+    let day = day_within_year(t);
+    (0..12)
+        .find(|&m| day < month_start(m + 1, leap))
+        .map(|m| m as f64)
+        .unwrap_or(11.0)
+}
+
+pub fn date_from_time(t: f64) -> f64 {
+    let leap = in_leap_year(t);
+    let month = month_from_time(t) as usize;
+    day_within_year(t) - month_start(month, leap) + 1.0
+}
+
+pub fn week_day(t: f64) -> f64 {
+    rem_euclid(day(t) + 4.0, 7.0)
+}
+
+pub fn hour_from_time(t: f64) -> f64 {
+    rem_euclid((t / MS_PER_HOUR).floor(), 24.0)
+}
+
+pub fn min_from_time(t: f64) -> f64 {
+    rem_euclid((t / MS_PER_MINUTE).floor(), 60.0)
+}
+
+pub fn sec_from_time(t: f64) -> f64 {
+    rem_euclid((t / MS_PER_SECOND).floor(), 60.0)
+}
+
+pub fn ms_from_time(t: f64) -> f64 {
+    rem_euclid(t, MS_PER_SECOND)
+}
+
+fn to_integer_or_infinity(n: f64) -> f64 {
+    if n.is_nan() {
+        0.0
+    } else {
+        n.trunc()
+    }
+}
+
+pub fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
+    if !hour.is_finite() || !min.is_finite() || !sec.is_finite() || !ms.is_finite() {
+        return f64::NAN;
+    }
+
+    to_integer_or_infinity(hour) * MS_PER_HOUR
+        + to_integer_or_infinity(min) * MS_PER_MINUTE
+        + to_integer_or_infinity(sec) * MS_PER_SECOND
+        + to_integer_or_infinity(ms)
+}
+
+pub fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    if !year.is_finite() || !month.is_finite() || !date.is_finite() {
+        return f64::NAN;
+    }
+
+    let y = to_integer_or_infinity(year);
+    let m = to_integer_or_infinity(month);
+    let dt = to_integer_or_infinity(date);
+    let ym = y + (m / 12.0).floor();
+    if !ym.is_finite() || ym.abs() > 400000.0 {
+        return f64::NAN;
+    }
+
+    let mn = rem_euclid(m, 12.0) as usize;
+    let leap = days_in_year(ym) == 366.0;
+    day_from_year(ym) + month_start(mn, leap) + dt - 1.0
+}
+
+pub fn make_date(day: f64, time: f64) -> f64 {
+    if !day.is_finite() || !time.is_finite() {
+        return f64::NAN;
+    }
+
+    let tv = day * MS_PER_DAY + time;
+    if !tv.is_finite() {
+        return f64::NAN;
+    }
+    tv
+}
+
+pub fn time_clip(time: f64) -> f64 {
+    if !time.is_finite() || time.abs() > MAX_TIME {
+        return f64::NAN;
+    }
+
+    // Adding +0 turns a -0 into a +0
+    to_integer_or_infinity(time) + 0.0
+}
+
+/// Returns the offset of local time from UTC at the given UTC time value, in milliseconds
+pub fn local_tza(sc: &mut LocalScope, t: f64) -> Result<f64, Value> {
+    if !t.is_finite() {
+        return Ok(0.0);
+    }
+
+    match sc.params().timezone_offset_callback() {
+        Some(callback) => callback(sc, t as i64).map(|offset| offset as f64),
+        None => Ok(0.0),
+    }
+}
+
+/// Converts a UTC time value to local time
+pub fn local_time(sc: &mut LocalScope, t: f64) -> Result<f64, Value> {
+    Ok(t + local_tza(sc, t)?)
+}
+
+/// Converts a local time value to UTC
+pub fn utc(sc: &mut LocalScope, t: f64) -> Result<f64, Value> {
+    if !t.is_finite() {
+        return Ok(f64::NAN);
+    }
+
+    // The offset is defined in terms of UTC times, so we first guess the UTC time using the offset at `t`
+    let guess = t - local_tza(sc, t)?;
+    Ok(t - local_tza(sc, guess)?)
+}
+
+fn push_year(out: &mut String, year: f64) {
+    if year < 0.0 {
+        let _ = write!(out, "-{:04}", -year);
+    } else {
+        let _ = write!(out, "{year:04}");
+    }
+}
+
+fn push_time(out: &mut String, t: f64) {
+    let _ = write!(
+        out,
+        "{:02}:{:02}:{:02}",
+        hour_from_time(t),
+        min_from_time(t),
+        sec_from_time(t)
+    );
+}
+
+/// Formats a time value as an ISO 8601 string (`YYYY-MM-DDTHH:mm:ss.sssZ`).
+///
+/// The time value must be finite.
+pub fn to_iso_string(t: f64) -> String {
+    let mut out = String::new();
+    let year = year_from_time(t);
+    if (0.0..=9999.0).contains(&year) {
+        let _ = write!(out, "{year:04}");
+    } else {
+        let sign = if year < 0.0 { '-' } else { '+' };
+        let _ = write!(out, "{sign}{:06}", year.abs());
+    }
+
+    let _ = write!(out, "-{:02}-{:02}T", month_from_time(t) + 1.0, date_from_time(t));
+    push_time(&mut out, t);
+    let _ = write!(out, ".{:03}Z", ms_from_time(t));
+    out
+}
+
+/// Formats the date portion of a (local) time value, e.g. `Tue Mar 01 2022`
+pub fn date_string(t: f64) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "{} {} {:02} ",
+        WEEKDAY_NAMES[week_day(t) as usize],
+        MONTH_NAMES[month_from_time(t) as usize],
+        date_from_time(t)
+    );
+    push_year(&mut out, year_from_time(t));
+    out
+}
+
+/// Formats the time portion of a (local) time value, e.g. `10:00:00 GMT+0100`
+pub fn time_string(t: f64, offset: f64) -> String {
+    let mut out = String::new();
+    push_time(&mut out, t);
+
+    let sign = if offset >= 0.0 { '+' } else { '-' };
+    let offset = offset.abs();
+    let _ = write!(
+        out,
+        " GMT{sign}{:02}{:02}",
+        (offset / MS_PER_HOUR).floor(),
+        min_from_time(offset)
+    );
+    out
+}
+
+/// Formats a UTC time value as specified by `Date.prototype.toUTCString`, e.g. `Tue, 01 Mar 2022 10:00:00 GMT`
+pub fn utc_string(t: f64) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "{}, {:02} {} ",
+        WEEKDAY_NAMES[week_day(t) as usize],
+        date_from_time(t),
+        MONTH_NAMES[month_from_time(t) as usize]
+    );
+    push_year(&mut out, year_from_time(t));
+    out.push(' ');
+    push_time(&mut out, t);
+    out.push_str(" GMT");
+    out
+}
+
+/// The result of parsing a date string
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParsedDate {
+    /// A time value in UTC
+    Utc(f64),
+    /// A time value in local time, which still needs to be converted to UTC
+    Local(f64),
+}
+
+/// Parses a date string in either the ISO 8601 format specified by ECMAScript,
+/// or one of the formats produced by `toString` and `toUTCString` (RFC 2822-like)
+pub fn parse(s: &str) -> Option<ParsedDate> {
+    let s = s.trim();
+    parse_iso(s).or_else(|| parse_fallback(s))
+}
+
+struct Cursor<'a> {
+    input: &'a [u8],
+    idx: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            idx: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.idx).copied()
+    }
+
+    fn is_eof(&self) -> bool {
+        self.idx >= self.input.len()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.idx += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        self.eat(c).then_some(())
+    }
+
+    /// Reads exactly `n` digits
+    fn digits(&mut self, n: usize) -> Option<f64> {
+        let digits = self.input.get(self.idx..self.idx + n)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        self.idx += n;
+        Some(digits.iter().fold(0.0, |acc, &d| acc * 10.0 + f64::from(d - b'0')))
+    }
+
+    /// Reads one or more digits
+    fn number(&mut self) -> Option<(f64, usize)> {
+        let start = self.idx;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.idx += 1;
+        }
+
+        let digits = &self.input[start..self.idx];
+        if digits.is_empty() {
+            return None;
+        }
+
+        let n = digits.iter().fold(0.0, |acc, &d| acc * 10.0 + f64::from(d - b'0'));
+        Some((n, digits.len()))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace() || c == b',') {
+            self.idx += 1;
+        }
+    }
+
+    fn word(&mut self) -> &'a str {
+        let start = self.idx;
+        while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            self.idx += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.idx]).unwrap_or_default()
+    }
+}
+
+fn parse_iso(s: &str) -> Option<ParsedDate> {
+    let mut cur = Cursor::new(s);
+
+    let year = match cur.peek()? {
+        sign @ (b'+' | b'-') => {
+            cur.idx += 1;
+            let year = cur.digits(6)?;
+            if sign == b'-' {
+                // -000000 is not a valid extended year
+                if year == 0.0 {
+                    return None;
+                }
+                -year
+            } else {
+                year
+            }
+        }
+        _ => cur.digits(4)?,
+    };
+
+    let mut month = 1.0;
+    let mut date = 1.0;
+    if cur.eat(b'-') {
+        month = cur.digits(2)?;
+        if cur.eat(b'-') {
+            date = cur.digits(2)?;
+        }
+    }
+
+    if !(1.0..=12.0).contains(&month) || !(1.0..=days_in_month(year, month as usize - 1)).contains(&date) {
+        return None;
+    }
+
+    let (mut hour, mut minute, mut second, mut ms) = (0.0, 0.0, 0.0, 0.0);
+    let mut offset = None;
+    let has_time = cur.eat(b'T') || cur.eat(b't') || cur.eat(b' ');
+
+    if has_time {
+        hour = cur.digits(2)?;
+        cur.expect(b':')?;
+        minute = cur.digits(2)?;
+        if cur.eat(b':') {
+            second = cur.digits(2)?;
+            if cur.eat(b'.') {
+                let (frac, len) = cur.number()?;
+                ms = (frac / 10f64.powi(len as i32 - 3)).floor();
+            }
+        }
+
+        if hour > 24.0 || minute > 59.0 || second > 59.0 || (hour == 24.0 && (minute > 0.0 || second > 0.0 || ms > 0.0))
+        {
+            return None;
+        }
+
+        match cur.peek() {
+            Some(b'Z' | b'z') => {
+                cur.idx += 1;
+                offset = Some(0.0);
+            }
+            Some(sign @ (b'+' | b'-')) => {
+                cur.idx += 1;
+                let oh = cur.digits(2)?;
+                cur.eat(b':');
+                let om = cur.digits(2)?;
+                let o = oh * MS_PER_HOUR + om * MS_PER_MINUTE;
+                offset = Some(if sign == b'-' { -o } else { o });
+            }
+            _ => {}
+        }
+    }
+
+    if !cur.is_eof() {
+        return None;
+    }
+
+    let day = make_day(year, month - 1.0, date);
+    let time = make_date(day, make_time(hour, minute, second, ms));
+
+    match offset {
+        Some(offset) => Some(ParsedDate::Utc(time - offset)),
+        // Date-only forms are interpreted as UTC, date-time forms without an offset as local time
+        None if has_time => Some(ParsedDate::Local(time)),
+        None => Some(ParsedDate::Utc(time)),
+    }
+}
+
+fn month_from_name(name: &str) -> Option<f64> {
+    let name = name.get(..3)?;
+    MONTH_NAMES
+        .iter()
+        .position(|m| m.eq_ignore_ascii_case(name))
+        .map(|m| m as f64)
+}
+
+/// Parses the less strict formats, such as `Tue Mar 01 2022 10:00:00 GMT+0100 (Central European Time)`
+/// or `Tue, 01 Mar 2022 10:00:00 GMT`
+fn parse_fallback(s: &str) -> Option<ParsedDate> {
+    let mut cur = Cursor::new(s);
+
+    let mut month = None;
+    let mut numbers = Vec::new();
+    let mut time = None;
+    let mut offset = None;
+
+    loop {
+        cur.skip_whitespace();
+
+        match cur.peek() {
+            None => break,
+            Some(b'(') => {
+                // Comment, e.g. the time zone name
+                while !cur.is_eof() && !cur.eat(b')') {
+                    cur.idx += 1;
+                }
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let word = cur.word();
+                if let Some(m) = month_from_name(word) {
+                    month = Some(m);
+                } else if word.eq_ignore_ascii_case("gmt") || word.eq_ignore_ascii_case("utc") || word == "Z" {
+                    offset = Some(0.0);
+                } else if !WEEKDAY_NAMES
+                    .iter()
+                    .any(|d| word.len() >= 3 && d.eq_ignore_ascii_case(&word[..3]))
+                {
+                    return None;
+                }
+            }
+            Some(sign @ (b'+' | b'-')) if offset.is_some() || time.is_some() => {
+                cur.idx += 1;
+                let oh = cur.digits(2)?;
+                cur.eat(b':');
+                let om = cur.digits(2)?;
+                let o = oh * MS_PER_HOUR + om * MS_PER_MINUTE;
+                offset = Some(if sign == b'-' { -o } else { o });
+            }
+            Some(b'-') => {
+                cur.idx += 1;
+                let (n, _) = cur.number()?;
+                numbers.push(-n);
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let (n, _) = cur.number()?;
+                if cur.eat(b':') {
+                    let (minute, _) = cur.number()?;
+                    let mut second = 0.0;
+                    if cur.eat(b':') {
+                        second = cur.number()?.0;
+                    }
+                    time = Some(make_time(n, minute, second, 0.0));
+                } else {
+                    numbers.push(n);
+                }
+            }
+            Some(_) => return None,
+        }
+    }
+
+    let (year, month, date) = match (month, numbers.as_slice()) {
+        // `Mar 01 2022` or `01 Mar 2022`
+        (Some(month), &[a, b]) => {
+            if a > 31.0 {
+                (a, month, b)
+            } else {
+                (b, month, a)
+            }
+        }
+        // `03/01/2022`-like formats are not supported
+        _ => return None,
+    };
+
+    let time = make_date(make_day(year, month, date), time.unwrap_or(0.0));
+
+    Some(match offset {
+        Some(offset) => ParsedDate::Utc(time - offset),
+        None => ParsedDate::Local(time),
+    })
+}
@@ -2,15 +2,18 @@ use std::any::Any;
 use std::fmt::Write;
 use std::rc::Rc;
 
+use dash_middle::compiler::line_table::SourcePosition;
 use dash_proc_macro::Trace;
 
 use crate::delegate;
+use crate::frame::Frame;
 use crate::gc::handle::Handle;
 use crate::local::LocalScope;
 use crate::Vm;
 
 use super::object::NamedObject;
 use super::object::Object;
+use super::object::PrivateElements;
 use super::object::PropertyKey;
 use super::object::PropertyValue;
 use super::Value;
@@ -20,6 +23,8 @@ pub struct Error {
     pub name: Rc<str>,
     pub message: Rc<str>,
     pub stack: Rc<str>,
+    /// The source position of the innermost frame at the time this error was created
+    pub position: Option<SourcePosition>,
     pub obj: NamedObject,
 }
 
@@ -28,7 +33,10 @@ fn get_stack_trace(name: &str, message: &str, vm: &Vm) -> Rc<str> {
 
     for frame in vm.frames.iter().rev().take(10) {
         let name = frame.function.name.as_deref().unwrap_or("<anonymous>");
-        let _ = write!(stack, "\n  at {name}");
+        let _ = match frame.source_position() {
+            Some(SourcePosition { line, column }) => write!(stack, "\n  at {name} (script.js:{line}:{column})"),
+            None => write!(stack, "\n  at {name}"),
+        };
     }
 
     stack.into()
@@ -51,20 +59,36 @@ impl Error {
         let name = name.into();
         let message = message.into();
         let stack = get_stack_trace(&name, &message, vm);
+        let position = vm.frames.last().and_then(Frame::source_position);
 
         Self {
             name,
             message,
             stack,
+            position,
             obj: NamedObject::with_prototype_and_constructor(proto, ctor),
         }
     }
 
+    /// Returns the underlying [`Error`] of a value, if it is an error object of any error type
+    pub fn from_value(value: &Value) -> Option<&Self> {
+        value
+            .downcast_ref::<Self>()
+            .or_else(|| value.downcast_ref::<EvalError>().map(|e| &e.inner))
+            .or_else(|| value.downcast_ref::<RangeError>().map(|e| &e.inner))
+            .or_else(|| value.downcast_ref::<ReferenceError>().map(|e| &e.inner))
+            .or_else(|| value.downcast_ref::<SyntaxError>().map(|e| &e.inner))
+            .or_else(|| value.downcast_ref::<TypeError>().map(|e| &e.inner))
+            .or_else(|| value.downcast_ref::<URIError>().map(|e| &e.inner))
+            .or_else(|| value.downcast_ref::<AggregateError>().map(|e| &e.inner))
+    }
+
     pub fn empty() -> Self {
         Self {
             name: "Error".into(),
             message: "".into(),
             stack: "".into(),
+            position: None,
             obj: NamedObject::null(),
         }
     }
@@ -74,6 +98,7 @@ impl Error {
             name: name.into(),
             message: "".into(),
             stack: "".into(),
+            position: None,
             obj: NamedObject::null(),
         }
     }
@@ -131,8 +156,12 @@ impl Object for Error {
         self.obj.get_prototype(sc)
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.obj.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.obj.own_keys(sc)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        self.obj.private_elements()
     }
 }
 
@@ -174,7 +203,8 @@ macro_rules! define_error_type {
                     get_prototype,
                     as_any,
                     apply,
-                    own_keys
+                    own_keys,
+                    private_elements
                 );
             }
         )*
@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+
+use crate::delegate;
+use crate::gc::handle::Handle;
+use crate::gc::trace::Trace;
+use crate::gc::weak::Finalizer;
+use crate::gc::weak::WeakContainer;
+use crate::Vm;
+
+use super::object::NamedObject;
+use super::object::Object;
+use super::Value;
+
+#[derive(Debug)]
+struct Cell {
+    /// The weakly held object whose collection triggers the callback
+    target: Handle<dyn Object>,
+    /// The value that is passed to the callback, which is held strongly
+    held_value: Value,
+    /// The weakly held token that can be used to unregister this cell
+    unregister_token: Option<Handle<dyn Object>>,
+}
+
+/// A registry of objects that calls a cleanup callback after a registered object has been collected
+#[derive(Debug)]
+pub struct FinalizationRegistry {
+    /// The cleanup callback, or `None` for the prototype object
+    callback: Option<Handle<dyn Object>>,
+    cells: RefCell<Vec<Cell>>,
+    obj: NamedObject,
+}
+
+impl FinalizationRegistry {
+    pub fn new(vm: &mut Vm, callback: Handle<dyn Object>) -> Self {
+        let prototype = vm.statics.finalization_registry_prototype.clone();
+        let ctor = vm.statics.finalization_registry_constructor.clone();
+        Self {
+            callback: Some(callback),
+            cells: RefCell::new(Vec::new()),
+            obj: NamedObject::with_prototype_and_constructor(prototype, ctor),
+        }
+    }
+
+    pub fn with_obj(obj: NamedObject) -> Self {
+        Self {
+            callback: None,
+            cells: RefCell::new(Vec::new()),
+            obj,
+        }
+    }
+
+    pub fn register(
+        &self,
+        target: Handle<dyn Object>,
+        held_value: Value,
+        unregister_token: Option<Handle<dyn Object>>,
+    ) {
+        self.cells.borrow_mut().push(Cell {
+            target,
+            held_value,
+            unregister_token,
+        });
+    }
+
+    /// Removes all cells that were registered with the given token, returning whether any were removed
+    pub fn unregister(&self, unregister_token: &Handle<dyn Object>) -> bool {
+        let mut cells = self.cells.borrow_mut();
+        let len = cells.len();
+        cells.retain(|cell| cell.unregister_token.as_ref() != Some(unregister_token));
+        cells.len() != len
+    }
+}
+
+unsafe impl Trace for FinalizationRegistry {
+    fn trace(&self) {
+        self.obj.trace();
+        self.callback.trace();
+        for cell in self.cells.borrow().iter() {
+            cell.held_value.trace();
+        }
+    }
+}
+
+impl WeakContainer for FinalizationRegistry {
+    fn sweep_dead(&self, finalizers: &mut Vec<Finalizer>) {
+        let mut cells = self.cells.borrow_mut();
+
+        cells.retain_mut(|cell| {
+            if cell.unregister_token.as_ref().is_some_and(|t| !t.is_live()) {
+                cell.unregister_token = None;
+            }
+
+            if cell.target.is_live() {
+                return true;
+            }
+
+            if let Some(callback) = &self.callback {
+                finalizers.push((callback.clone(), cell.held_value.clone()));
+            }
+            false
+        });
+    }
+}
+
+impl Object for FinalizationRegistry {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys,
+        private_elements
+    );
+
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        Some(self)
+    }
+}
@@ -1,11 +1,13 @@
 use dash_proc_macro::Trace;
 
 use crate::delegate;
+use crate::dispatch::HandleResult;
 use crate::gc::handle::Handle;
+use crate::js_std::generator::resume;
 use crate::local::LocalScope;
 use crate::value::object::NamedObject;
 use crate::value::object::Object;
-use crate::value::object::PropertyKey;
+use crate::value::promise::resolve_promise;
 use crate::value::promise::wrap_promise;
 use crate::value::promise::Promise;
 use crate::value::Typeof;
@@ -14,9 +16,8 @@ use crate::value::ValueContext;
 use crate::PromiseAction;
 use crate::Vm;
 
-use super::generator::as_generator;
 use super::generator::GeneratorFunction;
-use super::generator::GeneratorState;
+use super::generator::ResumeKind;
 use super::user::UserFunction;
 
 #[derive(Debug, Trace)]
@@ -44,48 +45,13 @@ impl AsyncFunction {
             .inner
             .handle_function_call(scope, callee, this, args, is_constructor_call)?;
 
-        let result = scope
-            .statics
-            .generator_iterator_next
-            .clone()
-            .apply(scope, generator_iter.clone(), Vec::new())
-            .and_then(|result| result.get_property(scope, PropertyKey::String("value".into())));
-
-        match &result {
-            Ok(value) => {
-                let is_done = as_generator(scope, &generator_iter)
-                    .map(|gen| matches!(&*gen.state().borrow(), GeneratorState::Finished))?;
-
-                if is_done {
-                    // Promise in resolved state
-                    let promise = wrap_promise(scope, value.clone());
-                    Ok(promise)
-                } else {
-                    // Promise in pending state
-                    let final_promise = Promise::new(scope);
-                    let final_promise = scope.register(final_promise);
-                    let then_task = ThenTask::new(scope, generator_iter.clone(), final_promise.clone());
-                    let then_task = scope.register(then_task);
-
-                    let promise = Value::Object(final_promise);
-
-                    scope.statics.promise_then.clone().apply(
-                        scope,
-                        match result {
-                            Ok(value) => value,
-                            Err(value) => value,
-                        },
-                        vec![Value::Object(then_task)],
-                    )?;
-
-                    Ok(promise)
-                }
-            }
-            Err(value) => {
-                let promise = wrap_promise(scope, value.clone());
-                Err(promise)
-            }
-        }
+        let final_promise = Promise::new(scope);
+        let final_promise = scope.register(final_promise);
+
+        let result = resume(scope, &generator_iter, ResumeKind::Next, Value::undefined());
+        handle_step(scope, &generator_iter, &final_promise, result)?;
+
+        Ok(Value::Object(final_promise))
     }
 
     pub fn inner(&self) -> &GeneratorFunction {
@@ -93,6 +59,43 @@ impl AsyncFunction {
     }
 }
 
+/// Drives an async function after its generator was resumed:
+/// the final promise is settled if the function returned or threw, otherwise the function continues
+/// once the awaited value settles
+fn handle_step(
+    scope: &mut LocalScope,
+    generator_iter: &Value,
+    final_promise: &Handle<dyn Object>,
+    result: Result<HandleResult, Value>,
+) -> Result<(), Value> {
+    match result {
+        Ok(HandleResult::Return(value)) => resolve_promise(scope, final_promise, value),
+        Ok(HandleResult::Await(value) | HandleResult::Yield(value)) => {
+            let (on_fulfilled, on_rejected) = {
+                let t1 = ThenTask::new(scope, generator_iter.clone(), final_promise.clone(), ResumeKind::Next);
+                let t2 = ThenTask::new(scope, generator_iter.clone(), final_promise.clone(), ResumeKind::Throw);
+                (scope.register(t1), scope.register(t2))
+            };
+            let value = wrap_promise(scope, value);
+
+            scope.statics.promise_then.clone().apply(
+                scope,
+                value,
+                vec![Value::Object(on_fulfilled), Value::Object(on_rejected)],
+            )?;
+        }
+        Err(value) => {
+            scope.drive_promise(
+                PromiseAction::Reject,
+                final_promise.as_any().downcast_ref::<Promise>().unwrap(),
+                vec![value],
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// A callable object that is passed to `.then()` on awaited promises.
 /// Calling this will drive the async function to the next await or return point.
 #[derive(Debug, Trace)]
@@ -100,15 +103,18 @@ pub struct ThenTask {
     /// The inner generator iterator of the async function
     generator_iter: Value,
     final_promise: Handle<dyn Object>,
+    /// Whether the awaited promise was fulfilled (`Next`) or rejected (`Throw`)
+    kind: ResumeKind,
     obj: NamedObject,
 }
 
 impl ThenTask {
-    pub fn new(vm: &mut Vm, generator_iter: Value, final_promise: Handle<dyn Object>) -> Self {
+    pub fn new(vm: &mut Vm, generator_iter: Value, final_promise: Handle<dyn Object>, kind: ResumeKind) -> Self {
         Self {
             generator_iter,
             obj: NamedObject::new(vm),
             final_promise,
+            kind,
         }
     }
 }
@@ -136,55 +142,8 @@ impl Object for ThenTask {
     ) -> Result<Value, Value> {
         let promise_value = args.first().unwrap_or_undefined();
 
-        // Call GeneratorIterator.prototype.next on the generator of async function
-        // TODO: this probably wont work because when it gets to an await point, the generator doesnt know how to handle it
-        let value = scope
-            .statics
-            .generator_iterator_next
-            .clone()
-            .apply(scope, self.generator_iter.clone(), vec![promise_value])
-            .and_then(|result| result.get_property(scope, PropertyKey::String("value".into())));
-
-        // - Repeat what we are doing above.
-        // Check if generator iterator is done:
-        //   - If yes, we can resolve the inner promise.
-        //   - If no, promisify value and attach then handler
-        // If value is an error, we can reject promise.
-
-        match value {
-            Ok(value) => {
-                let is_done = as_generator(scope, &self.generator_iter)
-                    .map(|gen| matches!(&*gen.state().borrow(), GeneratorState::Finished))?;
-
-                if is_done {
-                    // Promise in resolved state
-                    // TODO: value might be a promise
-                    scope.drive_promise(
-                        PromiseAction::Resolve,
-                        self.final_promise.as_any().downcast_ref::<Promise>().unwrap(),
-                        vec![value],
-                    );
-                } else {
-                    let then_task = ThenTask::new(scope, self.generator_iter.clone(), self.final_promise.clone());
-                    let then_task = scope.register(then_task);
-                    let value = wrap_promise(scope, value);
-
-                    scope
-                        .statics
-                        .promise_then
-                        .clone()
-                        .apply(scope, value, vec![Value::Object(then_task)])?;
-                }
-            }
-            Err(value) => {
-                // Promise in rejected state
-                scope.drive_promise(
-                    PromiseAction::Reject,
-                    self.final_promise.as_any().downcast_ref::<Promise>().unwrap(),
-                    vec![value],
-                );
-            }
-        }
+        let result = resume(scope, &self.generator_iter, self.kind, promise_value);
+        handle_step(scope, &self.generator_iter, &self.final_promise, result)?;
 
         Ok(Value::undefined())
     }
@@ -0,0 +1,373 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use dash_proc_macro::Trace;
+
+use crate::delegate;
+use crate::dispatch::HandleResult;
+use crate::gc::handle::Handle;
+use crate::gc::trace::Trace;
+use crate::js_std::generator::create_generator_value;
+use crate::js_std::generator::resume;
+use crate::local::LocalScope;
+use crate::value::object::NamedObject;
+use crate::value::object::Object;
+use crate::value::promise::wrap_promise;
+use crate::value::promise::Promise;
+use crate::value::Typeof;
+use crate::value::Value;
+use crate::value::ValueContext;
+use crate::PromiseAction;
+use crate::Vm;
+
+use super::generator::as_generator;
+use super::generator::GeneratorFunction;
+use super::generator::GeneratorState;
+use super::generator::ResumeKind;
+use super::user::UserFunction;
+
+#[derive(Debug, Trace)]
+pub struct AsyncGeneratorFunction {
+    /// Async generators are driven by an inner generator, which suspends at both `yield` and `await`
+    inner: GeneratorFunction,
+}
+
+impl AsyncGeneratorFunction {
+    pub fn new(fun: UserFunction) -> Self {
+        Self {
+            inner: GeneratorFunction::new(fun),
+        }
+    }
+
+    pub(crate) fn handle_function_call(
+        &self,
+        scope: &mut LocalScope,
+        callee: Handle<dyn Object>,
+        this: Value,
+        args: Vec<Value>,
+        is_constructor_call: bool,
+    ) -> Result<Value, Value> {
+        let generator = self
+            .inner
+            .handle_function_call(scope, callee, this, args, is_constructor_call)?;
+
+        let iter = AsyncGeneratorIterator::new(scope, generator);
+        Ok(scope.register(iter).into())
+    }
+
+    pub fn inner(&self) -> &GeneratorFunction {
+        &self.inner
+    }
+}
+
+/// A pending `next`, `return` or `throw` call on an async generator
+#[derive(Debug)]
+struct AsyncGeneratorRequest {
+    kind: ResumeKind,
+    value: Value,
+    promise: Handle<dyn Object>,
+}
+
+unsafe impl Trace for AsyncGeneratorRequest {
+    fn trace(&self) {
+        self.value.trace();
+        self.promise.trace();
+    }
+}
+
+#[derive(Debug, Trace)]
+pub struct AsyncGeneratorIterator {
+    /// The generator iterator that runs the body of the async generator function
+    generator: Value,
+    /// Requests that have not completed yet, in the order they were made.
+    /// The first request is the one the generator is currently working on.
+    queue: RefCell<Vec<AsyncGeneratorRequest>>,
+    /// Whether the generator is executing or waiting for an awaited value
+    running: Cell<bool>,
+    obj: NamedObject,
+}
+
+impl AsyncGeneratorIterator {
+    pub fn new(vm: &Vm, generator: Value) -> Self {
+        let proto = vm.statics.async_generator_iterator_prototype.clone();
+        let ctor = vm.statics.object_ctor.clone();
+
+        Self {
+            generator,
+            queue: RefCell::new(Vec::new()),
+            running: Cell::new(false),
+            obj: NamedObject::with_prototype_and_constructor(proto, ctor),
+        }
+    }
+
+    /// Queues a request and returns a promise for its iterator result (AsyncGeneratorEnqueue).
+    ///
+    /// `this` must be the value of this async generator.
+    pub fn enqueue(
+        &self,
+        scope: &mut LocalScope,
+        this: &Value,
+        kind: ResumeKind,
+        value: Value,
+    ) -> Result<Value, Value> {
+        let promise = Promise::new(scope);
+        let promise = scope.register(promise);
+
+        self.queue.borrow_mut().push(AsyncGeneratorRequest {
+            kind,
+            value,
+            promise: promise.clone(),
+        });
+
+        self.resume_next(scope, this)?;
+
+        Ok(Value::Object(promise))
+    }
+
+    /// Works through the queued requests until the generator has to wait for an awaited value
+    fn resume_next(&self, scope: &mut LocalScope, this: &Value) -> Result<(), Value> {
+        while !self.running.get() {
+            let (kind, value) = match self.queue.borrow().first() {
+                Some(request) => (request.kind, request.value.clone()),
+                None => break,
+            };
+
+            let is_suspended_at_yield = matches!(
+                &*as_generator(scope, &self.generator)?.state().borrow(),
+                GeneratorState::Running { ip, .. } if *ip != 0
+            );
+
+            self.running.set(true);
+
+            if kind == ResumeKind::Return && !is_suspended_at_yield {
+                // Values passed to `return()` are still awaited if the generator has not started or has finished
+                self.await_value(scope, this, value, ResumeKind::Return)?;
+            } else {
+                let result = resume(scope, &self.generator, kind, value);
+                self.complete_step(scope, this, result)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles the inner generator suspending, returning or throwing:
+    /// the current request is completed, unless the generator is awaiting a value
+    fn complete_step(
+        &self,
+        scope: &mut LocalScope,
+        this: &Value,
+        result: Result<HandleResult, Value>,
+    ) -> Result<(), Value> {
+        let (action, value) = match result {
+            Ok(HandleResult::Await(value)) => return self.await_value(scope, this, value, ResumeKind::Next),
+            Ok(HandleResult::Yield(value)) => (
+                PromiseAction::Resolve,
+                create_generator_value(scope, false, Some(value))?,
+            ),
+            Ok(HandleResult::Return(value)) => (
+                PromiseAction::Resolve,
+                create_generator_value(scope, true, Some(value))?,
+            ),
+            Err(value) => (PromiseAction::Reject, value),
+        };
+
+        let request = self.queue.borrow_mut().remove(0);
+        scope.drive_promise(
+            action,
+            request.promise.as_any().downcast_ref::<Promise>().unwrap(),
+            vec![value],
+        );
+        self.running.set(false);
+
+        Ok(())
+    }
+
+    /// Waits for a value to settle and then resumes the inner generator with the result.
+    ///
+    /// A fulfilled value is sent with the `fulfilled` completion, a rejected value is thrown.
+    fn await_value(
+        &self,
+        scope: &mut LocalScope,
+        this: &Value,
+        value: Value,
+        fulfilled: ResumeKind,
+    ) -> Result<(), Value> {
+        let (on_fulfilled, on_rejected) = {
+            let t1 = AwaitTask::new(scope, this.clone(), fulfilled);
+            let t2 = AwaitTask::new(scope, this.clone(), ResumeKind::Throw);
+            (scope.register(t1), scope.register(t2))
+        };
+        let value = wrap_promise(scope, value);
+
+        scope.statics.promise_then.clone().apply(
+            scope,
+            value,
+            vec![Value::Object(on_fulfilled), Value::Object(on_rejected)],
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Object for AsyncGeneratorIterator {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys
+    );
+}
+
+/// A callable object that is passed to `.then()` on values awaited by async generators.
+/// Calling this resumes the generator with the settled value.
+#[derive(Debug, Trace)]
+struct AwaitTask {
+    /// The async generator that is waiting
+    generator: Value,
+    /// How the generator is resumed with the settled value
+    kind: ResumeKind,
+    obj: NamedObject,
+}
+
+impl AwaitTask {
+    pub fn new(vm: &mut Vm, generator: Value, kind: ResumeKind) -> Self {
+        Self {
+            generator,
+            kind,
+            obj: NamedObject::new(vm),
+        }
+    }
+}
+
+impl Object for AwaitTask {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        let value = args.first().unwrap_or_undefined();
+        let generator = self
+            .generator
+            .downcast_ref::<AsyncGeneratorIterator>()
+            .expect("AwaitTask must belong to an async generator");
+
+        let result = resume(scope, &generator.generator, self.kind, value);
+        generator.complete_step(scope, &self.generator, result)?;
+        generator.resume_next(scope, &self.generator)?;
+
+        Ok(Value::undefined())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
+}
+
+/// An async iterator that wraps a sync iterator, used by `for await..of` loops over sync iterables
+/// (CreateAsyncFromSyncIterator)
+#[derive(Debug, Trace)]
+pub struct AsyncFromSyncIterator {
+    sync_iterator: Value,
+    obj: NamedObject,
+}
+
+impl AsyncFromSyncIterator {
+    pub fn new(vm: &Vm, sync_iterator: Value) -> Self {
+        let proto = vm.statics.async_from_sync_iterator_prototype.clone();
+        let ctor = vm.statics.object_ctor.clone();
+
+        Self {
+            sync_iterator,
+            obj: NamedObject::with_prototype_and_constructor(proto, ctor),
+        }
+    }
+
+    pub fn sync_iterator(&self) -> &Value {
+        &self.sync_iterator
+    }
+}
+
+impl Object for AsyncFromSyncIterator {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys
+    );
+}
+
+/// A callable object that is passed to `.then()` on the values of sync iterators,
+/// creating the iterator result once the value has settled (AsyncFromSyncIteratorContinuation)
+#[derive(Debug, Trace)]
+pub struct AsyncFromSyncIteratorContinuation {
+    done: bool,
+    obj: NamedObject,
+}
+
+impl AsyncFromSyncIteratorContinuation {
+    pub fn new(vm: &mut Vm, done: bool) -> Self {
+        Self {
+            done,
+            obj: NamedObject::new(vm),
+        }
+    }
+}
+
+impl Object for AsyncFromSyncIteratorContinuation {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        create_generator_value(scope, self.done, args.first().cloned())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
+}
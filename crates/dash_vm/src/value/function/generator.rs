@@ -4,6 +4,7 @@ use std::cell::RefCell;
 use dash_proc_macro::Trace;
 
 use crate::delegate;
+use crate::frame::TryBlock;
 use crate::gc::handle::Handle;
 use crate::gc::trace::Trace;
 use crate::local::LocalScope;
@@ -55,10 +56,27 @@ impl GeneratorFunction {
     }
 }
 
+/// The kind of completion a suspended generator is resumed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeKind {
+    /// `next(value)`: the suspended `yield` or `await` evaluates to the value
+    Next,
+    /// `throw(value)`: the value is thrown at the point where the generator is suspended
+    Throw,
+    /// `return(value)`: the generator returns the value, running any finally blocks
+    Return,
+}
+
 #[derive(Debug, Clone)]
 pub enum GeneratorState {
     Finished,
-    Running { ip: usize, stack: Vec<Value> },
+    Running {
+        ip: usize,
+        stack: Vec<Value>,
+        /// Try blocks that were active when the generator was suspended.
+        /// Their frame index and stack pointer are relative to the generator frame
+        try_blocks: Vec<TryBlock>,
+    },
 }
 
 impl GeneratorState {
@@ -75,6 +93,7 @@ impl Default for GeneratorState {
         Self::Running {
             ip: 0,
             stack: Vec::new(),
+            try_blocks: Vec::new(),
         }
     }
 }
@@ -102,7 +121,11 @@ impl GeneratorIterator {
         Self {
             function,
             obj: NamedObject::with_prototype_and_constructor(proto, ctor),
-            state: RefCell::new(GeneratorState::Running { ip: 0, stack }),
+            state: RefCell::new(GeneratorState::Running {
+                ip: 0,
+                stack,
+                try_blocks: Vec::new(),
+            }),
         }
     }
 
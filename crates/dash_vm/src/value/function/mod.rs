@@ -17,6 +17,7 @@ use crate::{
 };
 
 use self::{
+    async_generator::AsyncGeneratorFunction,
     generator::GeneratorFunction,
     native::{CallContext, NativeFunction},
     r#async::AsyncFunction,
@@ -25,11 +26,12 @@ use self::{
 
 use super::{
     array::Array,
-    object::{NamedObject, Object, PropertyKey, PropertyValue},
+    object::{NamedObject, Object, PrivateElements, PropertyKey, PropertyValue},
     Typeof, Value,
 };
 
 pub mod r#async;
+pub mod async_generator;
 pub mod bound;
 pub mod generator;
 pub mod native;
@@ -39,6 +41,7 @@ pub enum FunctionKind {
     User(UserFunction),
     Generator(GeneratorFunction),
     Async(AsyncFunction),
+    AsyncGenerator(AsyncGeneratorFunction),
 }
 
 unsafe impl Trace for FunctionKind {
@@ -47,6 +50,7 @@ unsafe impl Trace for FunctionKind {
             Self::User(user) => user.trace(),
             Self::Generator(generator) => generator.trace(),
             Self::Async(async_) => async_.trace(),
+            Self::AsyncGenerator(generator) => generator.trace(),
             Self::Native(_) => {}
         }
     }
@@ -80,6 +84,13 @@ impl FunctionKind {
             _ => None,
         }
     }
+
+    pub fn as_async_generator(&self) -> Option<&AsyncGeneratorFunction> {
+        match self {
+            Self::AsyncGenerator(f) => Some(f),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for FunctionKind {
@@ -89,6 +100,7 @@ impl fmt::Debug for FunctionKind {
             Self::User(..) => f.write_str("UserFunction"),
             Self::Generator(..) => f.write_str("GeneratorFunction"),
             Self::Async(..) => f.write_str("AsyncFunction"),
+            Self::AsyncGenerator(..) => f.write_str("AsyncGeneratorFunction"),
         }
     }
 }
@@ -167,6 +179,19 @@ impl Function {
         let this = scope.register(NamedObject::with_prototype_and_constructor(prototype, this_handle));
         Ok(this)
     }
+
+    /// Calls this function as a constructor with an already allocated `this` value.
+    ///
+    /// This is used by `super()` calls, where the instance is allocated by the derived class.
+    pub fn construct_with_this(
+        &self,
+        scope: &mut LocalScope,
+        callee: Handle<dyn Object>,
+        this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        handle_call(self, scope, callee, this, args, true)
+    }
 }
 
 fn handle_call(
@@ -193,6 +218,7 @@ fn handle_call(
             }),
         FunctionKind::Async(fun) => fun.handle_function_call(scope, callee, this, args, is_constructor_call),
         FunctionKind::Generator(fun) => fun.handle_function_call(scope, callee, this, args, is_constructor_call),
+        FunctionKind::AsyncGenerator(fun) => fun.handle_function_call(scope, callee, this, args, is_constructor_call),
     }
 }
 
@@ -265,10 +291,14 @@ impl Object for Function {
         self.obj.get_prototype(sc)
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(["length", "name"].iter().map(|&s| Value::String(s.into())).collect())
     }
 
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        self.obj.private_elements()
+    }
+
     fn type_of(&self) -> Typeof {
         Typeof::Function
     }
@@ -67,6 +67,7 @@ impl Object for Map {
         get_prototype,
         as_any,
         apply,
-        own_keys
+        own_keys,
+        private_elements
     );
 }
@@ -2,7 +2,9 @@ pub mod array;
 pub mod arraybuffer;
 pub mod boxed;
 pub mod conversions;
+pub mod date;
 pub mod error;
+pub mod finalization_registry;
 pub mod function;
 pub mod inspect;
 pub mod map;
@@ -10,15 +12,20 @@ pub mod object;
 pub mod ops;
 pub mod primitive;
 pub mod promise;
+pub mod proxy;
 pub mod regex;
 pub mod set;
 pub mod typedarray;
+pub mod weakmap;
+pub mod weakref;
+pub mod weakset;
 use std::rc::Rc;
 
 use dash_middle::compiler::{constant::Constant, external::External};
 use dash_middle::parser::statement::FunctionKind as ParserFunctionKind;
 use dash_middle::util::ThreadSafeStorage;
 use dash_proc_macro::Trace;
+use num_traits::Zero;
 
 use crate::{delegate, throw};
 use crate::{
@@ -29,9 +36,10 @@ use crate::{
     },
 };
 
+use self::function::async_generator::AsyncGeneratorFunction;
 use self::function::r#async::AsyncFunction;
 use self::object::PropertyValue;
-use self::primitive::{Number, PrimitiveCapabilities};
+use self::primitive::{BigInt, Number, PrimitiveCapabilities};
 use self::regex::RegExp;
 use self::{
     function::{generator::GeneratorFunction, user::UserFunction, Function},
@@ -59,6 +67,8 @@ pub enum Value {
     Null(Null),
     /// The symbol type
     Symbol(Symbol),
+    /// The bigint type
+    BigInt(BigInt),
     /// The object type
     Object(Handle<dyn Object>),
     /// An "external" value that is being used by other functions.
@@ -89,16 +99,22 @@ impl ExternalValue {
 impl Object for ExternalValue {
     delegate!(
         inner,
+        has_property,
         set_property,
+        set_property_with_this,
         delete_property,
         set_prototype,
         own_keys,
         get_own_property_descriptor,
         get_property,
+        get_property_with_this,
         get_property_descriptor,
         get_prototype,
         type_of,
-        as_primitive_capable
+        as_primitive_capable,
+        is_extensible,
+        prevent_extensions,
+        private_elements
     );
 
     // NB: this intentionally does not delegate to self.inner.as_any() because
@@ -180,6 +196,7 @@ fn register_function_externals(
             Value::Undefined(u) => rebox(vm, id, u),
             Value::Null(n) => rebox(vm, id, n),
             Value::Symbol(s) => rebox(vm, id, s),
+            Value::BigInt(b) => rebox(vm, id, b),
             Value::External(e) => e,
             Value::Object(o) => vm
                 .gc
@@ -202,8 +219,9 @@ impl Value {
             Constant::String(s) => Value::String(s),
             Constant::Undefined => Value::undefined(),
             Constant::Null => Value::null(),
-            Constant::Regex(nodes, source) => {
-                let regex = RegExp::new(nodes, source, vm);
+            Constant::BigInt(n) => Value::BigInt(n.into()),
+            Constant::Regex(regex, flags, source) => {
+                let regex = RegExp::new(regex, flags, source, vm);
                 Value::Object(vm.register(regex))
             }
             Constant::Function(f) => {
@@ -223,7 +241,13 @@ impl Value {
                             FunctionKind::User(fun)
                         }
                     }
-                    ParserFunctionKind::Generator => FunctionKind::Generator(GeneratorFunction::new(fun)),
+                    ParserFunctionKind::Generator => {
+                        if is_async {
+                            FunctionKind::AsyncGenerator(AsyncGeneratorFunction::new(fun))
+                        } else {
+                            FunctionKind::Generator(GeneratorFunction::new(fun))
+                        }
+                    }
                 };
 
                 let function = Function::new(vm, name, kind);
@@ -248,19 +272,22 @@ impl Value {
             Self::Undefined(u) => u.set_property(sc, key, value),
             Self::Null(n) => n.set_property(sc, key, value),
             Self::Symbol(s) => s.set_property(sc, key, value),
+            Self::BigInt(b) => b.set_property(sc, key, value),
         }
     }
 
     pub fn get_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Value, Value> {
         match self {
-            Self::Object(o) => o.get_property(sc, key),
+            // Getters are invoked with the object as `this`
+            Self::Object(o) => o.get_property_with_this(sc, self.clone(), key),
             Self::Number(n) => n.get_property(sc, key),
             Self::Boolean(b) => b.get_property(sc, key),
             Self::String(s) => s.get_property(sc, key),
-            Self::External(o) => o.get_property(sc, key),
+            Self::External(o) => o.get_property_with_this(sc, self.clone(), key),
             Self::Undefined(u) => u.get_property(sc, key),
             Self::Null(n) => n.get_property(sc, key),
-            Self::Symbol(s) => s.get_property(sc, key),
+            Self::Symbol(s) => s.get_property_with_this(sc, self.clone(), key),
+            Self::BigInt(b) => b.get_property(sc, key),
         }
     }
 
@@ -274,6 +301,7 @@ impl Value {
             Self::Undefined(u) => u.delete_property(sc, key),
             Self::Null(n) => n.delete_property(sc, key),
             Self::Symbol(s) => s.delete_property(sc, key),
+            Self::BigInt(b) => b.delete_property(sc, key),
         }
     }
 
@@ -286,7 +314,8 @@ impl Value {
             Self::String(s) => throw!(sc, TypeError, "{} is not a function", s),
             Self::Undefined(_) => throw!(sc, TypeError, "undefined is not a function"),
             Self::Null(_) => throw!(sc, TypeError, "null is not a function"),
-            Self::Symbol(s) => throw!(sc, TypeError, "{:?} is not a function", s),
+            Self::Symbol(s) => throw!(sc, TypeError, "{} is not a function", s),
+            Self::BigInt(b) => throw!(sc, TypeError, "{}n is not a function", b),
         }
     }
 
@@ -299,7 +328,8 @@ impl Value {
             Self::String(s) => throw!(sc, TypeError, "{} is not a constructor", s),
             Self::Undefined(_) => throw!(sc, TypeError, "undefined is not a constructor"),
             Self::Null(_) => throw!(sc, TypeError, "null is not a constructor"),
-            Self::Symbol(s) => throw!(sc, TypeError, "{:?} is not a constructor", s),
+            Self::Symbol(s) => throw!(sc, TypeError, "{} is not a constructor", s),
+            Self::BigInt(b) => throw!(sc, TypeError, "{}n is not a constructor", b),
         }
     }
 
@@ -309,6 +339,7 @@ impl Value {
             Value::String(s) => !s.is_empty(),
             Value::Number(Number(n)) => *n != 0.0 && !n.is_nan(),
             Value::Symbol(_) => true,
+            Value::BigInt(b) => !b.is_zero(),
             Value::Object(_) => true,
             Value::Undefined(_) => false,
             Value::Null(_) => false,
@@ -343,6 +374,7 @@ impl Value {
             Value::String(s) => s.unbox(),
             Value::Number(n) => n.unbox(),
             Value::Symbol(s) => s.unbox(),
+            Value::BigInt(b) => b.unbox(),
             Value::Object(o) => Value::Object(o),
             Value::Undefined(u) => u.unbox(),
             Value::Null(n) => n.unbox(),
@@ -370,6 +402,7 @@ impl Value {
             Self::Object(o) => o.type_of(),
             Self::Null(_) => Typeof::Object,
             Self::Symbol(_) => Typeof::Symbol,
+            Self::BigInt(_) => Typeof::Bigint,
         }
     }
 
@@ -380,11 +413,21 @@ impl Value {
             _ => return Ok(false),
         };
 
-        // TODO: repeat for all objects in prototype chain
         let target_proto = ctor.get_property(sc, "prototype".into())?;
-        let this_proto = obj.get_prototype(sc)?;
+        let mut this_proto = obj.get_prototype(sc)?;
 
-        Ok(this_proto == target_proto)
+        // Walk the prototype chain until we find the prototype of the constructor
+        loop {
+            if this_proto == target_proto {
+                return Ok(true);
+            }
+
+            this_proto = match &this_proto {
+                Value::Object(proto) => proto.get_prototype(sc)?,
+                Value::External(proto) => proto.inner.get_prototype(sc)?,
+                _ => return Ok(false),
+            };
+        }
     }
 
     /// Attempts to downcast this value to a concrete type `T`.
@@ -406,6 +449,7 @@ impl Value {
             Value::Undefined(v) => sc.register(v),
             Value::Null(v) => sc.register(v),
             Value::Symbol(v) => sc.register(v),
+            Value::BigInt(v) => sc.register(v),
             Value::Object(v) => v,
             Value::External(v) => v.into_dyn(),
         }
@@ -420,6 +464,7 @@ impl Value {
             Value::Undefined(v) => vm.register(v),
             Value::Null(v) => vm.register(v),
             Value::Symbol(v) => vm.register(v),
+            Value::BigInt(v) => vm.register(v),
             Value::Object(v) => v,
             Value::External(v) => v.into_dyn(),
         }
@@ -587,8 +632,8 @@ impl<O: Object + 'static> Object for PureBuiltin<O> {
         &self.inner
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.inner.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.inner.own_keys(sc)
     }
 
     fn as_primitive_capable(&self) -> Option<&dyn PrimitiveCapabilities> {
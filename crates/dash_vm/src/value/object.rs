@@ -1,6 +1,6 @@
-use std::{any::Any, borrow::Cow, cell::RefCell, fmt::Debug, ptr::addr_of};
+use std::{any::Any, borrow::Cow, cell::Cell, cell::RefCell, fmt, fmt::Debug, ptr::addr_of};
 
-use crate::gc::{persistent::Persistent, trace::Trace};
+use crate::gc::{persistent::Persistent, trace::Trace, weak::WeakContainer};
 use bitflags::bitflags;
 use dash_proc_macro::Trace;
 
@@ -9,6 +9,7 @@ use crate::{gc::handle::Handle, local::LocalScope, throw, Vm};
 use super::{
     ops::abstractions::conversions::ValueConversion,
     primitive::{PrimitiveCapabilities, Symbol},
+    proxy::Proxy,
     ExternalValue, Typeof, Value, ValueContext,
 };
 
@@ -32,6 +33,14 @@ pub trait Object: Debug + Trace {
         delegate_get_property(self, sc, key)
     }
 
+    /// Returns the value of a property, invoking a getter with the given `this` value
+    fn get_property_with_this(&self, sc: &mut LocalScope, this: Value, key: PropertyKey) -> Result<Value, Value> {
+        match self.get_property_descriptor(sc, key)? {
+            Some(descriptor) => descriptor.get_or_apply(sc, this),
+            None => Ok(Value::undefined()),
+        }
+    }
+
     fn get_property_descriptor(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Option<PropertyValue>, Value> {
         let own_descriptor = self.get_own_property_descriptor(sc, key.clone())?;
         if own_descriptor.is_some() {
@@ -46,8 +55,63 @@ pub trait Object: Debug + Trace {
         }
     }
 
+    /// Checks whether this object or an object in its prototype chain has a property with the given key
+    fn has_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<bool, Value> {
+        Ok(self.get_property_descriptor(sc, key)?.is_some())
+    }
+
     fn set_property(&self, sc: &mut LocalScope, key: PropertyKey<'static>, value: PropertyValue) -> Result<(), Value>;
 
+    /// Assigns a value to a property, invoking a setter with the given `this` value
+    /// and creating or updating a data property on `this` rather than on this object otherwise
+    ///
+    /// Returns whether the assignment succeeded
+    fn set_property_with_this(
+        &self,
+        sc: &mut LocalScope,
+        this: Value,
+        key: PropertyKey<'static>,
+        value: Value,
+    ) -> Result<bool, Value> {
+        if let Some(property) = self.get_property_descriptor(sc, key.clone())? {
+            match property.kind() {
+                PropertyValueKind::Trap { set: Some(set), .. } => {
+                    set.apply(sc, this, vec![value])?;
+                    return Ok(true);
+                }
+                PropertyValueKind::Trap { set: None, .. } => return Ok(false),
+                PropertyValueKind::Static(_) if !property.descriptor().contains(PropertyDataDescriptor::WRITABLE) => {
+                    return Ok(false)
+                }
+                PropertyValueKind::Static(_) => {}
+            }
+        }
+
+        let receiver = match this {
+            Value::Object(o) => o,
+            Value::External(o) => o.inner.clone(),
+            _ => return Ok(false),
+        };
+
+        // Proxies decide on their own whether the property can be defined
+        if let Some(proxy) = receiver.as_any().downcast_ref::<Proxy>() {
+            return proxy.define_property(sc, key, PropertyValue::static_default(value));
+        }
+
+        let success = match receiver.get_own_property_descriptor(sc, key.clone())? {
+            Some(existing) => {
+                matches!(existing.kind(), PropertyValueKind::Static(_))
+                    && existing.descriptor().contains(PropertyDataDescriptor::WRITABLE)
+            }
+            None => receiver.is_extensible(sc)?,
+        };
+
+        if success {
+            receiver.set_property(sc, key, PropertyValue::static_default(value))?;
+        }
+        Ok(success)
+    }
+
     fn delete_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Value, Value>;
 
     fn set_prototype(&self, sc: &mut LocalScope, value: Value) -> Result<(), Value>;
@@ -78,7 +142,31 @@ pub trait Object: Debug + Trace {
         None
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value>;
+    /// Returns this object as a [`WeakContainer`] if it holds weak references that the garbage collector needs to visit
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        None
+    }
+
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value>;
+
+    /// Checks whether new properties can be added to this object
+    fn is_extensible(&self, _sc: &mut LocalScope) -> Result<bool, Value> {
+        Ok(true)
+    }
+
+    /// Prevents new properties from being added to this object,
+    /// returning whether the object is non-extensible afterwards
+    fn prevent_extensions(&self, _sc: &mut LocalScope) -> Result<bool, Value> {
+        Ok(false)
+    }
+
+    /// Returns the private elements (`#name` class members) of this object, if it can have any
+    ///
+    /// Private elements are kept apart from regular properties and are never forwarded,
+    /// so reflection and proxy traps cannot observe them
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        None
+    }
 
     fn type_of(&self) -> Typeof {
         Typeof::Object
@@ -105,6 +193,16 @@ macro_rules! delegate {
             self.$field.get_property(sc, key)
         }
     };
+    (override $field:ident, get_property_with_this) => {
+        fn get_property_with_this(
+            &self,
+            sc: &mut $crate::local::LocalScope,
+            this: $crate::value::Value,
+            key: $crate::value::object::PropertyKey,
+        ) -> Result<$crate::value::Value, $crate::value::Value> {
+            self.$field.get_property_with_this(sc, this, key)
+        }
+    };
     (override $field:ident, get_property_descriptor) => {
         fn get_property_descriptor(
             &self,
@@ -114,6 +212,15 @@ macro_rules! delegate {
             self.$field.get_property_descriptor(sc, key)
         }
     };
+    (override $field:ident, has_property) => {
+        fn has_property(
+            &self,
+            sc: &mut $crate::local::LocalScope,
+            key: $crate::value::object::PropertyKey,
+        ) -> Result<bool, $crate::value::Value> {
+            self.$field.has_property(sc, key)
+        }
+    };
     (override $field:ident, set_property) => {
         fn set_property(
             &self,
@@ -124,6 +231,17 @@ macro_rules! delegate {
             self.$field.set_property(sc, key, value)
         }
     };
+    (override $field:ident, set_property_with_this) => {
+        fn set_property_with_this(
+            &self,
+            sc: &mut $crate::local::LocalScope,
+            this: $crate::value::Value,
+            key: $crate::value::object::PropertyKey<'static>,
+            value: $crate::value::Value,
+        ) -> Result<bool, $crate::value::Value> {
+            self.$field.set_property_with_this(sc, this, key, value)
+        }
+    };
     (override $field:ident, delete_property) => {
         fn delete_property(
             &self,
@@ -149,8 +267,8 @@ macro_rules! delegate {
         }
     };
     (override $field:ident, own_keys) => {
-        fn own_keys(&self) -> Result<Vec<$crate::value::Value>, $crate::value::Value> {
-            self.$field.own_keys()
+        fn own_keys(&self, sc: &mut $crate::local::LocalScope) -> Result<Vec<$crate::value::Value>, $crate::value::Value> {
+            self.$field.own_keys(sc)
         }
     };
     (override $field:ident, apply) => {
@@ -175,6 +293,21 @@ macro_rules! delegate {
             self.$field.construct(sc, handle, this, args)
         }
     };
+    (override $field:ident, is_extensible) => {
+        fn is_extensible(&self, sc: &mut $crate::local::LocalScope) -> Result<bool, $crate::value::Value> {
+            self.$field.is_extensible(sc)
+        }
+    };
+    (override $field:ident, prevent_extensions) => {
+        fn prevent_extensions(&self, sc: &mut $crate::local::LocalScope) -> Result<bool, $crate::value::Value> {
+            self.$field.prevent_extensions(sc)
+        }
+    };
+    (override $field:ident, private_elements) => {
+        fn private_elements(&self) -> Option<&$crate::value::object::PrivateElements> {
+            self.$field.private_elements()
+        }
+    };
     (override $field:ident, type_of) => {
         fn type_of(&self) -> $crate::value::Typeof {
             self.$field.type_of()
@@ -198,6 +331,35 @@ pub struct NamedObject {
     prototype: RefCell<Option<Handle<dyn Object>>>,
     constructor: RefCell<Option<Handle<dyn Object>>>,
     values: RefCell<ObjectMap<PropertyKey<'static>, PropertyValue>>,
+    /// Whether new properties can be added to this object
+    extensible: Cell<bool>,
+    private: PrivateElements,
+}
+
+/// The private elements of an object, keyed by the private name that their class declared them with
+#[derive(Debug, Clone, Default)]
+pub struct PrivateElements(RefCell<ObjectMap<Symbol, PropertyValue>>);
+
+impl PrivateElements {
+    pub fn get(&self, name: &Symbol) -> Option<PropertyValue> {
+        self.0.borrow().get(name).cloned()
+    }
+
+    pub fn has(&self, name: &Symbol) -> bool {
+        self.0.borrow().contains_key(name)
+    }
+
+    pub fn insert(&self, name: Symbol, value: PropertyValue) {
+        self.0.borrow_mut().insert(name, value);
+    }
+}
+
+unsafe impl Trace for PrivateElements {
+    fn trace(&self) {
+        for value in self.0.borrow().values() {
+            value.trace();
+        }
+    }
 }
 
 // TODO: optimization opportunity: some kind of Number variant for faster indexing without .to_string()
@@ -269,6 +431,10 @@ impl PropertyValue {
         &mut self.kind
     }
 
+    pub fn descriptor(&self) -> PropertyDataDescriptor {
+        self.descriptor
+    }
+
     pub fn into_parts(self) -> (PropertyValueKind, PropertyDataDescriptor) {
         (self.kind, self.descriptor)
     }
@@ -322,6 +488,50 @@ impl PropertyValue {
 
         Ok(Value::Object(sc.register(obj)))
     }
+
+    /// Converts a property descriptor object (e.g. `{ value: 1, writable: true }`) to a property value.
+    ///
+    /// Attributes that are not present on the descriptor default to `false`.
+    pub fn from_descriptor_value(sc: &mut LocalScope, value: Value) -> Result<Self, Value> {
+        if !matches!(value, Value::Object(_) | Value::External(_)) {
+            throw!(sc, TypeError, "Property description must be an object");
+        }
+
+        let mut descriptor = PropertyDataDescriptor::empty();
+        for (name, flag) in [
+            ("configurable", PropertyDataDescriptor::CONFIGURABLE),
+            ("enumerable", PropertyDataDescriptor::ENUMERABLE),
+            ("writable", PropertyDataDescriptor::WRITABLE),
+        ] {
+            if value.get_property(sc, name.into())?.is_truthy() {
+                descriptor |= flag;
+            }
+        }
+
+        fn accessor(sc: &mut LocalScope, value: Value, name: &str) -> Result<Option<Handle<dyn Object>>, Value> {
+            match value {
+                Value::Undefined(_) => Ok(None),
+                Value::Object(o) if matches!(o.type_of(), Typeof::Function) => Ok(Some(o)),
+                Value::External(o) if matches!(o.type_of(), Typeof::Function) => Ok(Some(o.inner.clone())),
+                _ => throw!(sc, TypeError, "{} must be a function", name),
+            }
+        }
+
+        let get = value.get_property(sc, "get".into())?;
+        let set = value.get_property(sc, "set".into())?;
+
+        let kind = match (get, set) {
+            (Value::Undefined(_), Value::Undefined(_)) => {
+                PropertyValueKind::Static(value.get_property(sc, "value".into())?)
+            }
+            (get, set) => PropertyValueKind::Trap {
+                get: accessor(sc, get, "Getter")?,
+                set: accessor(sc, set, "Setter")?,
+            },
+        };
+
+        Ok(Self::new(kind, descriptor))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -394,6 +604,15 @@ impl<'a> PropertyKey<'a> {
     }
 }
 
+impl fmt::Display for PropertyKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyKey::String(s) => f.write_str(s),
+            PropertyKey::Symbol(s) => write!(f, "{s}"),
+        }
+    }
+}
+
 impl<'a> From<&'a str> for PropertyKey<'a> {
     fn from(s: &'a str) -> Self {
         PropertyKey::String(Cow::Borrowed(s))
@@ -444,6 +663,8 @@ impl NamedObject {
             prototype: RefCell::new(Some(objp)),
             constructor: RefCell::new(Some(objc)),
             values: RefCell::new(values),
+            extensible: Cell::new(true),
+            private: PrivateElements::default(),
         }
     }
 
@@ -453,6 +674,8 @@ impl NamedObject {
             prototype: RefCell::new(None),
             constructor: RefCell::new(None),
             values: RefCell::new(ObjectMap::default()),
+            extensible: Cell::new(true),
+            private: PrivateElements::default(),
         }
     }
 
@@ -461,12 +684,19 @@ impl NamedObject {
             constructor: RefCell::new(Some(ctor)),
             prototype: RefCell::new(Some(prototype)),
             values: RefCell::new(ObjectMap::default()),
+            extensible: Cell::new(true),
+            private: PrivateElements::default(),
         }
     }
 
     pub fn get_raw_property(&self, pk: PropertyKey) -> Option<PropertyValue> {
         self.values.borrow().get(&pk).cloned()
     }
+
+    /// Sets a property without going through the `Object` trait, which does not require a scope
+    pub fn set_raw_property(&self, pk: PropertyKey<'static>, value: PropertyValue) {
+        self.values.borrow_mut().insert(pk, value);
+    }
 }
 
 unsafe impl Trace for NamedObject {
@@ -485,6 +715,8 @@ unsafe impl Trace for NamedObject {
         if let Some(constructor) = &*constructor {
             constructor.trace();
         }
+
+        self.private.trace();
     }
 }
 
@@ -494,6 +726,12 @@ impl Object for NamedObject {
         sc: &mut LocalScope,
         key: PropertyKey,
     ) -> Result<Option<PropertyValue>, Value> {
+        // `__proto__` and `constructor` are only stored as regular properties when they are defined
+        // as own data properties (e.g. by `JSON.parse`), in which case they take precedence
+        if let Some(value) = self.values.borrow().get(&key).cloned() {
+            return Ok(Some(value));
+        }
+
         if let PropertyKey::String(st) = &key {
             match st.as_ref() {
                 "__proto__" => return Ok(Some(PropertyValue::static_default(self.get_prototype(sc)?))),
@@ -510,11 +748,6 @@ impl Object for NamedObject {
             }
         };
 
-        let values = self.values.borrow();
-        if let Some(value) = values.get(&key).cloned() {
-            return Ok(Some(value));
-        }
-
         // if let Some(prototype) = self.prototype.borrow().as_ref() {
         //     return prototype.get_property_descriptor(sc, key);
         // }
@@ -548,7 +781,17 @@ impl Object for NamedObject {
         // TODO: check if we are invoking a setter
 
         let mut map = self.values.borrow_mut();
-        map.insert(key, value);
+        match map.get(&key) {
+            // Properties that are neither writable nor configurable cannot be changed
+            Some(existing)
+                if !existing
+                    .descriptor()
+                    .intersects(PropertyDataDescriptor::WRITABLE | PropertyDataDescriptor::CONFIGURABLE) => {}
+            Some(_) => drop(map.insert(key, value)),
+            // Adding properties to non-extensible objects silently fails, as in sloppy mode
+            None if !self.extensible.get() => {}
+            None => drop(map.insert(key, value)),
+        }
         Ok(())
     }
 
@@ -556,6 +799,15 @@ impl Object for NamedObject {
         let key = unsafe { &*addr_of!(key).cast::<PropertyKey<'static>>() };
 
         let mut values = self.values.borrow_mut();
+
+        // Non-configurable properties cannot be deleted
+        if values
+            .get(key)
+            .is_some_and(|value| !value.descriptor().contains(PropertyDataDescriptor::CONFIGURABLE))
+        {
+            return Ok(Value::undefined());
+        }
+
         let value = values.remove(key);
 
         match value.map(PropertyValue::into_kind) {
@@ -596,6 +848,10 @@ impl Object for NamedObject {
     }
 
     fn set_prototype(&self, sc: &mut LocalScope, value: Value) -> Result<(), Value> {
+        if !self.extensible.get() && value != self.get_prototype(sc)? {
+            throw!(sc, TypeError, "Cannot change the prototype of a non-extensible object");
+        }
+
         match value {
             Value::Null(_) => self.prototype.replace(None),
             Value::Object(handle) => self.prototype.replace(Some(handle)),
@@ -614,10 +870,23 @@ impl Object for NamedObject {
         }
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         let values = self.values.borrow();
         Ok(values.keys().map(PropertyKey::as_value).collect())
     }
+
+    fn is_extensible(&self, _sc: &mut LocalScope) -> Result<bool, Value> {
+        Ok(self.extensible.get())
+    }
+
+    fn prevent_extensions(&self, _sc: &mut LocalScope) -> Result<bool, Value> {
+        self.extensible.set(false);
+        Ok(true)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        Some(&self.private)
+    }
 }
 
 impl Object for Box<dyn Object> {
@@ -637,14 +906,32 @@ impl Object for Box<dyn Object> {
         (**self).get_property(sc, key)
     }
 
+    fn get_property_with_this(&self, sc: &mut LocalScope, this: Value, key: PropertyKey) -> Result<Value, Value> {
+        (**self).get_property_with_this(sc, this, key)
+    }
+
     fn get_property_descriptor(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Option<PropertyValue>, Value> {
         (**self).get_property_descriptor(sc, key)
     }
 
+    fn has_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<bool, Value> {
+        (**self).has_property(sc, key)
+    }
+
     fn set_property(&self, sc: &mut LocalScope, key: PropertyKey<'static>, value: PropertyValue) -> Result<(), Value> {
         (**self).set_property(sc, key, value)
     }
 
+    fn set_property_with_this(
+        &self,
+        sc: &mut LocalScope,
+        this: Value,
+        key: PropertyKey<'static>,
+        value: Value,
+    ) -> Result<bool, Value> {
+        (**self).set_property_with_this(sc, this, key, value)
+    }
+
     fn delete_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Value, Value> {
         (**self).delete_property(sc, key)
     }
@@ -681,8 +968,20 @@ impl Object for Box<dyn Object> {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        (**self).own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        (**self).own_keys(sc)
+    }
+
+    fn is_extensible(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        (**self).is_extensible(sc)
+    }
+
+    fn prevent_extensions(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        (**self).prevent_extensions(sc)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        (**self).private_elements()
     }
 
     fn type_of(&self) -> Typeof {
@@ -692,6 +991,10 @@ impl Object for Box<dyn Object> {
     fn as_primitive_capable(&self) -> Option<&dyn PrimitiveCapabilities> {
         (**self).as_primitive_capable()
     }
+
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        (**self).as_weak_container()
+    }
 }
 
 impl Object for Handle<dyn Object> {
@@ -711,14 +1014,32 @@ impl Object for Handle<dyn Object> {
         (**self).get_property(sc, key)
     }
 
+    fn get_property_with_this(&self, sc: &mut LocalScope, this: Value, key: PropertyKey) -> Result<Value, Value> {
+        (**self).get_property_with_this(sc, this, key)
+    }
+
     fn get_property_descriptor(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Option<PropertyValue>, Value> {
         (**self).get_property_descriptor(sc, key)
     }
 
+    fn has_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<bool, Value> {
+        (**self).has_property(sc, key)
+    }
+
     fn set_property(&self, sc: &mut LocalScope, key: PropertyKey<'static>, value: PropertyValue) -> Result<(), Value> {
         (**self).set_property(sc, key, value)
     }
 
+    fn set_property_with_this(
+        &self,
+        sc: &mut LocalScope,
+        this: Value,
+        key: PropertyKey<'static>,
+        value: Value,
+    ) -> Result<bool, Value> {
+        (**self).set_property_with_this(sc, this, key, value)
+    }
+
     fn delete_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Value, Value> {
         (**self).delete_property(sc, key)
     }
@@ -755,8 +1076,20 @@ impl Object for Handle<dyn Object> {
         (**self).as_any()
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        (**self).own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        (**self).own_keys(sc)
+    }
+
+    fn is_extensible(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        (**self).is_extensible(sc)
+    }
+
+    fn prevent_extensions(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        (**self).prevent_extensions(sc)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        (**self).private_elements()
     }
 
     fn type_of(&self) -> Typeof {
@@ -766,6 +1099,10 @@ impl Object for Handle<dyn Object> {
     fn as_primitive_capable(&self) -> Option<&dyn PrimitiveCapabilities> {
         (**self).as_primitive_capable()
     }
+
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        (**self).as_weak_container()
+    }
 }
 
 impl Handle<ExternalValue> {
@@ -3,11 +3,15 @@ use std::rc::Rc;
 use crate::gc::handle::Handle;
 use crate::local::LocalScope;
 use crate::throw;
+use crate::util::format_f64;
+use crate::value::boxed::BigInt as BoxedBigInt;
 use crate::value::boxed::Boolean;
 use crate::value::boxed::Number as BoxedNumber;
 use crate::value::boxed::String as BoxedString;
 use crate::value::boxed::Symbol as BoxedSymbol;
 use crate::value::object::Object;
+use crate::value::primitive::parse_bigint;
+use crate::value::primitive::BigInt;
 use crate::value::primitive::Number;
 use crate::value::primitive::MAX_SAFE_INTEGERF;
 use crate::value::Typeof;
@@ -95,6 +99,7 @@ impl ValueConversion for Value {
                 _ => Ok(s.parse::<f64>().unwrap_or(f64::NAN)),
             },
             Value::Symbol(_) => throw!(sc, TypeError, "Cannot convert symbol to number"),
+            Value::BigInt(b) => ValueConversion::to_number(b, sc),
             Value::Object(o) => object_to_number(self, o, sc),
             Value::External(o) => object_to_number(self, &o.inner, sc),
         }
@@ -108,6 +113,7 @@ impl ValueConversion for Value {
             Value::Number(Number(n)) => Ok(*n != 0.0 && !n.is_nan()),
             Value::String(s) => Ok(!s.is_empty()),
             Value::Symbol(_) => Ok(true),
+            Value::BigInt(b) => ValueConversion::to_boolean(b),
             Value::Object(_) => Ok(true),
             _ => todo!(), // TODO: implement other cases
         }
@@ -129,6 +135,7 @@ impl ValueConversion for Value {
             Value::Null(n) => ValueConversion::to_string(n, sc),
             Value::Undefined(u) => ValueConversion::to_string(u, sc),
             Value::Number(n) => ValueConversion::to_string(n, sc),
+            Value::BigInt(b) => ValueConversion::to_string(b, sc),
             Value::Object(o) => object_to_string(self, o, sc),
             Value::External(o) => object_to_string(self, &o.inner, sc),
             Value::Symbol(_) => throw!(sc, TypeError, "Cannot convert symbol to a string"),
@@ -196,6 +203,7 @@ impl ValueConversion for Value {
             Value::Symbol(s) => register_dyn(sc, |sc| BoxedSymbol::new(sc, s.clone())),
             Value::Number(Number(n)) => register_dyn(sc, |sc| BoxedNumber::new(sc, *n)),
             Value::String(s) => register_dyn(sc, |sc| BoxedString::new(sc, s.clone())),
+            Value::BigInt(b) => register_dyn(sc, |sc| BoxedBigInt::new(sc, b.clone())),
             Value::External(e) => Ok(e.inner.clone()), // TODO: is this correct?
         }
     }
@@ -220,6 +228,36 @@ impl PreferredType {
 }
 
 impl Value {
+    /// Converts this value to either a number or a BigInt (ToNumeric)
+    pub fn to_numeric(&self, sc: &mut LocalScope) -> Result<Value, Value> {
+        let primitive = self.to_primitive(sc, Some(PreferredType::Number))?;
+
+        match primitive {
+            Value::BigInt(_) => Ok(primitive),
+            _ => primitive.to_number(sc).map(Value::number),
+        }
+    }
+
+    /// Converts this value to a BigInt (ToBigInt).
+    ///
+    /// Unlike `BigInt()`, this does not accept numbers, as they might not be integers
+    pub fn to_bigint(&self, sc: &mut LocalScope) -> Result<BigInt, Value> {
+        let primitive = self.to_primitive(sc, Some(PreferredType::Number))?;
+
+        match primitive {
+            Value::BigInt(b) => Ok(b),
+            Value::Boolean(b) => Ok(BigInt::new((b as u8).into())),
+            Value::String(s) => match parse_bigint(&s) {
+                Some(b) => Ok(BigInt::new(b)),
+                None => throw!(sc, SyntaxError, "Cannot convert {} to a BigInt", s),
+            },
+            Value::Number(Number(n)) => throw!(sc, TypeError, "Cannot convert {} to a BigInt", format_f64(n)),
+            Value::Undefined(_) => throw!(sc, TypeError, "Cannot convert undefined to a BigInt"),
+            Value::Null(_) => throw!(sc, TypeError, "Cannot convert null to a BigInt"),
+            _ => throw!(sc, TypeError, "Cannot convert value to a BigInt"),
+        }
+    }
+
     pub fn ordinary_to_primitive(&self, sc: &mut LocalScope, preferred_type: PreferredType) -> Result<Value, Value> {
         let method_names = match preferred_type {
             PreferredType::String => ["toString", "valueOf"],
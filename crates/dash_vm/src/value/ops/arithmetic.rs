@@ -1,5 +1,13 @@
+use num_bigint::Sign;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+
 use crate::local::LocalScope;
+use crate::throw;
 use crate::value::object::Object;
+use crate::value::primitive::BigInt;
 use crate::value::Typeof;
 use crate::value::Value;
 
@@ -19,40 +27,72 @@ impl Value {
             let rstr = right.to_string(scope)?;
             Ok(Value::String(format!("{lstr}{rstr}").into()))
         } else {
-            let lnum = left.to_number(scope)?;
-            let rnum = right.to_number(scope)?;
-            Ok(Value::number(lnum + rnum))
+            left.numeric_op(&right, scope, |l, r| l + r, |l, r, _| Ok(l + r))
         }
     }
 
     pub fn sub(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let lnum = self.to_number(scope)?;
-        let rnum = other.to_number(scope)?;
-        Ok(Value::number(lnum - rnum))
+        self.numeric_op(other, scope, |l, r| l - r, |l, r, _| Ok(l - r))
     }
 
     pub fn mul(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let lnum = self.to_number(scope)?;
-        let rnum = other.to_number(scope)?;
-        Ok(Value::number(lnum * rnum))
+        self.numeric_op(other, scope, |l, r| l * r, |l, r, _| Ok(l * r))
     }
 
     pub fn div(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let lnum = self.to_number(scope)?;
-        let rnum = other.to_number(scope)?;
-        Ok(Value::number(lnum / rnum))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| l / r,
+            |l, r, sc| {
+                if r.is_zero() {
+                    throw!(sc, RangeError, "Division by zero");
+                }
+                Ok(l / r)
+            },
+        )
     }
 
     pub fn rem(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let lnum = self.to_number(scope)?;
-        let rnum = other.to_number(scope)?;
-        Ok(Value::number(lnum % rnum))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| l % r,
+            |l, r, sc| {
+                if r.is_zero() {
+                    throw!(sc, RangeError, "Division by zero");
+                }
+                Ok(l % r)
+            },
+        )
     }
 
     pub fn pow(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let lnum = self.to_number(scope)?;
-        let rnum = other.to_number(scope)?;
-        Ok(Value::number(lnum.powf(rnum)))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| l.powf(r),
+            |l, r, sc| {
+                if r.is_negative() {
+                    throw!(sc, RangeError, "Exponent must be non-negative");
+                }
+                // Powers of 0, 1 and -1 stay small no matter how large the exponent is
+                if l.is_zero() || l.magnitude().is_one() {
+                    return Ok(if r.is_zero() {
+                        num_bigint::BigInt::one()
+                    } else if l.is_negative() && r.bit(0) {
+                        -num_bigint::BigInt::one()
+                    } else {
+                        l.abs()
+                    });
+                }
+                // The result has at most `l.bits() * r` bits
+                match r.to_u32() {
+                    Some(r) if l.bits().saturating_mul(u64::from(r)) <= MAX_BIGINT_BITS => Ok(l.pow(r)),
+                    _ => throw!(sc, RangeError, "Maximum BigInt size exceeded"),
+                }
+            },
+        )
     }
 
     pub fn not(&self) -> Value {
@@ -60,44 +100,144 @@ impl Value {
     }
 
     pub fn bitor(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)?;
-        let that = other.to_int32(scope)?;
-        Ok(Value::number((this | that) as f64))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| (to_int32(l) | to_int32(r)) as f64,
+            |l, r, _| Ok(l | r),
+        )
     }
 
     pub fn bitxor(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)?;
-        let that = other.to_int32(scope)?;
-        Ok(Value::number((this ^ that) as f64))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| (to_int32(l) ^ to_int32(r)) as f64,
+            |l, r, _| Ok(l ^ r),
+        )
     }
 
     pub fn bitand(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)?;
-        let that = other.to_int32(scope)?;
-        Ok(Value::number((this & that) as f64))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| (to_int32(l) & to_int32(r)) as f64,
+            |l, r, _| Ok(l & r),
+        )
     }
 
     pub fn bitshl(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)?;
-        let that = other.to_int32(scope)?;
-        Ok(Value::number((this << that) as f64))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| (to_int32(l) << to_int32(r)) as f64,
+            bigint_shift_left,
+        )
     }
 
     pub fn bitshr(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)?;
-        let that = other.to_int32(scope)?;
-        Ok(Value::number((this >> that) as f64))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| (to_int32(l) >> to_int32(r)) as f64,
+            |l, r, sc| bigint_shift_left(l, &-r, sc),
+        )
     }
 
     pub fn bitushr(&self, other: &Self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)? as u32;
-        let that = other.to_int32(scope)? as u32;
-        Ok(Value::number((this.wrapping_shr(that)) as f64))
+        self.numeric_op(
+            other,
+            scope,
+            |l, r| (to_int32(l) as u32).wrapping_shr(to_int32(r) as u32) as f64,
+            |_, _, sc| throw!(sc, TypeError, "BigInts have no unsigned right shift, use >> instead"),
+        )
     }
 
     pub fn bitnot(&self, scope: &mut LocalScope) -> Result<Value, Value> {
-        let this = self.to_int32(scope)?;
-        Ok(Value::number((!this) as f64))
+        match self.to_numeric(scope)? {
+            Value::BigInt(b) => Ok(Value::BigInt(BigInt::new(!&*b))),
+            this => {
+                let this = this.to_int32(scope)?;
+                Ok(Value::number((!this) as f64))
+            }
+        }
+    }
+
+    pub fn neg(&self, scope: &mut LocalScope) -> Result<Value, Value> {
+        match self.to_numeric(scope)? {
+            Value::BigInt(b) => Ok(Value::BigInt(BigInt::new(-&*b))),
+            this => Ok(Value::number(-this.to_number(scope)?)),
+        }
+    }
+
+    /// Returns the value `1` of the same numeric type as this value, as added or subtracted by `++` and `--`.
+    ///
+    /// This value must already have been converted to a numeric value
+    pub fn numeric_one(&self) -> Value {
+        match self {
+            Value::BigInt(_) => Value::BigInt(BigInt::new(num_bigint::BigInt::one())),
+            _ => Value::number(1.0),
+        }
+    }
+
+    /// Applies an arithmetic operator to the numeric values (ToNumeric) of both operands,
+    /// using `number` if both are numbers and `bigint` if both are BigInts.
+    ///
+    /// Mixing numbers and BigInts throws a TypeError
+    fn numeric_op(
+        &self,
+        other: &Self,
+        scope: &mut LocalScope,
+        number: impl FnOnce(f64, f64) -> f64,
+        bigint: impl FnOnce(&num_bigint::BigInt, &num_bigint::BigInt, &mut LocalScope) -> Result<num_bigint::BigInt, Value>,
+    ) -> Result<Value, Value> {
+        let left = self.to_numeric(scope)?;
+        let right = other.to_numeric(scope)?;
+
+        match (left, right) {
+            (Value::BigInt(left), Value::BigInt(right)) => {
+                bigint(&left, &right, scope).map(|n| Value::BigInt(n.into()))
+            }
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => {
+                throw!(
+                    scope,
+                    TypeError,
+                    "Cannot mix BigInt and other types, use explicit conversions"
+                )
+            }
+            (left, right) => {
+                let lnum = left.to_number(scope)?;
+                let rnum = right.to_number(scope)?;
+                Ok(Value::number(number(lnum, rnum)))
+            }
+        }
+    }
+}
+
+/// The largest number of bits that BigInt operations may produce,
+/// since results larger than this would not fit in memory anyway
+pub const MAX_BIGINT_BITS: u64 = 1 << 30;
+
+fn to_int32(n: f64) -> i32 {
+    n as i64 as i32
+}
+
+/// Shifts `value` to the left by `amount` bits, or to the right if `amount` is negative
+fn bigint_shift_left(
+    value: &num_bigint::BigInt,
+    amount: &num_bigint::BigInt,
+    sc: &mut LocalScope,
+) -> Result<num_bigint::BigInt, Value> {
+    match (amount.sign(), amount.magnitude().to_u64()) {
+        (Sign::Minus, Some(amount)) if amount <= MAX_BIGINT_BITS => Ok(value >> amount),
+        // Shifting right by a huge amount leaves only the sign
+        (Sign::Minus, _) => Ok(if value.is_negative() {
+            -num_bigint::BigInt::one()
+        } else {
+            num_bigint::BigInt::zero()
+        }),
+        (_, Some(amount)) if amount <= MAX_BIGINT_BITS => Ok(value << amount),
+        _ => throw!(sc, RangeError, "Maximum BigInt size exceeded"),
     }
 }
 
@@ -110,6 +250,7 @@ macro_rules! delegate {
             Self::Undefined(u) => $func(u, $other, $sc),
             Self::Null(n) => $func(n, $other, $sc),
             Self::Symbol(s) => $func(s, $other, $sc),
+            Self::BigInt(b) => $func(b, $other, $sc),
             Self::Object(o) => {
                 if let Some(prim) = o.as_primitive_capable() {
                     $func(prim, $other, $sc)
@@ -136,28 +277,57 @@ macro_rules! delegate {
     };
 }
 
+/// Returns the right operand of a comparison if it is a BigInt and the left one is not.
+///
+/// Only BigInts know how to compare themselves with other types, so the operands are swapped in that case
+fn swapped_bigint<'a>(this: &Value, other: &'a Value) -> Option<&'a BigInt> {
+    match (this, other) {
+        (Value::BigInt(_), _) => None,
+        (_, Value::BigInt(other)) => Some(other),
+        _ => None,
+    }
+}
+
 impl ValueEquality for Value {
     fn lt(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        if let Some(other) = swapped_bigint(self, other) {
+            return ValueEquality::gt(other, self, sc);
+        }
         delegate!(self, other, sc, ValueEquality::lt)
     }
 
     fn le(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        if let Some(other) = swapped_bigint(self, other) {
+            return ValueEquality::ge(other, self, sc);
+        }
         delegate!(self, other, sc, ValueEquality::le)
     }
 
     fn gt(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        if let Some(other) = swapped_bigint(self, other) {
+            return ValueEquality::lt(other, self, sc);
+        }
         delegate!(self, other, sc, ValueEquality::gt)
     }
 
     fn ge(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        if let Some(other) = swapped_bigint(self, other) {
+            return ValueEquality::le(other, self, sc);
+        }
         delegate!(self, other, sc, ValueEquality::ge)
     }
 
     fn eq(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        if let Some(other) = swapped_bigint(self, other) {
+            return ValueEquality::eq(other, self, sc);
+        }
         delegate!(self, other, sc, ValueEquality::eq)
     }
 
     fn strict_eq(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        if let Some(other) = swapped_bigint(self, other) {
+            return ValueEquality::strict_eq(other, self, sc);
+        }
         delegate!(self, other, sc, ValueEquality::strict_eq)
     }
 }
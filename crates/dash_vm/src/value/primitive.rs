@@ -1,22 +1,32 @@
 use std::any::Any;
+use std::cmp::Ordering;
 use std::fmt;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::iter;
+use std::ops::Deref;
 use std::rc::Rc;
 
+use num_bigint::Sign;
+use num_traits::FromPrimitive;
+use num_traits::Num;
+use num_traits::Zero;
+
 use crate::gc::handle::Handle;
 use crate::local::LocalScope;
 use crate::throw;
 use crate::util::format_f64;
 
+use super::boxed::BigInt as BoxedBigInt;
 use super::boxed::Boolean as BoxedBoolean;
 use super::boxed::Number as BoxedNumber;
 use super::boxed::String as BoxedString;
 use super::boxed::Symbol as BoxedSymbol;
 use super::object::Object;
+use super::object::PropertyDataDescriptor;
 use super::object::PropertyKey;
 use super::object::PropertyValue;
+use super::object::PropertyValueKind;
 use super::ops::abstractions::conversions::PreferredType;
 use super::ops::abstractions::conversions::ValueConversion;
 use super::ops::equality::ValueEquality;
@@ -71,7 +81,7 @@ impl Object for f64 {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(Vec::new())
     }
 
@@ -128,7 +138,7 @@ impl Object for bool {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(Vec::new())
     }
 
@@ -186,8 +196,8 @@ impl Object for Rc<str> {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        str::own_keys(self)
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        str::own_keys(self, sc)
     }
 
     fn type_of(&self) -> Typeof {
@@ -218,11 +228,11 @@ impl Object for Undefined {
         sc: &mut LocalScope,
         key: PropertyKey,
     ) -> Result<Option<PropertyValue>, Value> {
-        throw!(sc, TypeError, "Cannot read property {:?} of undefined", key)
+        throw!(sc, TypeError, "Cannot read property '{}' of undefined", key)
     }
 
     fn set_property(&self, sc: &mut LocalScope, key: PropertyKey<'static>, _value: PropertyValue) -> Result<(), Value> {
-        throw!(sc, TypeError, "Cannot set property {:?} of undefined", key)
+        throw!(sc, TypeError, "Cannot set property '{}' of undefined", key)
     }
 
     fn delete_property(&self, _sc: &mut LocalScope, _key: PropertyKey) -> Result<Value, Value> {
@@ -251,7 +261,7 @@ impl Object for Undefined {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(Vec::new())
     }
 
@@ -270,11 +280,11 @@ impl Object for Null {
         sc: &mut LocalScope,
         key: PropertyKey,
     ) -> Result<Option<PropertyValue>, Value> {
-        throw!(sc, TypeError, "Cannot read property {:?} of null", key)
+        throw!(sc, TypeError, "Cannot read property '{}' of null", key)
     }
 
     fn set_property(&self, sc: &mut LocalScope, key: PropertyKey<'static>, _value: PropertyValue) -> Result<(), Value> {
-        throw!(sc, TypeError, "Cannot set property {:?} of null", key)
+        throw!(sc, TypeError, "Cannot set property '{}' of null", key)
     }
 
     fn delete_property(&self, _sc: &mut LocalScope, _key: PropertyKey) -> Result<Value, Value> {
@@ -303,7 +313,7 @@ impl Object for Null {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(Vec::new())
     }
 
@@ -319,16 +329,21 @@ impl Object for str {
         key: PropertyKey,
     ) -> Result<Option<PropertyValue>, Value> {
         if let PropertyKey::String(st) = key {
+            // The characters of a string are read-only but enumerable, and its length is neither
             if st == "length" {
-                return Ok(Some(PropertyValue::static_default(Value::number(self.len() as f64))));
+                return Ok(Some(PropertyValue::new(
+                    PropertyValueKind::Static(Value::number(self.len() as f64)),
+                    PropertyDataDescriptor::empty(),
+                )));
             }
 
             if let Ok(index) = st.parse::<usize>() {
                 let bytes = self.as_bytes();
                 if let Some(&byte) = bytes.get(index) {
-                    return Ok(Some(PropertyValue::static_default(Value::String(
-                        (byte as char).to_string().into(),
-                    ))));
+                    return Ok(Some(PropertyValue::new(
+                        PropertyValueKind::Static(Value::String((byte as char).to_string().into())),
+                        PropertyDataDescriptor::ENUMERABLE,
+                    )));
                 }
             }
         }
@@ -371,7 +386,7 @@ impl Object for str {
         panic!("cannot convert string to any")
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(array_like_keys(self.len()).collect())
     }
 
@@ -380,12 +395,74 @@ impl Object for str {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct Symbol(Rc<str>);
+/// A symbol value.
+///
+/// Every symbol has its own allocation and is compared and hashed by its address,
+/// so two symbols with the same description are still different property keys.
+#[derive(Debug, Clone)]
+pub struct Symbol(Rc<SymbolInner>);
+
+#[derive(Debug)]
+struct SymbolInner {
+    description: Option<Rc<str>>,
+    /// Whether this symbol is the private name of a class member, i.e. `#foo`
+    private: bool,
+}
 
 impl Symbol {
     pub fn new(description: Rc<str>) -> Self {
-        Symbol(description)
+        Symbol(Rc::new(SymbolInner {
+            description: Some(description),
+            private: false,
+        }))
+    }
+
+    /// Creates a symbol whose description is undefined, as in `Symbol()`
+    pub fn without_description() -> Self {
+        Symbol(Rc::new(SymbolInner {
+            description: None,
+            private: false,
+        }))
+    }
+
+    /// Creates the private name of a class member.
+    ///
+    /// Private names are never exposed to user code as values, and the `in` operator only looks for them
+    /// in the object itself, which makes them usable as a brand of the class that declares them
+    pub fn private(description: Rc<str>) -> Self {
+        Symbol(Rc::new(SymbolInner {
+            description: Some(description),
+            private: true,
+        }))
+    }
+
+    pub fn description(&self) -> Option<&Rc<str>> {
+        self.0.description.as_ref()
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.0.private
+    }
+}
+
+/// Formats the symbol as its descriptive string, e.g. `Symbol(foo)` (SymbolDescriptiveString)
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({})", self.description().map_or("", |d| d.as_ref()))
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state)
     }
 }
 
@@ -433,7 +510,7 @@ impl Object for Symbol {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
         Ok(Vec::new())
     }
 
@@ -456,6 +533,9 @@ pub trait PrimitiveCapabilities: ValueConversion + ValueEquality + std::fmt::Deb
     fn as_bool(&self) -> Option<bool> {
         None
     }
+    fn as_bigint(&self) -> Option<BigInt> {
+        None
+    }
     fn is_undefined(&self) -> bool {
         false
     }
@@ -826,11 +906,17 @@ impl ValueEquality for Symbol {
     }
 
     fn eq(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
-        other.to_number(sc).map(|other| Value::Boolean(0.0 == other))
+        match other {
+            Value::Object(_) | Value::External(_) => {
+                let other = other.to_primitive(sc, None)?;
+                ValueEquality::strict_eq(self, &other, sc)
+            }
+            _ => ValueEquality::strict_eq(self, other, sc),
+        }
     }
 
-    fn strict_eq(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
-        ValueEquality::eq(self, other, sc)
+    fn strict_eq(&self, other: &Value, _sc: &mut LocalScope) -> Result<Value, Value> {
+        Ok(Value::Boolean(matches!(other, Value::Symbol(other) if other == self)))
     }
 }
 
@@ -861,6 +947,242 @@ impl ValueConversion for Symbol {
     }
 }
 
+/// An arbitrary-precision integer value, as created by `42n` or `BigInt(42)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt(Rc<num_bigint::BigInt>);
+
+impl BigInt {
+    pub fn new(value: num_bigint::BigInt) -> Self {
+        Self(Rc::new(value))
+    }
+
+    /// Returns the lowest 64 bits of the two's complement representation of this BigInt
+    pub fn to_u64_wrapping(&self) -> u64 {
+        let (sign, digits) = self.0.to_u64_digits();
+        let low = digits.first().copied().unwrap_or(0);
+
+        match sign {
+            Sign::Minus => low.wrapping_neg(),
+            _ => low,
+        }
+    }
+
+    /// Compares this BigInt with any other value, as done by the relational operators.
+    ///
+    /// Returns `None` if the values are not comparable, e.g. because the other value is NaN
+    /// or a string that is not a valid integer
+    fn compare(&self, other: &Value, sc: &mut LocalScope) -> Result<Option<Ordering>, Value> {
+        match other {
+            Value::BigInt(other) => Ok(Some(self.0.cmp(&other.0))),
+            Value::Number(Number(n)) => Ok(compare_bigint_f64(&self.0, *n)),
+            Value::Boolean(b) => Ok(Some(self.0.as_ref().cmp(&num_bigint::BigInt::from(*b as u8)))),
+            Value::Null(_) => Ok(Some(self.0.sign().cmp(&Sign::NoSign))),
+            Value::Undefined(_) => Ok(None),
+            Value::String(s) => Ok(parse_bigint(s).map(|other| self.0.as_ref().cmp(&other))),
+            Value::Symbol(_) => throw!(sc, TypeError, "Cannot convert a Symbol value to a number"),
+            Value::Object(_) | Value::External(_) => {
+                let other = other.to_primitive(sc, Some(PreferredType::Number))?;
+                self.compare(&other, sc)
+            }
+        }
+    }
+}
+
+impl From<num_bigint::BigInt> for BigInt {
+    fn from(value: num_bigint::BigInt) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Rc<num_bigint::BigInt>> for BigInt {
+    fn from(value: Rc<num_bigint::BigInt>) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for BigInt {
+    type Target = num_bigint::BigInt;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parses a string to a BigInt (StringToBigInt), which unlike BigInt literals allows surrounding whitespace
+/// but no `n` suffix
+pub fn parse_bigint(s: &str) -> Option<num_bigint::BigInt> {
+    let s = s.trim();
+
+    let (radix, digits) = match s.get(..2) {
+        Some("0x" | "0X") => (16, &s[2..]),
+        Some("0o" | "0O") => (8, &s[2..]),
+        Some("0b" | "0B") => (2, &s[2..]),
+        _ if s.is_empty() => return Some(num_bigint::BigInt::zero()),
+        _ => (10, s),
+    };
+
+    // Prefixed literals cannot have a sign, and `from_str_radix` would accept one
+    if radix != 10 && digits.starts_with(['+', '-']) {
+        return None;
+    }
+
+    num_bigint::BigInt::from_str_radix(digits, radix).ok()
+}
+
+fn compare_bigint_f64(left: &num_bigint::BigInt, right: f64) -> Option<Ordering> {
+    if right.is_nan() {
+        return None;
+    }
+
+    if right.is_infinite() {
+        return Some(if right > 0.0 { Ordering::Less } else { Ordering::Greater });
+    }
+
+    let floor = right.floor();
+    let floor_int = num_bigint::BigInt::from_f64(floor)?;
+
+    match left.cmp(&floor_int) {
+        // The fractional part makes the number slightly larger than its floor
+        Ordering::Equal if floor != right => Some(Ordering::Less),
+        ordering => Some(ordering),
+    }
+}
+
+impl Object for BigInt {
+    fn get_own_property_descriptor(
+        &self,
+        _sc: &mut LocalScope,
+        _key: PropertyKey,
+    ) -> Result<Option<PropertyValue>, Value> {
+        Ok(None)
+    }
+
+    fn set_property(
+        &self,
+        _sc: &mut LocalScope,
+        _key: PropertyKey<'static>,
+        _value: PropertyValue,
+    ) -> Result<(), Value> {
+        Ok(())
+    }
+
+    fn delete_property(&self, _sc: &mut LocalScope, _key: PropertyKey) -> Result<Value, Value> {
+        Ok(Value::undefined())
+    }
+
+    fn set_prototype(&self, _sc: &mut LocalScope, _value: Value) -> Result<(), Value> {
+        Ok(())
+    }
+
+    fn get_prototype(&self, sc: &mut LocalScope) -> Result<Value, Value> {
+        Ok(sc.statics.bigint_prototype.clone().into())
+    }
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        _args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        throw!(scope, TypeError, "{}n is not a function", self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn own_keys(&self, _sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        Ok(Vec::new())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Bigint
+    }
+
+    fn as_primitive_capable(&self) -> Option<&dyn PrimitiveCapabilities> {
+        Some(self)
+    }
+}
+
+impl PrimitiveCapabilities for BigInt {
+    fn as_bigint(&self) -> Option<BigInt> {
+        Some(self.clone())
+    }
+
+    fn unbox(&self) -> Value {
+        Value::BigInt(self.clone())
+    }
+}
+
+impl ValueEquality for BigInt {
+    fn lt(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        self.compare(other, sc)
+            .map(|ord| Value::Boolean(ord == Some(Ordering::Less)))
+    }
+
+    fn le(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        self.compare(other, sc)
+            .map(|ord| Value::Boolean(matches!(ord, Some(Ordering::Less | Ordering::Equal))))
+    }
+
+    fn gt(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        self.compare(other, sc)
+            .map(|ord| Value::Boolean(ord == Some(Ordering::Greater)))
+    }
+
+    fn ge(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        self.compare(other, sc)
+            .map(|ord| Value::Boolean(matches!(ord, Some(Ordering::Greater | Ordering::Equal))))
+    }
+
+    fn eq(&self, other: &Value, sc: &mut LocalScope) -> Result<Value, Value> {
+        match other {
+            Value::Undefined(_) | Value::Null(_) | Value::Symbol(_) => Ok(Value::Boolean(false)),
+            _ => self
+                .compare(other, sc)
+                .map(|ord| Value::Boolean(ord == Some(Ordering::Equal))),
+        }
+    }
+
+    fn strict_eq(&self, other: &Value, _sc: &mut LocalScope) -> Result<Value, Value> {
+        Ok(Value::Boolean(matches!(other, Value::BigInt(other) if other == self)))
+    }
+}
+
+impl ValueConversion for BigInt {
+    fn to_primitive(&self, _sc: &mut LocalScope, _preferred_type: Option<PreferredType>) -> Result<Value, Value> {
+        Ok(Value::BigInt(self.clone()))
+    }
+
+    fn to_number(&self, sc: &mut LocalScope) -> Result<f64, Value> {
+        throw!(sc, TypeError, "Cannot convert a BigInt value to a number");
+    }
+
+    fn to_boolean(&self) -> Result<bool, Value> {
+        Ok(!self.0.is_zero())
+    }
+
+    fn to_string(&self, _sc: &mut LocalScope) -> Result<Rc<str>, Value> {
+        Ok(self.0.to_string().into())
+    }
+
+    fn length_of_array_like(&self, _sc: &mut LocalScope) -> Result<usize, Value> {
+        Ok(0)
+    }
+
+    fn to_object(&self, sc: &mut LocalScope) -> Result<Handle<dyn Object>, Value> {
+        let bigint = BoxedBigInt::new(sc, self.clone());
+        Ok(sc.register(bigint))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(transparent)]
 pub struct Number(pub f64);
@@ -918,8 +1240,8 @@ impl Object for Number {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.0.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.0.own_keys(sc)
     }
 
     fn type_of(&self) -> Typeof {
@@ -1,18 +1,25 @@
 use std::any::Any;
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::rc::Rc;
 
 use dash_proc_macro::Trace;
 
+use crate::delegate;
 use crate::gc::handle::Handle;
 use crate::gc::trace::Trace;
 use crate::local::LocalScope;
 use crate::PromiseAction;
 use crate::Vm;
 
+use super::error::TypeError;
 use super::object::NamedObject;
 use super::object::Object;
+use super::object::PrivateElements;
+use super::object::PropertyKey;
 use super::Typeof;
 use super::Value;
+use super::ValueContext;
 
 #[derive(Debug)]
 pub enum PromiseState {
@@ -37,9 +44,30 @@ unsafe impl Trace for PromiseState {
     }
 }
 
+/// Whether a handler has been registered on a promise, used for tracking rejections that are not handled
+/// (the [[PromiseIsHandled]] slot)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionTracking {
+    Unhandled,
+    /// The promise was rejected without a handler and this has been reported
+    /// to the [`VmParams`](crate::params::VmParams) rejection unhandled callback
+    Reported,
+    Handled,
+}
+
+/// A rejection of a promise that had no handler at the time.
+/// It is reported once the async task queue is empty, unless a handler has been registered until then
+#[derive(Debug, Trace)]
+pub(crate) struct PendingRejection {
+    pub tracking: Rc<Cell<RejectionTracking>>,
+    pub reason: Value,
+}
+
 #[derive(Debug, Trace)]
 pub struct Promise {
     state: RefCell<PromiseState>,
+    /// Shared with the [`PendingRejection`] of this promise, if it has been rejected without a handler
+    rejection_tracking: Rc<Cell<RejectionTracking>>,
     obj: NamedObject,
 }
 
@@ -50,6 +78,7 @@ impl Promise {
                 reject: Vec::new(),
                 resolve: Vec::new(),
             }),
+            rejection_tracking: Rc::new(Cell::new(RejectionTracking::Unhandled)),
             obj: NamedObject::with_prototype_and_constructor(
                 vm.statics.promise_proto.clone(),
                 vm.statics.promise_ctor.clone(),
@@ -59,6 +88,7 @@ impl Promise {
     pub fn resolved(vm: &mut Vm, value: Value) -> Self {
         Self {
             state: RefCell::new(PromiseState::Resolved(value)),
+            rejection_tracking: Rc::new(Cell::new(RejectionTracking::Unhandled)),
             obj: NamedObject::with_prototype_and_constructor(
                 vm.statics.promise_proto.clone(),
                 vm.statics.promise_ctor.clone(),
@@ -66,8 +96,12 @@ impl Promise {
         }
     }
     pub fn rejected(vm: &mut Vm, value: Value) -> Self {
+        let rejection_tracking = Rc::new(Cell::new(RejectionTracking::Unhandled));
+        vm.track_rejection(rejection_tracking.clone(), value.clone());
+
         Self {
             state: RefCell::new(PromiseState::Rejected(value)),
+            rejection_tracking,
             obj: NamedObject::with_prototype_and_constructor(
                 vm.statics.promise_proto.clone(),
                 vm.statics.promise_ctor.clone(),
@@ -77,6 +111,9 @@ impl Promise {
     pub fn state(&self) -> &RefCell<PromiseState> {
         &self.state
     }
+    pub fn rejection_tracking(&self) -> &Rc<Cell<RejectionTracking>> {
+        &self.rejection_tracking
+    }
 }
 
 impl Object for Promise {
@@ -127,21 +164,28 @@ impl Object for Promise {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.obj.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.obj.own_keys(sc)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        self.obj.private_elements()
     }
 }
 
 #[derive(Debug, Trace)]
 pub struct PromiseResolver {
     promise: Handle<dyn Object>,
+    /// Shared with the other resolving function of the promise, see [`create_resolving_functions`]
+    already_resolved: Rc<Cell<bool>>,
     obj: NamedObject,
 }
 
 impl PromiseResolver {
-    pub fn new(vm: &mut Vm, promise: Handle<dyn Object>) -> Self {
+    pub fn new(vm: &mut Vm, promise: Handle<dyn Object>, already_resolved: Rc<Cell<bool>>) -> Self {
         Self {
             promise,
+            already_resolved,
             obj: NamedObject::new(vm),
         }
     }
@@ -188,11 +232,9 @@ impl Object for PromiseResolver {
         _this: Value,
         args: Vec<Value>,
     ) -> Result<Value, Value> {
-        scope.drive_promise(
-            PromiseAction::Resolve,
-            self.promise.as_any().downcast_ref::<Promise>().unwrap(),
-            args,
-        );
+        if !self.already_resolved.replace(true) {
+            resolve_promise(scope, &self.promise, args.first().unwrap_or_undefined());
+        }
 
         Ok(Value::undefined())
     }
@@ -201,8 +243,8 @@ impl Object for PromiseResolver {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.obj.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.obj.own_keys(sc)
     }
 
     fn type_of(&self) -> super::Typeof {
@@ -213,13 +255,16 @@ impl Object for PromiseResolver {
 #[derive(Debug, Trace)]
 pub struct PromiseRejecter {
     promise: Handle<dyn Object>,
+    /// Shared with the other resolving function of the promise, see [`create_resolving_functions`]
+    already_resolved: Rc<Cell<bool>>,
     obj: NamedObject,
 }
 
 impl PromiseRejecter {
-    pub fn new(vm: &mut Vm, promise: Handle<dyn Object>) -> Self {
+    pub fn new(vm: &mut Vm, promise: Handle<dyn Object>, already_resolved: Rc<Cell<bool>>) -> Self {
         Self {
             promise,
+            already_resolved,
             obj: NamedObject::new(vm),
         }
     }
@@ -266,11 +311,13 @@ impl Object for PromiseRejecter {
         _this: Value,
         args: Vec<Value>,
     ) -> Result<Value, Value> {
-        scope.drive_promise(
-            PromiseAction::Reject,
-            self.promise.as_any().downcast_ref::<Promise>().unwrap(),
-            args,
-        );
+        if !self.already_resolved.replace(true) {
+            scope.drive_promise(
+                PromiseAction::Reject,
+                self.promise.as_any().downcast_ref::<Promise>().unwrap(),
+                args,
+            );
+        }
 
         Ok(Value::undefined())
     }
@@ -279,8 +326,8 @@ impl Object for PromiseRejecter {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.obj.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.obj.own_keys(sc)
     }
 
     fn type_of(&self) -> super::Typeof {
@@ -288,7 +335,61 @@ impl Object for PromiseRejecter {
     }
 }
 
-/// Wraps the passed value in a resolved promise, unless it already is a promise
+/// Creates the `resolve` and `reject` functions of a promise (CreateResolvingFunctions).
+///
+/// Once either of them has been called, calling them again has no effect,
+/// even if the promise is still pending because it was resolved with a thenable
+pub fn create_resolving_functions(
+    scope: &mut LocalScope,
+    promise: Handle<dyn Object>,
+) -> (Handle<dyn Object>, Handle<dyn Object>) {
+    let already_resolved = Rc::new(Cell::new(false));
+    let resolve = PromiseResolver::new(scope, promise.clone(), already_resolved.clone());
+    let reject = PromiseRejecter::new(scope, promise, already_resolved);
+    (scope.register(resolve), scope.register(reject))
+}
+
+/// Resolves a pending promise with a value (Promise Resolve Functions).
+///
+/// If the value is a thenable, the promise is not fulfilled with it but instead follows it,
+/// which is set up by calling its `then` method in a separate job
+pub fn resolve_promise(scope: &mut LocalScope, promise: &Handle<dyn Object>, resolution: Value) {
+    let promise_ref = promise.as_any().downcast_ref::<Promise>().unwrap();
+
+    if let Value::Object(object) = &resolution {
+        if object == promise {
+            let err = TypeError::new(scope, "Chaining cycle detected for promise");
+            let err = Value::Object(scope.register(err));
+            scope.drive_promise(PromiseAction::Reject, promise_ref, vec![err]);
+            return;
+        }
+
+        let then = match resolution.get_property(scope, PropertyKey::String("then".into())) {
+            Ok(then) => then,
+            Err(err) => {
+                scope.drive_promise(PromiseAction::Reject, promise_ref, vec![err]);
+                return;
+            }
+        };
+
+        if matches!(then.type_of(), Typeof::Function) {
+            let job = PromiseResolveThenableJob {
+                promise: promise.clone(),
+                thenable: resolution,
+                then,
+                obj: NamedObject::new(scope),
+            };
+            let job = scope.register(job);
+            scope.add_async_task(job);
+            return;
+        }
+    }
+
+    scope.drive_promise(PromiseAction::Resolve, promise_ref, vec![resolution]);
+}
+
+/// Returns the value if it is a promise, otherwise creates a new promise that is resolved with the value
+/// (PromiseResolve)
 pub fn wrap_promise(scope: &mut LocalScope, value: Value) -> Value {
     if let Value::Object(object) = &value {
         if object.as_any().is::<Promise>() {
@@ -296,6 +397,57 @@ pub fn wrap_promise(scope: &mut LocalScope, value: Value) -> Value {
         }
     }
 
-    let promise = Promise::resolved(scope, value);
-    Value::Object(scope.register(promise))
+    let promise = Promise::new(scope);
+    let promise = scope.register(promise);
+    resolve_promise(scope, &promise, value);
+    Value::Object(promise)
+}
+
+/// A job that makes a promise follow a thenable it was resolved with, by calling the thenable's `then` method
+/// with new resolving functions for the promise
+#[derive(Debug, Trace)]
+struct PromiseResolveThenableJob {
+    promise: Handle<dyn Object>,
+    thenable: Value,
+    then: Value,
+    obj: NamedObject,
+}
+
+impl Object for PromiseResolveThenableJob {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        _args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        let (resolve, reject) = create_resolving_functions(scope, self.promise.clone());
+
+        if let Err(err) = self.then.apply(
+            scope,
+            self.thenable.clone(),
+            vec![Value::Object(resolve), Value::Object(reject.clone())],
+        ) {
+            reject.apply(scope, Value::undefined(), vec![err])?;
+        }
+
+        Ok(Value::undefined())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
 }
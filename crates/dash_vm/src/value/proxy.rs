@@ -0,0 +1,742 @@
+use std::any::Any;
+use std::cell::RefCell;
+
+use dash_proc_macro::Trace;
+
+use crate::delegate;
+use crate::gc::handle::Handle;
+use crate::local::LocalScope;
+use crate::throw;
+use crate::Vm;
+
+use super::array::Array;
+use super::object::NamedObject;
+use super::object::Object;
+use super::object::PrivateElements;
+use super::object::PropertyDataDescriptor;
+use super::object::PropertyKey;
+use super::object::PropertyValue;
+use super::object::PropertyValueKind;
+use super::ops::abstractions::conversions::ValueConversion;
+use super::primitive::Number;
+use super::Typeof;
+use super::Value;
+use super::ValueContext;
+
+/// A proxy exotic object, which forwards property operations to the traps of its handler object
+#[derive(Debug, Trace)]
+pub struct Proxy {
+    target: Handle<dyn Object>,
+    /// The handler object, which is `None` once the proxy has been revoked
+    handler: RefCell<Option<Handle<dyn Object>>>,
+    /// The handle of this proxy, which traps receive as the receiver
+    receiver: RefCell<Option<Handle<dyn Object>>>,
+    /// Private elements added to the proxy itself, which are never forwarded to the target
+    private: PrivateElements,
+}
+
+/// A trap function and the handler object it was found on
+struct Trap {
+    handler: Handle<dyn Object>,
+    function: Value,
+}
+
+impl Trap {
+    fn call(self, sc: &mut LocalScope, args: Vec<Value>) -> Result<Value, Value> {
+        let result = self.function.apply(sc, Value::Object(self.handler), args)?;
+        sc.add_value(result.clone());
+        Ok(result)
+    }
+}
+
+impl Proxy {
+    /// Creates a new proxy and registers it in the given scope
+    pub fn register(
+        sc: &mut LocalScope,
+        target: Handle<dyn Object>,
+        handler: Handle<dyn Object>,
+    ) -> Handle<dyn Object> {
+        let proxy = sc.register(Self {
+            target,
+            handler: RefCell::new(Some(handler)),
+            receiver: RefCell::new(None),
+            private: PrivateElements::default(),
+        });
+
+        if let Some(this) = proxy.as_any().downcast_ref::<Self>() {
+            this.receiver.replace(Some(proxy.clone()));
+        }
+
+        proxy
+    }
+
+    pub fn target(&self) -> &Handle<dyn Object> {
+        &self.target
+    }
+
+    pub fn handler(&self) -> Option<Handle<dyn Object>> {
+        self.handler.borrow().clone()
+    }
+
+    /// Revokes this proxy, after which all operations on it throw a TypeError
+    pub fn revoke(&self) {
+        self.handler.replace(None);
+    }
+
+    fn receiver(&self) -> Value {
+        self.receiver.borrow().clone().map(Value::Object).unwrap_or_undefined()
+    }
+
+    /// Looks up a trap on the handler object, returning `None` if the handler does not define it
+    fn trap(&self, sc: &mut LocalScope, name: &str) -> Result<Option<Trap>, Value> {
+        let Some(handler) = self.handler() else {
+            throw!(
+                sc,
+                TypeError,
+                "Cannot perform '{}' on a proxy that has been revoked",
+                name
+            )
+        };
+
+        match handler.get_property(sc, name.into())? {
+            Value::Undefined(_) | Value::Null(_) => Ok(None),
+            function if matches!(function.type_of(), Typeof::Function) => Ok(Some(Trap { handler, function })),
+            _ => throw!(sc, TypeError, "Proxy trap '{}' is not a function", name),
+        }
+    }
+
+    /// Assigns a value to a property, returning the result of the `set` trap
+    ///
+    /// The receiver is passed on to the trap, or used as `this` for setters of the target if there is none
+    pub fn set(
+        &self,
+        sc: &mut LocalScope,
+        key: PropertyKey<'static>,
+        value: Value,
+        receiver: Value,
+    ) -> Result<bool, Value> {
+        let Some(trap) = self.trap(sc, "set")? else {
+            return self.target.set_property_with_this(sc, receiver, key, value);
+        };
+
+        let args = vec![
+            Value::Object(self.target.clone()),
+            key.as_value(),
+            value.clone(),
+            receiver,
+        ];
+        if !trap.call(sc, args)?.is_truthy() {
+            return Ok(false);
+        }
+
+        if let Some(target_desc) = self.target.get_own_property_descriptor(sc, key.clone())? {
+            if !is_configurable(&target_desc) {
+                match target_desc.kind() {
+                    PropertyValueKind::Static(target_value)
+                        if !is_writable(&target_desc) && !same_value(&value, target_value) =>
+                    {
+                        throw!(
+                            sc,
+                            TypeError,
+                            "'set' on proxy: trap returned truish for property '{}' which exists in the proxy target as a non-configurable and non-writable data property with a different value",
+                            key
+                        )
+                    }
+                    PropertyValueKind::Trap { set: None, .. } => throw!(
+                        sc,
+                        TypeError,
+                        "'set' on proxy: trap returned truish for property '{}' which exists in the proxy target as a non-configurable accessor property without a setter",
+                        key
+                    ),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Defines a property, returning the result of the `defineProperty` trap
+    pub fn define_property(
+        &self,
+        sc: &mut LocalScope,
+        key: PropertyKey<'static>,
+        value: PropertyValue,
+    ) -> Result<bool, Value> {
+        let Some(trap) = self.trap(sc, "defineProperty")? else {
+            self.target.set_property(sc, key, value)?;
+            return Ok(true);
+        };
+
+        let descriptor = value.to_descriptor_value(sc)?;
+        let args = vec![Value::Object(self.target.clone()), key.as_value(), descriptor];
+        if !trap.call(sc, args)?.is_truthy() {
+            return Ok(false);
+        }
+
+        let target_desc = self.target.get_own_property_descriptor(sc, key.clone())?;
+        let extensible = self.target.is_extensible(sc)?;
+        let setting_config_false = !is_configurable(&value);
+
+        match target_desc {
+            None if !extensible => throw!(
+                sc,
+                TypeError,
+                "'defineProperty' on proxy: trap returned truish for adding property '{}' to the non-extensible proxy target",
+                key
+            ),
+            Some(target_desc) if !is_compatible_descriptor(&target_desc, &value) => throw!(
+                sc,
+                TypeError,
+                "'defineProperty' on proxy: trap returned truish for adding property '{}' that is incompatible with the existing property in the proxy target",
+                key
+            ),
+            Some(target_desc)
+                if !is_configurable(&target_desc)
+                    && is_writable(&target_desc)
+                    && matches!(value.kind(), PropertyValueKind::Static(_))
+                    && !is_writable(&value) =>
+            {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{}' which cannot be non-writable, unless there exists a corresponding non-configurable, non-writable own property of the target object",
+                    key
+                )
+            }
+            target_desc if setting_config_false && target_desc.as_ref().is_none_or(is_configurable) => throw!(
+                sc,
+                TypeError,
+                "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{}' which is either non-existent or configurable in the proxy target",
+                key
+            ),
+            _ => Ok(true),
+        }
+    }
+
+    /// Deletes a property, returning the result of the `deleteProperty` trap
+    pub fn delete(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<bool, Value> {
+        let Some(trap) = self.trap(sc, "deleteProperty")? else {
+            self.target.delete_property(sc, key)?;
+            return Ok(true);
+        };
+
+        let args = vec![Value::Object(self.target.clone()), key.as_value()];
+        if !trap.call(sc, args)?.is_truthy() {
+            return Ok(false);
+        }
+
+        if let Some(target_desc) = self.target.get_own_property_descriptor(sc, key.clone())? {
+            if !is_configurable(&target_desc) {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'deleteProperty' on proxy: trap returned truish for property '{}' which is non-configurable in the proxy target",
+                    key
+                );
+            }
+
+            if !self.target.is_extensible(sc)? {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'deleteProperty' on proxy: trap returned truish for property '{}' but the proxy target is non-extensible",
+                    key
+                );
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// The function returned by `Proxy.revocable` that revokes its proxy when called
+#[derive(Debug, Trace)]
+pub struct ProxyRevoker {
+    /// The proxy to revoke, which is `None` once it has been revoked
+    proxy: RefCell<Option<Handle<dyn Object>>>,
+    obj: NamedObject,
+}
+
+impl ProxyRevoker {
+    pub fn new(vm: &mut Vm, proxy: Handle<dyn Object>) -> Self {
+        Self {
+            proxy: RefCell::new(Some(proxy)),
+            obj: NamedObject::new(vm),
+        }
+    }
+}
+
+impl Object for ProxyRevoker {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        own_keys
+    );
+
+    fn apply(
+        &self,
+        _scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        _this: Value,
+        _args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        if let Some(proxy) = self.proxy.take() {
+            if let Some(proxy) = proxy.as_any().downcast_ref::<Proxy>() {
+                proxy.revoke();
+            }
+        }
+
+        Ok(Value::undefined())
+    }
+
+    fn type_of(&self) -> Typeof {
+        Typeof::Function
+    }
+}
+
+fn is_configurable(value: &PropertyValue) -> bool {
+    value.descriptor().contains(PropertyDataDescriptor::CONFIGURABLE)
+}
+
+fn is_writable(value: &PropertyValue) -> bool {
+    value.descriptor().contains(PropertyDataDescriptor::WRITABLE)
+}
+
+/// Compares two values using the SameValue algorithm, which unlike `===` treats NaN as equal to itself
+/// and distinguishes +0 and -0
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(Number(a)), Value::Number(Number(b))) => {
+            (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits()
+        }
+        _ => a == b,
+    }
+}
+
+/// Checks whether a property of the proxy target could be changed to the descriptor returned by a trap
+/// (IsCompatiblePropertyDescriptor).
+///
+/// Configurable properties can be changed arbitrarily, non-configurable ones only by making them non-writable.
+fn is_compatible_descriptor(current: &PropertyValue, desc: &PropertyValue) -> bool {
+    if is_configurable(current) {
+        return true;
+    }
+
+    if is_configurable(desc)
+        || current.descriptor().contains(PropertyDataDescriptor::ENUMERABLE)
+            != desc.descriptor().contains(PropertyDataDescriptor::ENUMERABLE)
+    {
+        return false;
+    }
+
+    match (current.kind(), desc.kind()) {
+        (PropertyValueKind::Static(current_value), PropertyValueKind::Static(value)) => {
+            is_writable(current) || (!is_writable(desc) && same_value(current_value, value))
+        }
+        (
+            PropertyValueKind::Trap {
+                get: current_get,
+                set: current_set,
+            },
+            PropertyValueKind::Trap { get, set },
+        ) => current_get == get && current_set == set,
+        _ => false,
+    }
+}
+
+/// Creates an array from a list of values, which is how arguments are passed to the `apply` and `construct` traps
+fn create_array(sc: &mut LocalScope, values: Vec<Value>) -> Value {
+    let array = Array::from_vec(sc, values.into_iter().map(PropertyValue::static_default).collect());
+    Value::Object(sc.register(array))
+}
+
+impl Object for Proxy {
+    fn get_own_property_descriptor(
+        &self,
+        sc: &mut LocalScope,
+        key: PropertyKey,
+    ) -> Result<Option<PropertyValue>, Value> {
+        let Some(trap) = self.trap(sc, "getOwnPropertyDescriptor")? else {
+            return self.target.get_own_property_descriptor(sc, key);
+        };
+
+        let args = vec![Value::Object(self.target.clone()), key.as_value()];
+        let result = trap.call(sc, args)?;
+        let target_desc = self.target.get_own_property_descriptor(sc, key.clone())?;
+
+        let desc = match result {
+            Value::Undefined(_) => {
+                if let Some(target_desc) = target_desc {
+                    if !is_configurable(&target_desc) {
+                        throw!(
+                            sc,
+                            TypeError,
+                            "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{}' which is non-configurable in the proxy target",
+                            key
+                        );
+                    }
+
+                    if !self.target.is_extensible(sc)? {
+                        throw!(
+                            sc,
+                            TypeError,
+                            "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{}' which exists in the non-extensible proxy target",
+                            key
+                        );
+                    }
+                }
+
+                return Ok(None);
+            }
+            result @ (Value::Object(_) | Value::External(_)) => PropertyValue::from_descriptor_value(sc, result)?,
+            _ => throw!(
+                sc,
+                TypeError,
+                "'getOwnPropertyDescriptor' on proxy: trap returned neither object nor undefined for property '{}'",
+                key
+            ),
+        };
+
+        match target_desc {
+            None if !self.target.is_extensible(sc)? => throw!(
+                sc,
+                TypeError,
+                "'getOwnPropertyDescriptor' on proxy: trap returned descriptor for property '{}' that is incompatible with the existing property in the proxy target",
+                key
+            ),
+            Some(target_desc) if !is_compatible_descriptor(&target_desc, &desc) => throw!(
+                sc,
+                TypeError,
+                "'getOwnPropertyDescriptor' on proxy: trap returned descriptor for property '{}' that is incompatible with the existing property in the proxy target",
+                key
+            ),
+            Some(target_desc) if !is_configurable(&desc) && !is_writable(&desc) && is_writable(&target_desc) => {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'getOwnPropertyDescriptor' on proxy: trap reported non-configurable and writable for property '{}' which is non-configurable, non-writable in the proxy target",
+                    key
+                )
+            }
+            target_desc if !is_configurable(&desc) && target_desc.as_ref().is_none_or(is_configurable) => {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'getOwnPropertyDescriptor' on proxy: trap reported non-configurability for property '{}' which is either non-existent or configurable in the proxy target",
+                    key
+                )
+            }
+            _ => Ok(Some(desc)),
+        }
+    }
+
+    fn get_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Value, Value> {
+        self.get_property_with_this(sc, self.receiver(), key)
+    }
+
+    fn get_property_with_this(&self, sc: &mut LocalScope, this: Value, key: PropertyKey) -> Result<Value, Value> {
+        let Some(trap) = self.trap(sc, "get")? else {
+            return self.target.get_property_with_this(sc, this, key);
+        };
+
+        let args = vec![Value::Object(self.target.clone()), key.as_value(), this];
+        let value = trap.call(sc, args)?;
+
+        if let Some(target_desc) = self.target.get_own_property_descriptor(sc, key.clone())? {
+            if !is_configurable(&target_desc) {
+                match target_desc.kind() {
+                    PropertyValueKind::Static(target_value)
+                        if !is_writable(&target_desc) && !same_value(&value, target_value) =>
+                    {
+                        throw!(
+                            sc,
+                            TypeError,
+                            "'get' on proxy: property '{}' is a read-only and non-configurable data property on the proxy target but the proxy did not return its actual value",
+                            key
+                        )
+                    }
+                    PropertyValueKind::Trap { get: None, .. } if !matches!(value, Value::Undefined(_)) => throw!(
+                        sc,
+                        TypeError,
+                        "'get' on proxy: property '{}' is a non-configurable accessor property on the proxy target and does not have a getter function, but the trap did not return 'undefined'",
+                        key
+                    ),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn has_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<bool, Value> {
+        let Some(trap) = self.trap(sc, "has")? else {
+            return self.target.has_property(sc, key);
+        };
+
+        let args = vec![Value::Object(self.target.clone()), key.as_value()];
+        if trap.call(sc, args)?.is_truthy() {
+            return Ok(true);
+        }
+
+        if let Some(target_desc) = self.target.get_own_property_descriptor(sc, key.clone())? {
+            if !is_configurable(&target_desc) {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'has' on proxy: trap returned falsish for property '{}' which exists in the proxy target as non-configurable",
+                    key
+                );
+            }
+
+            if !self.target.is_extensible(sc)? {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'has' on proxy: trap returned falsish for property '{}' but the proxy target is not extensible",
+                    key
+                );
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn set_property(&self, sc: &mut LocalScope, key: PropertyKey<'static>, value: PropertyValue) -> Result<(), Value> {
+        // Assignments that return false from the trap are silently ignored, as in sloppy mode
+        match value.kind() {
+            PropertyValueKind::Static(value) => self.set(sc, key, value.clone(), self.receiver())?,
+            PropertyValueKind::Trap { .. } => self.define_property(sc, key, value)?,
+        };
+
+        Ok(())
+    }
+
+    fn set_property_with_this(
+        &self,
+        sc: &mut LocalScope,
+        this: Value,
+        key: PropertyKey<'static>,
+        value: Value,
+    ) -> Result<bool, Value> {
+        self.set(sc, key, value, this)
+    }
+
+    fn delete_property(&self, sc: &mut LocalScope, key: PropertyKey) -> Result<Value, Value> {
+        self.delete(sc, key)?;
+        Ok(Value::undefined())
+    }
+
+    fn set_prototype(&self, sc: &mut LocalScope, value: Value) -> Result<(), Value> {
+        let Some(trap) = self.trap(sc, "setPrototypeOf")? else {
+            return self.target.set_prototype(sc, value);
+        };
+
+        let args = vec![Value::Object(self.target.clone()), value.clone()];
+        if !trap.call(sc, args)?.is_truthy() {
+            throw!(sc, TypeError, "'setPrototypeOf' on proxy: trap returned falsish");
+        }
+
+        if !self.target.is_extensible(sc)? && !same_value(&value, &self.target.get_prototype(sc)?) {
+            throw!(
+                sc,
+                TypeError,
+                "'setPrototypeOf' on proxy: trap returned truish for setting a new prototype on the non-extensible proxy target"
+            );
+        }
+
+        Ok(())
+    }
+
+    fn get_prototype(&self, sc: &mut LocalScope) -> Result<Value, Value> {
+        let Some(trap) = self.trap(sc, "getPrototypeOf")? else {
+            return self.target.get_prototype(sc);
+        };
+
+        let args = vec![Value::Object(self.target.clone())];
+        let prototype = match trap.call(sc, args)? {
+            value @ (Value::Object(_) | Value::External(_) | Value::Null(_)) => value,
+            _ => throw!(
+                sc,
+                TypeError,
+                "'getPrototypeOf' on proxy: trap returned neither object nor null"
+            ),
+        };
+
+        if !self.target.is_extensible(sc)? && !same_value(&prototype, &self.target.get_prototype(sc)?) {
+            throw!(
+                sc,
+                TypeError,
+                "'getPrototypeOf' on proxy: proxy target is non-extensible but the trap did not return its actual prototype"
+            );
+        }
+
+        Ok(prototype)
+    }
+
+    fn apply(
+        &self,
+        scope: &mut LocalScope,
+        _callee: Handle<dyn Object>,
+        this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        if !matches!(self.target.type_of(), Typeof::Function) {
+            throw!(scope, TypeError, "Proxy target is not a function");
+        }
+
+        match self.trap(scope, "apply")? {
+            Some(trap) => {
+                let args = create_array(scope, args);
+                let args = vec![Value::Object(self.target.clone()), this, args];
+                trap.call(scope, args)
+            }
+            None => self.target.apply(scope, this, args),
+        }
+    }
+
+    fn construct(
+        &self,
+        scope: &mut LocalScope,
+        callee: Handle<dyn Object>,
+        this: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, Value> {
+        if !matches!(self.target.type_of(), Typeof::Function) {
+            throw!(scope, TypeError, "Proxy target is not a constructor");
+        }
+
+        match self.trap(scope, "construct")? {
+            Some(trap) => {
+                let args = create_array(scope, args);
+                let args = vec![Value::Object(self.target.clone()), args, Value::Object(callee)];
+                match trap.call(scope, args)? {
+                    value @ (Value::Object(_) | Value::External(_)) => Ok(value),
+                    _ => throw!(scope, TypeError, "'construct' on proxy: trap returned non-object"),
+                }
+            }
+            None => self.target.construct(scope, this, args),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        let Some(trap) = self.trap(sc, "ownKeys")? else {
+            return self.target.own_keys(sc);
+        };
+
+        let args = vec![Value::Object(self.target.clone())];
+        let keys = trap.call(sc, args)?;
+        if !matches!(keys, Value::Object(_) | Value::External(_)) {
+            throw!(sc, TypeError, "'ownKeys' on proxy: trap returned a non-object");
+        }
+
+        let len = keys.length_of_array_like(sc)?;
+        let mut result = Vec::with_capacity(len);
+        for i in 0..len {
+            let key = keys.get_property(sc, i.to_string().into())?;
+            if !matches!(key, Value::String(_) | Value::Symbol(_)) {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'ownKeys' on proxy: trap returned a key that is not a string or symbol"
+                );
+            }
+            if result.contains(&key) {
+                throw!(sc, TypeError, "'ownKeys' on proxy: trap returned duplicate entries");
+            }
+            result.push(key);
+        }
+
+        // Non-configurable properties must always be reported, and non-extensible targets must report exactly their keys
+        let extensible = self.target.is_extensible(sc)?;
+        let target_keys = self.target.own_keys(sc)?;
+        for target_key in &target_keys {
+            let key = PropertyKey::from_value(sc, target_key.clone())?;
+            let must_report = !extensible
+                || self
+                    .target
+                    .get_own_property_descriptor(sc, key.clone())?
+                    .is_some_and(|desc| !is_configurable(&desc));
+
+            if must_report && !result.contains(target_key) {
+                throw!(
+                    sc,
+                    TypeError,
+                    "'ownKeys' on proxy: trap result did not include '{}'",
+                    key
+                );
+            }
+        }
+
+        if !extensible && result.len() != target_keys.len() {
+            throw!(
+                sc,
+                TypeError,
+                "'ownKeys' on proxy: trap returned extra keys but proxy target is non-extensible"
+            );
+        }
+
+        Ok(result)
+    }
+
+    fn is_extensible(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        let Some(trap) = self.trap(sc, "isExtensible")? else {
+            return self.target.is_extensible(sc);
+        };
+
+        let args = vec![Value::Object(self.target.clone())];
+        let extensible = trap.call(sc, args)?.is_truthy();
+        let target_extensible = self.target.is_extensible(sc)?;
+        if extensible != target_extensible {
+            throw!(
+                sc,
+                TypeError,
+                "'isExtensible' on proxy: trap result does not reflect extensibility of proxy target (which is '{}')",
+                target_extensible
+            );
+        }
+
+        Ok(extensible)
+    }
+
+    fn prevent_extensions(&self, sc: &mut LocalScope) -> Result<bool, Value> {
+        let Some(trap) = self.trap(sc, "preventExtensions")? else {
+            return self.target.prevent_extensions(sc);
+        };
+
+        let args = vec![Value::Object(self.target.clone())];
+        if !trap.call(sc, args)?.is_truthy() {
+            return Ok(false);
+        }
+
+        if self.target.is_extensible(sc)? {
+            throw!(
+                sc,
+                TypeError,
+                "'preventExtensions' on proxy: trap returned truish but the proxy target is extensible"
+            );
+        }
+
+        Ok(true)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        Some(&self.private)
+    }
+
+    fn type_of(&self) -> Typeof {
+        self.target.type_of()
+    }
+}
@@ -1,17 +1,21 @@
 use std::rc::Rc;
 
 use dash_proc_macro::Trace;
-use dash_regex::node::Node;
+use dash_regex::Flags;
+use dash_regex::ParsedRegex;
 
 use crate::delegate;
 use crate::Vm;
 
 use super::object::NamedObject;
 use super::object::Object;
+use super::object::PropertyValue;
+use super::Value;
 
 #[derive(Debug)]
 pub struct RegExpInner {
-    nodes: Vec<Node>,
+    regex: ParsedRegex,
+    flags: Flags,
     source: Rc<str>,
 }
 
@@ -22,13 +26,16 @@ pub struct RegExp {
 }
 
 impl RegExp {
-    pub fn new(nodes: Vec<Node>, source: Rc<str>, vm: &mut Vm) -> Self {
+    pub fn new(regex: ParsedRegex, flags: Flags, source: Rc<str>, vm: &mut Vm) -> Self {
         let proto = vm.statics.regexp_prototype.clone();
         let ctor = vm.statics.regexp_ctor.clone();
 
+        let object = NamedObject::with_prototype_and_constructor(proto, ctor);
+        object.set_raw_property("lastIndex".into(), PropertyValue::static_default(Value::number(0.0)));
+
         Self {
-            inner: Some(RegExpInner { nodes, source }),
-            object: NamedObject::with_prototype_and_constructor(proto, ctor),
+            inner: Some(RegExpInner { regex, flags, source }),
+            object,
         }
     }
 
@@ -39,10 +46,10 @@ impl RegExp {
         }
     }
 
-    pub fn inner(&self) -> Option<(&[Node], &str)> {
+    pub fn inner(&self) -> Option<(&ParsedRegex, Flags, &str)> {
         self.inner
             .as_ref()
-            .map(|inner| (inner.nodes.as_slice(), inner.source.as_ref()))
+            .map(|inner| (&inner.regex, inner.flags, inner.source.as_ref()))
     }
 }
 
@@ -58,6 +65,7 @@ impl Object for RegExp {
         get_prototype,
         as_any,
         apply,
-        own_keys
+        own_keys,
+        private_elements
     );
 }
@@ -69,6 +69,7 @@ impl Object for Set {
         get_prototype,
         as_any,
         apply,
-        own_keys
+        own_keys,
+        private_elements
     );
 }
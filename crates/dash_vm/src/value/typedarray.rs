@@ -9,9 +9,11 @@ use crate::Vm;
 use super::arraybuffer::ArrayBuffer;
 use super::object::NamedObject;
 use super::object::Object;
+use super::object::PrivateElements;
 use super::object::PropertyKey;
 use super::object::PropertyValue;
 use super::ops::abstractions::conversions::ValueConversion;
+use super::primitive::BigInt;
 use super::Value;
 
 #[derive(Debug, Copy, Clone)]
@@ -25,6 +27,8 @@ pub enum TypedArrayKind {
     Uint32Array,
     Float32Array,
     Float64Array,
+    BigInt64Array,
+    BigUint64Array,
 }
 
 impl TypedArrayKind {
@@ -39,6 +43,8 @@ impl TypedArrayKind {
             TypedArrayKind::Uint32Array => 4,
             TypedArrayKind::Float32Array => 4,
             TypedArrayKind::Float64Array => 8,
+            TypedArrayKind::BigInt64Array => 8,
+            TypedArrayKind::BigUint64Array => 8,
         }
     }
 }
@@ -62,6 +68,8 @@ impl TypedArray {
             TypedArrayKind::Uint32Array => (&vm.statics.uint32array_prototype, &vm.statics.uint32array_ctor),
             TypedArrayKind::Float32Array => (&vm.statics.float32array_prototype, &vm.statics.float32array_ctor),
             TypedArrayKind::Float64Array => (&vm.statics.float64array_prototype, &vm.statics.float64array_ctor),
+            TypedArrayKind::BigInt64Array => (&vm.statics.bigint64array_prototype, &vm.statics.bigint64array_ctor),
+            TypedArrayKind::BigUint64Array => (&vm.statics.biguint64array_prototype, &vm.statics.biguint64array_ctor),
         };
 
         Self {
@@ -95,6 +103,9 @@ impl Object for TypedArray {
 
                 macro_rules! decode_from {
                     (ty: $ty:ty, size: $size:expr) => {
+                        decode_from!(ty: $ty, size: $size, map: |n| Value::number(f64::from(n)))
+                    };
+                    (ty: $ty:ty, size: $size:expr, map: $map:expr) => {
                         bytes
                             .get(index..index + $size)
                             .map(|x| {
@@ -105,7 +116,7 @@ impl Object for TypedArray {
                                 arr
                             })
                             .map(<$ty>::from_ne_bytes)
-                            .map(f64::from)
+                            .map($map)
                     };
                 }
 
@@ -119,10 +130,16 @@ impl Object for TypedArray {
                     TypedArrayKind::Uint32Array => decode_from!(ty: u32, size: 4),
                     TypedArrayKind::Float32Array => decode_from!(ty: f32, size: 4),
                     TypedArrayKind::Float64Array => decode_from!(ty: f64, size: 8),
+                    TypedArrayKind::BigInt64Array => {
+                        decode_from!(ty: i64, size: 8, map: |n| Value::BigInt(BigInt::new(n.into())))
+                    }
+                    TypedArrayKind::BigUint64Array => {
+                        decode_from!(ty: u64, size: 8, map: |n| Value::BigInt(BigInt::new(n.into())))
+                    }
                 };
 
                 if let Some(value) = value {
-                    return Ok(Some(PropertyValue::static_default(Value::number(value))));
+                    return Ok(Some(PropertyValue::static_default(value)));
                 }
             }
         }
@@ -136,16 +153,18 @@ impl Object for TypedArray {
 
             // TODO: not undefined as this
             let value = value.kind().get_or_apply(sc, Value::undefined())?;
-            let value = value.to_number(sc)?;
             if let Some(arraybuffer) = arraybuffer {
                 let bytes = arraybuffer.storage();
                 let index = index * self.kind.bytes_per_element();
 
                 macro_rules! encode_into {
-                    (ty: $ty:ty, size: $size:expr) => {{
+                    (ty: $ty:ty, size: $size:expr) => {
+                        encode_into!(ty: $ty, size: $size, value: value.to_number(sc)? as $ty)
+                    };
+                    (ty: $ty:ty, size: $size:expr, value: $value:expr) => {{
                         let size = $size;
+                        let src = <$ty>::to_ne_bytes($value);
                         let dest = bytes.get(index..index + size);
-                        let src = <$ty>::to_ne_bytes(value as $ty);
 
                         if let Some(dest) = dest {
                             assert!(dest.len() >= size);
@@ -169,6 +188,12 @@ impl Object for TypedArray {
                     TypedArrayKind::Uint32Array => encode_into!(ty: u32, size: 4),
                     TypedArrayKind::Float32Array => encode_into!(ty: f32, size: 4),
                     TypedArrayKind::Float64Array => encode_into!(ty: f64, size: 8),
+                    TypedArrayKind::BigInt64Array => {
+                        encode_into!(ty: i64, size: 8, value: value.to_bigint(sc)?.to_u64_wrapping() as i64)
+                    }
+                    TypedArrayKind::BigUint64Array => {
+                        encode_into!(ty: u64, size: 8, value: value.to_bigint(sc)?.to_u64_wrapping())
+                    }
                 }
             }
         }
@@ -202,7 +227,11 @@ impl Object for TypedArray {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.obj.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.obj.own_keys(sc)
+    }
+
+    fn private_elements(&self) -> Option<&PrivateElements> {
+        self.obj.private_elements()
     }
 }
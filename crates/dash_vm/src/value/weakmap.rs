@@ -0,0 +1,97 @@
+use std::cell::RefCell;
+
+use ahash::HashMap;
+
+use crate::delegate;
+use crate::gc::handle::Handle;
+use crate::gc::trace::Trace;
+use crate::gc::weak::trace_ephemeron_value;
+use crate::gc::weak::Finalizer;
+use crate::gc::weak::WeakContainer;
+use crate::Vm;
+
+use super::object::NamedObject;
+use super::object::Object;
+use super::Value;
+
+/// A map whose keys are held weakly. Values are only kept alive as long as their key is.
+#[derive(Debug)]
+pub struct WeakMap {
+    inner: RefCell<HashMap<Handle<dyn Object>, Value>>,
+    obj: NamedObject,
+}
+
+impl WeakMap {
+    pub fn new(vm: &mut Vm) -> Self {
+        let prototype = vm.statics.weakmap_prototype.clone();
+        let ctor = vm.statics.weakmap_constructor.clone();
+        Self::with_obj(NamedObject::with_prototype_and_constructor(prototype, ctor))
+    }
+
+    pub fn with_obj(obj: NamedObject) -> Self {
+        Self {
+            inner: RefCell::new(HashMap::default()),
+            obj,
+        }
+    }
+
+    pub fn set(&self, key: Handle<dyn Object>, value: Value) {
+        self.inner.borrow_mut().insert(key, value);
+    }
+
+    pub fn has(&self, key: &Handle<dyn Object>) -> bool {
+        self.inner.borrow().contains_key(key)
+    }
+
+    pub fn get(&self, key: &Handle<dyn Object>) -> Option<Value> {
+        self.inner.borrow().get(key).cloned()
+    }
+
+    pub fn delete(&self, key: &Handle<dyn Object>) -> bool {
+        self.inner.borrow_mut().remove(key).is_some()
+    }
+}
+
+unsafe impl Trace for WeakMap {
+    fn trace(&self) {
+        // Entries are traced in `trace_ephemerons`
+        self.obj.trace();
+    }
+}
+
+impl WeakContainer for WeakMap {
+    fn trace_ephemerons(&self) -> bool {
+        let mut marked_any = false;
+        for (key, value) in self.inner.borrow().iter() {
+            if key.is_live() {
+                marked_any |= trace_ephemeron_value(value);
+            }
+        }
+        marked_any
+    }
+
+    fn sweep_dead(&self, _: &mut Vec<Finalizer>) {
+        self.inner.borrow_mut().retain(|key, _| key.is_live());
+    }
+}
+
+impl Object for WeakMap {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys,
+        private_elements
+    );
+
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        Some(self)
+    }
+}
@@ -0,0 +1,77 @@
+use std::cell::RefCell;
+
+use crate::delegate;
+use crate::gc::handle::Handle;
+use crate::gc::trace::Trace;
+use crate::gc::weak::Finalizer;
+use crate::gc::weak::WeakContainer;
+use crate::Vm;
+
+use super::object::NamedObject;
+use super::object::Object;
+
+/// A reference to an object that does not keep it alive
+#[derive(Debug)]
+pub struct WeakRef {
+    /// The referenced object, or `None` once it has been collected
+    target: RefCell<Option<Handle<dyn Object>>>,
+    obj: NamedObject,
+}
+
+impl WeakRef {
+    pub fn new(vm: &mut Vm, target: Handle<dyn Object>) -> Self {
+        let prototype = vm.statics.weakref_prototype.clone();
+        let ctor = vm.statics.weakref_constructor.clone();
+        Self {
+            target: RefCell::new(Some(target)),
+            obj: NamedObject::with_prototype_and_constructor(prototype, ctor),
+        }
+    }
+
+    pub fn with_obj(obj: NamedObject) -> Self {
+        Self {
+            target: RefCell::new(None),
+            obj,
+        }
+    }
+
+    pub fn target(&self) -> Option<Handle<dyn Object>> {
+        self.target.borrow().clone()
+    }
+}
+
+unsafe impl Trace for WeakRef {
+    fn trace(&self) {
+        self.obj.trace();
+    }
+}
+
+impl WeakContainer for WeakRef {
+    fn sweep_dead(&self, _: &mut Vec<Finalizer>) {
+        let mut target = self.target.borrow_mut();
+        if target.as_ref().is_some_and(|t| !t.is_live()) {
+            *target = None;
+        }
+    }
+}
+
+impl Object for WeakRef {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys,
+        private_elements
+    );
+
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        Some(self)
+    }
+}
@@ -0,0 +1,80 @@
+use std::cell::RefCell;
+
+use ahash::HashSet;
+
+use crate::delegate;
+use crate::gc::handle::Handle;
+use crate::gc::trace::Trace;
+use crate::gc::weak::Finalizer;
+use crate::gc::weak::WeakContainer;
+use crate::Vm;
+
+use super::object::NamedObject;
+use super::object::Object;
+
+/// A set whose items are held weakly
+#[derive(Debug)]
+pub struct WeakSet {
+    inner: RefCell<HashSet<Handle<dyn Object>>>,
+    obj: NamedObject,
+}
+
+impl WeakSet {
+    pub fn new(vm: &mut Vm) -> Self {
+        let prototype = vm.statics.weakset_prototype.clone();
+        let ctor = vm.statics.weakset_constructor.clone();
+        Self::with_obj(NamedObject::with_prototype_and_constructor(prototype, ctor))
+    }
+
+    pub fn with_obj(obj: NamedObject) -> Self {
+        Self {
+            inner: RefCell::new(HashSet::default()),
+            obj,
+        }
+    }
+
+    pub fn add(&self, item: Handle<dyn Object>) {
+        self.inner.borrow_mut().insert(item);
+    }
+
+    pub fn has(&self, item: &Handle<dyn Object>) -> bool {
+        self.inner.borrow().contains(item)
+    }
+
+    pub fn delete(&self, item: &Handle<dyn Object>) -> bool {
+        self.inner.borrow_mut().remove(item)
+    }
+}
+
+unsafe impl Trace for WeakSet {
+    fn trace(&self) {
+        self.obj.trace();
+    }
+}
+
+impl WeakContainer for WeakSet {
+    fn sweep_dead(&self, _: &mut Vec<Finalizer>) {
+        self.inner.borrow_mut().retain(|item| item.is_live());
+    }
+}
+
+impl Object for WeakSet {
+    delegate!(
+        obj,
+        get_own_property_descriptor,
+        get_property,
+        get_property_descriptor,
+        set_property,
+        delete_property,
+        set_prototype,
+        get_prototype,
+        as_any,
+        apply,
+        own_keys,
+        private_elements
+    );
+
+    fn as_weak_container(&self) -> Option<&dyn WeakContainer> {
+        Some(self)
+    }
+}
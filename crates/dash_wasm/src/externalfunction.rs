@@ -76,8 +76,8 @@ impl Object for ExternalFunction {
         self
     }
 
-    fn own_keys(&self) -> Result<Vec<Value>, Value> {
-        self.1.own_keys()
+    fn own_keys(&self, sc: &mut LocalScope) -> Result<Vec<Value>, Value> {
+        self.1.own_keys(sc)
     }
 
     fn type_of(&self) -> Typeof {
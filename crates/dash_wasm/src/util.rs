@@ -24,6 +24,7 @@ pub fn wasm_value_from_dash_value(_scope: &mut LocalScope, value: DashValue) ->
         DashValue::String(s) => Ok(WasmValue::from_str(&s)),
         DashValue::Object(o) => Ok(WasmValue::from(JsValue::from(DashValue::Object(o)))),
         DashValue::Symbol(_) => Err("Unhandled symbol".into()),
+        DashValue::BigInt(_) => Err("Unhandled bigint".into()),
         DashValue::External(_) => Err("Unhandled external".into()),
     }
 }